@@ -0,0 +1,63 @@
+-- Create appraisals table
+CREATE TABLE IF NOT EXISTS appraisals (
+    id UUID PRIMARY KEY,
+    reference_number VARCHAR(255),
+    client_id UUID NOT NULL,
+    property_id UUID NOT NULL,
+    appraiser_id UUID,
+    report_id UUID,
+    status VARCHAR(32) NOT NULL DEFAULT 'new',
+    due_date TIMESTAMP WITH TIME ZONE,
+    purpose VARCHAR(32) NOT NULL,
+    appraisal_type VARCHAR(32) NOT NULL,
+    instructions TEXT,
+    fee DOUBLE PRECISION,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX IF NOT EXISTS idx_appraisals_appraiser ON appraisals (appraiser_id);
+CREATE INDEX IF NOT EXISTS idx_appraisals_status ON appraisals (status);
+
+-- Create inspection appointments table
+CREATE TABLE IF NOT EXISTS inspection_appointments (
+    id UUID PRIMARY KEY,
+    appraisal_id UUID NOT NULL REFERENCES appraisals (id) ON DELETE CASCADE,
+    appraiser_id UUID NOT NULL,
+    scheduled_start TIMESTAMP WITH TIME ZONE NOT NULL,
+    scheduled_end TIMESTAMP WITH TIME ZONE NOT NULL,
+    timezone VARCHAR(64) NOT NULL,
+    contact JSONB NOT NULL,
+    access_notes TEXT,
+    status VARCHAR(32) NOT NULL DEFAULT 'scheduled',
+    reschedule_history JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (scheduled_end > scheduled_start)
+);
+
+CREATE INDEX IF NOT EXISTS idx_inspection_appointments_appraisal ON inspection_appointments (appraisal_id);
+CREATE INDEX IF NOT EXISTS idx_inspection_appointments_appraiser_time
+    ON inspection_appointments (appraiser_id, scheduled_start, scheduled_end);
+
+-- Create appraiser availability slots table
+CREATE TABLE IF NOT EXISTS appraiser_availability_slots (
+    id UUID PRIMARY KEY,
+    appraiser_id UUID NOT NULL,
+    starts_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    ends_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    timezone VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    CHECK (ends_at > starts_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_availability_slots_appraiser_time
+    ON appraiser_availability_slots (appraiser_id, starts_at, ends_at);
+
+-- Create calendar feed tokens table (one active feed per appraiser)
+CREATE TABLE IF NOT EXISTS appraiser_calendar_feeds (
+    appraiser_id UUID PRIMARY KEY,
+    token VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,43 @@
+-- Create properties table (address, characteristics and valuation stored as JSON documents)
+CREATE TABLE IF NOT EXISTS properties (
+    id UUID PRIMARY KEY,
+    address JSONB NOT NULL,
+    characteristics JSONB NOT NULL,
+    valuation JSONB,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Create appraiser licenses table
+CREATE TABLE IF NOT EXISTS appraiser_licenses (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    state VARCHAR(2) NOT NULL,
+    license_number VARCHAR(64) NOT NULL,
+    level VARCHAR(32) NOT NULL,
+    issued_on DATE,
+    expires_on DATE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (state, license_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_appraiser_licenses_user ON appraiser_licenses (user_id);
+CREATE INDEX IF NOT EXISTS idx_appraiser_licenses_expiry ON appraiser_licenses (expires_on);
+
+-- Create E&O insurance policies table
+CREATE TABLE IF NOT EXISTS insurance_policies (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    carrier VARCHAR(255) NOT NULL,
+    policy_number VARCHAR(128) NOT NULL,
+    coverage_amount DOUBLE PRECISION NOT NULL,
+    effective_on DATE NOT NULL,
+    expires_on DATE NOT NULL,
+    document_url VARCHAR(1024),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_insurance_policies_user ON insurance_policies (user_id);
+CREATE INDEX IF NOT EXISTS idx_insurance_policies_expiry ON insurance_policies (expires_on);
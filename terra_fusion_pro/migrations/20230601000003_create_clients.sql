@@ -0,0 +1,31 @@
+-- Create clients table
+CREATE TABLE IF NOT EXISTS clients (
+    id UUID PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    client_type VARCHAR(32) NOT NULL,
+    contacts JSONB NOT NULL DEFAULT '[]',
+    billing_address JSONB,
+    default_instructions TEXT,
+    required_report_types JSONB NOT NULL DEFAULT '[]',
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_clients_name ON clients (name);
+
+-- Create client portal users table
+CREATE TABLE IF NOT EXISTS client_portal_users (
+    id UUID PRIMARY KEY,
+    client_id UUID NOT NULL REFERENCES clients (id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (client_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_client_portal_users_user ON client_portal_users (user_id);
+
+-- Appraisals now reference a real client (existing rows are not re-checked)
+ALTER TABLE appraisals
+    ADD CONSTRAINT fk_appraisals_client FOREIGN KEY (client_id) REFERENCES clients (id) NOT VALID;
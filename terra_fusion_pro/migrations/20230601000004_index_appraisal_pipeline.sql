@@ -0,0 +1,3 @@
+-- Support pipeline filtering and sorting by due date and client
+CREATE INDEX IF NOT EXISTS idx_appraisals_due_date ON appraisals (due_date);
+CREATE INDEX IF NOT EXISTS idx_appraisals_client ON appraisals (client_id);
@@ -0,0 +1,18 @@
+-- Create revision requests table (one row per review round, items stored as JSON)
+CREATE TABLE IF NOT EXISTS revision_requests (
+    id UUID PRIMARY KEY,
+    appraisal_id UUID NOT NULL REFERENCES appraisals (id) ON DELETE CASCADE,
+    round INTEGER NOT NULL,
+    requested_by VARCHAR(255) NOT NULL,
+    summary TEXT,
+    items JSONB NOT NULL DEFAULT '[]',
+    status VARCHAR(32) NOT NULL DEFAULT 'open',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    closed_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE (appraisal_id, round)
+);
+
+-- At most one unfinished round per appraisal
+CREATE UNIQUE INDEX IF NOT EXISTS idx_revision_requests_unfinished
+    ON revision_requests (appraisal_id) WHERE status <> 'closed';
@@ -0,0 +1,21 @@
+-- Create appraisal message threads
+CREATE TABLE IF NOT EXISTS appraisal_messages (
+    id UUID PRIMARY KEY,
+    appraisal_id UUID NOT NULL REFERENCES appraisals (id) ON DELETE CASCADE,
+    author_id VARCHAR(255) NOT NULL,
+    author_role VARCHAR(32) NOT NULL,
+    body TEXT NOT NULL,
+    attachments JSONB NOT NULL DEFAULT '[]',
+    visibility VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_appraisal_messages_appraisal ON appraisal_messages (appraisal_id, created_at);
+
+-- Create message read receipts
+CREATE TABLE IF NOT EXISTS message_read_receipts (
+    message_id UUID NOT NULL REFERENCES appraisal_messages (id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    read_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (message_id, user_id)
+);
@@ -0,0 +1,24 @@
+-- Create engagement letters table (one row per letter version)
+CREATE TABLE IF NOT EXISTS engagement_letters (
+    id UUID PRIMARY KEY,
+    appraisal_id UUID NOT NULL REFERENCES appraisals (id) ON DELETE CASCADE,
+    appraiser_id UUID NOT NULL,
+    version INTEGER NOT NULL,
+    scope_of_work TEXT NOT NULL,
+    fee DOUBLE PRECISION,
+    due_date TIMESTAMP WITH TIME ZONE,
+    intended_use TEXT NOT NULL,
+    intended_users JSONB NOT NULL DEFAULT '[]',
+    body TEXT NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'pending',
+    counter_offer JSONB,
+    decline_reason TEXT,
+    responded_at TIMESTAMP WITH TIME ZONE,
+    responded_ip VARCHAR(64),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (appraisal_id, version)
+);
+
+-- At most one letter awaiting a response per appraisal
+CREATE UNIQUE INDEX IF NOT EXISTS idx_engagement_letters_pending
+    ON engagement_letters (appraisal_id) WHERE status = 'pending';
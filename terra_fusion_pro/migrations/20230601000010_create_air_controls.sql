@@ -0,0 +1,34 @@
+-- Flag client portal users who work in loan production
+ALTER TABLE client_portal_users
+    ADD COLUMN IF NOT EXISTS is_loan_production BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Create AIR violations table (blocked attempts to influence appraisers)
+CREATE TABLE IF NOT EXISTS air_violations (
+    id UUID PRIMARY KEY,
+    client_id UUID NOT NULL REFERENCES clients (id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    appraisal_id UUID REFERENCES appraisals (id) ON DELETE SET NULL,
+    action VARCHAR(64) NOT NULL,
+    detail TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_air_violations_client ON air_violations (client_id, created_at DESC);
+
+-- Create panel rotation policies table (clients without a row rotate openly)
+CREATE TABLE IF NOT EXISTS panel_rotation_policies (
+    client_id UUID PRIMARY KEY REFERENCES clients (id) ON DELETE CASCADE,
+    mode VARCHAR(32) NOT NULL DEFAULT 'open',
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Create appraiser panel members table
+CREATE TABLE IF NOT EXISTS panel_members (
+    client_id UUID NOT NULL REFERENCES clients (id) ON DELETE CASCADE,
+    appraiser_id UUID NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    assignment_count INTEGER NOT NULL DEFAULT 0,
+    last_assigned_at TIMESTAMP WITH TIME ZONE,
+    added_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (client_id, appraiser_id)
+);
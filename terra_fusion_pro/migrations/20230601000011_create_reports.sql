@@ -0,0 +1,22 @@
+-- Create reports table
+CREATE TABLE IF NOT EXISTS reports (
+    id UUID PRIMARY KEY,
+    title VARCHAR(255) NOT NULL,
+    property_id UUID NOT NULL,
+    appraiser_id UUID NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'draft',
+    report_type VARCHAR(32) NOT NULL,
+    valuation_amount DOUBLE PRECISION,
+    content JSONB NOT NULL DEFAULT '{}',
+    pdf_url TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    submitted_at TIMESTAMP WITH TIME ZONE,
+    reviewed_at TIMESTAMP WITH TIME ZONE,
+    reviewer_id UUID,
+    review_comments TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_reports_property ON reports (property_id);
+CREATE INDEX IF NOT EXISTS idx_reports_appraiser ON reports (appraiser_id);
+CREATE INDEX IF NOT EXISTS idx_reports_status ON reports (status);
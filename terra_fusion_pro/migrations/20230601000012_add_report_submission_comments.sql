@@ -0,0 +1,4 @@
+-- Keep the appraiser's comments from the latest report submission
+ALTER TABLE reports ADD COLUMN IF NOT EXISTS submission_comments TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_reports_reviewer ON reports (reviewer_id);
@@ -0,0 +1,45 @@
+-- Finalized reports are frozen: changes are made in an amended version that references the original
+ALTER TABLE reports ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE reports ADD COLUMN IF NOT EXISTS amends_id UUID REFERENCES reports (id);
+
+-- A finalized report has at most one amendment; later changes amend the amendment
+CREATE UNIQUE INDEX IF NOT EXISTS idx_reports_amends ON reports (amends_id) WHERE amends_id IS NOT NULL;
+
+-- What was delivered when a report was finalized, with the SHA-256 manifest of its artifacts
+CREATE TABLE IF NOT EXISTS report_snapshots (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL UNIQUE REFERENCES reports (id),
+    version INTEGER NOT NULL,
+    manifest JSONB NOT NULL,
+    manifest_sha256 CHAR(64) NOT NULL,
+    finalized_by UUID NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE OR REPLACE FUNCTION reject_finalized_report_change() RETURNS trigger AS $$
+BEGIN
+    IF OLD.status = 'finalized' THEN
+        RAISE EXCEPTION 'Report % is finalized and cannot be changed', OLD.id;
+    END IF;
+    IF TG_OP = 'DELETE' THEN
+        RETURN OLD;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS reports_frozen_when_finalized ON reports;
+CREATE TRIGGER reports_frozen_when_finalized
+    BEFORE UPDATE OR DELETE ON reports
+    FOR EACH ROW EXECUTE FUNCTION reject_finalized_report_change();
+
+CREATE OR REPLACE FUNCTION reject_snapshot_change() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'Report snapshots cannot be changed';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS report_snapshots_immutable ON report_snapshots;
+CREATE TRIGGER report_snapshots_immutable
+    BEFORE UPDATE OR DELETE ON report_snapshots
+    FOR EACH ROW EXECUTE FUNCTION reject_snapshot_change();
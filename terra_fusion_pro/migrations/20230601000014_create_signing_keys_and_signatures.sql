@@ -0,0 +1,36 @@
+-- Ed25519 keys appraisers sign reports with; private keys are sealed with SIGNING_KEY_SECRET
+CREATE TABLE IF NOT EXISTS signing_keys (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL REFERENCES users (id),
+    public_key CHAR(64) NOT NULL UNIQUE,
+    sealed_private_key TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMP WITH TIME ZONE
+);
+
+-- A user signs with one key at a time; retired keys are kept so old signatures still verify
+CREATE UNIQUE INDEX IF NOT EXISTS idx_signing_keys_active ON signing_keys (user_id) WHERE revoked_at IS NULL;
+
+-- Signatures over a finalized report's snapshot, at most one per role
+CREATE TABLE IF NOT EXISTS report_signatures (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES reports (id),
+    snapshot_id UUID NOT NULL REFERENCES report_snapshots (id),
+    key_id UUID NOT NULL REFERENCES signing_keys (id),
+    role VARCHAR(32) NOT NULL,
+    payload JSONB NOT NULL,
+    signature CHAR(128) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (report_id, role)
+);
+
+CREATE OR REPLACE FUNCTION reject_signature_change() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'Report signatures cannot be changed';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS report_signatures_immutable ON report_signatures;
+CREATE TRIGGER report_signatures_immutable
+    BEFORE UPDATE OR DELETE ON report_signatures
+    FOR EACH ROW EXECUTE FUNCTION reject_signature_change();
@@ -0,0 +1,15 @@
+-- Reusable narrative templates, in each appraiser's personal library or the shared organization library
+CREATE TABLE IF NOT EXISTS narrative_snippets (
+    id UUID PRIMARY KEY,
+    scope VARCHAR(32) NOT NULL,
+    owner_id UUID NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    section VARCHAR(64) NOT NULL,
+    field VARCHAR(64) NOT NULL,
+    template TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_narrative_snippets_owner ON narrative_snippets (owner_id);
+CREATE INDEX IF NOT EXISTS idx_narrative_snippets_section ON narrative_snippets (section, field);
@@ -0,0 +1,11 @@
+-- The content of a report as it stood at each submission for review, for comparing resubmissions
+CREATE TABLE IF NOT EXISTS report_revisions (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES reports (id) ON DELETE CASCADE,
+    revision INTEGER NOT NULL,
+    valuation_amount DOUBLE PRECISION,
+    content JSONB NOT NULL,
+    submission_comments TEXT,
+    submitted_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (report_id, revision)
+);
@@ -0,0 +1,31 @@
+-- Signed, expiring links that deliver an artifact of a finalized report to someone without a login
+CREATE TABLE IF NOT EXISTS share_links (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES reports (id) ON DELETE CASCADE,
+    artifact VARCHAR(255) NOT NULL,
+    recipient_role VARCHAR(32) NOT NULL,
+    recipient_name VARCHAR(255),
+    recipient_email VARCHAR(255),
+    password_hash TEXT,
+    expires_at TIMESTAMP WITH TIME ZONE NOT NULL,
+    max_downloads INTEGER,
+    download_count INTEGER NOT NULL DEFAULT 0,
+    first_downloaded_at TIMESTAMP WITH TIME ZONE,
+    created_by UUID NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX IF NOT EXISTS idx_share_links_report ON share_links (report_id);
+
+-- Every attempt to open a share link, kept as evidence of delivery
+CREATE TABLE IF NOT EXISTS share_link_accesses (
+    id UUID PRIMARY KEY,
+    link_id UUID NOT NULL REFERENCES share_links (id) ON DELETE CASCADE,
+    outcome VARCHAR(32) NOT NULL,
+    ip_address VARCHAR(64),
+    user_agent TEXT,
+    accessed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_share_link_accesses_link ON share_link_accesses (link_id, accessed_at);
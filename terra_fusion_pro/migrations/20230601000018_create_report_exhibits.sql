@@ -0,0 +1,16 @@
+-- Photos, sketches, maps and uploaded PDFs printed after the body of a report, in position order
+CREATE TABLE IF NOT EXISTS report_exhibits (
+    id UUID PRIMARY KEY,
+    report_id UUID NOT NULL REFERENCES reports (id) ON DELETE CASCADE,
+    kind VARCHAR(32) NOT NULL,
+    caption TEXT,
+    position INTEGER NOT NULL,
+    comparable_number INTEGER,
+    media_type VARCHAR(64) NOT NULL,
+    storage_key VARCHAR(512) NOT NULL,
+    page_count INTEGER,
+    byte_size BIGINT NOT NULL,
+    uploaded_by UUID NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    UNIQUE (report_id, position) DEFERRABLE INITIALLY DEFERRED
+);
@@ -0,0 +1,12 @@
+-- Refuse overlapping bookings in the database, so two requests checked at the same time
+-- cannot both book the same appraiser
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+ALTER TABLE inspection_appointments
+    ADD CONSTRAINT inspection_appointments_no_overlap
+    EXCLUDE USING gist (appraiser_id WITH =, tstzrange(scheduled_start, scheduled_end) WITH &&)
+    WHERE (status = 'scheduled');
+
+ALTER TABLE appraiser_availability_slots
+    ADD CONSTRAINT appraiser_availability_slots_no_overlap
+    EXCLUDE USING gist (appraiser_id WITH =, tstzrange(starts_at, ends_at) WITH &&);
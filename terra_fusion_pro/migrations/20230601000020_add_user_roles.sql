@@ -0,0 +1,2 @@
+-- Platform role of each user; staff and admins are granted their role directly in the database
+ALTER TABLE users ADD COLUMN IF NOT EXISTS role VARCHAR(32) NOT NULL DEFAULT 'member';
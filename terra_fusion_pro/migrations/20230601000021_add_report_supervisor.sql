@@ -0,0 +1,2 @@
+-- Supervisory appraiser the report's appraiser names to co-sign it
+ALTER TABLE reports ADD COLUMN IF NOT EXISTS supervisor_id UUID;
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::air::{AddPanelMemberRequest, UpdateRotationPolicyRequest},
+};
+use uuid::Uuid;
+
+use crate::service::air_service::AirService;
+
+/// Query parameters for listing AIR violations
+#[derive(Debug, Deserialize)]
+struct ViolationListQuery {
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+}
+
+/// Configure appraiser independence routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_air_violations)
+        .service(get_rotation_policy)
+        .service(update_rotation_policy)
+        .service(get_panel)
+        .service(add_panel_member)
+        .service(remove_panel_member);
+}
+
+/// List a client's blocked AIR violations
+#[get("/clients/{id}/air-violations")]
+async fn get_air_violations(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    query: web::Query<ViolationListQuery>,
+) -> impl Responder {
+    let service = AirService::new(db.get_ref().clone());
+
+    match service.list_violations(path.into_inner(), query.from, query.to, session.user_id()).await {
+        Ok(violations) => HttpResponse::Ok().json(violations),
+        Err(err) => {
+            log::error!("Error listing AIR violations: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Get a client's panel rotation policy
+#[get("/clients/{id}/panel-policy")]
+async fn get_rotation_policy(
+    db: web::Data<Arc<Database>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = AirService::new(db.get_ref().clone());
+
+    match service.rotation_policy(path.into_inner()).await {
+        Ok(policy) => HttpResponse::Ok().json(policy),
+        Err(err) => {
+            log::error!("Error finding rotation policy: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Change a client's panel rotation policy
+#[put("/clients/{id}/panel-policy")]
+async fn update_rotation_policy(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<UpdateRotationPolicyRequest>,
+) -> impl Responder {
+    let service = AirService::new(db.get_ref().clone());
+
+    match service.update_rotation_policy(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(policy) => HttpResponse::Ok().json(policy),
+        Err(err) => {
+            log::error!("Error updating rotation policy: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List a client's appraiser panel in rotation order
+#[get("/clients/{id}/panel")]
+async fn get_panel(
+    db: web::Data<Arc<Database>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = AirService::new(db.get_ref().clone());
+
+    match service.list_panel(path.into_inner()).await {
+        Ok(members) => HttpResponse::Ok().json(members),
+        Err(err) => {
+            log::error!("Error listing panel members: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Add an appraiser to a client's panel
+#[post("/clients/{id}/panel")]
+async fn add_panel_member(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<AddPanelMemberRequest>,
+) -> impl Responder {
+    let service = AirService::new(db.get_ref().clone());
+
+    match service.add_panel_member(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(member) => HttpResponse::Created().json(member),
+        Err(err) => {
+            log::error!("Error adding panel member: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Take an appraiser off a client's panel
+#[delete("/clients/{id}/panel/{appraiser_id}")]
+async fn remove_panel_member(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (client_id, appraiser_id) = path.into_inner();
+    let service = AirService::new(db.get_ref().clone());
+
+    match service.remove_panel_member(client_id, appraiser_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error removing panel member: {:?}", err);
+            err.error_response()
+        }
+    }
+}
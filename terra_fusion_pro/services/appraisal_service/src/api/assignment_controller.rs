@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::appraisal::AssignAppraiserRequest,
+    notifications::Notifier,
+    webhooks::WebhookDispatcher,
+};
+use uuid::Uuid;
+
+use crate::service::assignment_service::AssignmentService;
+
+/// Configure appraiser assignment routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(assign_appraiser);
+}
+
+/// Assign an appraiser to an appraisal
+#[post("/appraisals/{id}/assign")]
+async fn assign_appraiser(
+    db: web::Data<Arc<Database>>,
+    notifier: web::Data<Arc<Notifier>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<AssignAppraiserRequest>,
+) -> impl Responder {
+    let service = AssignmentService::new(
+        db.get_ref().clone(),
+        notifier.get_ref().clone(),
+        webhooks.get_ref().clone(),
+    );
+
+    match service
+        .assign_appraiser(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(appraisal) => HttpResponse::Ok().json(appraisal),
+        Err(err) => {
+            log::error!("Error assigning appraiser: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use serde::Deserialize;
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::client::{AddPortalUserRequest, CreateClientRequest, UpdateClientRequest},
+};
+use uuid::Uuid;
+
+use crate::service::client_service::ClientService;
+
+/// Query parameters for listing clients
+#[derive(Debug, Deserialize)]
+struct ClientListQuery {
+    include_inactive: Option<bool>,
+}
+
+/// Configure client routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_clients)
+        .service(get_client_by_id)
+        .service(create_client)
+        .service(update_client)
+        .service(delete_client)
+        .service(get_portal_users)
+        .service(add_portal_user)
+        .service(remove_portal_user);
+}
+
+/// Get a list of clients
+#[get("/clients")]
+async fn get_clients(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    query: web::Query<ClientListQuery>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.list_clients(query.include_inactive.unwrap_or(false), session.user_id()).await {
+        Ok(clients) => HttpResponse::Ok().json(clients),
+        Err(err) => {
+            log::error!("Error listing clients: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Get a client by ID
+#[get("/clients/{id}")]
+async fn get_client_by_id(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.find_client_by_id(path.into_inner(), session.user_id()).await {
+        Ok(client) => HttpResponse::Ok().json(client),
+        Err(err) => {
+            log::error!("Error finding client: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Create a new client
+#[post("/clients")]
+async fn create_client(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    request: web::Json<CreateClientRequest>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.create_client(request.into_inner(), session.user_id()).await {
+        Ok(client) => HttpResponse::Created().json(client),
+        Err(err) => {
+            log::error!("Error creating client: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Update a client
+#[put("/clients/{id}")]
+async fn update_client(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<UpdateClientRequest>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.update_client(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(client) => HttpResponse::Ok().json(client),
+        Err(err) => {
+            log::error!("Error updating client: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Delete a client
+#[delete("/clients/{id}")]
+async fn delete_client(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.delete_client(path.into_inner(), session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error deleting client: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List the portal users of a client
+#[get("/clients/{id}/portal-users")]
+async fn get_portal_users(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.list_portal_users(path.into_inner(), session.user_id()).await {
+        Ok(users) => HttpResponse::Ok().json(users),
+        Err(err) => {
+            log::error!("Error listing portal users: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Grant a user access to a client's portal
+#[post("/clients/{id}/portal-users")]
+async fn add_portal_user(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<AddPortalUserRequest>,
+) -> impl Responder {
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.add_portal_user(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(user) => HttpResponse::Created().json(user),
+        Err(err) => {
+            log::error!("Error adding portal user: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Revoke a user's access to a client's portal
+#[delete("/clients/{id}/portal-users/{user_id}")]
+async fn remove_portal_user(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, String)>,
+) -> impl Responder {
+    let (client_id, user_id) = path.into_inner();
+    let service = ClientService::new(db.get_ref().clone());
+
+    match service.remove_portal_user(client_id, &user_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error removing portal user: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use actix_web::{get, post, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::engagement::RespondToEngagementRequest,
+};
+use uuid::Uuid;
+
+use crate::service::engagement_service::EngagementService;
+
+/// Configure engagement letter routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_engagement_letter)
+        .service(get_engagement_letters)
+        .service(respond_to_engagement_letter)
+        .service(accept_counter_offer);
+}
+
+/// Get the current engagement letter of an appraisal
+#[get("/appraisals/{id}/engagement-letter")]
+async fn get_engagement_letter(
+    db: web::Data<Arc<Database>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = EngagementService::new(db.get_ref().clone());
+
+    match service.current_letter(path.into_inner()).await {
+        Ok(letter) => HttpResponse::Ok().json(letter),
+        Err(err) => {
+            log::error!("Error finding engagement letter: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List every engagement letter version of an appraisal
+#[get("/appraisals/{id}/engagement-letters")]
+async fn get_engagement_letters(
+    db: web::Data<Arc<Database>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = EngagementService::new(db.get_ref().clone());
+
+    match service.list_letters(path.into_inner()).await {
+        Ok(letters) => HttpResponse::Ok().json(letters),
+        Err(err) => {
+            log::error!("Error listing engagement letters: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Accept or decline the current engagement letter
+#[post("/appraisals/{id}/engagement-letter/response")]
+async fn respond_to_engagement_letter(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    request: web::Json<RespondToEngagementRequest>,
+) -> impl Responder {
+    let service = EngagementService::new(db.get_ref().clone());
+    // The socket peer is recorded rather than forwarded headers, which the client could forge
+    let ip_address = req.peer_addr().map(|addr| addr.ip().to_string());
+
+    match service
+        .respond(path.into_inner(), request.into_inner(), session.user_id(), ip_address)
+        .await
+    {
+        Ok(letter) => HttpResponse::Ok().json(letter),
+        Err(err) => {
+            log::error!("Error responding to engagement letter: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Agree to the appraiser's counter-offer and issue a revised letter
+#[post("/appraisals/{id}/engagement-letter/counter-offer/accept")]
+async fn accept_counter_offer(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = EngagementService::new(db.get_ref().clone());
+
+    match service.accept_counter_offer(path.into_inner(), session.user_id()).await {
+        Ok(letter) => HttpResponse::Created().json(letter),
+        Err(err) => {
+            log::error!("Error accepting counter-offer: {:?}", err);
+            err.error_response()
+        }
+    }
+}
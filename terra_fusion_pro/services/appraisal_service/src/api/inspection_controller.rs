@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::inspection::{
+        CreateAvailabilitySlotRequest, CreateInspectionRequest, RescheduleInspectionRequest,
+        UpdateInspectionRequest,
+    },
+    webhooks::WebhookDispatcher,
+};
+use uuid::Uuid;
+
+use crate::service::inspection_service::InspectionService;
+
+/// Configure inspection scheduling routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(book_inspection)
+        .service(get_inspections)
+        .service(update_inspection)
+        .service(reschedule_inspection)
+        .service(get_availability)
+        .service(add_availability)
+        .service(delete_availability)
+        .service(issue_calendar_feed)
+        .service(get_calendar_feed);
+}
+
+/// Book an inspection appointment for an appraisal
+#[post("/appraisals/{id}/inspections")]
+async fn book_inspection(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    path: web::Path<Uuid>,
+    request: web::Json<CreateInspectionRequest>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.book_inspection(path.into_inner(), request.into_inner()).await {
+        Ok(appointment) => HttpResponse::Created().json(appointment),
+        Err(err) => {
+            log::error!("Error booking inspection: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List inspection appointments for an appraisal
+#[get("/appraisals/{id}/inspections")]
+async fn get_inspections(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.list_inspections(path.into_inner()).await {
+        Ok(appointments) => HttpResponse::Ok().json(appointments),
+        Err(err) => {
+            log::error!("Error listing inspections: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Update contact details, access notes or status of an appointment
+#[put("/inspections/{id}")]
+async fn update_inspection(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    path: web::Path<Uuid>,
+    request: web::Json<UpdateInspectionRequest>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.update_inspection(path.into_inner(), request.into_inner()).await {
+        Ok(appointment) => HttpResponse::Ok().json(appointment),
+        Err(err) => {
+            log::error!("Error updating inspection: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Reschedule an appointment to a new time
+#[post("/inspections/{id}/reschedule")]
+async fn reschedule_inspection(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<RescheduleInspectionRequest>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service
+        .reschedule_inspection(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(appointment) => HttpResponse::Ok().json(appointment),
+        Err(err) => {
+            log::error!("Error rescheduling inspection: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List an appraiser's upcoming availability slots
+#[get("/appraisers/{id}/availability")]
+async fn get_availability(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.list_availability_slots(path.into_inner()).await {
+        Ok(slots) => HttpResponse::Ok().json(slots),
+        Err(err) => {
+            log::error!("Error listing availability: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Add an availability slot for an appraiser
+#[post("/appraisers/{id}/availability")]
+async fn add_availability(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<CreateAvailabilitySlotRequest>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service
+        .add_availability_slot(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(slot) => HttpResponse::Created().json(slot),
+        Err(err) => {
+            log::error!("Error adding availability: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Remove an availability slot
+#[delete("/appraisers/{id}/availability/{slot_id}")]
+async fn delete_availability(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (appraiser_id, slot_id) = path.into_inner();
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.delete_availability_slot(appraiser_id, slot_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error deleting availability: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Issue (or rotate) the iCalendar feed URL for an appraiser
+#[post("/appraisers/{id}/calendar-feed")]
+async fn issue_calendar_feed(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.issue_calendar_feed(path.into_inner(), session.user_id()).await {
+        Ok(feed) => HttpResponse::Created().json(serde_json::json!({
+            "appraiser_id": feed.appraiser_id,
+            "feed_path": format!("/api/v1/calendar/{}.ics", feed.token),
+            "created_at": feed.created_at,
+        })),
+        Err(err) => {
+            log::error!("Error issuing calendar feed: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Serve an appraiser's inspections as an iCalendar feed
+#[get("/calendar/{token}.ics")]
+async fn get_calendar_feed(
+    db: web::Data<Arc<Database>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let service = InspectionService::new(db.get_ref().clone(), webhooks.get_ref().clone());
+
+    match service.render_calendar_feed(&path.into_inner()).await {
+        Ok(calendar) => HttpResponse::Ok()
+            .content_type("text/calendar; charset=utf-8")
+            .body(calendar),
+        Err(err) => {
+            log::error!("Error rendering calendar feed: {:?}", err);
+            err.error_response()
+        }
+    }
+}
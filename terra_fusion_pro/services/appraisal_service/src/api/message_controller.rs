@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use actix_web::{get, http::header, post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::message::PostMessageRequest,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::message_service::MessageService;
+
+/// Largest accepted message body; attachments arrive base64-encoded inside the JSON
+const MAX_MESSAGE_PAYLOAD: usize = 50 * 1024 * 1024;
+
+/// Configure message thread routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/appraisals/{id}/messages")
+            .app_data(web::JsonConfig::default().limit(MAX_MESSAGE_PAYLOAD))
+            .route(web::get().to(get_messages))
+            .route(web::post().to(post_message))
+    )
+    .service(mark_thread_read)
+    .service(get_unread_counts)
+    .service(download_attachment);
+}
+
+/// List the messages of an appraisal thread
+async fn get_messages(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = MessageService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.list_messages(path.into_inner(), session.user_id()).await {
+        Ok(messages) => HttpResponse::Ok().json(messages),
+        Err(err) => {
+            log::error!("Error listing messages: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Post a message to an appraisal thread
+async fn post_message(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<PostMessageRequest>,
+) -> impl Responder {
+    let service = MessageService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service
+        .post_message(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(message) => HttpResponse::Created().json(message),
+        Err(err) => {
+            log::error!("Error posting message: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Mark every message of an appraisal thread as read
+#[post("/appraisals/{id}/messages/read")]
+async fn mark_thread_read(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = MessageService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.mark_thread_read(path.into_inner(), session.user_id()).await {
+        Ok(marked) => HttpResponse::Ok().json(serde_json::json!({ "marked_read": marked })),
+        Err(err) => {
+            log::error!("Error marking messages as read: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Get unread message counts per appraisal for the current user
+#[get("/messages/unread")]
+async fn get_unread_counts(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+) -> impl Responder {
+    let service = MessageService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.unread_counts(session.user_id()).await {
+        Ok(counts) => HttpResponse::Ok().json(counts),
+        Err(err) => {
+            log::error!("Error counting unread messages: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Download a message attachment
+#[get("/messages/{id}/attachments/{attachment_id}")]
+async fn download_attachment(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (message_id, attachment_id) = path.into_inner();
+    let service = MessageService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.download_attachment(message_id, attachment_id, session.user_id()).await {
+        Ok((attachment, bytes)) => HttpResponse::Ok()
+            .content_type(attachment.content_type.as_str())
+            .insert_header(header::ContentDisposition::attachment(attachment.file_name))
+            .body(bytes),
+        Err(err) => {
+            log::error!("Error downloading attachment: {:?}", err);
+            err.error_response()
+        }
+    }
+}
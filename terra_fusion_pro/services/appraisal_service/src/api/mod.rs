@@ -0,0 +1,24 @@
+mod air_controller;
+mod assignment_controller;
+mod client_controller;
+mod engagement_controller;
+mod inspection_controller;
+mod message_controller;
+mod pipeline_controller;
+mod revision_controller;
+mod webhook_controller;
+
+use actix_web::web;
+
+/// Configure all routes for the API
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    air_controller::configure_routes(cfg);
+    assignment_controller::configure_routes(cfg);
+    client_controller::configure_routes(cfg);
+    engagement_controller::configure_routes(cfg);
+    inspection_controller::configure_routes(cfg);
+    message_controller::configure_routes(cfg);
+    pipeline_controller::configure_routes(cfg);
+    revision_controller::configure_routes(cfg);
+    webhook_controller::configure_routes(cfg);
+}
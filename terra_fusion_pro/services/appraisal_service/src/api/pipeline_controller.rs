@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use shared::{db::Database, models::appraisal::AppraisalFilter};
+
+use crate::service::pipeline_service::PipelineService;
+
+/// Configure appraisal pipeline routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_dashboard)
+        .service(get_appraisals);
+}
+
+/// List appraisals in the pipeline
+#[get("/appraisals")]
+async fn get_appraisals(
+    db: web::Data<Arc<Database>>,
+    query: web::Query<AppraisalFilter>,
+) -> impl Responder {
+    let service = PipelineService::new(db.get_ref().clone());
+
+    match service.list_appraisals(query.into_inner()).await {
+        Ok(appraisals) => HttpResponse::Ok().json(appraisals),
+        Err(err) => {
+            log::error!("Error listing appraisals: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Get aggregate pipeline counts for the operations dashboard
+#[get("/appraisals/dashboard")]
+async fn get_dashboard(
+    db: web::Data<Arc<Database>>,
+    query: web::Query<AppraisalFilter>,
+) -> impl Responder {
+    let service = PipelineService::new(db.get_ref().clone());
+
+    match service.dashboard(query.into_inner()).await {
+        Ok(dashboard) => HttpResponse::Ok().json(dashboard),
+        Err(err) => {
+            log::error!("Error building pipeline dashboard: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use actix_web::{get, post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    notifications::Notifier,
+    webhooks::WebhookDispatcher,
+    models::revision::{CreateRevisionRequest, ResolveItemRequest, RespondToItemRequest},
+};
+use uuid::Uuid;
+
+use crate::service::revision_service::RevisionService;
+
+/// Configure revision request routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_revisions)
+        .service(request_revisions)
+        .service(respond_to_item)
+        .service(resolve_item)
+        .service(resubmit);
+}
+
+/// List every revision round of an appraisal
+#[get("/appraisals/{id}/revisions")]
+async fn get_revisions(
+    db: web::Data<Arc<Database>>,
+    notifier: web::Data<Arc<Notifier>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = RevisionService::new(
+        db.get_ref().clone(),
+        notifier.get_ref().clone(),
+        webhooks.get_ref().clone(),
+    );
+
+    match service.list_revisions(path.into_inner()).await {
+        Ok(revisions) => HttpResponse::Ok().json(revisions),
+        Err(err) => {
+            log::error!("Error listing revision requests: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Request revisions to an appraisal under review
+#[post("/appraisals/{id}/revisions")]
+async fn request_revisions(
+    db: web::Data<Arc<Database>>,
+    notifier: web::Data<Arc<Notifier>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<CreateRevisionRequest>,
+) -> impl Responder {
+    let service = RevisionService::new(
+        db.get_ref().clone(),
+        notifier.get_ref().clone(),
+        webhooks.get_ref().clone(),
+    );
+
+    match service
+        .request_revisions(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(revision) => HttpResponse::Created().json(revision),
+        Err(err) => {
+            log::error!("Error requesting revisions: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Respond to a revision item
+#[post("/appraisals/{id}/revisions/{revision_id}/items/{item_id}/response")]
+async fn respond_to_item(
+    db: web::Data<Arc<Database>>,
+    notifier: web::Data<Arc<Notifier>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid, Uuid)>,
+    request: web::Json<RespondToItemRequest>,
+) -> impl Responder {
+    let (appraisal_id, revision_id, item_id) = path.into_inner();
+    let service = RevisionService::new(
+        db.get_ref().clone(),
+        notifier.get_ref().clone(),
+        webhooks.get_ref().clone(),
+    );
+
+    match service
+        .respond_to_item(appraisal_id, revision_id, item_id, request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(revision) => HttpResponse::Ok().json(revision),
+        Err(err) => {
+            log::error!("Error responding to revision item: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Clear or reject a revision item
+#[post("/appraisals/{id}/revisions/{revision_id}/items/{item_id}/resolution")]
+async fn resolve_item(
+    db: web::Data<Arc<Database>>,
+    notifier: web::Data<Arc<Notifier>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid, Uuid)>,
+    request: web::Json<ResolveItemRequest>,
+) -> impl Responder {
+    let (appraisal_id, revision_id, item_id) = path.into_inner();
+    let service = RevisionService::new(
+        db.get_ref().clone(),
+        notifier.get_ref().clone(),
+        webhooks.get_ref().clone(),
+    );
+
+    match service
+        .resolve_item(appraisal_id, revision_id, item_id, request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(revision) => HttpResponse::Ok().json(revision),
+        Err(err) => {
+            log::error!("Error resolving revision item: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Resubmit an appraisal once every revision item is answered
+#[post("/appraisals/{id}/revisions/{revision_id}/resubmit")]
+async fn resubmit(
+    db: web::Data<Arc<Database>>,
+    notifier: web::Data<Arc<Notifier>>,
+    webhooks: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (appraisal_id, revision_id) = path.into_inner();
+    let service = RevisionService::new(
+        db.get_ref().clone(),
+        notifier.get_ref().clone(),
+        webhooks.get_ref().clone(),
+    );
+
+    match service.resubmit(appraisal_id, revision_id, session.user_id()).await {
+        Ok(revision) => HttpResponse::Ok().json(revision),
+        Err(err) => {
+            log::error!("Error resubmitting appraisal: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use serde::Deserialize;
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::webhook::{CreateWebhookEndpointRequest, UpdateWebhookEndpointRequest},
+    webhooks::WebhookDispatcher,
+};
+use uuid::Uuid;
+
+use crate::service::webhook_service::WebhookService;
+
+/// Query parameters for listing deliveries
+#[derive(Debug, Deserialize)]
+struct DeliveryListQuery {
+    limit: Option<i64>,
+}
+
+/// Configure webhook routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_webhooks)
+        .service(create_webhook)
+        .service(update_webhook)
+        .service(delete_webhook)
+        .service(rotate_webhook_secret)
+        .service(get_webhook_deliveries)
+        .service(redeliver_webhook);
+}
+
+/// List a client's webhook endpoints
+#[get("/clients/{id}/webhooks")]
+async fn get_webhooks(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.list_endpoints(path.into_inner(), session.user_id()).await {
+        Ok(endpoints) => HttpResponse::Ok().json(endpoints),
+        Err(err) => {
+            log::error!("Error listing webhook endpoints: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Register a webhook endpoint; the response is the only one that includes the signing secret
+#[post("/clients/{id}/webhooks")]
+async fn create_webhook(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<CreateWebhookEndpointRequest>,
+) -> impl Responder {
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.create_endpoint(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(endpoint) => HttpResponse::Created().json(endpoint),
+        Err(err) => {
+            log::error!("Error creating webhook endpoint: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Update a webhook endpoint
+#[put("/clients/{id}/webhooks/{webhook_id}")]
+async fn update_webhook(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+    request: web::Json<UpdateWebhookEndpointRequest>,
+) -> impl Responder {
+    let (client_id, webhook_id) = path.into_inner();
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.update_endpoint(client_id, webhook_id, request.into_inner(), session.user_id()).await {
+        Ok(endpoint) => HttpResponse::Ok().json(endpoint),
+        Err(err) => {
+            log::error!("Error updating webhook endpoint: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Delete a webhook endpoint
+#[delete("/clients/{id}/webhooks/{webhook_id}")]
+async fn delete_webhook(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (client_id, webhook_id) = path.into_inner();
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.delete_endpoint(client_id, webhook_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error deleting webhook endpoint: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Replace a webhook endpoint's signing secret
+#[post("/clients/{id}/webhooks/{webhook_id}/rotate-secret")]
+async fn rotate_webhook_secret(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (client_id, webhook_id) = path.into_inner();
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.rotate_secret(client_id, webhook_id, session.user_id()).await {
+        Ok(endpoint) => HttpResponse::Ok().json(endpoint),
+        Err(err) => {
+            log::error!("Error rotating webhook secret: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List recent deliveries to a webhook endpoint
+#[get("/clients/{id}/webhooks/{webhook_id}/deliveries")]
+async fn get_webhook_deliveries(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+    query: web::Query<DeliveryListQuery>,
+) -> impl Responder {
+    let (client_id, webhook_id) = path.into_inner();
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.list_deliveries(client_id, webhook_id, query.limit, session.user_id()).await {
+        Ok(deliveries) => HttpResponse::Ok().json(deliveries),
+        Err(err) => {
+            log::error!("Error listing webhook deliveries: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Send a past delivery again
+#[post("/clients/{id}/webhooks/{webhook_id}/deliveries/{delivery_id}/redeliver")]
+async fn redeliver_webhook(
+    db: web::Data<Arc<Database>>,
+    dispatcher: web::Data<Arc<WebhookDispatcher>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid, Uuid)>,
+) -> impl Responder {
+    let (client_id, webhook_id, delivery_id) = path.into_inner();
+    let service = WebhookService::new(db.get_ref().clone(), dispatcher.get_ref().clone());
+
+    match service.redeliver(client_id, webhook_id, delivery_id, session.user_id()).await {
+        Ok(delivery) => HttpResponse::Accepted().json(delivery),
+        Err(err) => {
+            log::error!("Error redelivering webhook: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use actix_web::{web, HttpResponse};
+use async_graphql::{EmptySubscription, Schema};
+use async_graphql_actix_web::{GraphQLRequest, GraphQLResponse};
+use std::sync::Arc;
+
+use shared::auth::session::SessionData;
+use shared::db::Database;
+use shared::storage::AttachmentStore;
+
+mod query;
+mod mutation;
+mod types;
+
+use query::QueryRoot;
+use mutation::MutationRoot;
+
+pub type AppraisalSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+
+/// ID of the user making a GraphQL request, if authenticated
+pub struct CurrentUser(pub Option<String>);
+
+/// Create GraphQL schema with database connection and attachment storage
+pub fn create_schema(db: Arc<Database>, attachments: Arc<dyn AttachmentStore>) -> AppraisalSchema {
+    Schema::build(QueryRoot, MutationRoot, EmptySubscription)
+        .data(db)
+        .data(attachments)
+        .finish()
+}
+
+/// Configure GraphQL routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("")
+            .route(web::post().to(graphql_handler))
+            .route(web::get().to(graphql_playground))
+    );
+}
+
+/// Handle GraphQL requests
+async fn graphql_handler(
+    schema: web::Data<AppraisalSchema>,
+    session: Option<web::ReqData<SessionData>>,
+    req: GraphQLRequest,
+) -> GraphQLResponse {
+    let user = CurrentUser(session.and_then(|s| s.user_id()));
+    schema.execute(req.into_inner().data(user)).await.into()
+}
+
+/// GraphQL playground UI
+async fn graphql_playground() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .body(
+            async_graphql::http::playground_source(
+                async_graphql::http::GraphQLPlaygroundConfig::new("/graphql")
+            )
+        )
+}
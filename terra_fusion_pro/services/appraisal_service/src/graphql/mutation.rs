@@ -0,0 +1,105 @@
+use async_graphql::{Context, Object, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use shared::db::Database;
+use shared::storage::AttachmentStore;
+
+use crate::service::client_service::ClientService;
+use crate::service::message_service::MessageService;
+use super::types::{
+    Client, ClientInput, Message, PortalUser, PortalUserInput, PostMessageInput, UpdateClientInput,
+};
+use super::CurrentUser;
+
+/// GraphQL mutation root
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    /// Create a new client
+    async fn create_client(&self, ctx: &Context<'_>, input: ClientInput) -> Result<Client> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.create_client(input.into(), user.0.clone()).await {
+            Ok(client) => Ok(client.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Update an existing client
+    async fn update_client(&self, ctx: &Context<'_>, id: Uuid, input: UpdateClientInput) -> Result<Client> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.update_client(id, input.into(), user.0.clone()).await {
+            Ok(client) => Ok(client.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Delete a client
+    async fn delete_client(&self, ctx: &Context<'_>, id: Uuid) -> Result<bool> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.delete_client(id, user.0.clone()).await {
+            Ok(_) => Ok(true),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Grant a user access to a client's portal
+    async fn add_client_portal_user(&self, ctx: &Context<'_>, client_id: Uuid, input: PortalUserInput) -> Result<PortalUser> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.add_portal_user(client_id, input.into(), user.0.clone()).await {
+            Ok(user) => Ok(user.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Revoke a user's access to a client's portal
+    async fn remove_client_portal_user(&self, ctx: &Context<'_>, client_id: Uuid, user_id: String) -> Result<bool> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.remove_portal_user(client_id, &user_id, user.0.clone()).await {
+            Ok(_) => Ok(true),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Post a message to an appraisal thread
+    async fn post_message(&self, ctx: &Context<'_>, appraisal_id: Uuid, input: PostMessageInput) -> Result<Message> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = MessageService::new(db.clone(), store.clone());
+        
+        match service.post_message(appraisal_id, input.into(), user.0.clone()).await {
+            Ok(message) => Ok(message.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Mark every message of an appraisal thread as read, returning how many were newly marked
+    async fn mark_messages_read(&self, ctx: &Context<'_>, appraisal_id: Uuid) -> Result<u64> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = MessageService::new(db.clone(), store.clone());
+        
+        match service.mark_thread_read(appraisal_id, user.0.clone()).await {
+            Ok(marked) => Ok(marked),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use actix_web::ResponseError;
+use async_graphql::{Context, Object, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use shared::db::Database;
+use shared::error::AppError;
+use shared::storage::AttachmentStore;
+
+use crate::service::client_service::ClientService;
+use crate::service::message_service::MessageService;
+use super::types::{Client, Message, PortalUser, UnreadCount};
+use super::CurrentUser;
+
+/// GraphQL query root
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Get a client by ID
+    async fn client(&self, ctx: &Context<'_>, id: Uuid) -> Result<Client> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.find_client_by_id(id, user.0.clone()).await {
+            Ok(client) => Ok(client.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// List clients
+    async fn clients(&self, ctx: &Context<'_>, include_inactive: Option<bool>) -> Result<Vec<Client>> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.list_clients(include_inactive.unwrap_or(false), user.0.clone()).await {
+            Ok(clients) => Ok(clients.into_iter().map(|c| c.into()).collect()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// List the portal users of a client
+    async fn client_portal_users(&self, ctx: &Context<'_>, client_id: Uuid) -> Result<Vec<PortalUser>> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ClientService::new(db.clone());
+        
+        match service.list_portal_users(client_id, user.0.clone()).await {
+            Ok(users) => Ok(users.into_iter().map(|u| u.into()).collect()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// List the messages of an appraisal thread visible to the current user
+    async fn appraisal_messages(&self, ctx: &Context<'_>, appraisal_id: Uuid) -> Result<Vec<Message>> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = MessageService::new(db.clone(), store.clone());
+        
+        match service.list_messages(appraisal_id, user.0.clone()).await {
+            Ok(messages) => Ok(messages.into_iter().map(|m| m.into()).collect()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Unread message counts per appraisal for the current user
+    async fn unread_message_counts(&self, ctx: &Context<'_>) -> Result<Vec<UnreadCount>> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = MessageService::new(db.clone(), store.clone());
+        
+        match service.unread_counts(user.0.clone()).await {
+            Ok(counts) => Ok(counts.into_iter().map(|c| c.into()).collect()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Convert application errors to GraphQL errors
+impl From<AppError> for async_graphql::Error {
+    fn from(err: AppError) -> Self {
+        async_graphql::Error::new(err.to_string())
+            .extend_with(|_, e| {
+                e.set("type", err.error_type());
+                e.set("status", err.status_code().as_u16());
+            })
+    }
+}
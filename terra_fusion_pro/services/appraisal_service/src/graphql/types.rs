@@ -0,0 +1,489 @@
+use async_graphql::{SimpleObject, InputObject, Enum};
+use chrono::{DateTime, Utc};
+use shared::models::client::{ClientType as ModelClientType, PortalRole as ModelPortalRole};
+use shared::models::message::{
+    MessageVisibility as ModelMessageVisibility, ParticipantRole as ModelParticipantRole,
+};
+use shared::models::report::ReportType as ModelReportType;
+use uuid::Uuid;
+
+/// GraphQL representation of a client organization
+#[derive(SimpleObject)]
+pub struct Client {
+    pub id: Uuid,
+    pub name: String,
+    pub client_type: ClientType,
+    pub contacts: Vec<ClientContact>,
+    pub billing_address: Option<Address>,
+    pub default_instructions: Option<String>,
+    pub required_report_types: Vec<ReportType>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// GraphQL representation of a client contact
+#[derive(SimpleObject)]
+pub struct ClientContact {
+    pub name: String,
+    pub title: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub is_primary: bool,
+}
+
+/// GraphQL representation of an address
+#[derive(SimpleObject)]
+pub struct Address {
+    pub street1: String,
+    pub street2: Option<String>,
+    pub city: String,
+    pub state: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+/// GraphQL representation of a client portal user
+#[derive(SimpleObject)]
+pub struct PortalUser {
+    pub id: Uuid,
+    pub client_id: Uuid,
+    pub user_id: String,
+    pub role: PortalRole,
+    pub is_loan_production: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// GraphQL representation of a message in an appraisal thread
+#[derive(SimpleObject)]
+pub struct Message {
+    pub id: Uuid,
+    pub appraisal_id: Uuid,
+    pub author_id: String,
+    pub author_role: ParticipantRole,
+    pub body: String,
+    pub attachments: Vec<MessageAttachment>,
+    pub visibility: MessageVisibility,
+    pub read_by: Vec<ReadReceipt>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// GraphQL representation of a message attachment
+#[derive(SimpleObject)]
+pub struct MessageAttachment {
+    pub id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+}
+
+/// GraphQL representation of a read receipt
+#[derive(SimpleObject)]
+pub struct ReadReceipt {
+    pub user_id: String,
+    pub read_at: DateTime<Utc>,
+}
+
+/// GraphQL representation of a thread's unread count
+#[derive(SimpleObject)]
+pub struct UnreadCount {
+    pub appraisal_id: Uuid,
+    pub unread: i64,
+}
+
+/// GraphQL enum for client types
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ClientType {
+    Lender,
+    Amc,
+    Private,
+}
+
+/// GraphQL enum for client portal roles
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum PortalRole {
+    Admin,
+    Orderer,
+    Viewer,
+}
+
+/// GraphQL enum for thread participant roles
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ParticipantRole {
+    Client,
+    Appraiser,
+    Reviewer,
+}
+
+/// GraphQL enum for message visibility
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum MessageVisibility {
+    Internal,
+    ClientVisible,
+}
+
+/// GraphQL enum for report types
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ReportType {
+    Form1004,
+    Form1073,
+    Form1025,
+    Form1004C,
+    Form2055,
+    CommercialForm,
+    DesktopAppraisal,
+    BPO,
+    Other,
+}
+
+/// Input type for creating a client
+#[derive(InputObject)]
+pub struct ClientInput {
+    pub name: String,
+    pub client_type: ClientType,
+    pub contacts: Option<Vec<ClientContactInput>>,
+    pub billing_address: Option<AddressInput>,
+    pub default_instructions: Option<String>,
+    pub required_report_types: Option<Vec<ReportType>>,
+}
+
+/// Input type for updating a client
+#[derive(InputObject)]
+pub struct UpdateClientInput {
+    pub name: Option<String>,
+    pub client_type: Option<ClientType>,
+    pub contacts: Option<Vec<ClientContactInput>>,
+    pub billing_address: Option<AddressInput>,
+    pub default_instructions: Option<String>,
+    pub required_report_types: Option<Vec<ReportType>>,
+    pub is_active: Option<bool>,
+}
+
+/// Input type for a client contact
+#[derive(InputObject)]
+pub struct ClientContactInput {
+    pub name: String,
+    pub title: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub is_primary: Option<bool>,
+}
+
+/// Input type for an address
+#[derive(InputObject)]
+pub struct AddressInput {
+    pub street1: String,
+    pub street2: Option<String>,
+    pub city: String,
+    pub state: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+/// Input type for adding a portal user
+#[derive(InputObject)]
+pub struct PortalUserInput {
+    pub user_id: String,
+    pub role: PortalRole,
+    pub is_loan_production: Option<bool>,
+}
+
+/// Input type for posting a message
+#[derive(InputObject)]
+pub struct PostMessageInput {
+    pub body: String,
+    pub visibility: MessageVisibility,
+    pub attachments: Option<Vec<AttachmentUploadInput>>,
+}
+
+/// Input type for a message attachment
+#[derive(InputObject)]
+pub struct AttachmentUploadInput {
+    pub file_name: String,
+    pub content_type: String,
+    pub content_base64: String,
+}
+
+/// Conversion functions between GraphQL and domain model types
+impl From<shared::models::client::Client> for Client {
+    fn from(c: shared::models::client::Client) -> Self {
+        Self {
+            id: c.id,
+            name: c.name,
+            client_type: c.client_type.into(),
+            contacts: c.contacts.into_iter().map(|contact| contact.into()).collect(),
+            billing_address: c.billing_address.map(|a| a.into()),
+            default_instructions: c.default_instructions,
+            required_report_types: c.required_report_types.into_iter().map(|rt| rt.into()).collect(),
+            is_active: c.is_active,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        }
+    }
+}
+
+impl From<shared::models::client::ClientContact> for ClientContact {
+    fn from(c: shared::models::client::ClientContact) -> Self {
+        Self {
+            name: c.name,
+            title: c.title,
+            email: c.email,
+            phone: c.phone,
+            is_primary: c.is_primary,
+        }
+    }
+}
+
+impl From<shared::models::property::Address> for Address {
+    fn from(a: shared::models::property::Address) -> Self {
+        Self {
+            street1: a.street1,
+            street2: a.street2,
+            city: a.city,
+            state: a.state,
+            postal_code: a.postal_code,
+            country: a.country,
+        }
+    }
+}
+
+impl From<shared::models::client::ClientPortalUser> for PortalUser {
+    fn from(u: shared::models::client::ClientPortalUser) -> Self {
+        Self {
+            id: u.id,
+            client_id: u.client_id,
+            user_id: u.user_id,
+            role: u.role.into(),
+            is_loan_production: u.is_loan_production,
+            created_at: u.created_at,
+        }
+    }
+}
+
+impl From<ModelClientType> for ClientType {
+    fn from(ct: ModelClientType) -> Self {
+        match ct {
+            ModelClientType::Lender => ClientType::Lender,
+            ModelClientType::Amc => ClientType::Amc,
+            ModelClientType::Private => ClientType::Private,
+        }
+    }
+}
+
+impl From<ClientType> for ModelClientType {
+    fn from(ct: ClientType) -> Self {
+        match ct {
+            ClientType::Lender => ModelClientType::Lender,
+            ClientType::Amc => ModelClientType::Amc,
+            ClientType::Private => ModelClientType::Private,
+        }
+    }
+}
+
+impl From<ModelPortalRole> for PortalRole {
+    fn from(r: ModelPortalRole) -> Self {
+        match r {
+            ModelPortalRole::Admin => PortalRole::Admin,
+            ModelPortalRole::Orderer => PortalRole::Orderer,
+            ModelPortalRole::Viewer => PortalRole::Viewer,
+        }
+    }
+}
+
+impl From<PortalRole> for ModelPortalRole {
+    fn from(r: PortalRole) -> Self {
+        match r {
+            PortalRole::Admin => ModelPortalRole::Admin,
+            PortalRole::Orderer => ModelPortalRole::Orderer,
+            PortalRole::Viewer => ModelPortalRole::Viewer,
+        }
+    }
+}
+
+impl From<ModelReportType> for ReportType {
+    fn from(rt: ModelReportType) -> Self {
+        match rt {
+            ModelReportType::Form1004 => ReportType::Form1004,
+            ModelReportType::Form1073 => ReportType::Form1073,
+            ModelReportType::Form1025 => ReportType::Form1025,
+            ModelReportType::Form1004C => ReportType::Form1004C,
+            ModelReportType::Form2055 => ReportType::Form2055,
+            ModelReportType::CommercialForm => ReportType::CommercialForm,
+            ModelReportType::DesktopAppraisal => ReportType::DesktopAppraisal,
+            ModelReportType::BPO => ReportType::BPO,
+            ModelReportType::Other => ReportType::Other,
+        }
+    }
+}
+
+impl From<ReportType> for ModelReportType {
+    fn from(rt: ReportType) -> Self {
+        match rt {
+            ReportType::Form1004 => ModelReportType::Form1004,
+            ReportType::Form1073 => ModelReportType::Form1073,
+            ReportType::Form1025 => ModelReportType::Form1025,
+            ReportType::Form1004C => ModelReportType::Form1004C,
+            ReportType::Form2055 => ModelReportType::Form2055,
+            ReportType::CommercialForm => ModelReportType::CommercialForm,
+            ReportType::DesktopAppraisal => ModelReportType::DesktopAppraisal,
+            ReportType::BPO => ModelReportType::BPO,
+            ReportType::Other => ModelReportType::Other,
+        }
+    }
+}
+
+impl From<ClientContactInput> for shared::models::client::ClientContact {
+    fn from(c: ClientContactInput) -> Self {
+        Self {
+            name: c.name,
+            title: c.title,
+            email: c.email,
+            phone: c.phone,
+            is_primary: c.is_primary.unwrap_or(false),
+        }
+    }
+}
+
+impl From<AddressInput> for shared::models::property::Address {
+    fn from(a: AddressInput) -> Self {
+        Self {
+            street1: a.street1,
+            street2: a.street2,
+            city: a.city,
+            state: a.state,
+            postal_code: a.postal_code,
+            country: a.country,
+            latitude: None,
+            longitude: None,
+        }
+    }
+}
+
+impl From<ClientInput> for shared::models::client::CreateClientRequest {
+    fn from(c: ClientInput) -> Self {
+        Self {
+            name: c.name,
+            client_type: c.client_type.into(),
+            contacts: c.contacts.map(|cs| cs.into_iter().map(|contact| contact.into()).collect()),
+            billing_address: c.billing_address.map(|a| a.into()),
+            default_instructions: c.default_instructions,
+            required_report_types: c.required_report_types.map(|rts| rts.into_iter().map(|rt| rt.into()).collect()),
+        }
+    }
+}
+
+impl From<UpdateClientInput> for shared::models::client::UpdateClientRequest {
+    fn from(c: UpdateClientInput) -> Self {
+        Self {
+            name: c.name,
+            client_type: c.client_type.map(|ct| ct.into()),
+            contacts: c.contacts.map(|cs| cs.into_iter().map(|contact| contact.into()).collect()),
+            billing_address: c.billing_address.map(|a| a.into()),
+            default_instructions: c.default_instructions,
+            required_report_types: c.required_report_types.map(|rts| rts.into_iter().map(|rt| rt.into()).collect()),
+            is_active: c.is_active,
+        }
+    }
+}
+
+impl From<PortalUserInput> for shared::models::client::AddPortalUserRequest {
+    fn from(u: PortalUserInput) -> Self {
+        Self {
+            user_id: u.user_id,
+            role: u.role.into(),
+            is_loan_production: u.is_loan_production,
+        }
+    }
+}
+
+impl From<shared::models::message::Message> for Message {
+    fn from(m: shared::models::message::Message) -> Self {
+        Self {
+            id: m.id,
+            appraisal_id: m.appraisal_id,
+            author_id: m.author_id,
+            author_role: m.author_role.into(),
+            body: m.body,
+            attachments: m.attachments.into_iter().map(|a| a.into()).collect(),
+            visibility: m.visibility.into(),
+            read_by: m.read_by.into_iter().map(|r| r.into()).collect(),
+            created_at: m.created_at,
+        }
+    }
+}
+
+impl From<shared::models::message::MessageAttachment> for MessageAttachment {
+    fn from(a: shared::models::message::MessageAttachment) -> Self {
+        Self {
+            id: a.id,
+            file_name: a.file_name,
+            content_type: a.content_type,
+            size_bytes: a.size_bytes,
+        }
+    }
+}
+
+impl From<shared::models::message::ReadReceipt> for ReadReceipt {
+    fn from(r: shared::models::message::ReadReceipt) -> Self {
+        Self {
+            user_id: r.user_id,
+            read_at: r.read_at,
+        }
+    }
+}
+
+impl From<shared::models::message::UnreadCount> for UnreadCount {
+    fn from(c: shared::models::message::UnreadCount) -> Self {
+        Self {
+            appraisal_id: c.appraisal_id,
+            unread: c.unread,
+        }
+    }
+}
+
+impl From<ModelParticipantRole> for ParticipantRole {
+    fn from(r: ModelParticipantRole) -> Self {
+        match r {
+            ModelParticipantRole::Client => ParticipantRole::Client,
+            ModelParticipantRole::Appraiser => ParticipantRole::Appraiser,
+            ModelParticipantRole::Reviewer => ParticipantRole::Reviewer,
+        }
+    }
+}
+
+impl From<ModelMessageVisibility> for MessageVisibility {
+    fn from(v: ModelMessageVisibility) -> Self {
+        match v {
+            ModelMessageVisibility::Internal => MessageVisibility::Internal,
+            ModelMessageVisibility::ClientVisible => MessageVisibility::ClientVisible,
+        }
+    }
+}
+
+impl From<MessageVisibility> for ModelMessageVisibility {
+    fn from(v: MessageVisibility) -> Self {
+        match v {
+            MessageVisibility::Internal => ModelMessageVisibility::Internal,
+            MessageVisibility::ClientVisible => ModelMessageVisibility::ClientVisible,
+        }
+    }
+}
+
+impl From<PostMessageInput> for shared::models::message::PostMessageRequest {
+    fn from(m: PostMessageInput) -> Self {
+        Self {
+            body: m.body,
+            visibility: m.visibility.into(),
+            attachments: m.attachments.map(|uploads| {
+                uploads
+                    .into_iter()
+                    .map(|u| shared::models::message::AttachmentUpload {
+                        file_name: u.file_name,
+                        content_type: u.content_type,
+                        content_base64: u.content_base64,
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
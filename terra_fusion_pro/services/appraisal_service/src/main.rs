@@ -0,0 +1,109 @@
+mod api;
+mod repository;
+mod service;
+mod graphql;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_cors::Cors;
+use actix_session::CookieSession;
+use actix_web::{web, App, HttpResponse, HttpServer, middleware};
+
+use shared::{
+    auth::{
+        middleware::AuthenticationMiddleware,
+        replit_auth::ReplitAuth,
+    },
+    db::Database,
+    config::Config,
+    notifications::{Notifier, SmtpConfig, SmtpMailer},
+    storage::{AttachmentStore, LocalAttachmentStore},
+    webhooks::WebhookDispatcher,
+};
+
+use crate::service::reminder_service::ReminderService;
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+    
+    // Load configuration
+    let config = Config::from_env().expect("Failed to load configuration");
+    
+    // Initialize database
+    let db = Arc::new(Database::connect(&config.database_url).await.expect("Failed to connect to database"));
+    log::info!("Connected to database");
+    
+    // Initialize Replit Auth for session handling
+    let replit_auth_config = shared::auth::replit_auth::ReplitAuthConfig {
+        client_id: config.replit_auth.client_id.clone(),
+        domain: config.replit_auth.domain.clone(),
+        discovery_url: "https://replit.com/oidc".to_string(),
+    };
+    let replit_auth = Arc::new(ReplitAuth::new(replit_auth_config));
+    
+    // Initialize attachment storage
+    let attachments: Arc<dyn AttachmentStore> = Arc::new(LocalAttachmentStore::new(&config.attachments_dir));
+    
+    // Initialize notifications and start background delivery
+    let smtp_config = SmtpConfig::from_env().expect("Failed to load SMTP configuration");
+    let mailer = Arc::new(SmtpMailer::new(&smtp_config).expect("Failed to configure SMTP mailer"));
+    let notifier = Arc::new(Notifier::new(db.clone(), mailer));
+    tokio::spawn(notifier.clone().run(Duration::from_secs(60)));
+    tokio::spawn(ReminderService::new(db.clone(), notifier.clone()).run(Duration::from_secs(15 * 60)));
+    
+    // Initialize outbound webhooks and start background delivery
+    let webhooks = Arc::new(WebhookDispatcher::new(db.clone(), config.is_development()).expect("Failed to configure webhook dispatcher"));
+    tokio::spawn(webhooks.clone().run(Duration::from_secs(15)));
+    
+    // Set up GraphQL schema
+    let schema = web::Data::new(graphql::create_schema(db.clone(), attachments.clone()));
+    
+    // Start HTTP server
+    log::info!("Starting Appraisal Service on {}:{}", config.host, config.port);
+    
+    HttpServer::new(move || {
+        let cors = Cors::default()
+            .allow_any_origin()
+            .allow_any_method()
+            .allow_any_header()
+            .max_age(3600);
+            
+        App::new()
+            .wrap(middleware::Logger::default())
+            .wrap(cors)
+            .wrap(middleware::NormalizePath::trim())
+            .wrap(CookieSession::signed(&[0; 32]) // In production, use a proper key
+                .secure(false)) // In production, set to true for HTTPS
+            .wrap(AuthenticationMiddleware::new(replit_auth.clone()))
+            .app_data(web::Data::new(db.clone()))
+            .app_data(web::Data::new(attachments.clone()))
+            .app_data(web::Data::new(notifier.clone()))
+            .app_data(web::Data::new(webhooks.clone()))
+            .app_data(schema.clone())
+            // Add health check endpoint
+            .route("/health", web::get().to(health_check))
+            // Add API routes
+            .service(
+                web::scope("/api/v1")
+                    .configure(api::configure_routes)
+            )
+            // Add GraphQL endpoint
+            .service(
+                web::scope("/graphql")
+                    .configure(graphql::configure_routes)
+            )
+    })
+    .bind((config.host.as_str(), config.port))?
+    .run()
+    .await
+}
+
+/// Health check endpoint
+async fn health_check() -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "healthy",
+        "service": "appraisal-service"
+    }))
+}
@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::air::{AirViolation, PanelMember, PanelRotationPolicy};
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+/// Repository for Appraiser Independence Requirements records: violations, panels and
+/// rotation policies
+pub struct AirRepository {
+    db: Arc<Database>,
+}
+
+impl AirRepository {
+    /// Create a new AIR repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Record a blocked attempt
+    pub async fn record_violation(&self, violation: &AirViolation) -> AppResult<()> {
+        sqlx::query(
+            "INSERT INTO air_violations (id, client_id, user_id, appraisal_id, action, detail, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)"
+        )
+        .bind(violation.id)
+        .bind(violation.client_id)
+        .bind(&violation.user_id)
+        .bind(violation.appraisal_id)
+        .bind(encode_enum(&violation.action))
+        .bind(&violation.detail)
+        .bind(violation.created_at)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record AIR violation: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Get a client's violations within a time window, newest first
+    pub async fn violations_for_client(
+        &self,
+        client_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> AppResult<Vec<AirViolation>> {
+        let rows = sqlx::query(
+            "SELECT * FROM air_violations
+             WHERE client_id = $1
+               AND ($2::timestamptz IS NULL OR created_at >= $2)
+               AND ($3::timestamptz IS NULL OR created_at <= $3)
+             ORDER BY created_at DESC"
+        )
+        .bind(client_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch AIR violations: {}", e)))?;
+
+        rows.iter().map(row_to_violation).collect()
+    }
+
+    /// Get a client's rotation policy, if one was set
+    pub async fn get_policy(&self, client_id: Uuid) -> AppResult<Option<PanelRotationPolicy>> {
+        let row = sqlx::query("SELECT * FROM panel_rotation_policies WHERE client_id = $1")
+            .bind(client_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch rotation policy: {}", e)))?;
+
+        row.as_ref().map(row_to_policy).transpose()
+    }
+
+    /// Insert or replace a client's rotation policy
+    pub async fn save_policy(&self, policy: &PanelRotationPolicy) -> AppResult<PanelRotationPolicy> {
+        let row = sqlx::query(
+            "INSERT INTO panel_rotation_policies (client_id, mode, updated_at)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (client_id) DO UPDATE SET mode = EXCLUDED.mode, updated_at = EXCLUDED.updated_at
+             RETURNING *"
+        )
+        .bind(policy.client_id)
+        .bind(encode_enum(&policy.mode))
+        .bind(policy.updated_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to save rotation policy: {}", e)))?;
+
+        row_to_policy(&row)
+    }
+
+    /// Get a client's panel in rotation order: least recently assigned first, then by seniority
+    pub async fn panel_members(&self, client_id: Uuid) -> AppResult<Vec<PanelMember>> {
+        let rows = sqlx::query(
+            "SELECT * FROM panel_members WHERE client_id = $1
+             ORDER BY last_assigned_at ASC NULLS FIRST, added_at ASC"
+        )
+        .bind(client_id)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch panel members: {}", e)))?;
+
+        rows.iter().map(row_to_member).collect()
+    }
+
+    /// Add an appraiser to a panel, reactivating them if they were removed
+    pub async fn add_panel_member(&self, member: &PanelMember) -> AppResult<PanelMember> {
+        let row = sqlx::query(
+            "INSERT INTO panel_members
+                (client_id, appraiser_id, is_active, assignment_count, last_assigned_at, added_at)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (client_id, appraiser_id) DO UPDATE SET is_active = TRUE
+             RETURNING *"
+        )
+        .bind(member.client_id)
+        .bind(member.appraiser_id)
+        .bind(member.is_active)
+        .bind(member.assignment_count)
+        .bind(member.last_assigned_at)
+        .bind(member.added_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to add panel member: {}", e)))?;
+
+        row_to_member(&row)
+    }
+
+    /// Take an appraiser off a panel, keeping their assignment history
+    pub async fn deactivate_panel_member(&self, client_id: Uuid, appraiser_id: Uuid) -> AppResult<()> {
+        let result = sqlx::query(
+            "UPDATE panel_members SET is_active = FALSE WHERE client_id = $1 AND appraiser_id = $2"
+        )
+        .bind(client_id)
+        .bind(appraiser_id)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to remove panel member: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!(
+                "Appraiser {} is not on the panel of client {}",
+                appraiser_id, client_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Move a panel member to the back of the rotation after an assignment
+    pub async fn record_assignment(&self, client_id: Uuid, appraiser_id: Uuid, at: DateTime<Utc>) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE panel_members
+             SET assignment_count = assignment_count + 1, last_assigned_at = $3
+             WHERE client_id = $1 AND appraiser_id = $2"
+        )
+        .bind(client_id)
+        .bind(appraiser_id)
+        .bind(at)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record panel assignment: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// Convert a database row to a violation model
+fn row_to_violation(row: &PgRow) -> AppResult<AirViolation> {
+    Ok(AirViolation {
+        id: column(row, "id")?,
+        client_id: column(row, "client_id")?,
+        user_id: column(row, "user_id")?,
+        appraisal_id: column(row, "appraisal_id")?,
+        action: decode_enum(&column::<String>(row, "action")?)?,
+        detail: column(row, "detail")?,
+        created_at: column(row, "created_at")?,
+    })
+}
+
+/// Convert a database row to a rotation policy model
+fn row_to_policy(row: &PgRow) -> AppResult<PanelRotationPolicy> {
+    Ok(PanelRotationPolicy {
+        client_id: column(row, "client_id")?,
+        mode: decode_enum(&column::<String>(row, "mode")?)?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to a panel member model
+fn row_to_member(row: &PgRow) -> AppResult<PanelMember> {
+    Ok(PanelMember {
+        client_id: column(row, "client_id")?,
+        appraiser_id: column(row, "appraiser_id")?,
+        is_active: column(row, "is_active")?,
+        assignment_count: column(row, "assignment_count")?,
+        last_assigned_at: column(row, "last_assigned_at")?,
+        added_at: column(row, "added_at")?,
+    })
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::{
+    Appraisal, AppraisalFilter, AppraisalStatus, AppraisalSummary, AppraiserWorkload, StatusCount,
+};
+use shared::models::property::Address;
+use shared::utils::format::{format_address_single_line, format_name};
+use sqlx::postgres::{PgArguments, PgRow};
+use sqlx::query::Query;
+use sqlx::types::Json;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+/// WHERE clause shared by the pipeline queries; parameters are bound by `bind_filter`
+const PIPELINE_FILTER: &str = "
+    ($1::text IS NULL OR a.status = $1)
+    AND ($2::timestamptz IS NULL OR a.due_date >= $2)
+    AND ($3::timestamptz IS NULL OR a.due_date <= $3)
+    AND ($4::uuid IS NULL OR a.client_id = $4)
+    AND ($5::uuid IS NULL OR a.appraiser_id = $5)
+    AND ($6::boolean IS NULL OR COALESCE(a.due_date < $8 AND a.status <> ALL($7), false) = $6)";
+
+/// Expression that is true for appraisals past their due date and still open
+const OVERDUE: &str = "a.due_date < $8 AND a.status <> ALL($7)";
+
+/// Repository for appraisal operations
+#[derive(Clone)]
+pub struct AppraisalRepository {
+    db: Arc<Database>,
+}
+
+impl AppraisalRepository {
+    /// Create a new appraisal repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get an appraisal by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<Appraisal> {
+        let row = sqlx::query("SELECT * FROM appraisals WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch appraisal: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_appraisal(&row),
+            None => Err(AppError::NotFound(format!("Appraisal not found with ID: {}", id))),
+        }
+    }
+
+    /// Whether a user is the reviewer of a report on the appraisal's property by its appraiser
+    pub async fn is_report_reviewer(&self, appraisal: &Appraisal, user_id: &str) -> AppResult<bool> {
+        let row = sqlx::query(
+            "SELECT EXISTS (
+                 SELECT 1 FROM reports
+                  WHERE property_id = $1 AND appraiser_id = $2 AND reviewer_id::text = $3
+             ) AS present"
+        )
+        .bind(appraisal.property_id)
+        .bind(appraisal.appraiser_id)
+        .bind(user_id)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to check report reviewer: {}", e)))?;
+
+        column(&row, "present")
+    }
+
+    /// Set the status of an appraisal
+    pub async fn update_status(&self, id: Uuid, status: &AppraisalStatus) -> AppResult<Appraisal> {
+        let row = sqlx::query(
+            "UPDATE appraisals SET status = $2, updated_at = $3 WHERE id = $1 RETURNING *"
+        )
+        .bind(id)
+        .bind(encode_enum(status))
+        .bind(Utc::now())
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to update appraisal status: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_appraisal(&row),
+            None => Err(AppError::NotFound(format!("Appraisal not found with ID: {}", id))),
+        }
+    }
+
+    /// Assign an appraiser and move the appraisal to `Assigned`
+    pub async fn assign_appraiser(&self, id: Uuid, appraiser_id: Uuid) -> AppResult<Appraisal> {
+        let row = sqlx::query(
+            "UPDATE appraisals SET appraiser_id = $2, status = $3, updated_at = $4 WHERE id = $1 RETURNING *"
+        )
+        .bind(id)
+        .bind(appraiser_id)
+        .bind(encode_enum(&AppraisalStatus::Assigned))
+        .bind(Utc::now())
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to assign appraiser: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_appraisal(&row),
+            None => Err(AppError::NotFound(format!("Appraisal not found with ID: {}", id))),
+        }
+    }
+
+    /// Get open, assigned appraisals due within a time window
+    pub async fn assigned_due_between(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> AppResult<Vec<Appraisal>> {
+        let closed: Vec<String> = AppraisalStatus::ALL
+            .iter()
+            .filter(|status| status.is_closed())
+            .map(encode_enum)
+            .collect();
+
+        let rows = sqlx::query(
+            "SELECT * FROM appraisals
+             WHERE appraiser_id IS NOT NULL AND due_date >= $1 AND due_date < $2 AND status <> ALL($3)
+             ORDER BY due_date"
+        )
+        .bind(from)
+        .bind(to)
+        .bind(closed)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch appraisals due soon: {}", e)))?;
+
+        rows.iter().map(row_to_appraisal).collect()
+    }
+
+    /// List pipeline summaries matching a filter, soonest due first
+    pub async fn list_summaries(&self, filter: &AppraisalFilter, now: DateTime<Utc>) -> AppResult<Vec<AppraisalSummary>> {
+        let sql = format!(
+            "SELECT a.id, a.reference_number, c.name AS client_name, p.address,
+                    u.first_name, u.last_name, a.status, a.due_date, a.appraisal_type, a.created_at
+             FROM appraisals a
+             LEFT JOIN clients c ON c.id = a.client_id
+             LEFT JOIN properties p ON p.id = a.property_id
+             LEFT JOIN users u ON u.id = a.appraiser_id::text
+             WHERE {}
+             ORDER BY a.due_date ASC NULLS LAST, a.created_at ASC",
+            PIPELINE_FILTER
+        );
+
+        let rows = bind_filter(sqlx::query(&sql), filter, now)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch appraisal pipeline: {}", e)))?;
+
+        rows.iter().map(row_to_summary).collect()
+    }
+
+    /// Count appraisals matching a filter per status; statuses without appraisals are omitted
+    pub async fn count_by_status(&self, filter: &AppraisalFilter, now: DateTime<Utc>) -> AppResult<Vec<StatusCount>> {
+        let sql = format!(
+            "SELECT a.status, COUNT(*) AS count FROM appraisals a WHERE {} GROUP BY a.status",
+            PIPELINE_FILTER
+        );
+
+        let rows = bind_filter(sqlx::query(&sql), filter, now)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to count appraisals by status: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(StatusCount {
+                    status: decode_enum(&column::<String>(row, "status")?)?,
+                    count: column(row, "count")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Count overdue appraisals matching a filter
+    pub async fn count_overdue(&self, filter: &AppraisalFilter, now: DateTime<Utc>) -> AppResult<i64> {
+        let sql = format!(
+            "SELECT COUNT(*) AS count FROM appraisals a WHERE {} AND {}",
+            PIPELINE_FILTER, OVERDUE
+        );
+
+        let row = bind_filter(sqlx::query(&sql), filter, now)
+            .fetch_one(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to count overdue appraisals: {}", e)))?;
+
+        column(&row, "count")
+    }
+
+    /// Open and overdue counts per appraiser for appraisals matching a filter, busiest first
+    pub async fn workload_by_appraiser(&self, filter: &AppraisalFilter, now: DateTime<Utc>) -> AppResult<Vec<AppraiserWorkload>> {
+        let sql = format!(
+            "SELECT a.appraiser_id, u.first_name, u.last_name,
+                    COUNT(*) AS open, COUNT(*) FILTER (WHERE {}) AS overdue
+             FROM appraisals a
+             LEFT JOIN users u ON u.id = a.appraiser_id::text
+             WHERE {} AND a.status <> ALL($7)
+             GROUP BY a.appraiser_id, u.first_name, u.last_name
+             ORDER BY open DESC, overdue DESC",
+            OVERDUE, PIPELINE_FILTER
+        );
+
+        let rows = bind_filter(sqlx::query(&sql), filter, now)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to count appraiser workload: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(AppraiserWorkload {
+                    appraiser_id: column(row, "appraiser_id")?,
+                    appraiser_name: display_name(column(row, "first_name")?, column(row, "last_name")?),
+                    open: column(row, "open")?,
+                    overdue: column(row, "overdue")?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Bind the parameters referenced by `PIPELINE_FILTER` and `OVERDUE`
+fn bind_filter<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    filter: &AppraisalFilter,
+    now: DateTime<Utc>,
+) -> Query<'q, Postgres, PgArguments> {
+    let closed: Vec<String> = AppraisalStatus::ALL
+        .iter()
+        .filter(|status| status.is_closed())
+        .map(encode_enum)
+        .collect();
+
+    query
+        .bind(filter.status.as_ref().map(encode_enum))
+        .bind(filter.due_from)
+        .bind(filter.due_to)
+        .bind(filter.client_id)
+        .bind(filter.appraiser_id)
+        .bind(filter.overdue)
+        .bind(closed)
+        .bind(now)
+}
+
+/// Join a user's first and last name, if either is known
+fn display_name(first_name: Option<String>, last_name: Option<String>) -> Option<String> {
+    match (first_name, last_name) {
+        (Some(first), Some(last)) => Some(format_name(&first, &last)),
+        (Some(name), None) | (None, Some(name)) => Some(name),
+        (None, None) => None,
+    }
+}
+
+/// Convert a pipeline query row to an appraisal summary. The client and property are left
+/// joined, so an appraisal whose records are missing is still listed.
+fn row_to_summary(row: &PgRow) -> AppResult<AppraisalSummary> {
+    let address = column::<Option<Json<Address>>>(row, "address")?.map(|address| address.0);
+
+    Ok(AppraisalSummary {
+        id: column(row, "id")?,
+        reference_number: column(row, "reference_number")?,
+        client_name: column(row, "client_name")?,
+        property_address: address.map(|address| {
+            format_address_single_line(
+                &address.street1,
+                address.street2.as_deref(),
+                &address.city,
+                &address.state,
+                &address.postal_code,
+            )
+        }),
+        appraiser_name: display_name(column(row, "first_name")?, column(row, "last_name")?),
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        due_date: column(row, "due_date")?,
+        appraisal_type: decode_enum(&column::<String>(row, "appraisal_type")?)?,
+        created_at: column(row, "created_at")?,
+    })
+}
+
+/// Convert a database row to an appraisal model
+pub fn row_to_appraisal(row: &PgRow) -> AppResult<Appraisal> {
+    Ok(Appraisal {
+        id: column(row, "id")?,
+        reference_number: column(row, "reference_number")?,
+        client_id: column(row, "client_id")?,
+        property_id: column(row, "property_id")?,
+        appraiser_id: column(row, "appraiser_id")?,
+        report_id: column(row, "report_id")?,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        due_date: column(row, "due_date")?,
+        purpose: decode_enum(&column::<String>(row, "purpose")?)?,
+        appraisal_type: decode_enum(&column::<String>(row, "appraisal_type")?)?,
+        instructions: column(row, "instructions")?,
+        fee: column(row, "fee")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+        completed_at: column(row, "completed_at")?,
+    })
+}
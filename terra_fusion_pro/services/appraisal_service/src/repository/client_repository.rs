@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::client::{Client, ClientPortalUser};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Repository for client organizations and their portal users
+#[derive(Clone)]
+pub struct ClientRepository {
+    db: Arc<Database>,
+}
+
+impl ClientRepository {
+    /// Create a new client repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get all clients ordered by name
+    pub async fn get_all(&self, include_inactive: bool) -> AppResult<Vec<Client>> {
+        let rows = sqlx::query(
+            "SELECT * FROM clients WHERE ($1 OR is_active) ORDER BY name"
+        )
+        .bind(include_inactive)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch clients: {}", e)))?;
+
+        rows.iter().map(row_to_client).collect()
+    }
+
+    /// Get a client by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<Client> {
+        let row = sqlx::query("SELECT * FROM clients WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch client: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_client(&row),
+            None => Err(AppError::NotFound(format!("Client not found with ID: {}", id))),
+        }
+    }
+
+    /// Insert a new client
+    pub async fn create(&self, client: &Client) -> AppResult<Client> {
+        let row = sqlx::query(
+            "INSERT INTO clients
+                (id, name, client_type, contacts, billing_address, default_instructions,
+                 required_report_types, is_active, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING *"
+        )
+        .bind(client.id)
+        .bind(&client.name)
+        .bind(encode_enum(&client.client_type))
+        .bind(Json(&client.contacts))
+        .bind(client.billing_address.as_ref().map(Json))
+        .bind(&client.default_instructions)
+        .bind(Json(&client.required_report_types))
+        .bind(client.is_active)
+        .bind(client.created_at)
+        .bind(client.updated_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create client: {}", e)))?;
+
+        row_to_client(&row)
+    }
+
+    /// Persist changes to an existing client
+    pub async fn update(&self, client: &Client) -> AppResult<Client> {
+        let row = sqlx::query(
+            "UPDATE clients
+             SET name = $2, client_type = $3, contacts = $4, billing_address = $5,
+                 default_instructions = $6, required_report_types = $7, is_active = $8, updated_at = $9
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(client.id)
+        .bind(&client.name)
+        .bind(encode_enum(&client.client_type))
+        .bind(Json(&client.contacts))
+        .bind(client.billing_address.as_ref().map(Json))
+        .bind(&client.default_instructions)
+        .bind(Json(&client.required_report_types))
+        .bind(client.is_active)
+        .bind(client.updated_at)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to update client: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_client(&row),
+            None => Err(AppError::NotFound(format!("Client not found with ID: {}", client.id))),
+        }
+    }
+
+    /// Delete a client
+    pub async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM clients WHERE id = $1")
+            .bind(id)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete client: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Client not found with ID: {}", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Count appraisals ordered by a client
+    pub async fn count_appraisals(&self, id: Uuid) -> AppResult<i64> {
+        let row = sqlx::query("SELECT COUNT(*) AS count FROM appraisals WHERE client_id = $1")
+            .bind(id)
+            .fetch_one(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to count client appraisals: {}", e)))?;
+
+        column(&row, "count")
+    }
+
+    /// Get the portal users of a client
+    pub async fn portal_users(&self, client_id: Uuid) -> AppResult<Vec<ClientPortalUser>> {
+        let rows = sqlx::query(
+            "SELECT * FROM client_portal_users WHERE client_id = $1 ORDER BY created_at"
+        )
+        .bind(client_id)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch portal users: {}", e)))?;
+
+        rows.iter().map(row_to_portal_user).collect()
+    }
+
+    /// Find the portal membership of a user, if any
+    pub async fn find_portal_user(&self, user_id: &str) -> AppResult<Option<ClientPortalUser>> {
+        let row = sqlx::query("SELECT * FROM client_portal_users WHERE user_id = $1 LIMIT 1")
+            .bind(user_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch portal user: {}", e)))?;
+
+        row.as_ref().map(row_to_portal_user).transpose()
+    }
+
+    /// Add a portal user to a client, updating the role and loan-production flag if already present
+    pub async fn add_portal_user(&self, user: &ClientPortalUser) -> AppResult<ClientPortalUser> {
+        let row = sqlx::query(
+            "INSERT INTO client_portal_users (id, client_id, user_id, role, is_loan_production, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (client_id, user_id)
+             DO UPDATE SET role = EXCLUDED.role, is_loan_production = EXCLUDED.is_loan_production
+             RETURNING *"
+        )
+        .bind(user.id)
+        .bind(user.client_id)
+        .bind(&user.user_id)
+        .bind(encode_enum(&user.role))
+        .bind(user.is_loan_production)
+        .bind(user.created_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to add portal user: {}", e)))?;
+
+        row_to_portal_user(&row)
+    }
+
+    /// Remove a portal user from a client
+    pub async fn remove_portal_user(&self, client_id: Uuid, user_id: &str) -> AppResult<()> {
+        let result = sqlx::query(
+            "DELETE FROM client_portal_users WHERE client_id = $1 AND user_id = $2"
+        )
+        .bind(client_id)
+        .bind(user_id)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to remove portal user: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Portal user {} not found for client {}", user_id, client_id)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert a database row to a client model
+fn row_to_client(row: &PgRow) -> AppResult<Client> {
+    Ok(Client {
+        id: column(row, "id")?,
+        name: column(row, "name")?,
+        client_type: decode_enum(&column::<String>(row, "client_type")?)?,
+        contacts: column::<Json<_>>(row, "contacts")?.0,
+        billing_address: column::<Option<Json<_>>>(row, "billing_address")?.map(|a| a.0),
+        default_instructions: column(row, "default_instructions")?,
+        required_report_types: column::<Json<_>>(row, "required_report_types")?.0,
+        is_active: column(row, "is_active")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to a portal user model
+fn row_to_portal_user(row: &PgRow) -> AppResult<ClientPortalUser> {
+    Ok(ClientPortalUser {
+        id: column(row, "id")?,
+        client_id: column(row, "client_id")?,
+        user_id: column(row, "user_id")?,
+        role: decode_enum(&column::<String>(row, "role")?)?,
+        is_loan_production: column(row, "is_loan_production")?,
+        created_at: column(row, "created_at")?,
+    })
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::engagement::{EngagementLetter, EngagementStatus};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Repository for engagement letters
+pub struct EngagementRepository {
+    db: Arc<Database>,
+}
+
+impl EngagementRepository {
+    /// Create a new engagement letter repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get every letter version of an appraisal, oldest first
+    pub async fn list_for_appraisal(&self, appraisal_id: Uuid) -> AppResult<Vec<EngagementLetter>> {
+        let rows = sqlx::query("SELECT * FROM engagement_letters WHERE appraisal_id = $1 ORDER BY version")
+            .bind(appraisal_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch engagement letters: {}", e)))?;
+
+        rows.iter().map(row_to_letter).collect()
+    }
+
+    /// Get the newest letter version of an appraisal, if any
+    pub async fn latest(&self, appraisal_id: Uuid) -> AppResult<Option<EngagementLetter>> {
+        let row = sqlx::query(
+            "SELECT * FROM engagement_letters WHERE appraisal_id = $1 ORDER BY version DESC LIMIT 1"
+        )
+        .bind(appraisal_id)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch engagement letter: {}", e)))?;
+
+        row.as_ref().map(row_to_letter).transpose()
+    }
+
+    /// Insert a new letter version, superseding earlier letters that were not accepted.
+    /// With `update_terms` the appraisal's fee and due date are set to the letter's in the
+    /// same transaction.
+    pub async fn issue(&self, letter: &EngagementLetter, update_terms: bool) -> AppResult<EngagementLetter> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        sqlx::query("UPDATE engagement_letters SET status = $2 WHERE appraisal_id = $1 AND status IN ($3, $4)")
+            .bind(letter.appraisal_id)
+            .bind(encode_enum(&EngagementStatus::Superseded))
+            .bind(encode_enum(&EngagementStatus::Pending))
+            .bind(encode_enum(&EngagementStatus::Declined))
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to supersede engagement letters: {}", e)))?;
+
+        if update_terms {
+            sqlx::query("UPDATE appraisals SET fee = $2, due_date = $3, updated_at = $4 WHERE id = $1")
+                .bind(letter.appraisal_id)
+                .bind(letter.fee)
+                .bind(letter.due_date)
+                .bind(letter.created_at)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| AppError::Database(format!("Failed to update appraisal terms: {}", e)))?;
+        }
+
+        let row = sqlx::query(
+            "INSERT INTO engagement_letters
+                (id, appraisal_id, appraiser_id, version, scope_of_work, fee, due_date, intended_use,
+                 intended_users, body, status, counter_offer, decline_reason, responded_at, responded_ip, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+             RETURNING *"
+        )
+        .bind(letter.id)
+        .bind(letter.appraisal_id)
+        .bind(letter.appraiser_id)
+        .bind(letter.version)
+        .bind(&letter.scope_of_work)
+        .bind(letter.fee)
+        .bind(letter.due_date)
+        .bind(&letter.intended_use)
+        .bind(Json(&letter.intended_users))
+        .bind(&letter.body)
+        .bind(encode_enum(&letter.status))
+        .bind(letter.counter_offer.as_ref().map(Json))
+        .bind(&letter.decline_reason)
+        .bind(letter.responded_at)
+        .bind(&letter.responded_ip)
+        .bind(letter.created_at)
+        .fetch_one(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create engagement letter: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit engagement letter: {}", e)))?;
+
+        row_to_letter(&row)
+    }
+
+    /// Record the appraiser's response to a pending letter
+    pub async fn save_response(&self, letter: &EngagementLetter) -> AppResult<EngagementLetter> {
+        let row = sqlx::query(
+            "UPDATE engagement_letters
+             SET status = $2, counter_offer = $3, decline_reason = $4, responded_at = $5, responded_ip = $6
+             WHERE id = $1 AND status = $7
+             RETURNING *"
+        )
+        .bind(letter.id)
+        .bind(encode_enum(&letter.status))
+        .bind(letter.counter_offer.as_ref().map(Json))
+        .bind(&letter.decline_reason)
+        .bind(letter.responded_at)
+        .bind(&letter.responded_ip)
+        .bind(encode_enum(&EngagementStatus::Pending))
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record engagement response: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_letter(&row),
+            None => Err(AppError::Conflict(
+                "Engagement letter has already been responded to".to_string(),
+            )),
+        }
+    }
+}
+
+/// Convert a database row to an engagement letter model
+fn row_to_letter(row: &PgRow) -> AppResult<EngagementLetter> {
+    Ok(EngagementLetter {
+        id: column(row, "id")?,
+        appraisal_id: column(row, "appraisal_id")?,
+        appraiser_id: column(row, "appraiser_id")?,
+        version: column(row, "version")?,
+        scope_of_work: column(row, "scope_of_work")?,
+        fee: column(row, "fee")?,
+        due_date: column(row, "due_date")?,
+        intended_use: column(row, "intended_use")?,
+        intended_users: column::<Json<_>>(row, "intended_users")?.0,
+        body: column(row, "body")?,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        counter_offer: column::<Option<Json<_>>>(row, "counter_offer")?.map(|c| c.0),
+        decline_reason: column(row, "decline_reason")?,
+        responded_at: column(row, "responded_at")?,
+        responded_ip: column(row, "responded_ip")?,
+        created_at: column(row, "created_at")?,
+    })
+}
@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, violates_constraint, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::AppraisalStatus;
+use shared::models::inspection::{
+    AppointmentStatus, AvailabilitySlot, CalendarFeed, InspectionAppointment,
+};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Exclusion constraint keeping an appraiser's scheduled appointments from overlapping
+const APPOINTMENT_OVERLAP: &str = "inspection_appointments_no_overlap";
+
+/// Exclusion constraint keeping an appraiser's availability slots from overlapping
+const SLOT_OVERLAP: &str = "appraiser_availability_slots_no_overlap";
+
+/// Repository for inspection appointments, availability slots and calendar feeds
+#[derive(Clone)]
+pub struct InspectionRepository {
+    db: Arc<Database>,
+}
+
+impl InspectionRepository {
+    /// Create a new inspection repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Insert an appointment and move its appraisal from `Assigned` to `Scheduled`
+    pub async fn book_appointment(&self, appointment: &InspectionAppointment) -> AppResult<InspectionAppointment> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query(
+            "INSERT INTO inspection_appointments
+                (id, appraisal_id, appraiser_id, scheduled_start, scheduled_end, timezone, contact,
+                 access_notes, status, reschedule_history, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+             RETURNING *"
+        )
+        .bind(appointment.id)
+        .bind(appointment.appraisal_id)
+        .bind(appointment.appraiser_id)
+        .bind(appointment.scheduled_start)
+        .bind(appointment.scheduled_end)
+        .bind(&appointment.timezone)
+        .bind(Json(&appointment.contact))
+        .bind(&appointment.access_notes)
+        .bind(encode_enum(&appointment.status))
+        .bind(Json(&appointment.reschedule_history))
+        .bind(appointment.created_at)
+        .bind(appointment.updated_at)
+        .fetch_one(&mut tx)
+        .await
+        .map_err(|e| appointment_error(e, "Failed to create inspection appointment"))?;
+
+        sqlx::query(
+            "UPDATE appraisals SET status = $2, updated_at = $3 WHERE id = $1 AND status = $4"
+        )
+        .bind(appointment.appraisal_id)
+        .bind(encode_enum(&AppraisalStatus::Scheduled))
+        .bind(Utc::now())
+        .bind(encode_enum(&AppraisalStatus::Assigned))
+        .execute(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to mark appraisal as scheduled: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit appointment: {}", e)))?;
+
+        row_to_appointment(&row)
+    }
+
+    /// Get an appointment by ID
+    pub async fn get_appointment(&self, id: Uuid) -> AppResult<InspectionAppointment> {
+        let row = sqlx::query("SELECT * FROM inspection_appointments WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch inspection appointment: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_appointment(&row),
+            None => Err(AppError::NotFound(format!("Inspection appointment not found with ID: {}", id))),
+        }
+    }
+
+    /// List all appointments for an appraisal, earliest first
+    pub async fn list_for_appraisal(&self, appraisal_id: Uuid) -> AppResult<Vec<InspectionAppointment>> {
+        let rows = sqlx::query(
+            "SELECT * FROM inspection_appointments WHERE appraisal_id = $1 ORDER BY scheduled_start"
+        )
+        .bind(appraisal_id)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch inspection appointments: {}", e)))?;
+
+        rows.iter().map(row_to_appointment).collect()
+    }
+
+    /// List an appraiser's appointments ending after the given time, earliest first
+    pub async fn list_for_appraiser(
+        &self,
+        appraiser_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> AppResult<Vec<InspectionAppointment>> {
+        let rows = sqlx::query(
+            "SELECT * FROM inspection_appointments
+             WHERE appraiser_id = $1 AND scheduled_end >= $2
+             ORDER BY scheduled_start"
+        )
+        .bind(appraiser_id)
+        .bind(since)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch inspection appointments: {}", e)))?;
+
+        rows.iter().map(row_to_appointment).collect()
+    }
+
+    /// Find the appraiser's active appointments overlapping a time range
+    pub async fn find_overlapping_appointments(
+        &self,
+        appraiser_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        exclude_id: Option<Uuid>,
+    ) -> AppResult<Vec<InspectionAppointment>> {
+        let rows = sqlx::query(
+            "SELECT * FROM inspection_appointments
+             WHERE appraiser_id = $1
+               AND status = $2
+               AND scheduled_start < $4
+               AND scheduled_end > $3
+               AND ($5::UUID IS NULL OR id <> $5)
+             ORDER BY scheduled_start"
+        )
+        .bind(appraiser_id)
+        .bind(encode_enum(&AppointmentStatus::Scheduled))
+        .bind(start)
+        .bind(end)
+        .bind(exclude_id)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to check appointment conflicts: {}", e)))?;
+
+        rows.iter().map(row_to_appointment).collect()
+    }
+
+    /// Persist changes to an existing appointment
+    pub async fn update_appointment(&self, appointment: &InspectionAppointment) -> AppResult<InspectionAppointment> {
+        let row = sqlx::query(
+            "UPDATE inspection_appointments
+             SET scheduled_start = $2, scheduled_end = $3, contact = $4, access_notes = $5,
+                 status = $6, reschedule_history = $7, updated_at = $8
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(appointment.id)
+        .bind(appointment.scheduled_start)
+        .bind(appointment.scheduled_end)
+        .bind(Json(&appointment.contact))
+        .bind(&appointment.access_notes)
+        .bind(encode_enum(&appointment.status))
+        .bind(Json(&appointment.reschedule_history))
+        .bind(appointment.updated_at)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| appointment_error(e, "Failed to update inspection appointment"))?;
+
+        match row {
+            Some(row) => row_to_appointment(&row),
+            None => Err(AppError::NotFound(format!("Inspection appointment not found with ID: {}", appointment.id))),
+        }
+    }
+
+    /// Insert an availability slot
+    pub async fn create_slot(&self, slot: &AvailabilitySlot) -> AppResult<AvailabilitySlot> {
+        let row = sqlx::query(
+            "INSERT INTO appraiser_availability_slots (id, appraiser_id, starts_at, ends_at, timezone, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             RETURNING *"
+        )
+        .bind(slot.id)
+        .bind(slot.appraiser_id)
+        .bind(slot.starts_at)
+        .bind(slot.ends_at)
+        .bind(&slot.timezone)
+        .bind(slot.created_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| {
+            if violates_constraint(&e, SLOT_OVERLAP) {
+                AppError::Conflict("Availability overlaps an existing slot".to_string())
+            } else {
+                AppError::Database(format!("Failed to create availability slot: {}", e))
+            }
+        })?;
+
+        row_to_slot(&row)
+    }
+
+    /// List an appraiser's availability slots ending after the given time
+    pub async fn list_slots(&self, appraiser_id: Uuid, since: DateTime<Utc>) -> AppResult<Vec<AvailabilitySlot>> {
+        let rows = sqlx::query(
+            "SELECT * FROM appraiser_availability_slots
+             WHERE appraiser_id = $1 AND ends_at >= $2
+             ORDER BY starts_at"
+        )
+        .bind(appraiser_id)
+        .bind(since)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch availability slots: {}", e)))?;
+
+        rows.iter().map(row_to_slot).collect()
+    }
+
+    /// Find the appraiser's availability slots overlapping a time range
+    pub async fn find_overlapping_slots(
+        &self,
+        appraiser_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Vec<AvailabilitySlot>> {
+        let rows = sqlx::query(
+            "SELECT * FROM appraiser_availability_slots
+             WHERE appraiser_id = $1 AND starts_at < $3 AND ends_at > $2
+             ORDER BY starts_at"
+        )
+        .bind(appraiser_id)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to check availability conflicts: {}", e)))?;
+
+        rows.iter().map(row_to_slot).collect()
+    }
+
+    /// Find a slot that fully covers a time range, if any
+    pub async fn find_covering_slot(
+        &self,
+        appraiser_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> AppResult<Option<AvailabilitySlot>> {
+        let row = sqlx::query(
+            "SELECT * FROM appraiser_availability_slots
+             WHERE appraiser_id = $1 AND starts_at <= $2 AND ends_at >= $3
+             LIMIT 1"
+        )
+        .bind(appraiser_id)
+        .bind(start)
+        .bind(end)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch availability slot: {}", e)))?;
+
+        row.as_ref().map(row_to_slot).transpose()
+    }
+
+    /// Delete an availability slot
+    pub async fn delete_slot(&self, appraiser_id: Uuid, slot_id: Uuid) -> AppResult<()> {
+        let result = sqlx::query(
+            "DELETE FROM appraiser_availability_slots WHERE id = $1 AND appraiser_id = $2"
+        )
+        .bind(slot_id)
+        .bind(appraiser_id)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to delete availability slot: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Availability slot not found with ID: {}", slot_id)));
+        }
+
+        Ok(())
+    }
+
+    /// Create or replace the calendar feed token for an appraiser
+    pub async fn upsert_calendar_feed(&self, appraiser_id: Uuid, token: &str) -> AppResult<CalendarFeed> {
+        let row = sqlx::query(
+            "INSERT INTO appraiser_calendar_feeds (appraiser_id, token, created_at)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (appraiser_id)
+             DO UPDATE SET token = EXCLUDED.token, created_at = EXCLUDED.created_at
+             RETURNING *"
+        )
+        .bind(appraiser_id)
+        .bind(token)
+        .bind(Utc::now())
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to save calendar feed: {}", e)))?;
+
+        row_to_feed(&row)
+    }
+
+    /// Look up a calendar feed by its token
+    pub async fn find_calendar_feed(&self, token: &str) -> AppResult<Option<CalendarFeed>> {
+        let row = sqlx::query("SELECT * FROM appraiser_calendar_feeds WHERE token = $1")
+            .bind(token)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch calendar feed: {}", e)))?;
+
+        row.as_ref().map(row_to_feed).transpose()
+    }
+}
+
+/// Map a failed appointment write, reporting a clash with another booking as a conflict
+fn appointment_error(err: sqlx::Error, context: &str) -> AppError {
+    if violates_constraint(&err, APPOINTMENT_OVERLAP) {
+        AppError::Conflict("Appraiser already has an inspection booked at that time".to_string())
+    } else {
+        AppError::Database(format!("{}: {}", context, err))
+    }
+}
+
+/// Convert a database row to an inspection appointment
+fn row_to_appointment(row: &PgRow) -> AppResult<InspectionAppointment> {
+    Ok(InspectionAppointment {
+        id: column(row, "id")?,
+        appraisal_id: column(row, "appraisal_id")?,
+        appraiser_id: column(row, "appraiser_id")?,
+        scheduled_start: column(row, "scheduled_start")?,
+        scheduled_end: column(row, "scheduled_end")?,
+        timezone: column(row, "timezone")?,
+        contact: column::<Json<_>>(row, "contact")?.0,
+        access_notes: column(row, "access_notes")?,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        reschedule_history: column::<Json<_>>(row, "reschedule_history")?.0,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to an availability slot
+fn row_to_slot(row: &PgRow) -> AppResult<AvailabilitySlot> {
+    Ok(AvailabilitySlot {
+        id: column(row, "id")?,
+        appraiser_id: column(row, "appraiser_id")?,
+        starts_at: column(row, "starts_at")?,
+        ends_at: column(row, "ends_at")?,
+        timezone: column(row, "timezone")?,
+        created_at: column(row, "created_at")?,
+    })
+}
+
+/// Convert a database row to a calendar feed
+fn row_to_feed(row: &PgRow) -> AppResult<CalendarFeed> {
+    Ok(CalendarFeed {
+        appraiser_id: column(row, "appraiser_id")?,
+        token: column(row, "token")?,
+        created_at: column(row, "created_at")?,
+    })
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::message::{Message, MessageVisibility, UnreadCount};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Columns selected for a message, including its read receipts as a JSON array
+const MESSAGE_COLUMNS: &str = "m.*, COALESCE(
+        (SELECT json_agg(json_build_object('user_id', r.user_id, 'read_at', r.read_at) ORDER BY r.read_at)
+         FROM message_read_receipts r WHERE r.message_id = m.id),
+        '[]'::json) AS read_by";
+
+/// Repository for appraisal message threads
+#[derive(Clone)]
+pub struct MessageRepository {
+    db: Arc<Database>,
+}
+
+impl MessageRepository {
+    /// Create a new message repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a message by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<Message> {
+        let sql = format!("SELECT {} FROM appraisal_messages m WHERE m.id = $1", MESSAGE_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch message: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_message(&row),
+            None => Err(AppError::NotFound(format!("Message not found with ID: {}", id))),
+        }
+    }
+
+    /// Get the messages of an appraisal thread, oldest first
+    pub async fn list_for_appraisal(&self, appraisal_id: Uuid, include_internal: bool) -> AppResult<Vec<Message>> {
+        let sql = format!(
+            "SELECT {} FROM appraisal_messages m
+             WHERE m.appraisal_id = $1 AND ($2 OR m.visibility = $3)
+             ORDER BY m.created_at",
+            MESSAGE_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(appraisal_id)
+            .bind(include_internal)
+            .bind(encode_enum(&MessageVisibility::ClientVisible))
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch messages: {}", e)))?;
+
+        rows.iter().map(row_to_message).collect()
+    }
+
+    /// Insert a new message
+    pub async fn create(&self, message: &Message) -> AppResult<Message> {
+        sqlx::query(
+            "INSERT INTO appraisal_messages
+                (id, appraisal_id, author_id, author_role, body, attachments, visibility, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
+        )
+        .bind(message.id)
+        .bind(message.appraisal_id)
+        .bind(&message.author_id)
+        .bind(encode_enum(&message.author_role))
+        .bind(&message.body)
+        .bind(Json(&message.attachments))
+        .bind(encode_enum(&message.visibility))
+        .bind(message.created_at)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create message: {}", e)))?;
+
+        self.get_by_id(message.id).await
+    }
+
+    /// Record that a user has read every message of a thread they can see; returns how many
+    /// messages were newly marked
+    pub async fn mark_thread_read(
+        &self,
+        appraisal_id: Uuid,
+        user_id: &str,
+        include_internal: bool,
+        read_at: DateTime<Utc>,
+    ) -> AppResult<u64> {
+        let result = sqlx::query(
+            "INSERT INTO message_read_receipts (message_id, user_id, read_at)
+             SELECT m.id, $2, $3 FROM appraisal_messages m
+             WHERE m.appraisal_id = $1 AND m.author_id <> $2 AND ($4 OR m.visibility = $5)
+             ON CONFLICT (message_id, user_id) DO NOTHING"
+        )
+        .bind(appraisal_id)
+        .bind(user_id)
+        .bind(read_at)
+        .bind(include_internal)
+        .bind(encode_enum(&MessageVisibility::ClientVisible))
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to mark messages as read: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Count unread messages per thread for a user.
+    /// Client portal users (`client_id` set) see client-visible messages on their client's
+    /// appraisals; everyone else sees threads they are assigned to, have posted in, or reviewed.
+    pub async fn unread_counts(&self, user_id: &str, client_id: Option<Uuid>) -> AppResult<Vec<UnreadCount>> {
+        let rows = sqlx::query(
+            "SELECT m.appraisal_id, COUNT(*) AS unread
+             FROM appraisal_messages m
+             JOIN appraisals a ON a.id = m.appraisal_id
+             WHERE m.author_id <> $1
+               AND NOT EXISTS (
+                   SELECT 1 FROM message_read_receipts r WHERE r.message_id = m.id AND r.user_id = $1)
+               AND CASE WHEN $2::uuid IS NOT NULL
+                   THEN a.client_id = $2 AND m.visibility = $3
+                   ELSE a.appraiser_id::text = $1
+                       OR EXISTS (SELECT 1 FROM appraisal_messages mine
+                                  WHERE mine.appraisal_id = a.id AND mine.author_id = $1)
+                       OR EXISTS (SELECT 1 FROM revision_requests rr
+                                  WHERE rr.appraisal_id = a.id AND rr.requested_by = $1)
+                   END
+             GROUP BY m.appraisal_id
+             ORDER BY m.appraisal_id"
+        )
+        .bind(user_id)
+        .bind(client_id)
+        .bind(encode_enum(&MessageVisibility::ClientVisible))
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to count unread messages: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(UnreadCount {
+                    appraisal_id: column(row, "appraisal_id")?,
+                    unread: column(row, "unread")?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Convert a database row to a message model
+fn row_to_message(row: &PgRow) -> AppResult<Message> {
+    Ok(Message {
+        id: column(row, "id")?,
+        appraisal_id: column(row, "appraisal_id")?,
+        author_id: column(row, "author_id")?,
+        author_role: decode_enum(&column::<String>(row, "author_role")?)?,
+        body: column(row, "body")?,
+        attachments: column::<Json<_>>(row, "attachments")?.0,
+        visibility: decode_enum(&column::<String>(row, "visibility")?)?,
+        read_by: column::<Json<_>>(row, "read_by")?.0,
+        created_at: column(row, "created_at")?,
+    })
+}
@@ -0,0 +1,8 @@
+pub mod air_repository;
+pub mod appraisal_repository;
+pub mod client_repository;
+pub mod engagement_repository;
+pub mod inspection_repository;
+pub mod message_repository;
+pub mod property_repository;
+pub mod revision_repository;
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::AppraisalStatus;
+use shared::models::revision::{RevisionRequest, RevisionStatus};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use sqlx::{Postgres, Transaction};
+use uuid::Uuid;
+
+/// Repository for appraisal revision requests
+#[derive(Clone)]
+pub struct RevisionRepository {
+    db: Arc<Database>,
+}
+
+impl RevisionRepository {
+    /// Create a new revision repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a revision request by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<RevisionRequest> {
+        let row = sqlx::query("SELECT * FROM revision_requests WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch revision request: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_revision(&row),
+            None => Err(AppError::NotFound(format!("Revision request not found with ID: {}", id))),
+        }
+    }
+
+    /// Get every revision round of an appraisal, oldest first
+    pub async fn list_for_appraisal(&self, appraisal_id: Uuid) -> AppResult<Vec<RevisionRequest>> {
+        let rows = sqlx::query(
+            "SELECT * FROM revision_requests WHERE appraisal_id = $1 ORDER BY round"
+        )
+        .bind(appraisal_id)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch revision requests: {}", e)))?;
+
+        rows.iter().map(row_to_revision).collect()
+    }
+
+    /// Find the revision round of an appraisal that is not yet closed, if any
+    pub async fn find_unfinished(&self, appraisal_id: Uuid) -> AppResult<Option<RevisionRequest>> {
+        let row = sqlx::query(
+            "SELECT * FROM revision_requests WHERE appraisal_id = $1 AND status <> $2"
+        )
+        .bind(appraisal_id)
+        .bind(encode_enum(&RevisionStatus::Closed))
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch revision request: {}", e)))?;
+
+        row.as_ref().map(row_to_revision).transpose()
+    }
+
+    /// Get the highest revision round of an appraisal, or 0 when none exist
+    pub async fn latest_round(&self, appraisal_id: Uuid) -> AppResult<i32> {
+        let row = sqlx::query(
+            "SELECT COALESCE(MAX(round), 0) AS round FROM revision_requests WHERE appraisal_id = $1"
+        )
+        .bind(appraisal_id)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch revision round: {}", e)))?;
+
+        column(&row, "round")
+    }
+
+    /// Insert a revision request and move the appraisal from `PendingReview` to `RevisionNeeded`
+    pub async fn create(&self, request: &RevisionRequest) -> AppResult<RevisionRequest> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query(
+            "INSERT INTO revision_requests
+                (id, appraisal_id, round, requested_by, summary, items, status, created_at, updated_at, closed_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING *"
+        )
+        .bind(request.id)
+        .bind(request.appraisal_id)
+        .bind(request.round)
+        .bind(&request.requested_by)
+        .bind(&request.summary)
+        .bind(Json(&request.items))
+        .bind(encode_enum(&request.status))
+        .bind(request.created_at)
+        .bind(request.updated_at)
+        .bind(request.closed_at)
+        .fetch_one(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create revision request: {}", e)))?;
+
+        transition_appraisal(
+            &mut tx,
+            request.appraisal_id,
+            &AppraisalStatus::PendingReview,
+            &AppraisalStatus::RevisionNeeded,
+        )
+        .await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit revision request: {}", e)))?;
+
+        row_to_revision(&row)
+    }
+
+    /// Persist changes to a revision request, optionally moving the appraisal between statuses
+    /// in the same transaction. `read_at` is the `updated_at` the request had when it was read;
+    /// if it has changed since, the update is refused rather than overwriting the other change.
+    pub async fn update(
+        &self,
+        request: &RevisionRequest,
+        read_at: DateTime<Utc>,
+        transition: Option<(AppraisalStatus, AppraisalStatus)>,
+    ) -> AppResult<RevisionRequest> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query(
+            "UPDATE revision_requests
+             SET items = $2, status = $3, updated_at = $4, closed_at = $5
+             WHERE id = $1 AND updated_at = $6
+             RETURNING *"
+        )
+        .bind(request.id)
+        .bind(Json(&request.items))
+        .bind(encode_enum(&request.status))
+        .bind(request.updated_at)
+        .bind(request.closed_at)
+        .bind(read_at)
+        .fetch_optional(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to update revision request: {}", e)))?;
+
+        let row = match row {
+            Some(row) => row,
+            None => {
+                return Err(AppError::Conflict(format!(
+                    "Revision request {} was changed by someone else; reload it and try again",
+                    request.id
+                )))
+            }
+        };
+
+        if let Some((from, to)) = transition {
+            transition_appraisal(&mut tx, request.appraisal_id, &from, &to).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit revision request: {}", e)))?;
+
+        row_to_revision(&row)
+    }
+}
+
+/// Move an appraisal between statuses, failing if it is no longer in the expected one
+async fn transition_appraisal(
+    tx: &mut Transaction<'_, Postgres>,
+    appraisal_id: Uuid,
+    from: &AppraisalStatus,
+    to: &AppraisalStatus,
+) -> AppResult<()> {
+    let result = sqlx::query(
+        "UPDATE appraisals SET status = $2, updated_at = $3 WHERE id = $1 AND status = $4"
+    )
+    .bind(appraisal_id)
+    .bind(encode_enum(to))
+    .bind(Utc::now())
+    .bind(encode_enum(from))
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| AppError::Database(format!("Failed to update appraisal status: {}", e)))?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::Conflict(format!(
+            "Appraisal {} is no longer in status {:?}",
+            appraisal_id, from
+        )));
+    }
+
+    Ok(())
+}
+
+/// Convert a database row to a revision request model
+fn row_to_revision(row: &PgRow) -> AppResult<RevisionRequest> {
+    Ok(RevisionRequest {
+        id: column(row, "id")?,
+        appraisal_id: column(row, "appraisal_id")?,
+        round: column(row, "round")?,
+        requested_by: column(row, "requested_by")?,
+        summary: column(row, "summary")?,
+        items: column::<Json<_>>(row, "items")?.0,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+        closed_at: column(row, "closed_at")?,
+    })
+}
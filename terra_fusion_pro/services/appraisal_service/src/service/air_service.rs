@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::auth::access::AccessControl;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::air::{
+    AddPanelMemberRequest, AirAction, AirViolation, PanelMember, PanelRotationPolicy,
+    UpdateRotationPolicyRequest,
+};
+use shared::models::appraisal::Appraisal;
+use shared::models::user::UserRole;
+use uuid::Uuid;
+
+use super::require_user;
+use crate::repository::air_repository::AirRepository;
+use crate::repository::client_repository::ClientRepository;
+
+/// Service enforcing Appraiser Independence Requirements (AIR): keeps loan-production staff
+/// away from appraisers and manages the appraiser panels assignments rotate through
+pub struct AirService {
+    air: AirRepository,
+    clients: ClientRepository,
+    access: AccessControl,
+}
+
+impl AirService {
+    /// Create a new AIR service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            air: AirRepository::new(db.clone()),
+            clients: ClientRepository::new(db.clone()),
+            access: AccessControl::new(db),
+        }
+    }
+
+    /// Block the action if the user is loan-production staff, logging the attempt
+    pub async fn guard(
+        &self,
+        user_id: &str,
+        action: AirAction,
+        appraisal: Option<&Appraisal>,
+        detail: &str,
+    ) -> AppResult<()> {
+        let membership = match self.clients.find_portal_user(user_id).await? {
+            Some(membership) if membership.is_loan_production => membership,
+            _ => return Ok(()),
+        };
+
+        let violation = AirViolation {
+            id: Uuid::new_v4(),
+            client_id: membership.client_id,
+            user_id: user_id.to_string(),
+            appraisal_id: appraisal.map(|a| a.id),
+            action,
+            detail: detail.to_string(),
+            created_at: Utc::now(),
+        };
+
+        log::warn!("Blocked AIR violation {:?} by user {}: {}", action, user_id, detail);
+        if let Err(err) = self.air.record_violation(&violation).await {
+            log::error!("Failed to record AIR violation {}: {:?}", violation.id, err);
+        }
+
+        Err(AppError::Authorization(
+            "Appraiser independence rules do not allow loan-production staff to do this".to_string(),
+        ))
+    }
+
+    /// List a client's blocked attempts within an optional time window, newest first
+    pub async fn list_violations(
+        &self,
+        client_id: Uuid,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        user_id: Option<String>,
+    ) -> AppResult<Vec<AirViolation>> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+        self.clients.get_by_id(client_id).await?;
+        self.air.violations_for_client(client_id, from, to).await
+    }
+
+    /// Get a client's rotation policy, or the default open policy
+    pub async fn rotation_policy(&self, client_id: Uuid) -> AppResult<PanelRotationPolicy> {
+        self.clients.get_by_id(client_id).await?;
+        Ok(self
+            .air
+            .get_policy(client_id)
+            .await?
+            .unwrap_or_else(|| PanelRotationPolicy::defaults_for(client_id)))
+    }
+
+    /// Change a client's rotation policy
+    pub async fn update_rotation_policy(
+        &self,
+        client_id: Uuid,
+        request: UpdateRotationPolicyRequest,
+        user_id: Option<String>,
+    ) -> AppResult<PanelRotationPolicy> {
+        self.clients.get_by_id(client_id).await?;
+        self.authorize_panel_change(
+            user_id,
+            &format!("Attempted to change the rotation policy of client {} to {:?}", client_id, request.mode),
+        )
+        .await?;
+
+        let policy = PanelRotationPolicy {
+            client_id,
+            mode: request.mode,
+            updated_at: Utc::now(),
+        };
+
+        self.air.save_policy(&policy).await
+    }
+
+    /// List a client's panel in rotation order, including removed members
+    pub async fn list_panel(&self, client_id: Uuid) -> AppResult<Vec<PanelMember>> {
+        self.clients.get_by_id(client_id).await?;
+        self.air.panel_members(client_id).await
+    }
+
+    /// Add an appraiser to a client's panel
+    pub async fn add_panel_member(
+        &self,
+        client_id: Uuid,
+        request: AddPanelMemberRequest,
+        user_id: Option<String>,
+    ) -> AppResult<PanelMember> {
+        self.clients.get_by_id(client_id).await?;
+        self.authorize_panel_change(
+            user_id,
+            &format!("Attempted to add appraiser {} to the panel of client {}", request.appraiser_id, client_id),
+        )
+        .await?;
+
+        let member = PanelMember {
+            client_id,
+            appraiser_id: request.appraiser_id,
+            is_active: true,
+            assignment_count: 0,
+            last_assigned_at: None,
+            added_at: Utc::now(),
+        };
+
+        self.air.add_panel_member(&member).await
+    }
+
+    /// Take an appraiser off a client's panel
+    pub async fn remove_panel_member(
+        &self,
+        client_id: Uuid,
+        appraiser_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<()> {
+        self.authorize_panel_change(
+            user_id,
+            &format!("Attempted to remove appraiser {} from the panel of client {}", appraiser_id, client_id),
+        )
+        .await?;
+
+        self.air.deactivate_panel_member(client_id, appraiser_id).await
+    }
+
+    /// The client's rotation policy and its active panel members in rotation order
+    pub async fn rotation_for(&self, client_id: Uuid) -> AppResult<(PanelRotationPolicy, Vec<PanelMember>)> {
+        let policy = self
+            .air
+            .get_policy(client_id)
+            .await?
+            .unwrap_or_else(|| PanelRotationPolicy::defaults_for(client_id));
+        let members = self
+            .air
+            .panel_members(client_id)
+            .await?
+            .into_iter()
+            .filter(|member| member.is_active)
+            .collect();
+
+        Ok((policy, members))
+    }
+
+    /// Move an appraiser to the back of the client's rotation
+    pub async fn record_assignment(&self, client_id: Uuid, appraiser_id: Uuid) -> AppResult<()> {
+        self.air.record_assignment(client_id, appraiser_id, Utc::now()).await
+    }
+
+    /// Require an admin to change a panel. Loan-production staff are refused first, so their
+    /// attempt is logged as a violation.
+    async fn authorize_panel_change(&self, user_id: Option<String>, detail: &str) -> AppResult<()> {
+        let user_id = require_user(user_id)?;
+        self.guard(&user_id, AirAction::AppraiserSelection, None, detail).await?;
+        self.access.require_role(Some(user_id), UserRole::Admin).await?;
+
+        Ok(())
+    }
+}
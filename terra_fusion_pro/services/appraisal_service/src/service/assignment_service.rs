@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use serde_json::json;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::air::{AirAction, RotationMode};
+use shared::models::appraisal::{Appraisal, AppraisalStatus, AssignAppraiserRequest};
+use shared::models::notification::NotificationEvent;
+use shared::models::property::Property;
+use shared::models::webhook::WebhookEventType;
+use shared::notifications::Notifier;
+use shared::repository::credential_repository::CredentialRepository;
+use shared::utils::format::format_address_single_line;
+use shared::webhooks::WebhookDispatcher;
+use uuid::Uuid;
+
+use super::air_service::AirService;
+use super::engagement_service::EngagementService;
+use super::publish_order_event;
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::service::qualification::ensure_appraiser_qualified;
+
+/// Service for assigning appraisers to appraisal orders
+pub struct AssignmentService {
+    appraisals: AppraisalRepository,
+    properties: PropertyRepository,
+    credentials: CredentialRepository,
+    engagements: EngagementService,
+    air: AirService,
+    notifier: Arc<Notifier>,
+    webhooks: Arc<WebhookDispatcher>,
+}
+
+impl AssignmentService {
+    /// Create a new assignment service
+    pub fn new(db: Arc<Database>, notifier: Arc<Notifier>, webhooks: Arc<WebhookDispatcher>) -> Self {
+        Self {
+            appraisals: AppraisalRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            credentials: CredentialRepository::new(db.clone()),
+            engagements: EngagementService::new(db.clone()),
+            air: AirService::new(db),
+            notifier,
+            webhooks,
+        }
+    }
+
+    /// Assign an appraiser, rejecting appraisers not licensed for the subject property or not
+    /// allowed by the client's panel rotation, and issue the engagement letter they must accept
+    /// before starting
+    pub async fn assign_appraiser(
+        &self,
+        appraisal_id: Uuid,
+        request: AssignAppraiserRequest,
+        assigned_by: Option<String>,
+    ) -> AppResult<Appraisal> {
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+
+        if let Some(user_id) = &assigned_by {
+            self.air
+                .guard(
+                    user_id,
+                    AirAction::AppraiserSelection,
+                    Some(&appraisal),
+                    &format!("Attempted to assign appraiser {}", request.appraiser_id),
+                )
+                .await?;
+        }
+
+        if !matches!(appraisal.status, AppraisalStatus::New | AppraisalStatus::Assigned) {
+            return Err(AppError::Validation(format!(
+                "Cannot assign an appraiser to an appraisal with status {:?}",
+                appraisal.status
+            )));
+        }
+
+        let property = self.properties.get_by_id(appraisal.property_id).await?;
+        let today = Utc::now().date_naive();
+
+        ensure_appraiser_qualified(&self.credentials, request.appraiser_id, &property, today).await?;
+        if appraisal.appraiser_id != Some(request.appraiser_id) {
+            self.ensure_rotation_allows(&appraisal, request.appraiser_id, &property, today).await?;
+        }
+
+        let assigned = self.appraisals.assign_appraiser(appraisal_id, request.appraiser_id).await?;
+        self.air.record_assignment(assigned.client_id, request.appraiser_id).await?;
+        self.engagements.issue_for(&assigned).await?;
+
+        let address = &property.address;
+        let property_address = format_address_single_line(
+            &address.street1,
+            address.street2.as_deref(),
+            &address.city,
+            &address.state,
+            &address.postal_code,
+        );
+        let event = NotificationEvent::OrderAssigned {
+            appraisal_id,
+            reference_number: assigned.reference_number.clone(),
+            property_address: property_address.clone(),
+            due_date: assigned.due_date,
+        };
+        if let Err(err) = self.notifier.notify(&event, &[request.appraiser_id.to_string()]).await {
+            log::warn!("Failed to notify appraiser of assignment {}: {:?}", appraisal_id, err);
+        }
+
+        publish_order_event(
+            &self.webhooks,
+            &assigned,
+            WebhookEventType::OrderAssigned,
+            json!({ "appraiser_id": request.appraiser_id, "property_address": property_address }),
+        )
+        .await;
+
+        Ok(assigned)
+    }
+
+    /// Ensure the client's panel rotation policy allows the appraiser. Round-robin clients must
+    /// get the qualified panel member who has gone longest without one of their orders.
+    async fn ensure_rotation_allows(
+        &self,
+        appraisal: &Appraisal,
+        appraiser_id: Uuid,
+        property: &Property,
+        today: NaiveDate,
+    ) -> AppResult<()> {
+        let (policy, panel) = self.air.rotation_for(appraisal.client_id).await?;
+
+        match policy.mode {
+            RotationMode::Open => Ok(()),
+            RotationMode::PanelOnly => {
+                if panel.iter().any(|member| member.appraiser_id == appraiser_id) {
+                    Ok(())
+                } else {
+                    Err(AppError::Validation(
+                        "The client only accepts appraisers from its panel".to_string(),
+                    ))
+                }
+            }
+            RotationMode::RoundRobin => {
+                for member in &panel {
+                    // Unqualified members are skipped; failing to check a member is an error
+                    match ensure_appraiser_qualified(&self.credentials, member.appraiser_id, property, today).await {
+                        Ok(_) => {}
+                        Err(AppError::Validation(_)) | Err(AppError::Authorization(_)) => continue,
+                        Err(err) => return Err(err),
+                    }
+
+                    if member.appraiser_id == appraiser_id {
+                        return Ok(());
+                    }
+
+                    return Err(AppError::Validation(format!(
+                        "The client's panel rotation requires assigning appraiser {} next",
+                        member.appraiser_id
+                    )));
+                }
+
+                Err(AppError::Validation(
+                    "No panel member is qualified for this property".to_string(),
+                ))
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::auth::access::AccessControl;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::client::{
+    AddPortalUserRequest, Client, ClientContact, ClientPortalUser, CreateClientRequest,
+    UpdateClientRequest,
+};
+use shared::models::user::UserRole;
+use shared::repository::user_repository::UserRepository;
+use uuid::Uuid;
+
+use crate::repository::client_repository::ClientRepository;
+
+/// Service for managing client organizations
+pub struct ClientService {
+    clients: ClientRepository,
+    users: UserRepository,
+    access: AccessControl,
+}
+
+impl ClientService {
+    /// Create a new client service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            clients: ClientRepository::new(db.clone()),
+            users: UserRepository::new(db.clone()),
+            access: AccessControl::new(db),
+        }
+    }
+
+    /// List clients, optionally including deactivated ones
+    pub async fn list_clients(&self, include_inactive: bool, user_id: Option<String>) -> AppResult<Vec<Client>> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+        self.clients.get_all(include_inactive).await
+    }
+
+    /// Find a client by ID
+    pub async fn find_client_by_id(&self, id: Uuid, user_id: Option<String>) -> AppResult<Client> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+        self.clients.get_by_id(id).await
+    }
+
+    /// Create a new client
+    pub async fn create_client(&self, request: CreateClientRequest, user_id: Option<String>) -> AppResult<Client> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+
+        let contacts = request.contacts.unwrap_or_default();
+        validate_client(&request.name, &contacts)?;
+
+        let now = Utc::now();
+        let client = Client {
+            id: Uuid::new_v4(),
+            name: request.name.trim().to_string(),
+            client_type: request.client_type,
+            contacts,
+            billing_address: request.billing_address,
+            default_instructions: request.default_instructions,
+            required_report_types: request.required_report_types.unwrap_or_default(),
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.clients.create(&client).await
+    }
+
+    /// Update an existing client
+    pub async fn update_client(
+        &self,
+        id: Uuid,
+        request: UpdateClientRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Client> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+        let mut client = self.clients.get_by_id(id).await?;
+
+        if let Some(name) = request.name {
+            client.name = name.trim().to_string();
+        }
+        if let Some(client_type) = request.client_type {
+            client.client_type = client_type;
+        }
+        if let Some(contacts) = request.contacts {
+            client.contacts = contacts;
+        }
+        if request.billing_address.is_some() {
+            client.billing_address = request.billing_address;
+        }
+        if request.default_instructions.is_some() {
+            client.default_instructions = request.default_instructions;
+        }
+        if let Some(report_types) = request.required_report_types {
+            client.required_report_types = report_types;
+        }
+        if let Some(is_active) = request.is_active {
+            client.is_active = is_active;
+        }
+
+        validate_client(&client.name, &client.contacts)?;
+        client.updated_at = Utc::now();
+
+        self.clients.update(&client).await
+    }
+
+    /// Delete a client, refusing when it already has appraisals on record
+    pub async fn delete_client(&self, id: Uuid, user_id: Option<String>) -> AppResult<()> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+        self.clients.get_by_id(id).await?;
+
+        if self.clients.count_appraisals(id).await? > 0 {
+            return Err(AppError::Conflict(
+                "Client has appraisals on record; deactivate it instead".to_string(),
+            ));
+        }
+
+        self.clients.delete(id).await
+    }
+
+    /// List the portal users of a client
+    pub async fn list_portal_users(
+        &self,
+        client_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<Vec<ClientPortalUser>> {
+        self.access.require_role(user_id, UserRole::Staff).await?;
+        self.clients.get_by_id(client_id).await?;
+        self.clients.portal_users(client_id).await
+    }
+
+    /// Grant a user access to a client's portal
+    pub async fn add_portal_user(
+        &self,
+        client_id: Uuid,
+        request: AddPortalUserRequest,
+        current_user_id: Option<String>,
+    ) -> AppResult<ClientPortalUser> {
+        self.access.require_role(current_user_id, UserRole::Staff).await?;
+        self.clients.get_by_id(client_id).await?;
+        self.users.get_by_id(request.user_id.clone()).await?;
+
+        if let Some(existing) = self.clients.find_portal_user(&request.user_id).await? {
+            if existing.client_id != client_id {
+                return Err(AppError::Conflict(
+                    "User already belongs to another client's portal".to_string(),
+                ));
+            }
+        }
+
+        let user = ClientPortalUser {
+            id: Uuid::new_v4(),
+            client_id,
+            user_id: request.user_id,
+            role: request.role,
+            is_loan_production: request.is_loan_production.unwrap_or(false),
+            created_at: Utc::now(),
+        };
+
+        self.clients.add_portal_user(&user).await
+    }
+
+    /// Revoke a user's access to a client's portal
+    pub async fn remove_portal_user(
+        &self,
+        client_id: Uuid,
+        user_id: &str,
+        current_user_id: Option<String>,
+    ) -> AppResult<()> {
+        self.access.require_role(current_user_id, UserRole::Staff).await?;
+        self.clients.remove_portal_user(client_id, user_id).await
+    }
+}
+
+/// Validate client name and contacts
+fn validate_client(name: &str, contacts: &[ClientContact]) -> AppResult<()> {
+    if name.trim().is_empty() {
+        return Err(AppError::Validation("Client name is required".to_string()));
+    }
+
+    if contacts.iter().filter(|c| c.is_primary).count() > 1 {
+        return Err(AppError::Validation("Only one contact may be primary".to_string()));
+    }
+
+    if contacts.iter().any(|c| c.name.trim().is_empty()) {
+        return Err(AppError::Validation("Every contact needs a name".to_string()));
+    }
+
+    Ok(())
+}
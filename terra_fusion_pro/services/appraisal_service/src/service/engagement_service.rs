@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::air::AirAction;
+use shared::models::appraisal::Appraisal;
+use shared::models::engagement::{
+    CounterOffer, EngagementDecision, EngagementLetter, EngagementStatus, RespondToEngagementRequest,
+};
+use shared::notifications::templates::render;
+use shared::utils::format::{format_address_single_line, format_currency};
+use uuid::Uuid;
+
+use super::air_service::AirService;
+use super::require_user;
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::client_repository::ClientRepository;
+use crate::repository::engagement_repository::EngagementRepository;
+use crate::repository::property_repository::PropertyRepository;
+
+/// Engagement letter text; `{{name}}` placeholders are replaced with order values
+const ENGAGEMENT_LETTER: &str = "ENGAGEMENT LETTER\n\n\
+    Order: {{order}}\n\
+    Subject property: {{address}}\n\
+    Letter version: {{version}}\n\n\
+    {{client}} engages you to provide {{scope}}.\n\n\
+    Fee: {{fee}}\n\
+    Due date: {{due_date}}\n\n\
+    Intended use: the appraisal is intended {{intended_use}}.\n\
+    Intended users: {{intended_users}}. No other party is an intended user.\n\n\
+    {{instructions}}\
+    By accepting this letter you confirm that you are competent to complete the assignment, \
+    that you have no current or prospective interest in the subject property or the parties \
+    involved, and that the assignment is not contingent on a predetermined value or result.\n";
+
+/// Service for engagement letters between clients and assigned appraisers
+pub struct EngagementService {
+    letters: EngagementRepository,
+    appraisals: AppraisalRepository,
+    clients: ClientRepository,
+    properties: PropertyRepository,
+    air: AirService,
+}
+
+impl EngagementService {
+    /// Create a new engagement letter service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            letters: EngagementRepository::new(db.clone()),
+            appraisals: AppraisalRepository::new(db.clone()),
+            clients: ClientRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            air: AirService::new(db),
+        }
+    }
+
+    /// List every letter version of an appraisal, oldest first
+    pub async fn list_letters(&self, appraisal_id: Uuid) -> AppResult<Vec<EngagementLetter>> {
+        self.appraisals.get_by_id(appraisal_id).await?;
+        self.letters.list_for_appraisal(appraisal_id).await
+    }
+
+    /// Get the current letter of an appraisal
+    pub async fn current_letter(&self, appraisal_id: Uuid) -> AppResult<EngagementLetter> {
+        self.appraisals.get_by_id(appraisal_id).await?;
+        self.letters.latest(appraisal_id).await?.ok_or_else(|| {
+            AppError::NotFound(format!("No engagement letter found for appraisal {}", appraisal_id))
+        })
+    }
+
+    /// Generate a letter for the appraisal's assigned appraiser using its current terms
+    pub async fn issue_for(&self, appraisal: &Appraisal) -> AppResult<EngagementLetter> {
+        self.issue(appraisal, appraisal.fee, appraisal.due_date).await
+    }
+
+    /// Accept or decline the current letter. Declining may carry a counter-offer for the fee
+    /// or due date. The response time and IP address are recorded.
+    pub async fn respond(
+        &self,
+        appraisal_id: Uuid,
+        request: RespondToEngagementRequest,
+        user_id: Option<String>,
+        ip_address: Option<String>,
+    ) -> AppResult<EngagementLetter> {
+        let user_id = require_user(user_id)?;
+        let mut letter = self.current_letter(appraisal_id).await?;
+
+        if letter.appraiser_id.to_string() != user_id {
+            return Err(AppError::Authorization(
+                "Only the appraiser the letter is addressed to can respond".to_string(),
+            ));
+        }
+
+        if letter.status != EngagementStatus::Pending {
+            return Err(AppError::Validation(format!(
+                "Cannot respond to an engagement letter with status {:?}",
+                letter.status
+            )));
+        }
+
+        match request.decision {
+            EngagementDecision::Accept => {
+                if request.counter_offer.is_some() {
+                    return Err(AppError::Validation(
+                        "A counter-offer can only be made when declining".to_string(),
+                    ));
+                }
+                letter.status = EngagementStatus::Accepted;
+            }
+            EngagementDecision::Decline => {
+                if let Some(offer) = &request.counter_offer {
+                    validate_counter_offer(offer)?;
+                }
+                letter.status = EngagementStatus::Declined;
+                letter.decline_reason = request.reason;
+                letter.counter_offer = request.counter_offer;
+            }
+        }
+
+        letter.responded_at = Some(Utc::now());
+        letter.responded_ip = ip_address;
+
+        self.letters.save_response(&letter).await
+    }
+
+    /// Agree to the appraiser's counter-offer, updating the order terms and issuing a revised
+    /// letter for the appraiser to accept
+    pub async fn accept_counter_offer(
+        &self,
+        appraisal_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<EngagementLetter> {
+        let user_id = require_user(user_id)?;
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+        self.air
+            .guard(
+                &user_id,
+                AirAction::FeeNegotiation,
+                Some(&appraisal),
+                "Attempted to accept an appraiser's counter-offer",
+            )
+            .await?;
+
+        let letter = self.current_letter(appraisal_id).await?;
+        if letter.appraiser_id.to_string() == user_id {
+            return Err(AppError::Authorization(
+                "The appraiser cannot accept their own counter-offer".to_string(),
+            ));
+        }
+
+        let offer = match (&letter.status, &letter.counter_offer) {
+            (EngagementStatus::Declined, Some(offer)) => offer.clone(),
+            _ => {
+                return Err(AppError::Validation(
+                    "The current engagement letter has no open counter-offer".to_string(),
+                ))
+            }
+        };
+
+        if appraisal.appraiser_id != Some(letter.appraiser_id) {
+            return Err(AppError::Conflict(
+                "The appraiser who made the counter-offer is no longer assigned".to_string(),
+            ));
+        }
+
+        self.issue(
+            &appraisal,
+            offer.fee.or(letter.fee),
+            offer.due_date.or(letter.due_date),
+        )
+        .await
+    }
+
+    /// Ensure the appraiser has accepted the current letter before starting work
+    pub async fn ensure_accepted(&self, appraisal_id: Uuid, appraiser_id: Uuid) -> AppResult<()> {
+        let accepted = self
+            .letters
+            .latest(appraisal_id)
+            .await?
+            .map_or(false, |letter| {
+                letter.status == EngagementStatus::Accepted && letter.appraiser_id == appraiser_id
+            });
+
+        if !accepted {
+            return Err(AppError::Validation(
+                "The assigned appraiser must accept the engagement letter first".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Render and store a new letter version with the given fee and due date
+    async fn issue(
+        &self,
+        appraisal: &Appraisal,
+        fee: Option<f64>,
+        due_date: Option<DateTime<Utc>>,
+    ) -> AppResult<EngagementLetter> {
+        let appraiser_id = appraisal.appraiser_id.ok_or_else(|| {
+            AppError::Validation("An appraiser must be assigned before an engagement letter is issued".to_string())
+        })?;
+
+        let client = self.clients.get_by_id(appraisal.client_id).await?;
+        let property = self.properties.get_by_id(appraisal.property_id).await?;
+        let version = self
+            .letters
+            .latest(appraisal.id)
+            .await?
+            .map_or(1, |letter| letter.version + 1);
+
+        let scope_of_work = appraisal.appraisal_type.scope_of_work().to_string();
+        let intended_use = appraisal.purpose.intended_use().to_string();
+        let intended_users = vec![client.name.clone()];
+
+        let address = &property.address;
+        let mut values = BTreeMap::new();
+        values.insert(
+            "order",
+            appraisal.reference_number.clone().unwrap_or_else(|| appraisal.id.to_string()),
+        );
+        values.insert(
+            "address",
+            format_address_single_line(
+                &address.street1,
+                address.street2.as_deref(),
+                &address.city,
+                &address.state,
+                &address.postal_code,
+            ),
+        );
+        values.insert("version", version.to_string());
+        values.insert("client", client.name.clone());
+        values.insert("scope", scope_of_work.clone());
+        values.insert("fee", fee.map(format_currency).unwrap_or_else(|| "to be agreed".to_string()));
+        values.insert(
+            "due_date",
+            due_date
+                .map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+                .unwrap_or_else(|| "to be agreed".to_string()),
+        );
+        values.insert("intended_use", intended_use.clone());
+        values.insert("intended_users", intended_users.join(", "));
+        values.insert(
+            "instructions",
+            appraisal
+                .instructions
+                .as_deref()
+                .map(|text| format!("Client instructions: {}\n\n", text.trim()))
+                .unwrap_or_default(),
+        );
+
+        let letter = EngagementLetter {
+            id: Uuid::new_v4(),
+            appraisal_id: appraisal.id,
+            appraiser_id,
+            version,
+            scope_of_work,
+            fee,
+            due_date,
+            intended_use,
+            intended_users,
+            body: render(ENGAGEMENT_LETTER, &values),
+            status: EngagementStatus::Pending,
+            counter_offer: None,
+            decline_reason: None,
+            responded_at: None,
+            responded_ip: None,
+            created_at: Utc::now(),
+        };
+
+        let terms_changed = fee != appraisal.fee || due_date != appraisal.due_date;
+        self.letters.issue(&letter, terms_changed).await
+    }
+}
+
+/// A counter-offer must change the fee or the due date to something sensible
+fn validate_counter_offer(offer: &CounterOffer) -> AppResult<()> {
+    if offer.fee.is_none() && offer.due_date.is_none() {
+        return Err(AppError::Validation(
+            "A counter-offer must propose a fee or a due date".to_string(),
+        ));
+    }
+
+    if offer.fee.map_or(false, |fee| !fee.is_finite() || fee <= 0.0) {
+        return Err(AppError::Validation("Counter-offer fee must be positive".to_string()));
+    }
+
+    if offer.due_date.map_or(false, |due| due <= Utc::now()) {
+        return Err(AppError::Validation("Counter-offer due date must be in the future".to_string()));
+    }
+
+    Ok(())
+}
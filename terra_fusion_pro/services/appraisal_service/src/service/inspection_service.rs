@@ -0,0 +1,456 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use rand::{distributions::Alphanumeric, Rng};
+use serde_json::json;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::AppraisalStatus;
+use shared::models::inspection::{
+    AppointmentStatus, AvailabilitySlot, CalendarFeed, CreateAvailabilitySlotRequest,
+    CreateInspectionRequest, InspectionAppointment, RescheduleEntry, RescheduleInspectionRequest,
+    UpdateInspectionRequest,
+};
+use shared::models::webhook::WebhookEventType;
+use shared::repository::credential_repository::CredentialRepository;
+use shared::utils::format::format_address_single_line;
+use shared::utils::ical::{render_calendar, CalendarEvent};
+use shared::webhooks::WebhookDispatcher;
+use uuid::Uuid;
+
+use super::engagement_service::EngagementService;
+use super::{publish_order_event, require_user};
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::inspection_repository::InspectionRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::service::qualification::ensure_appraiser_qualified;
+
+/// How far back past appointments are kept in calendar feeds
+const CALENDAR_FEED_HISTORY_DAYS: i64 = 90;
+
+/// Inspection scheduling service
+pub struct InspectionService {
+    appraisals: AppraisalRepository,
+    inspections: InspectionRepository,
+    properties: PropertyRepository,
+    credentials: CredentialRepository,
+    engagements: EngagementService,
+    webhooks: Arc<WebhookDispatcher>,
+}
+
+impl InspectionService {
+    /// Create a new inspection service
+    pub fn new(db: Arc<Database>, webhooks: Arc<WebhookDispatcher>) -> Self {
+        Self {
+            appraisals: AppraisalRepository::new(db.clone()),
+            inspections: InspectionRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            credentials: CredentialRepository::new(db.clone()),
+            engagements: EngagementService::new(db),
+            webhooks,
+        }
+    }
+
+    /// Book an inspection for an appraisal and mark the appraisal as scheduled
+    pub async fn book_inspection(
+        &self,
+        appraisal_id: Uuid,
+        request: CreateInspectionRequest,
+    ) -> AppResult<InspectionAppointment> {
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+
+        let appraiser_id = appraisal.appraiser_id.ok_or_else(|| {
+            AppError::Validation("An appraiser must be assigned before an inspection can be booked".to_string())
+        })?;
+
+        match appraisal.status {
+            AppraisalStatus::Assigned | AppraisalStatus::Scheduled | AppraisalStatus::InProgress => {}
+            ref status => {
+                return Err(AppError::Validation(format!(
+                    "Cannot book an inspection for an appraisal with status {:?}",
+                    status
+                )));
+            }
+        }
+
+        self.engagements.ensure_accepted(appraisal_id, appraiser_id).await?;
+
+        validate_time_range(request.scheduled_start, request.scheduled_end)?;
+        let tz = validate_timezone(&request.timezone)?;
+
+        if request.scheduled_start <= Utc::now() {
+            return Err(AppError::Validation("Inspection must be scheduled in the future".to_string()));
+        }
+
+        let existing = self.inspections.list_for_appraisal(appraisal_id).await?;
+        if existing.iter().any(|a| a.status == AppointmentStatus::Scheduled) {
+            return Err(AppError::Conflict(
+                "Appraisal already has a scheduled inspection; reschedule it instead".to_string(),
+            ));
+        }
+
+        self.ensure_licensed_on(appraisal.property_id, appraiser_id, request.scheduled_start, tz)
+            .await?;
+        self.ensure_bookable(appraiser_id, request.scheduled_start, request.scheduled_end, None)
+            .await?;
+
+        let now = Utc::now();
+        let appointment = InspectionAppointment {
+            id: Uuid::new_v4(),
+            appraisal_id,
+            appraiser_id,
+            scheduled_start: request.scheduled_start,
+            scheduled_end: request.scheduled_end,
+            timezone: request.timezone,
+            contact: request.contact,
+            access_notes: request.access_notes,
+            status: AppointmentStatus::Scheduled,
+            reschedule_history: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let appointment = self.inspections.book_appointment(&appointment).await?;
+
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+        publish_order_event(
+            &self.webhooks,
+            &appraisal,
+            WebhookEventType::InspectionScheduled,
+            json!({
+                "appointment_id": appointment.id,
+                "scheduled_start": appointment.scheduled_start,
+                "scheduled_end": appointment.scheduled_end,
+                "timezone": appointment.timezone,
+            }),
+        )
+        .await;
+
+        Ok(appointment)
+    }
+
+    /// Move an appointment to a new time, recording the change in its history
+    pub async fn reschedule_inspection(
+        &self,
+        appointment_id: Uuid,
+        request: RescheduleInspectionRequest,
+        rescheduled_by: Option<String>,
+    ) -> AppResult<InspectionAppointment> {
+        let mut appointment = self.inspections.get_appointment(appointment_id).await?;
+
+        if appointment.status != AppointmentStatus::Scheduled {
+            return Err(AppError::Validation(format!(
+                "Cannot reschedule an appointment with status {:?}",
+                appointment.status
+            )));
+        }
+
+        validate_time_range(request.scheduled_start, request.scheduled_end)?;
+
+        let appraisal = self.appraisals.get_by_id(appointment.appraisal_id).await?;
+        let tz = validate_timezone(&appointment.timezone)?;
+        self.ensure_licensed_on(appraisal.property_id, appointment.appraiser_id, request.scheduled_start, tz)
+            .await?;
+        self.ensure_bookable(
+            appointment.appraiser_id,
+            request.scheduled_start,
+            request.scheduled_end,
+            Some(appointment.id),
+        )
+        .await?;
+
+        let now = Utc::now();
+        appointment.reschedule_history.push(RescheduleEntry {
+            previous_start: appointment.scheduled_start,
+            previous_end: appointment.scheduled_end,
+            new_start: request.scheduled_start,
+            new_end: request.scheduled_end,
+            reason: request.reason,
+            rescheduled_by,
+            rescheduled_at: now,
+        });
+        appointment.scheduled_start = request.scheduled_start;
+        appointment.scheduled_end = request.scheduled_end;
+        appointment.updated_at = now;
+
+        self.inspections.update_appointment(&appointment).await
+    }
+
+    /// Update contact details, access notes or status of an appointment
+    pub async fn update_inspection(
+        &self,
+        appointment_id: Uuid,
+        request: UpdateInspectionRequest,
+    ) -> AppResult<InspectionAppointment> {
+        let mut appointment = self.inspections.get_appointment(appointment_id).await?;
+
+        if let Some(contact) = request.contact {
+            appointment.contact = contact;
+        }
+        if request.access_notes.is_some() {
+            appointment.access_notes = request.access_notes;
+        }
+
+        let status_changed = match request.status {
+            Some(status) if status != appointment.status => {
+                if appointment.status != AppointmentStatus::Scheduled {
+                    return Err(AppError::Validation(format!(
+                        "Cannot change an appointment with status {:?}",
+                        appointment.status
+                    )));
+                }
+                appointment.status = status;
+                true
+            }
+            _ => false,
+        };
+
+        appointment.updated_at = Utc::now();
+        let appointment = self.inspections.update_appointment(&appointment).await?;
+
+        if status_changed {
+            self.sync_appraisal_status(&appointment).await?;
+        }
+
+        Ok(appointment)
+    }
+
+    /// List all appointments for an appraisal
+    pub async fn list_inspections(&self, appraisal_id: Uuid) -> AppResult<Vec<InspectionAppointment>> {
+        self.appraisals.get_by_id(appraisal_id).await?;
+        self.inspections.list_for_appraisal(appraisal_id).await
+    }
+
+    /// Add an availability slot, rejecting overlaps with existing slots
+    pub async fn add_availability_slot(
+        &self,
+        appraiser_id: Uuid,
+        request: CreateAvailabilitySlotRequest,
+        user_id: Option<String>,
+    ) -> AppResult<AvailabilitySlot> {
+        ensure_own_schedule(appraiser_id, user_id)?;
+        validate_time_range(request.starts_at, request.ends_at)?;
+        validate_timezone(&request.timezone)?;
+
+        let overlapping = self
+            .inspections
+            .find_overlapping_slots(appraiser_id, request.starts_at, request.ends_at)
+            .await?;
+
+        if let Some(slot) = overlapping.first() {
+            return Err(AppError::Conflict(format!(
+                "Availability overlaps existing slot {} ({} - {})",
+                slot.id,
+                slot.starts_at.to_rfc3339(),
+                slot.ends_at.to_rfc3339()
+            )));
+        }
+
+        let slot = AvailabilitySlot {
+            id: Uuid::new_v4(),
+            appraiser_id,
+            starts_at: request.starts_at,
+            ends_at: request.ends_at,
+            timezone: request.timezone,
+            created_at: Utc::now(),
+        };
+
+        self.inspections.create_slot(&slot).await
+    }
+
+    /// List an appraiser's current and upcoming availability
+    pub async fn list_availability_slots(&self, appraiser_id: Uuid) -> AppResult<Vec<AvailabilitySlot>> {
+        self.inspections.list_slots(appraiser_id, Utc::now()).await
+    }
+
+    /// Remove an availability slot
+    pub async fn delete_availability_slot(
+        &self,
+        appraiser_id: Uuid,
+        slot_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<()> {
+        ensure_own_schedule(appraiser_id, user_id)?;
+        self.inspections.delete_slot(appraiser_id, slot_id).await
+    }
+
+    /// Issue a new calendar feed token for an appraiser, invalidating any previous one
+    pub async fn issue_calendar_feed(&self, appraiser_id: Uuid, user_id: Option<String>) -> AppResult<CalendarFeed> {
+        ensure_own_schedule(appraiser_id, user_id)?;
+
+        let token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(48)
+            .map(char::from)
+            .collect();
+
+        self.inspections.upsert_calendar_feed(appraiser_id, &token).await
+    }
+
+    /// Render the iCalendar feed identified by a token
+    pub async fn render_calendar_feed(&self, token: &str) -> AppResult<String> {
+        let feed = self
+            .inspections
+            .find_calendar_feed(token)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Calendar feed not found".to_string()))?;
+
+        let since = Utc::now() - Duration::days(CALENDAR_FEED_HISTORY_DAYS);
+        let appointments = self.inspections.list_for_appraiser(feed.appraiser_id, since).await?;
+
+        let mut events = Vec::with_capacity(appointments.len());
+        for appointment in &appointments {
+            let appraisal = self.appraisals.get_by_id(appointment.appraisal_id).await?;
+            let property = self.properties.get_by_id(appraisal.property_id).await?;
+            let address = &property.address;
+            let reference = appraisal
+                .reference_number
+                .unwrap_or_else(|| appraisal.id.to_string());
+
+            events.push(CalendarEvent {
+                uid: format!("{}@terrafusionpro", appointment.id),
+                start: appointment.scheduled_start,
+                end: appointment.scheduled_end,
+                summary: format!("Inspection: {}", reference),
+                description: Some(describe_appointment(appointment)),
+                location: Some(format_address_single_line(
+                    &address.street1,
+                    address.street2.as_deref(),
+                    &address.city,
+                    &address.state,
+                    &address.postal_code,
+                )),
+                cancelled: appointment.status == AppointmentStatus::Cancelled,
+                sequence: appointment.reschedule_history.len() as i32,
+                last_modified: appointment.updated_at,
+            });
+        }
+
+        Ok(render_calendar("TerraFusionPro Inspections", &events))
+    }
+
+    /// Check the appraiser is licensed for the property on the local date of the inspection
+    async fn ensure_licensed_on(
+        &self,
+        property_id: Uuid,
+        appraiser_id: Uuid,
+        start: DateTime<Utc>,
+        tz: Tz,
+    ) -> AppResult<()> {
+        let property = self.properties.get_by_id(property_id).await?;
+        let inspection_date = start.with_timezone(&tz).date_naive();
+
+        ensure_appraiser_qualified(&self.credentials, appraiser_id, &property, inspection_date).await?;
+        Ok(())
+    }
+
+    /// Check a time range is inside the appraiser's availability and free of other bookings. A
+    /// booking racing this check is refused by the database's overlap constraint instead.
+    async fn ensure_bookable(
+        &self,
+        appraiser_id: Uuid,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        exclude_id: Option<Uuid>,
+    ) -> AppResult<()> {
+        if self
+            .inspections
+            .find_covering_slot(appraiser_id, start, end)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::Conflict(
+                "Requested time is outside the appraiser's availability".to_string(),
+            ));
+        }
+
+        let conflicts = self
+            .inspections
+            .find_overlapping_appointments(appraiser_id, start, end, exclude_id)
+            .await?;
+
+        if let Some(conflict) = conflicts.first() {
+            return Err(AppError::Conflict(format!(
+                "Appraiser already has an inspection from {} to {}",
+                conflict.scheduled_start.to_rfc3339(),
+                conflict.scheduled_end.to_rfc3339()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Keep the appraisal status in step with the outcome of its inspection
+    async fn sync_appraisal_status(&self, appointment: &InspectionAppointment) -> AppResult<()> {
+        let appraisal = self.appraisals.get_by_id(appointment.appraisal_id).await?;
+
+        if appraisal.status != AppraisalStatus::Scheduled {
+            return Ok(());
+        }
+
+        let next = match appointment.status {
+            AppointmentStatus::Completed => AppraisalStatus::InProgress,
+            AppointmentStatus::Cancelled | AppointmentStatus::NoShow => AppraisalStatus::Assigned,
+            AppointmentStatus::Scheduled => return Ok(()),
+        };
+
+        self.appraisals.update_status(appraisal.id, &next).await?;
+        Ok(())
+    }
+}
+
+/// Only appraisers manage their own availability and calendar feed
+fn ensure_own_schedule(appraiser_id: Uuid, user_id: Option<String>) -> AppResult<()> {
+    let user_id = require_user(user_id)?;
+    if appraiser_id.to_string() != user_id {
+        return Err(AppError::Authorization(
+            "Appraisers can only manage their own schedule".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Ensure a time range ends after it starts
+fn validate_time_range(start: DateTime<Utc>, end: DateTime<Utc>) -> AppResult<()> {
+    if end <= start {
+        return Err(AppError::Validation("End time must be after start time".to_string()));
+    }
+    Ok(())
+}
+
+/// Ensure a timezone is a known IANA name
+fn validate_timezone(timezone: &str) -> AppResult<Tz> {
+    timezone
+        .parse::<Tz>()
+        .map_err(|_| AppError::Validation(format!("Unknown timezone: {}", timezone)))
+}
+
+/// Build the calendar event description for an appointment
+fn describe_appointment(appointment: &InspectionAppointment) -> String {
+    let mut lines = Vec::new();
+
+    if let Ok(tz) = appointment.timezone.parse::<Tz>() {
+        let local = appointment.scheduled_start.with_timezone(&tz);
+        lines.push(format!("Local time: {}", local.format("%B %d, %Y at %I:%M %p %Z")));
+    }
+
+    let contact = &appointment.contact;
+    let mut contact_line = format!("Contact: {}", contact.name);
+    if let Some(relationship) = &contact.relationship {
+        contact_line.push_str(&format!(" ({})", relationship));
+    }
+    lines.push(contact_line);
+
+    if let Some(phone) = &contact.phone {
+        lines.push(format!("Phone: {}", phone));
+    }
+    if let Some(email) = &contact.email {
+        lines.push(format!("Email: {}", email));
+    }
+    if let Some(notes) = &appointment.access_notes {
+        lines.push(format!("Access notes: {}", notes));
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::auth::access::AccessControl;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::air::AirAction;
+use shared::models::appraisal::Appraisal;
+use shared::models::message::{
+    AttachmentUpload, Message, MessageAttachment, MessageVisibility, ParticipantRole,
+    PostMessageRequest, UnreadCount, MAX_ATTACHMENT_BYTES,
+};
+use shared::storage::AttachmentStore;
+use uuid::Uuid;
+
+use super::air_service::AirService;
+use super::require_user;
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::client_repository::ClientRepository;
+use crate::repository::message_repository::MessageRepository;
+
+/// Service for the message thread attached to each appraisal
+pub struct MessageService {
+    messages: MessageRepository,
+    appraisals: AppraisalRepository,
+    clients: ClientRepository,
+    air: AirService,
+    access: AccessControl,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl MessageService {
+    /// Create a new message service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            messages: MessageRepository::new(db.clone()),
+            appraisals: AppraisalRepository::new(db.clone()),
+            clients: ClientRepository::new(db.clone()),
+            air: AirService::new(db.clone()),
+            access: AccessControl::new(db),
+            store,
+        }
+    }
+
+    /// List the messages of an appraisal thread visible to the user, oldest first
+    pub async fn list_messages(&self, appraisal_id: Uuid, user_id: Option<String>) -> AppResult<Vec<Message>> {
+        let user_id = require_user(user_id)?;
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+        let role = self.participant_role(&appraisal, &user_id).await?;
+
+        self.messages
+            .list_for_appraisal(appraisal_id, role != ParticipantRole::Client)
+            .await
+    }
+
+    /// Post a message, storing any attachments first
+    pub async fn post_message(
+        &self,
+        appraisal_id: Uuid,
+        request: PostMessageRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Message> {
+        let user_id = require_user(user_id)?;
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+        let role = self.participant_role(&appraisal, &user_id).await?;
+
+        if role == ParticipantRole::Client {
+            self.air
+                .guard(
+                    &user_id,
+                    AirAction::DirectMessage,
+                    Some(&appraisal),
+                    "Attempted to post a message on the appraisal thread",
+                )
+                .await?;
+        }
+
+        if role == ParticipantRole::Client && request.visibility == MessageVisibility::Internal {
+            return Err(AppError::Authorization(
+                "Clients cannot post internal messages".to_string(),
+            ));
+        }
+
+        let uploads = request.attachments.unwrap_or_default();
+        if request.body.trim().is_empty() && uploads.is_empty() {
+            return Err(AppError::Validation("Message needs text or an attachment".to_string()));
+        }
+
+        let message_id = Uuid::new_v4();
+        let decoded = uploads
+            .into_iter()
+            .map(decode_upload)
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let mut attachments = Vec::with_capacity(decoded.len());
+        for (upload, bytes) in decoded {
+            let id = Uuid::new_v4();
+            let storage_key = format!("messages/{}/{}/{}", appraisal_id, message_id, id);
+
+            if let Err(err) = self.store.put(&storage_key, &bytes).await {
+                self.discard(&attachments).await;
+                return Err(err);
+            }
+
+            attachments.push(MessageAttachment {
+                id,
+                file_name: upload.file_name,
+                content_type: upload.content_type,
+                size_bytes: bytes.len() as i64,
+                storage_key,
+            });
+        }
+
+        let message = Message {
+            id: message_id,
+            appraisal_id,
+            author_id: user_id,
+            author_role: role,
+            body: request.body.trim().to_string(),
+            attachments,
+            visibility: request.visibility,
+            read_by: Vec::new(),
+            created_at: Utc::now(),
+        };
+
+        match self.messages.create(&message).await {
+            Ok(message) => Ok(message),
+            Err(err) => {
+                self.discard(&message.attachments).await;
+                Err(err)
+            }
+        }
+    }
+
+    /// Mark every message of a thread visible to the user as read
+    pub async fn mark_thread_read(&self, appraisal_id: Uuid, user_id: Option<String>) -> AppResult<u64> {
+        let user_id = require_user(user_id)?;
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+        let role = self.participant_role(&appraisal, &user_id).await?;
+
+        self.messages
+            .mark_thread_read(appraisal_id, &user_id, role != ParticipantRole::Client, Utc::now())
+            .await
+    }
+
+    /// Count unread messages per thread for the user
+    pub async fn unread_counts(&self, user_id: Option<String>) -> AppResult<Vec<UnreadCount>> {
+        let user_id = require_user(user_id)?;
+        let membership = self.clients.find_portal_user(&user_id).await?;
+
+        self.messages
+            .unread_counts(&user_id, membership.map(|m| m.client_id))
+            .await
+    }
+
+    /// Load an attachment the user is allowed to see
+    pub async fn download_attachment(
+        &self,
+        message_id: Uuid,
+        attachment_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<(MessageAttachment, Vec<u8>)> {
+        let user_id = require_user(user_id)?;
+        let message = self.messages.get_by_id(message_id).await?;
+        let appraisal = self.appraisals.get_by_id(message.appraisal_id).await?;
+        let role = self.participant_role(&appraisal, &user_id).await?;
+
+        if role == ParticipantRole::Client && message.visibility == MessageVisibility::Internal {
+            return Err(AppError::NotFound(format!("Message not found with ID: {}", message_id)));
+        }
+
+        let attachment = message
+            .attachments
+            .into_iter()
+            .find(|a| a.id == attachment_id)
+            .ok_or_else(|| AppError::NotFound(format!("Attachment not found with ID: {}", attachment_id)))?;
+        let bytes = self.store.get(&attachment.storage_key).await?;
+
+        Ok((attachment, bytes))
+    }
+
+    /// Work out the role a user plays on an appraisal.
+    /// Portal users may only take part in their own client's threads, and only the report's
+    /// reviewer or staff take part as reviewers.
+    async fn participant_role(&self, appraisal: &Appraisal, user_id: &str) -> AppResult<ParticipantRole> {
+        if appraisal.appraiser_id.map_or(false, |id| id.to_string() == user_id) {
+            return Ok(ParticipantRole::Appraiser);
+        }
+
+        if let Some(membership) = self.clients.find_portal_user(user_id).await? {
+            if membership.client_id == appraisal.client_id {
+                return Ok(ParticipantRole::Client);
+            }
+        } else if self.appraisals.is_report_reviewer(appraisal, user_id).await?
+            || self.access.is_staff(user_id).await?
+        {
+            return Ok(ParticipantRole::Reviewer);
+        }
+
+        Err(AppError::Authorization(
+            "Not allowed to access this appraisal's messages".to_string(),
+        ))
+    }
+
+    /// Remove stored attachments after a failed post
+    async fn discard(&self, attachments: &[MessageAttachment]) {
+        for attachment in attachments {
+            if let Err(err) = self.store.delete(&attachment.storage_key).await {
+                log::warn!("Failed to remove orphaned attachment {}: {:?}", attachment.storage_key, err);
+            }
+        }
+    }
+}
+
+/// Validate an upload and decode its content
+fn decode_upload(upload: AttachmentUpload) -> AppResult<(AttachmentUpload, Vec<u8>)> {
+    if upload.file_name.trim().is_empty() {
+        return Err(AppError::Validation("Attachment file name is required".to_string()));
+    }
+
+    let bytes = base64::decode(&upload.content_base64).map_err(|_| {
+        AppError::Validation(format!("Attachment {} is not valid base64", upload.file_name))
+    })?;
+
+    if bytes.len() > MAX_ATTACHMENT_BYTES {
+        return Err(AppError::Validation(format!(
+            "Attachment {} exceeds the {} byte limit",
+            upload.file_name, MAX_ATTACHMENT_BYTES
+        )));
+    }
+
+    Ok((upload, bytes))
+}
@@ -0,0 +1,45 @@
+pub mod air_service;
+pub mod assignment_service;
+pub mod client_service;
+pub mod engagement_service;
+pub mod inspection_service;
+pub mod message_service;
+pub mod pipeline_service;
+pub mod qualification;
+pub mod reminder_service;
+pub mod revision_service;
+pub mod webhook_service;
+
+use serde_json::{json, Value};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::Appraisal;
+use shared::models::webhook::WebhookEventType;
+use shared::webhooks::WebhookDispatcher;
+
+/// Require an authenticated user
+fn require_user(user_id: Option<String>) -> AppResult<String> {
+    user_id.ok_or_else(|| AppError::Authentication("Authentication required".to_string()))
+}
+
+/// Queue a webhook event for the appraisal's client, merging `extra` into the order fields.
+/// Failures are logged rather than failing the workflow that raised the event.
+async fn publish_order_event(
+    webhooks: &WebhookDispatcher,
+    appraisal: &Appraisal,
+    event_type: WebhookEventType,
+    extra: Value,
+) {
+    let mut data = json!({
+        "appraisal_id": appraisal.id,
+        "reference_number": appraisal.reference_number,
+        "status": appraisal.status,
+        "due_date": appraisal.due_date,
+    });
+    if let (Some(data), Value::Object(extra)) = (data.as_object_mut(), extra) {
+        data.extend(extra);
+    }
+
+    if let Err(err) = webhooks.publish(appraisal.client_id, event_type, data).await {
+        log::warn!("Failed to queue {:?} webhook for appraisal {}: {:?}", event_type, appraisal.id, err);
+    }
+}
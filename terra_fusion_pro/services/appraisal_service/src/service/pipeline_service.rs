@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::{
+    AppraisalFilter, AppraisalStatus, AppraisalSummary, PipelineDashboard, StatusCount,
+};
+
+use crate::repository::appraisal_repository::AppraisalRepository;
+
+/// Service for the appraisal pipeline listing and operations dashboard
+pub struct PipelineService {
+    appraisals: AppraisalRepository,
+}
+
+impl PipelineService {
+    /// Create a new pipeline service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            appraisals: AppraisalRepository::new(db),
+        }
+    }
+
+    /// List appraisals matching a filter, soonest due first
+    pub async fn list_appraisals(&self, filter: AppraisalFilter) -> AppResult<Vec<AppraisalSummary>> {
+        validate_filter(&filter)?;
+        self.appraisals.list_summaries(&filter, Utc::now()).await
+    }
+
+    /// Aggregate counts per status and per appraiser for appraisals matching a filter
+    pub async fn dashboard(&self, filter: AppraisalFilter) -> AppResult<PipelineDashboard> {
+        validate_filter(&filter)?;
+        let now = Utc::now();
+
+        let counts = self.appraisals.count_by_status(&filter, now).await?;
+        let overdue = self.appraisals.count_overdue(&filter, now).await?;
+        let by_appraiser = self.appraisals.workload_by_appraiser(&filter, now).await?;
+
+        // Report every status so the dashboard can render empty columns
+        let by_status: Vec<StatusCount> = AppraisalStatus::ALL
+            .iter()
+            .map(|status| StatusCount {
+                status: status.clone(),
+                count: counts
+                    .iter()
+                    .find(|c| &c.status == status)
+                    .map(|c| c.count)
+                    .unwrap_or(0),
+            })
+            .collect();
+
+        Ok(PipelineDashboard {
+            total: by_status.iter().map(|c| c.count).sum(),
+            overdue,
+            by_status,
+            by_appraiser,
+        })
+    }
+}
+
+/// Reject inverted date ranges
+fn validate_filter(filter: &AppraisalFilter) -> AppResult<()> {
+    if let (Some(from), Some(to)) = (filter.due_from, filter.due_to) {
+        if from > to {
+            return Err(AppError::Validation("due_from must not be after due_to".to_string()));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use shared::error::{AppError, AppResult};
+use shared::models::credential::{find_qualifying_license, AppraiserLicense};
+use shared::models::property::Property;
+use shared::repository::credential_repository::CredentialRepository;
+use uuid::Uuid;
+
+/// Ensure an appraiser holds a license covering the property's state and type on a date
+///
+/// Returns the qualifying license so callers can record which license the work was done under.
+pub async fn ensure_appraiser_qualified(
+    credentials: &CredentialRepository,
+    appraiser_id: Uuid,
+    property: &Property,
+    on_date: NaiveDate,
+) -> AppResult<AppraiserLicense> {
+    // Appraiser IDs are the user IDs issued at sign-up
+    let licenses = credentials.licenses_for_user(&appraiser_id.to_string()).await?;
+
+    find_qualifying_license(
+        &licenses,
+        &property.address.state,
+        &property.characteristics.property_type,
+        on_date,
+    )
+    .cloned()
+    .map_err(AppError::Validation)
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, Utc};
+use shared::db::Database;
+use shared::error::AppResult;
+use shared::models::notification::NotificationEvent;
+use shared::notifications::Notifier;
+
+use crate::repository::appraisal_repository::AppraisalRepository;
+
+/// How far ahead of the due date appraisers are reminded
+const DUE_SOON_WINDOW_HOURS: i64 = 24;
+
+/// Service that reminds appraisers of orders approaching their due date
+pub struct ReminderService {
+    appraisals: AppraisalRepository,
+    notifier: Arc<Notifier>,
+}
+
+impl ReminderService {
+    /// Create a new reminder service
+    pub fn new(db: Arc<Database>, notifier: Arc<Notifier>) -> Self {
+        Self {
+            appraisals: AppraisalRepository::new(db),
+            notifier,
+        }
+    }
+
+    /// Notify the appraiser of every open order due within the window, once per order;
+    /// returns how many reminders were sent
+    pub async fn send_due_soon_reminders(&self, now: DateTime<Utc>) -> AppResult<usize> {
+        let due = self
+            .appraisals
+            .assigned_due_between(now, now + Duration::hours(DUE_SOON_WINDOW_HOURS))
+            .await?;
+        let mut sent = 0;
+
+        for appraisal in due {
+            let (appraiser_id, due_date) = match (appraisal.appraiser_id, appraisal.due_date) {
+                (Some(appraiser_id), Some(due_date)) => (appraiser_id.to_string(), due_date),
+                _ => continue,
+            };
+
+            let event = NotificationEvent::DueSoon {
+                appraisal_id: appraisal.id,
+                reference_number: appraisal.reference_number.clone(),
+                due_date,
+            };
+
+            if self.notifier.already_notified(&event, &appraiser_id).await? {
+                continue;
+            }
+
+            self.notifier.notify(&event, &[appraiser_id]).await?;
+            sent += 1;
+        }
+
+        Ok(sent)
+    }
+
+    /// Send due-soon reminders forever, pausing between passes
+    pub async fn run(self, interval: StdDuration) {
+        loop {
+            if let Err(err) = self.send_due_soon_reminders(Utc::now()).await {
+                log::error!("Error sending due-soon reminders: {:?}", err);
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+}
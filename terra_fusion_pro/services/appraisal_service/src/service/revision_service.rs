@@ -0,0 +1,436 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::json;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::air::AirAction;
+use shared::models::appraisal::{Appraisal, AppraisalStatus};
+use shared::models::notification::NotificationEvent;
+use shared::models::revision::{
+    CreateRevisionRequest, ItemResolution, ResolveItemRequest, RespondToItemRequest,
+    RevisionItem, RevisionRequest, RevisionResponse, RevisionStatus,
+};
+use shared::models::webhook::WebhookEventType;
+use shared::notifications::Notifier;
+use shared::webhooks::WebhookDispatcher;
+use uuid::Uuid;
+
+use super::air_service::AirService;
+use super::{publish_order_event, require_user};
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::revision_repository::RevisionRepository;
+
+/// Service for revision rounds between reviewers and appraisers
+pub struct RevisionService {
+    revisions: RevisionRepository,
+    appraisals: AppraisalRepository,
+    air: AirService,
+    notifier: Arc<Notifier>,
+    webhooks: Arc<WebhookDispatcher>,
+}
+
+impl RevisionService {
+    /// Create a new revision service
+    pub fn new(db: Arc<Database>, notifier: Arc<Notifier>, webhooks: Arc<WebhookDispatcher>) -> Self {
+        Self {
+            revisions: RevisionRepository::new(db.clone()),
+            appraisals: AppraisalRepository::new(db.clone()),
+            air: AirService::new(db),
+            notifier,
+            webhooks,
+        }
+    }
+
+    /// List every revision round of an appraisal, oldest first
+    pub async fn list_revisions(&self, appraisal_id: Uuid) -> AppResult<Vec<RevisionRequest>> {
+        self.appraisals.get_by_id(appraisal_id).await?;
+        self.revisions.list_for_appraisal(appraisal_id).await
+    }
+
+    /// Open a revision round on an appraisal that is pending review
+    pub async fn request_revisions(
+        &self,
+        appraisal_id: Uuid,
+        request: CreateRevisionRequest,
+        reviewer_id: Option<String>,
+    ) -> AppResult<RevisionRequest> {
+        let reviewer_id = require_user(reviewer_id)?;
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+
+        if appraisal.status != AppraisalStatus::PendingReview {
+            return Err(AppError::Validation(format!(
+                "Revisions can only be requested while pending review, not {:?}",
+                appraisal.status
+            )));
+        }
+
+        if is_assigned_appraiser(&appraisal, &reviewer_id) {
+            return Err(AppError::Authorization(
+                "The assigned appraiser cannot review their own report".to_string(),
+            ));
+        }
+
+        if request.items.is_empty() {
+            return Err(AppError::Validation("A revision request needs at least one item".to_string()));
+        }
+
+        let value_related: Vec<String> = request
+            .items
+            .iter()
+            .filter(|item| item.category.is_value_related())
+            .map(|item| format!("{:?}", item.category))
+            .collect();
+        if !value_related.is_empty() {
+            self.air
+                .guard(
+                    &reviewer_id,
+                    AirAction::ValueRelatedRevision,
+                    Some(&appraisal),
+                    &format!("Attempted to request value-related revisions: {}", value_related.join(", ")),
+                )
+                .await?;
+        }
+
+        if request.items.iter().any(|item| item.description.trim().is_empty()) {
+            return Err(AppError::Validation("Every revision item needs a description".to_string()));
+        }
+
+        if self.revisions.find_unfinished(appraisal_id).await?.is_some() {
+            return Err(AppError::Conflict(
+                "Appraisal already has an unfinished revision request".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let revision = RevisionRequest {
+            id: Uuid::new_v4(),
+            appraisal_id,
+            round: self.revisions.latest_round(appraisal_id).await? + 1,
+            requested_by: reviewer_id,
+            summary: request.summary,
+            items: request
+                .items
+                .into_iter()
+                .map(|item| RevisionItem {
+                    id: Uuid::new_v4(),
+                    category: item.category,
+                    description: item.description.trim().to_string(),
+                    report_section: item.report_section,
+                    responses: Vec::new(),
+                    resolution: ItemResolution::Pending,
+                    reviewer_note: None,
+                    resolved_at: None,
+                })
+                .collect(),
+            status: RevisionStatus::Open,
+            created_at: now,
+            updated_at: now,
+            closed_at: None,
+        };
+
+        let revision = self.revisions.create(&revision).await?;
+        self.notify_status_change(
+            &appraisal,
+            AppraisalStatus::PendingReview,
+            AppraisalStatus::RevisionNeeded,
+            appraiser_recipient(&appraisal),
+        )
+        .await;
+        self.publish_revision_requested(appraisal, &revision).await;
+
+        Ok(revision)
+    }
+
+    /// Record the appraiser's answer to a revision item
+    pub async fn respond_to_item(
+        &self,
+        appraisal_id: Uuid,
+        revision_id: Uuid,
+        item_id: Uuid,
+        request: RespondToItemRequest,
+        user_id: Option<String>,
+    ) -> AppResult<RevisionRequest> {
+        let user_id = require_user(user_id)?;
+        let (appraisal, mut revision) = self.load(appraisal_id, revision_id).await?;
+
+        if !is_assigned_appraiser(&appraisal, &user_id) {
+            return Err(AppError::Authorization(
+                "Only the assigned appraiser can respond to revision items".to_string(),
+            ));
+        }
+
+        if revision.status != RevisionStatus::Open {
+            return Err(AppError::Validation(format!(
+                "Cannot respond to a revision request with status {:?}",
+                revision.status
+            )));
+        }
+
+        if request.body.trim().is_empty() {
+            return Err(AppError::Validation("Response text is required".to_string()));
+        }
+
+        let read_at = revision.updated_at;
+        let now = Utc::now();
+        let item = find_item(&mut revision, item_id)?;
+
+        if item.resolution == ItemResolution::Cleared {
+            return Err(AppError::Validation("Revision item has already been cleared".to_string()));
+        }
+
+        item.responses.push(RevisionResponse {
+            body: request.body.trim().to_string(),
+            responded_by: Some(user_id),
+            responded_at: now,
+        });
+        // A fresh answer to a rejected item goes back to the reviewer
+        item.resolution = ItemResolution::Pending;
+        revision.updated_at = now;
+
+        self.revisions.update(&revision, read_at, None).await
+    }
+
+    /// Send the appraisal back for review once every item is answered
+    pub async fn resubmit(
+        &self,
+        appraisal_id: Uuid,
+        revision_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<RevisionRequest> {
+        let user_id = require_user(user_id)?;
+        let (appraisal, mut revision) = self.load(appraisal_id, revision_id).await?;
+
+        if !is_assigned_appraiser(&appraisal, &user_id) {
+            return Err(AppError::Authorization(
+                "Only the assigned appraiser can resubmit".to_string(),
+            ));
+        }
+
+        if revision.status != RevisionStatus::Open {
+            return Err(AppError::Validation(format!(
+                "Cannot resubmit a revision request with status {:?}",
+                revision.status
+            )));
+        }
+
+        let unanswered = revision.items.iter().filter(|item| !item.is_answered()).count();
+        if unanswered > 0 {
+            return Err(AppError::Validation(format!(
+                "{} revision item(s) still need a response",
+                unanswered
+            )));
+        }
+
+        let read_at = revision.updated_at;
+        revision.status = RevisionStatus::Responded;
+        revision.updated_at = Utc::now();
+
+        let revision = self.revisions
+            .update(
+                &revision,
+                read_at,
+                Some((AppraisalStatus::RevisionNeeded, AppraisalStatus::PendingReview)),
+            )
+            .await?;
+        self.notify_status_change(
+            &appraisal,
+            AppraisalStatus::RevisionNeeded,
+            AppraisalStatus::PendingReview,
+            vec![revision.requested_by.clone()],
+        )
+        .await;
+        self.publish_status_change(appraisal, AppraisalStatus::RevisionNeeded, AppraisalStatus::PendingReview)
+            .await;
+
+        Ok(revision)
+    }
+
+    /// Clear or reject the appraiser's answer to a revision item.
+    /// Once no answer awaits a decision the round closes if everything was cleared,
+    /// or goes back to the appraiser if anything was rejected.
+    pub async fn resolve_item(
+        &self,
+        appraisal_id: Uuid,
+        revision_id: Uuid,
+        item_id: Uuid,
+        request: ResolveItemRequest,
+        reviewer_id: Option<String>,
+    ) -> AppResult<RevisionRequest> {
+        let reviewer_id = require_user(reviewer_id)?;
+        let (appraisal, mut revision) = self.load(appraisal_id, revision_id).await?;
+
+        if is_assigned_appraiser(&appraisal, &reviewer_id) {
+            return Err(AppError::Authorization(
+                "The assigned appraiser cannot clear their own revision items".to_string(),
+            ));
+        }
+
+        if revision.status != RevisionStatus::Responded {
+            return Err(AppError::Validation(format!(
+                "Cannot resolve items of a revision request with status {:?}",
+                revision.status
+            )));
+        }
+
+        if request.resolution == ItemResolution::Pending {
+            return Err(AppError::Validation("Resolution must be cleared or rejected".to_string()));
+        }
+
+        if request.resolution == ItemResolution::Rejected
+            && request.note.as_deref().map_or(true, |note| note.trim().is_empty())
+        {
+            return Err(AppError::Validation("A note is required when rejecting an item".to_string()));
+        }
+
+        let read_at = revision.updated_at;
+        let now = Utc::now();
+        let item = find_item(&mut revision, item_id)?;
+        item.resolution = request.resolution;
+        item.reviewer_note = request.note;
+        item.resolved_at = Some(now);
+        revision.updated_at = now;
+
+        let awaiting_decision = revision
+            .items
+            .iter()
+            .any(|item| item.resolution == ItemResolution::Pending);
+        if awaiting_decision {
+            return self.revisions.update(&revision, read_at, None).await;
+        }
+
+        if revision.items.iter().all(|item| item.resolution == ItemResolution::Cleared) {
+            revision.status = RevisionStatus::Closed;
+            revision.closed_at = Some(now);
+            let revision = self.revisions.update(&revision, read_at, None).await?;
+
+            let event = NotificationEvent::ReviewComplete {
+                appraisal_id,
+                reference_number: appraisal.reference_number.clone(),
+                outcome: format!("all revision items from round {} were cleared", revision.round),
+            };
+            self.notify(&event, appraiser_recipient(&appraisal)).await;
+
+            Ok(revision)
+        } else {
+            revision.status = RevisionStatus::Open;
+            let revision = self.revisions
+                .update(
+                    &revision,
+                    read_at,
+                    Some((AppraisalStatus::PendingReview, AppraisalStatus::RevisionNeeded)),
+                )
+                .await?;
+            self.notify_status_change(
+                &appraisal,
+                AppraisalStatus::PendingReview,
+                AppraisalStatus::RevisionNeeded,
+                appraiser_recipient(&appraisal),
+            )
+            .await;
+            self.publish_revision_requested(appraisal, &revision).await;
+
+            Ok(revision)
+        }
+    }
+
+    /// Load an appraisal and one of its revision requests
+    async fn load(&self, appraisal_id: Uuid, revision_id: Uuid) -> AppResult<(Appraisal, RevisionRequest)> {
+        let appraisal = self.appraisals.get_by_id(appraisal_id).await?;
+        let revision = self.revisions.get_by_id(revision_id).await?;
+
+        if revision.appraisal_id != appraisal_id {
+            return Err(AppError::NotFound(format!(
+                "Revision request {} not found for appraisal {}",
+                revision_id, appraisal_id
+            )));
+        }
+
+        Ok((appraisal, revision))
+    }
+
+    /// Tell recipients that an appraisal moved between statuses
+    async fn notify_status_change(
+        &self,
+        appraisal: &Appraisal,
+        from: AppraisalStatus,
+        to: AppraisalStatus,
+        recipients: Vec<String>,
+    ) {
+        let event = NotificationEvent::StatusChanged {
+            appraisal_id: appraisal.id,
+            reference_number: appraisal.reference_number.clone(),
+            from,
+            to,
+        };
+        self.notify(&event, recipients).await;
+    }
+
+    /// Tell the client's webhooks that a round of revisions was sent to the appraiser
+    async fn publish_revision_requested(&self, mut appraisal: Appraisal, revision: &RevisionRequest) {
+        let from = appraisal.status.clone();
+        appraisal.status = AppraisalStatus::RevisionNeeded;
+
+        publish_order_event(
+            &self.webhooks,
+            &appraisal,
+            WebhookEventType::RevisionRequested,
+            json!({
+                "revision_id": revision.id,
+                "round": revision.round,
+                "item_count": revision.items.len(),
+            }),
+        )
+        .await;
+        publish_order_event(
+            &self.webhooks,
+            &appraisal,
+            WebhookEventType::OrderStatusChanged,
+            json!({ "from": from, "to": AppraisalStatus::RevisionNeeded }),
+        )
+        .await;
+    }
+
+    /// Tell the client's webhooks that an appraisal moved between statuses
+    async fn publish_status_change(&self, mut appraisal: Appraisal, from: AppraisalStatus, to: AppraisalStatus) {
+        appraisal.status = to.clone();
+
+        publish_order_event(
+            &self.webhooks,
+            &appraisal,
+            WebhookEventType::OrderStatusChanged,
+            json!({ "from": from, "to": to }),
+        )
+        .await;
+    }
+
+    /// Send a notification; failures are logged rather than failing the revision workflow
+    async fn notify(&self, event: &NotificationEvent, recipients: Vec<String>) {
+        if let Err(err) = self.notifier.notify(event, &recipients).await {
+            log::warn!("Failed to send {:?} notification for appraisal {}: {:?}", event.kind(), event.appraisal_id(), err);
+        }
+    }
+}
+
+/// The assigned appraiser as a notification recipient, if any
+fn appraiser_recipient(appraisal: &Appraisal) -> Vec<String> {
+    appraisal.appraiser_id.map(|id| id.to_string()).into_iter().collect()
+}
+
+/// Whether a user is the appraiser assigned to an appraisal
+fn is_assigned_appraiser(appraisal: &Appraisal, user_id: &str) -> bool {
+    appraisal
+        .appraiser_id
+        .map_or(false, |appraiser_id| appraiser_id.to_string() == user_id)
+}
+
+/// Find an item of a revision request by ID
+fn find_item(revision: &mut RevisionRequest, item_id: Uuid) -> AppResult<&mut RevisionItem> {
+    let revision_id = revision.id;
+    revision.item_mut(item_id).ok_or_else(|| {
+        AppError::NotFound(format!(
+            "Revision item {} not found in request {}",
+            item_id, revision_id
+        ))
+    })
+}
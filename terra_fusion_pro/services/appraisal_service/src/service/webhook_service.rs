@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::auth::access::AccessControl;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::webhook::{
+    CreateWebhookEndpointRequest, IssuedWebhookEndpoint, UpdateWebhookEndpointRequest,
+    WebhookDelivery, WebhookEndpoint, WebhookEventType,
+};
+use shared::repository::webhook_repository::WebhookRepository;
+use shared::webhooks::{signing::generate_secret, WebhookDispatcher};
+use uuid::Uuid;
+
+use super::require_user;
+use crate::repository::client_repository::ClientRepository;
+
+/// Number of deliveries returned when no limit is given
+const DEFAULT_DELIVERY_LIMIT: i64 = 50;
+
+/// Largest number of deliveries returned at once
+const MAX_DELIVERY_LIMIT: i64 = 200;
+
+/// Service for managing client webhook endpoints and their delivery history
+pub struct WebhookService {
+    webhooks: WebhookRepository,
+    clients: ClientRepository,
+    access: AccessControl,
+    dispatcher: Arc<WebhookDispatcher>,
+}
+
+impl WebhookService {
+    /// Create a new webhook service
+    pub fn new(db: Arc<Database>, dispatcher: Arc<WebhookDispatcher>) -> Self {
+        Self {
+            webhooks: WebhookRepository::new(db.clone()),
+            clients: ClientRepository::new(db.clone()),
+            access: AccessControl::new(db),
+            dispatcher,
+        }
+    }
+
+    /// List a client's endpoints
+    pub async fn list_endpoints(&self, client_id: Uuid, user_id: Option<String>) -> AppResult<Vec<WebhookEndpoint>> {
+        self.authorize(client_id, user_id).await?;
+        self.clients.get_by_id(client_id).await?;
+        self.webhooks.endpoints_for_client(client_id).await
+    }
+
+    /// Register an endpoint, returning it with its signing secret
+    pub async fn create_endpoint(
+        &self,
+        client_id: Uuid,
+        request: CreateWebhookEndpointRequest,
+        user_id: Option<String>,
+    ) -> AppResult<IssuedWebhookEndpoint> {
+        self.authorize(client_id, user_id).await?;
+        self.clients.get_by_id(client_id).await?;
+
+        let url = self.dispatcher.check_url(&request.url).await?;
+        let event_types = validate_event_types(request.event_types)?;
+
+        let now = Utc::now();
+        let endpoint = WebhookEndpoint {
+            id: Uuid::new_v4(),
+            client_id,
+            url,
+            description: request.description,
+            event_types,
+            secret: generate_secret(),
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let endpoint = self.webhooks.create_endpoint(&endpoint).await?;
+        Ok(issued(endpoint))
+    }
+
+    /// Update an endpoint's URL, subscriptions or active flag
+    pub async fn update_endpoint(
+        &self,
+        client_id: Uuid,
+        endpoint_id: Uuid,
+        request: UpdateWebhookEndpointRequest,
+        user_id: Option<String>,
+    ) -> AppResult<WebhookEndpoint> {
+        self.authorize(client_id, user_id).await?;
+        let mut endpoint = self.load(client_id, endpoint_id).await?;
+
+        if let Some(url) = request.url {
+            endpoint.url = self.dispatcher.check_url(&url).await?;
+        }
+        if request.description.is_some() {
+            endpoint.description = request.description;
+        }
+        if let Some(event_types) = request.event_types {
+            endpoint.event_types = validate_event_types(event_types)?;
+        }
+        if let Some(is_active) = request.is_active {
+            endpoint.is_active = is_active;
+        }
+        endpoint.updated_at = Utc::now();
+
+        self.webhooks.update_endpoint(&endpoint).await
+    }
+
+    /// Remove an endpoint and its delivery history
+    pub async fn delete_endpoint(&self, client_id: Uuid, endpoint_id: Uuid, user_id: Option<String>) -> AppResult<()> {
+        self.authorize(client_id, user_id).await?;
+        self.load(client_id, endpoint_id).await?;
+        self.webhooks.delete_endpoint(endpoint_id).await
+    }
+
+    /// Replace an endpoint's signing secret, returning the new one
+    pub async fn rotate_secret(
+        &self,
+        client_id: Uuid,
+        endpoint_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<IssuedWebhookEndpoint> {
+        self.authorize(client_id, user_id).await?;
+        let mut endpoint = self.load(client_id, endpoint_id).await?;
+        endpoint.secret = generate_secret();
+        endpoint.updated_at = Utc::now();
+
+        let endpoint = self.webhooks.update_endpoint(&endpoint).await?;
+        Ok(issued(endpoint))
+    }
+
+    /// Recent deliveries to an endpoint, newest first
+    pub async fn list_deliveries(
+        &self,
+        client_id: Uuid,
+        endpoint_id: Uuid,
+        limit: Option<i64>,
+        user_id: Option<String>,
+    ) -> AppResult<Vec<WebhookDelivery>> {
+        self.authorize(client_id, user_id).await?;
+        self.load(client_id, endpoint_id).await?;
+
+        let limit = limit.unwrap_or(DEFAULT_DELIVERY_LIMIT).clamp(1, MAX_DELIVERY_LIMIT);
+        self.webhooks.deliveries_for_endpoint(endpoint_id, limit).await
+    }
+
+    /// Queue a past delivery to be sent again
+    pub async fn redeliver(
+        &self,
+        client_id: Uuid,
+        endpoint_id: Uuid,
+        delivery_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<WebhookDelivery> {
+        self.authorize(client_id, user_id).await?;
+        let endpoint = self.load(client_id, endpoint_id).await?;
+        let delivery = self.webhooks.get_delivery(delivery_id).await?;
+
+        if delivery.endpoint_id != endpoint.id {
+            return Err(AppError::NotFound(format!(
+                "Webhook delivery {} not found for endpoint {}",
+                delivery_id, endpoint_id
+            )));
+        }
+
+        if !endpoint.is_active {
+            return Err(AppError::Validation("Cannot redeliver to an inactive endpoint".to_string()));
+        }
+
+        self.dispatcher.redeliver(delivery_id).await
+    }
+
+    /// Require a portal user of the client, or staff, to manage its webhooks
+    async fn authorize(&self, client_id: Uuid, user_id: Option<String>) -> AppResult<()> {
+        let user_id = require_user(user_id)?;
+
+        if let Some(membership) = self.clients.find_portal_user(&user_id).await? {
+            if membership.client_id == client_id {
+                return Ok(());
+            }
+        } else if self.access.is_staff(&user_id).await? {
+            return Ok(());
+        }
+
+        Err(AppError::Authorization(
+            "Not allowed to manage this client's webhooks".to_string(),
+        ))
+    }
+
+    /// Load an endpoint, ensuring it belongs to the client
+    async fn load(&self, client_id: Uuid, endpoint_id: Uuid) -> AppResult<WebhookEndpoint> {
+        let endpoint = self.webhooks.get_endpoint(endpoint_id).await?;
+
+        if endpoint.client_id != client_id {
+            return Err(AppError::NotFound(format!(
+                "Webhook endpoint {} not found for client {}",
+                endpoint_id, client_id
+            )));
+        }
+
+        Ok(endpoint)
+    }
+}
+
+/// Pair an endpoint with its secret for the one response that reveals it
+fn issued(endpoint: WebhookEndpoint) -> IssuedWebhookEndpoint {
+    let secret = endpoint.secret.clone();
+    IssuedWebhookEndpoint { endpoint, secret }
+}
+
+/// Require at least one event type, dropping duplicates
+fn validate_event_types(event_types: Vec<WebhookEventType>) -> AppResult<Vec<WebhookEventType>> {
+    let mut unique = Vec::new();
+    for event_type in event_types {
+        if !unique.contains(&event_type) {
+            unique.push(event_type);
+        }
+    }
+
+    if unique.is_empty() {
+        return Err(AppError::Validation("At least one event type is required".to_string()));
+    }
+
+    Ok(unique)
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, put, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::exhibit::{ReorderExhibitsRequest, UpdateExhibitRequest, UploadExhibitQuery, MAX_EXHIBIT_BYTES},
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::exhibit_service::ExhibitService;
+
+/// Configure report exhibit routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/reports/{id}/exhibits")
+            .app_data(web::PayloadConfig::new(MAX_EXHIBIT_BYTES))
+            .route(web::post().to(upload_exhibit))
+            .route(web::get().to(list_exhibits))
+    )
+    .service(reorder_exhibits)
+    .service(check_exhibits)
+    .service(download_exhibit)
+    .service(update_exhibit)
+    .service(delete_exhibit);
+}
+
+/// Upload a JPEG or PDF exhibit; the file is the request body
+async fn upload_exhibit(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    query: web::Query<UploadExhibitQuery>,
+    body: web::Bytes,
+) -> impl Responder {
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.upload_exhibit(path.into_inner(), query.into_inner(), &body, session.user_id()).await {
+        Ok(exhibit) => HttpResponse::Created().json(exhibit),
+        Err(err) => {
+            log::error!("Error uploading exhibit: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List the exhibits of a report in print order
+async fn list_exhibits(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.list_exhibits(path.into_inner(), session.user_id()).await {
+        Ok(exhibits) => HttpResponse::Ok().json(exhibits),
+        Err(err) => {
+            log::error!("Error listing exhibits: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Put a report's exhibits in a new print order
+#[put("/reports/{id}/exhibits/order")]
+async fn reorder_exhibits(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<ReorderExhibitsRequest>,
+) -> impl Responder {
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.reorder_exhibits(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(exhibits) => HttpResponse::Ok().json(exhibits),
+        Err(err) => {
+            log::error!("Error reordering exhibits: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Check whether a report has the exhibits its type requires
+#[get("/reports/{id}/exhibits/check")]
+async fn check_exhibits(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.check_exhibits(path.into_inner(), session.user_id()).await {
+        Ok(check) => HttpResponse::Ok().json(check),
+        Err(err) => {
+            log::error!("Error checking exhibits: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Download the uploaded file of an exhibit
+#[get("/reports/{id}/exhibits/{exhibit_id}/file")]
+async fn download_exhibit(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (id, exhibit_id) = path.into_inner();
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.download_exhibit(id, exhibit_id, session.user_id()).await {
+        Ok((exhibit, bytes)) => HttpResponse::Ok().content_type(exhibit.media_type).body(bytes),
+        Err(err) => {
+            log::error!("Error downloading exhibit: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Change the caption of an exhibit
+#[put("/reports/{id}/exhibits/{exhibit_id}")]
+async fn update_exhibit(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+    request: web::Json<UpdateExhibitRequest>,
+) -> impl Responder {
+    let (id, exhibit_id) = path.into_inner();
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.update_exhibit(id, exhibit_id, request.into_inner(), session.user_id()).await {
+        Ok(exhibit) => HttpResponse::Ok().json(exhibit),
+        Err(err) => {
+            log::error!("Error updating exhibit: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Remove an exhibit from a report
+#[delete("/reports/{id}/exhibits/{exhibit_id}")]
+async fn delete_exhibit(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (id, exhibit_id) = path.into_inner();
+    let service = ExhibitService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.delete_exhibit(id, exhibit_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error deleting exhibit: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_web::{get, http::header, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::mismo::SchemaValidator;
+use crate::service::export_service::ExportService;
+
+/// Configure report export routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(export_mismo);
+}
+
+/// Export a report as MISMO 2.6 GSE XML
+#[get("/reports/{id}/mismo")]
+async fn export_mismo(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    schema: web::Data<Arc<SchemaValidator>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let id = path.into_inner();
+    let service = ExportService::new(db.get_ref().clone(), store.get_ref().clone(), schema.get_ref().clone());
+
+    match service.export_mismo(id, session.user_id()).await {
+        Ok(xml) => HttpResponse::Ok()
+            .content_type("application/xml")
+            .insert_header(header::ContentDisposition::attachment(format!("report-{}.xml", id)))
+            .body(xml),
+        Err(err) => {
+            log::error!("Error exporting report to MISMO: {:?}", err);
+            err.error_response()
+        }
+    }
+}
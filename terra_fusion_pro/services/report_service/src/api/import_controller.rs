@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::report::ImportReportQuery,
+    storage::AttachmentStore,
+};
+
+use crate::service::import_service::ImportService;
+
+/// Largest accepted import; MISMO documents carry the report PDF base64-encoded
+const MAX_IMPORT_PAYLOAD: usize = 50 * 1024 * 1024;
+
+/// Configure report import routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/reports/import")
+            .app_data(web::PayloadConfig::new(MAX_IMPORT_PAYLOAD))
+            .route(web::post().to(import_mismo))
+    );
+}
+
+/// Import an appraisal delivered as MISMO 2.6 XML; a dry run unless `commit=true`
+async fn import_mismo(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    query: web::Query<ImportReportQuery>,
+    body: web::Bytes,
+) -> impl Responder {
+    let service = ImportService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.import_mismo(&body, query.into_inner(), session.user_id()).await {
+        Ok(result) if result.committed => HttpResponse::Created().json(result),
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(err) => {
+            log::error!("Error importing MISMO report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
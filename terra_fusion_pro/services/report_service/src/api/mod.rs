@@ -0,0 +1,36 @@
+mod exhibit_controller;
+mod export_controller;
+mod import_controller;
+mod photo_controller;
+mod preview_controller;
+mod reconciliation_controller;
+mod render_controller;
+mod report_controller;
+mod review_controller;
+mod revision_controller;
+mod schema_controller;
+mod share_controller;
+mod signature_controller;
+mod snapshot_controller;
+mod snippet_controller;
+
+use actix_web::web;
+
+/// Configure all routes for the API
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    exhibit_controller::configure_routes(cfg);
+    export_controller::configure_routes(cfg);
+    import_controller::configure_routes(cfg);
+    photo_controller::configure_routes(cfg);
+    preview_controller::configure_routes(cfg);
+    reconciliation_controller::configure_routes(cfg);
+    render_controller::configure_routes(cfg);
+    report_controller::configure_routes(cfg);
+    review_controller::configure_routes(cfg);
+    revision_controller::configure_routes(cfg);
+    schema_controller::configure_routes(cfg);
+    share_controller::configure_routes(cfg);
+    signature_controller::configure_routes(cfg);
+    snapshot_controller::configure_routes(cfg);
+    snippet_controller::configure_routes(cfg);
+}
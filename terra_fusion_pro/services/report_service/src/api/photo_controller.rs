@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::content::{UploadReportPhotoQuery, MAX_REPORT_PHOTO_BYTES},
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::exhibit_service::JPEG_MEDIA_TYPE;
+use crate::service::photo_service::PhotoService;
+
+/// Configure report photo routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("/reports/{id}/photos")
+            .app_data(web::PayloadConfig::new(MAX_REPORT_PHOTO_BYTES))
+            .route(web::post().to(upload_photo))
+    )
+    .service(download_photo);
+}
+
+/// Upload a JPEG for the report's photo addendum; the file is the request body
+async fn upload_photo(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    query: web::Query<UploadReportPhotoQuery>,
+    body: web::Bytes,
+) -> impl Responder {
+    let service = PhotoService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.upload_photo(path.into_inner(), query.into_inner(), &body, session.user_id()).await {
+        Ok(photo) => HttpResponse::Created().json(photo),
+        Err(err) => {
+            log::error!("Error uploading report photo: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Download a photo uploaded for a report
+#[get("/reports/{id}/photos/{photo_id}")]
+async fn download_photo(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (id, photo_id) = path.into_inner();
+    let service = PhotoService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.download_photo(id, photo_id, session.user_id()).await {
+        Ok(bytes) => HttpResponse::Ok().content_type(JPEG_MEDIA_TYPE).body(bytes),
+        Err(err) => {
+            log::error!("Error downloading report photo: {:?}", err);
+            err.error_response()
+        }
+    }
+}
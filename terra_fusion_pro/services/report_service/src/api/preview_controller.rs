@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use actix_web::{get, http::header, web, HttpResponse, Responder, ResponseError};
+use futures::stream::{self, StreamExt};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::report::ReportPreviewQuery,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::preview_service::{PreviewEvent, PreviewService};
+
+/// Configure report preview routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(preview_report)
+        .service(preview_events);
+}
+
+/// Preview a report as HTML laid out like its PDF, reloading as it is edited unless `live=false`
+#[get("/reports/{id}/preview")]
+async fn preview_report(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    query: web::Query<ReportPreviewQuery>,
+) -> impl Responder {
+    let service = PreviewService::new(db.get_ref().clone(), store.get_ref().clone());
+    let live = query.live.unwrap_or(true);
+
+    match service.render_preview(path.into_inner(), live, session.user_id()).await {
+        Ok(html) => HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .insert_header(header::CacheControl(vec![header::CacheDirective::NoStore]))
+            .body(html),
+        Err(err) => {
+            log::error!("Error rendering report preview: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Server-sent events announcing each save of a report, for live previews
+#[get("/reports/{id}/preview/events")]
+async fn preview_events(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = PreviewService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.watch(path.into_inner(), session.user_id()).await {
+        Ok(events) => {
+            let events = events
+                .map(|event| match event {
+                    PreviewEvent::Updated(at) => format!("event: updated\ndata: {}\n\n", at.to_rfc3339()),
+                    PreviewEvent::Unchanged => ": keep-alive\n\n".to_string(),
+                })
+                // Tell the page to stop listening rather than reconnect once the report is gone
+                .chain(stream::once(async { "event: closed\ndata: \n\n".to_string() }))
+                .map(|chunk| Ok::<_, actix_web::Error>(web::Bytes::from(chunk)));
+
+            HttpResponse::Ok()
+                .content_type("text/event-stream")
+                .insert_header(header::CacheControl(vec![header::CacheDirective::NoCache]))
+                .streaming(events)
+        }
+        Err(err) => {
+            log::error!("Error watching report for preview: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::reconciliation::{ReconcileReportQuery, ReconcileReportRequest},
+};
+use uuid::Uuid;
+
+use crate::reconciliation::ReconciliationRules;
+use crate::service::reconciliation_service::ReconciliationService;
+
+/// Configure reconciliation routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(reconcile_report);
+}
+
+/// Reconcile a report's approaches to value into its final value; a preview unless `commit=true`
+#[post("/reports/{id}/reconcile")]
+async fn reconcile_report(
+    db: web::Data<Arc<Database>>,
+    rules: web::Data<Arc<ReconciliationRules>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    query: web::Query<ReconcileReportQuery>,
+    request: web::Json<ReconcileReportRequest>,
+) -> impl Responder {
+    let service = ReconciliationService::new(db.get_ref().clone(), rules.get_ref().clone());
+
+    match service
+        .reconcile_report(path.into_inner(), &query, request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(result) => HttpResponse::Ok().json(result),
+        Err(err) => {
+            log::error!("Error reconciling report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use actix_web::{get, http::header, post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::render_service::RenderService;
+
+/// Configure report rendering routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(render_pdf)
+        .service(download_pdf);
+}
+
+/// Render a report to PDF and store it
+#[post("/reports/{id}/pdf")]
+async fn render_pdf(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = RenderService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.render_pdf(path.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error rendering report PDF: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Download the rendered PDF of a report
+#[get("/reports/{id}/pdf")]
+async fn download_pdf(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let id = path.into_inner();
+    let service = RenderService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.download_pdf(id, session.user_id()).await {
+        Ok(bytes) => HttpResponse::Ok()
+            .content_type("application/pdf")
+            .insert_header(header::ContentDisposition::attachment(format!("report-{}.pdf", id)))
+            .body(bytes),
+        Err(err) => {
+            log::error!("Error downloading report PDF: {:?}", err);
+            err.error_response()
+        }
+    }
+}
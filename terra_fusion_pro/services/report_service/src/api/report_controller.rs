@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::report::{CreateReportRequest, ReportQuery, UpdateReportRequest},
+};
+use uuid::Uuid;
+
+use crate::service::report_service::ReportService;
+
+/// Configure report routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_reports)
+        .service(get_report_by_id)
+        .service(create_report)
+        .service(update_report)
+        .service(delete_report);
+}
+
+/// Get a page of report summaries
+#[get("/reports")]
+async fn get_reports(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    query: web::Query<ReportQuery>,
+) -> impl Responder {
+    let service = ReportService::new(db.get_ref().clone());
+
+    match service.list_reports(&query, session.user_id()).await {
+        Ok(reports) => HttpResponse::Ok().json(reports),
+        Err(err) => {
+            log::error!("Error listing reports: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Get a report by ID
+#[get("/reports/{id}")]
+async fn get_report_by_id(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ReportService::new(db.get_ref().clone());
+
+    match service.find_report_by_id(path.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error finding report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Start a new draft report
+#[post("/reports")]
+async fn create_report(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    request: web::Json<CreateReportRequest>,
+) -> impl Responder {
+    let service = ReportService::new(db.get_ref().clone());
+
+    match service.create_report(request.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Created().json(report),
+        Err(err) => {
+            log::error!("Error creating report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Update a report
+#[put("/reports/{id}")]
+async fn update_report(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<UpdateReportRequest>,
+) -> impl Responder {
+    let service = ReportService::new(db.get_ref().clone());
+
+    match service
+        .update_report(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error updating report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Delete a draft report
+#[delete("/reports/{id}")]
+async fn delete_report(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ReportService::new(db.get_ref().clone());
+
+    match service.delete_report(path.into_inner(), session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error deleting report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use actix_web::{get, post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::report::{ReviewReportRequest, SubmitReportRequest},
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::review_service::ReviewService;
+
+/// Configure report submission and review routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(validate_uad)
+        .service(submit_report)
+        .service(claim_review)
+        .service(review_report)
+        .service(finalize_report);
+}
+
+/// Check a report against the UAD specification; submission is refused while it has errors
+#[get("/reports/{id}/uad")]
+async fn validate_uad(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ReviewService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.validate_uad(path.into_inner(), session.user_id()).await {
+        Ok(validation) => HttpResponse::Ok().json(validation),
+        Err(err) => {
+            log::error!("Error validating report against UAD: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Submit a report for review
+#[post("/reports/{id}/submit")]
+async fn submit_report(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<SubmitReportRequest>,
+) -> impl Responder {
+    let service = ReviewService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service
+        .submit_report(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error submitting report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Claim a submitted report for review
+#[post("/reports/{id}/claim-review")]
+async fn claim_review(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ReviewService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.claim_review(path.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error claiming report review: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Record the outcome of a review
+#[post("/reports/{id}/review")]
+async fn review_report(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<ReviewReportRequest>,
+) -> impl Responder {
+    let service = ReviewService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service
+        .review_report(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error reviewing report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Finalize an approved report
+#[post("/reports/{id}/finalize")]
+async fn finalize_report(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ReviewService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.finalize_report(path.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error finalizing report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
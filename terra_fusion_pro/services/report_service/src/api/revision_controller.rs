@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::report::RevisionDiffQuery,
+};
+use uuid::Uuid;
+
+use crate::service::revision_service::RevisionService;
+
+/// Configure report revision routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_revisions)
+        .service(diff_revisions);
+}
+
+/// List the revisions recorded each time a report was submitted
+#[get("/reports/{id}/revisions")]
+async fn list_revisions(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = RevisionService::new(db.get_ref().clone());
+
+    match service.list_revisions(path.into_inner(), session.user_id()).await {
+        Ok(revisions) => HttpResponse::Ok().json(revisions),
+        Err(err) => {
+            log::error!("Error listing report revisions: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Show what changed between two submitted revisions of a report
+#[get("/reports/{id}/revisions/diff")]
+async fn diff_revisions(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    query: web::Query<RevisionDiffQuery>,
+) -> impl Responder {
+    let service = RevisionService::new(db.get_ref().clone());
+
+    match service.diff_revisions(path.into_inner(), &query, session.user_id()).await {
+        Ok(diff) => HttpResponse::Ok().json(diff),
+        Err(err) => {
+            log::error!("Error comparing report revisions: {:?}", err);
+            err.error_response()
+        }
+    }
+}
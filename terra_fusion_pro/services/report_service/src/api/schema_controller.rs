@@ -0,0 +1,13 @@
+use actix_web::{get, web, HttpResponse, Responder};
+use shared::{content::content_schema, models::report::ReportType};
+
+/// Configure report content schema routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_content_schema);
+}
+
+/// Get the JSON Schema that the content of reports of a type is validated against
+#[get("/report-schemas/{report_type}")]
+async fn get_content_schema(path: web::Path<ReportType>) -> impl Responder {
+    HttpResponse::Ok().json(content_schema(&path.into_inner()))
+}
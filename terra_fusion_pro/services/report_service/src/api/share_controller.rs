@@ -0,0 +1,168 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, http::header, post, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::share::{CreateShareLinkRequest, OpenShareLinkRequest},
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::share_service::{ShareLinkVisit, ShareService};
+use crate::sharing::ShareLinkSigner;
+
+/// Configure report share link routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(create_share_link)
+        .service(list_share_links)
+        .service(revoke_share_link)
+        .service(list_share_link_accesses)
+        .service(open_share_link)
+        .service(open_protected_share_link);
+}
+
+/// Share an artifact of a finalized report through a signed, expiring link
+#[post("/reports/{id}/share-links")]
+async fn create_share_link(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<CreateShareLinkRequest>,
+) -> impl Responder {
+    let service = ShareService::new(db.get_ref().clone(), store.get_ref().clone(), signer.get_ref().clone());
+
+    match service.create_link(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(link) => HttpResponse::Created().json(link),
+        Err(err) => {
+            log::error!("Error creating share link: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List the share links of a report
+#[get("/reports/{id}/share-links")]
+async fn list_share_links(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = ShareService::new(db.get_ref().clone(), store.get_ref().clone(), signer.get_ref().clone());
+
+    match service.list_links(path.into_inner(), session.user_id()).await {
+        Ok(links) => HttpResponse::Ok().json(links),
+        Err(err) => {
+            log::error!("Error listing share links: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Revoke a share link so it can no longer be opened
+#[delete("/reports/{id}/share-links/{link_id}")]
+async fn revoke_share_link(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (id, link_id) = path.into_inner();
+    let service = ShareService::new(db.get_ref().clone(), store.get_ref().clone(), signer.get_ref().clone());
+
+    match service.revoke_link(id, link_id, session.user_id()).await {
+        Ok(link) => HttpResponse::Ok().json(link),
+        Err(err) => {
+            log::error!("Error revoking share link: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// List who opened a share link and when, including refused attempts
+#[get("/reports/{id}/share-links/{link_id}/accesses")]
+async fn list_share_link_accesses(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let (id, link_id) = path.into_inner();
+    let service = ShareService::new(db.get_ref().clone(), store.get_ref().clone(), signer.get_ref().clone());
+
+    match service.list_accesses(id, link_id, session.user_id()).await {
+        Ok(accesses) => HttpResponse::Ok().json(accesses),
+        Err(err) => {
+            log::error!("Error listing share link accesses: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Download the artifact behind a share link; open to anyone holding the link
+#[get("/shared/{token}")]
+async fn open_share_link(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    open(db, store, signer, &req, &path.into_inner(), None).await
+}
+
+/// Download the artifact behind a password-protected share link
+#[post("/shared/{token}")]
+async fn open_protected_share_link(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    request: web::Json<OpenShareLinkRequest>,
+) -> impl Responder {
+    open(db, store, signer, &req, &path.into_inner(), request.into_inner().password).await
+}
+
+/// Open a share link for the requester, recording where the request came from
+async fn open(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    signer: web::Data<Arc<ShareLinkSigner>>,
+    req: &HttpRequest,
+    token: &str,
+    password: Option<String>,
+) -> HttpResponse {
+    let service = ShareService::new(db.get_ref().clone(), store.get_ref().clone(), signer.get_ref().clone());
+    // The socket peer is recorded rather than forwarded headers, which the visitor could forge
+    let visit = ShareLinkVisit {
+        ip_address: req.peer_addr().map(|addr| addr.ip().to_string()),
+        user_agent: req
+            .headers()
+            .get(header::USER_AGENT)
+            .and_then(|agent| agent.to_str().ok())
+            .map(str::to_string),
+        password,
+    };
+
+    match service.open_link(token, visit).await {
+        Ok(artifact) => HttpResponse::Ok()
+            .content_type(artifact.media_type)
+            .insert_header(header::CacheControl(vec![header::CacheDirective::NoStore]))
+            .insert_header(header::ContentDisposition::attachment(format!(
+                "report-{}-{}",
+                artifact.report_id, artifact.name
+            )))
+            .body(artifact.bytes),
+        Err(err) => {
+            log::error!("Error opening share link: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use actix_web::{get, post, put, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::signature::{AssignSupervisorRequest, SignReportRequest},
+    signing::KeyVault,
+};
+use uuid::Uuid;
+
+use crate::service::signature_service::SignatureService;
+
+/// Configure report signature routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(assign_supervisor)
+        .service(sign_report)
+        .service(verify_signatures);
+}
+
+/// Name the supervisory appraiser who co-signs a report
+#[put("/reports/{id}/supervisor")]
+async fn assign_supervisor(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<AssignSupervisorRequest>,
+) -> impl Responder {
+    let service = SignatureService::new(db.get_ref().clone(), vault.get_ref().clone());
+
+    match service.assign_supervisor(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error assigning report supervisor: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Sign a finalized report as its appraiser or supervisory appraiser
+#[post("/reports/{id}/signatures")]
+async fn sign_report(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<SignReportRequest>,
+) -> impl Responder {
+    let service = SignatureService::new(db.get_ref().clone(), vault.get_ref().clone());
+
+    match service.sign_report(path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(signature) => HttpResponse::Created().json(signature),
+        Err(err) => {
+            log::error!("Error signing report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Verify the signatures on a finalized report; open to anyone
+#[get("/reports/{id}/signatures/verify")]
+async fn verify_signatures(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = SignatureService::new(db.get_ref().clone(), vault.get_ref().clone());
+
+    match service.verify_signatures(path.into_inner()).await {
+        Ok(verification) => HttpResponse::Ok().json(verification),
+        Err(err) => {
+            log::error!("Error verifying report signatures: {:?}", err);
+            err.error_response()
+        }
+    }
+}
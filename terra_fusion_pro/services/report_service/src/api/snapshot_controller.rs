@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use actix_web::{get, http::header, post, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::snapshot_service::SnapshotService;
+
+/// Largest file accepted for verification
+const MAX_VERIFY_PAYLOAD: usize = 50 * 1024 * 1024;
+
+/// Configure finalized report snapshot routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_snapshot)
+        .service(download_artifact)
+        .service(
+            web::resource("/reports/{id}/snapshot/verify")
+                .app_data(web::PayloadConfig::new(MAX_VERIFY_PAYLOAD))
+                .route(web::post().to(verify_artifact))
+        )
+        .service(amend_report);
+}
+
+/// Get the snapshot manifest of a finalized report
+#[get("/reports/{id}/snapshot")]
+async fn get_snapshot(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = SnapshotService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.get_snapshot(path.into_inner(), session.user_id()).await {
+        Ok(snapshot) => HttpResponse::Ok().json(snapshot),
+        Err(err) => {
+            log::error!("Error fetching report snapshot: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Download an artifact delivered with a finalized report
+#[get("/reports/{id}/snapshot/artifacts/{name}")]
+async fn download_artifact(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, String)>,
+) -> impl Responder {
+    let (id, name) = path.into_inner();
+    let service = SnapshotService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.download_artifact(id, &name, session.user_id()).await {
+        Ok((bytes, media_type)) => HttpResponse::Ok()
+            .content_type(media_type)
+            .insert_header(header::ContentDisposition::attachment(format!("report-{}-{}", id, name)))
+            .body(bytes),
+        Err(err) => {
+            log::error!("Error downloading report artifact: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Check whether the request body is one of the artifacts delivered with a finalized report
+async fn verify_artifact(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    body: web::Bytes,
+) -> impl Responder {
+    let service = SnapshotService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.verify(path.into_inner(), &body, session.user_id()).await {
+        Ok(verification) => HttpResponse::Ok().json(verification),
+        Err(err) => {
+            log::error!("Error verifying report artifact: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Start an amended version of a finalized report
+#[post("/reports/{id}/amend")]
+async fn amend_report(
+    db: web::Data<Arc<Database>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = SnapshotService::new(db.get_ref().clone(), store.get_ref().clone());
+
+    match service.amend_report(path.into_inner(), session.user_id()).await {
+        Ok(report) => HttpResponse::Created().json(report),
+        Err(err) => {
+            log::error!("Error amending report: {:?}", err);
+            err.error_response()
+        }
+    }
+}
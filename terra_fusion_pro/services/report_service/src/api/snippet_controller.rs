@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::snippet::{ApplySnippetRequest, SnippetQuery, SnippetRequest},
+};
+use uuid::Uuid;
+
+use crate::service::snippet_service::SnippetService;
+
+/// Configure narrative snippet routes
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(list_snippets)
+        .service(create_snippet)
+        .service(update_snippet)
+        .service(delete_snippet)
+        .service(preview_snippet)
+        .service(apply_snippet);
+}
+
+/// List the organization's snippets and the current user's personal snippets
+#[get("/snippets")]
+async fn list_snippets(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    query: web::Query<SnippetQuery>,
+) -> impl Responder {
+    let service = SnippetService::new(db.get_ref().clone());
+
+    match service.list_snippets(&query, session.user_id()).await {
+        Ok(snippets) => HttpResponse::Ok().json(snippets),
+        Err(err) => {
+            log::error!("Error listing snippets: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Add a snippet to the current user's or the organization's library
+#[post("/snippets")]
+async fn create_snippet(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    request: web::Json<SnippetRequest>,
+) -> impl Responder {
+    let service = SnippetService::new(db.get_ref().clone());
+
+    match service.create_snippet(request.into_inner(), session.user_id()).await {
+        Ok(snippet) => HttpResponse::Created().json(snippet),
+        Err(err) => {
+            log::error!("Error creating snippet: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Replace a snippet the current user wrote
+#[put("/snippets/{id}")]
+async fn update_snippet(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+    request: web::Json<SnippetRequest>,
+) -> impl Responder {
+    let service = SnippetService::new(db.get_ref().clone());
+
+    match service
+        .update_snippet(path.into_inner(), request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(snippet) => HttpResponse::Ok().json(snippet),
+        Err(err) => {
+            log::error!("Error updating snippet: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Delete a snippet the current user wrote
+#[delete("/snippets/{id}")]
+async fn delete_snippet(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<Uuid>,
+) -> impl Responder {
+    let service = SnippetService::new(db.get_ref().clone());
+
+    match service.delete_snippet(path.into_inner(), session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(err) => {
+            log::error!("Error deleting snippet: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Render a snippet against a report without changing it
+#[post("/reports/{id}/snippets/{snippet_id}/preview")]
+async fn preview_snippet(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+) -> impl Responder {
+    let service = SnippetService::new(db.get_ref().clone());
+    let (id, snippet_id) = path.into_inner();
+
+    match service.preview_snippet(id, snippet_id, session.user_id()).await {
+        Ok(rendered) => HttpResponse::Ok().json(rendered),
+        Err(err) => {
+            log::error!("Error previewing snippet: {:?}", err);
+            err.error_response()
+        }
+    }
+}
+
+/// Render a snippet against a report and write it into the report's content
+#[post("/reports/{id}/snippets/{snippet_id}/apply")]
+async fn apply_snippet(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(Uuid, Uuid)>,
+    request: web::Json<ApplySnippetRequest>,
+) -> impl Responder {
+    let service = SnippetService::new(db.get_ref().clone());
+    let (id, snippet_id) = path.into_inner();
+
+    match service
+        .apply_snippet(id, snippet_id, request.into_inner(), session.user_id())
+        .await
+    {
+        Ok(report) => HttpResponse::Ok().json(report),
+        Err(err) => {
+            log::error!("Error applying snippet: {:?}", err);
+            err.error_response()
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Structural comparison of two revisions of a report's content, labelled from the form layout
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+use shared::models::report::{AdjustmentChange, AmountChange, ChangeKind, ContentChange, TextOp, TextSpan};
+use similar::{ChangeTag, TextDiff};
+
+use crate::rendering::layout::{humanize_key, FieldFormat, FieldLayout, FormLayout};
+
+/// Separator between the parts of a human-readable location
+const LABEL_SEPARATOR: &str = " › ";
+
+/// Every value that differs between two versions of a report's content, in form order
+pub fn diff_content(layout: &FormLayout, before: &Value, after: &Value) -> Vec<ContentChange> {
+    let mut walker = Walker { changes: Vec::new() };
+
+    let preferred = layout
+        .sections
+        .iter()
+        .map(|section| section.key)
+        .chain(["comparables"]);
+
+    for key in ordered_keys(Some(before), Some(after), preferred) {
+        let location = Location {
+            path: key.clone(),
+            label: match layout.sections.iter().find(|section| section.key == key) {
+                Some(section) => section.heading.to_string(),
+                None => humanize_key(&key),
+            },
+            relative: String::new(),
+            fields: layout.sections.iter().find(|section| section.key == key).map_or(&[], |s| s.fields),
+        };
+
+        if key == "comparables" {
+            walker.comparables(before.get(&key), after.get(&key), &location, layout.comparable_rows);
+        } else {
+            walker.walk(before.get(&key), after.get(&key), &location);
+        }
+    }
+
+    walker.changes
+}
+
+/// Comparables whose net adjustment or adjusted sale price differ between two versions of a
+/// report's content
+pub fn diff_adjustments(before: &Value, after: &Value) -> Vec<AdjustmentChange> {
+    let comparables = |content: &Value| content.get("comparables").and_then(Value::as_array).cloned();
+    let before = comparables(before).unwrap_or_default();
+    let after = comparables(after).unwrap_or_default();
+
+    (0..before.len().max(after.len()))
+        .filter_map(|index| {
+            let amount = |comparables: &[Value], key: &str| {
+                comparables.get(index).and_then(|c| c.get(key)).and_then(Value::as_f64)
+            };
+
+            let net_adjustment = diff_amount(amount(&before, "net_adjustment"), amount(&after, "net_adjustment"));
+            let adjusted_price = diff_amount(amount(&before, "adjusted_price"), amount(&after, "adjusted_price"));
+            if net_adjustment.is_none() && adjusted_price.is_none() {
+                return None;
+            }
+
+            Some(AdjustmentChange {
+                comparable: index + 1,
+                address: after
+                    .get(index)
+                    .or_else(|| before.get(index))
+                    .and_then(|c| c.get("address"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                net_adjustment,
+                adjusted_price,
+            })
+        })
+        .collect()
+}
+
+/// The change between two amounts, or `None` when they are equal
+pub fn diff_amount(before: Option<f64>, after: Option<f64>) -> Option<AmountChange> {
+    if before == after {
+        return None;
+    }
+
+    Some(AmountChange {
+        before,
+        after,
+        difference: before.zip(after).map(|(before, after)| after - before),
+    })
+}
+
+/// Where a value sits in the content, and the layout fields describing its container
+struct Location {
+    /// Machine path, e.g. "comparables[1].net_adjustment"
+    path: String,
+
+    /// Human-readable path
+    label: String,
+
+    /// Dotted path within the current section or comparable, matched against `fields`
+    relative: String,
+
+    /// Layout fields of the current section or comparable
+    fields: &'static [FieldLayout],
+}
+
+impl Location {
+    /// Location of a key within an object
+    fn key(&self, key: &str) -> Location {
+        let relative = if self.relative.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", self.relative, key)
+        };
+        let label = match self.fields.iter().find(|field| field.path == relative) {
+            Some(field) => field.label.to_string(),
+            None => humanize_key(key),
+        };
+
+        Location {
+            path: format!("{}.{}", self.path, key),
+            label: format!("{}{}{}", self.label, LABEL_SEPARATOR, label),
+            relative,
+            fields: self.fields,
+        }
+    }
+
+    /// Location of an entry of an array
+    fn index(&self, index: usize, name: &str, fields: &'static [FieldLayout]) -> Location {
+        Location {
+            path: format!("{}[{}]", self.path, index),
+            label: format!("{}{}{} {}", self.label, LABEL_SEPARATOR, name, index + 1),
+            relative: String::new(),
+            fields,
+        }
+    }
+
+    /// Whether the value here is a narrative
+    fn is_narrative(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|field| field.path == self.relative && matches!(field.format, FieldFormat::Narrative))
+    }
+}
+
+/// Collects changes while walking two versions of the content side by side
+struct Walker {
+    changes: Vec<ContentChange>,
+}
+
+impl Walker {
+    /// Compare the sales comparison grid entry by entry
+    fn comparables(
+        &mut self,
+        before: Option<&Value>,
+        after: Option<&Value>,
+        location: &Location,
+        rows: &'static [FieldLayout],
+    ) {
+        match (before, after) {
+            (Some(Value::Array(before)), Some(Value::Array(after))) => {
+                for index in 0..before.len().max(after.len()) {
+                    let entry = location.index(index, "Comparable", rows);
+                    self.walk(before.get(index), after.get(index), &entry);
+                }
+            }
+            _ => self.walk(before, after, location),
+        }
+    }
+
+    /// Compare two values, descending into objects and arrays present in both
+    fn walk(&mut self, before: Option<&Value>, after: Option<&Value>, location: &Location) {
+        match (before, after) {
+            (Some(Value::Object(_)), Some(Value::Object(_))) => {
+                for key in ordered_keys(before, after, location.fields.iter().map(|field| field.path)) {
+                    let child = location.key(&key);
+                    self.walk(before.and_then(|v| v.get(&key)), after.and_then(|v| v.get(&key)), &child);
+                }
+            }
+            (Some(Value::Array(items_before)), Some(Value::Array(items_after))) => {
+                for index in 0..items_before.len().max(items_after.len()) {
+                    let entry = location.index(index, "Item", location.fields);
+                    let entry = Location { relative: location.relative.clone(), ..entry };
+                    self.walk(items_before.get(index), items_after.get(index), &entry);
+                }
+            }
+            _ => self.leaf(before, after, location),
+        }
+    }
+
+    /// Record a change to a single value
+    fn leaf(&mut self, before: Option<&Value>, after: Option<&Value>, location: &Location) {
+        let before = before.filter(|value| !value.is_null());
+        let after = after.filter(|value| !value.is_null());
+
+        let kind = match (before, after) {
+            (None, None) => return,
+            (Some(before), Some(after)) if before == after => return,
+            (Some(_), Some(_)) => ChangeKind::Changed,
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+        };
+
+        let text_diff = match (before, after) {
+            (Some(Value::String(old)), Some(Value::String(new)))
+                if location.is_narrative() || old.contains('\n') || new.contains('\n') =>
+            {
+                Some(diff_text(old, new))
+            }
+            _ => None,
+        };
+
+        self.changes.push(ContentChange {
+            path: location.path.clone(),
+            label: location.label.clone(),
+            kind,
+            before: before.cloned(),
+            after: after.cloned(),
+            text_diff,
+        });
+    }
+}
+
+/// Keys of two objects: the preferred keys that are present first, then the rest alphabetically
+fn ordered_keys<'a>(
+    before: Option<&Value>,
+    after: Option<&Value>,
+    preferred: impl Iterator<Item = &'a str>,
+) -> Vec<String> {
+    let mut remaining = [before, after]
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object)
+        .flat_map(|object| object.keys().cloned())
+        .collect::<BTreeSet<_>>();
+
+    let mut keys = Vec::with_capacity(remaining.len());
+    for key in preferred {
+        if remaining.remove(key) {
+            keys.push(key.to_string());
+        }
+    }
+    keys.extend(remaining);
+    keys
+}
+
+/// Word-level diff of two narratives, with adjacent words of the same kind merged into one span
+fn diff_text(before: &str, after: &str) -> Vec<TextSpan> {
+    let mut spans: Vec<TextSpan> = Vec::new();
+
+    for change in TextDiff::from_words(before, after).iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => TextOp::Equal,
+            ChangeTag::Insert => TextOp::Insert,
+            ChangeTag::Delete => TextOp::Delete,
+        };
+
+        match spans.last_mut() {
+            Some(span) if span.op == op => span.text.push_str(change.value()),
+            _ => spans.push(TextSpan {
+                op,
+                text: change.value().to_string(),
+            }),
+        }
+    }
+
+    spans
+}
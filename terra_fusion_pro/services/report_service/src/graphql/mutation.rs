@@ -0,0 +1,133 @@
+use async_graphql::{Context, Object, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use shared::db::Database;
+use shared::storage::AttachmentStore;
+
+use crate::service::render_service::RenderService;
+use crate::service::report_service::ReportService;
+use crate::service::review_service::ReviewService;
+use crate::service::snapshot_service::SnapshotService;
+use super::types::{Report, ReportInput, ReviewReportInput, SubmitReportInput, UpdateReportInput};
+use super::CurrentUser;
+
+/// GraphQL mutation root
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    /// Start a new draft report
+    async fn create_report(&self, ctx: &Context<'_>, input: ReportInput) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReportService::new(db.clone());
+        
+        match service.create_report(input.into(), user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Update an existing report
+    async fn update_report(&self, ctx: &Context<'_>, id: Uuid, input: UpdateReportInput) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReportService::new(db.clone());
+        
+        match service.update_report(id, input.into(), user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Delete a draft report
+    async fn delete_report(&self, ctx: &Context<'_>, id: Uuid) -> Result<bool> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReportService::new(db.clone());
+        
+        match service.delete_report(id, user.0.clone()).await {
+            Ok(_) => Ok(true),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Submit a report for review
+    async fn submit_report(&self, ctx: &Context<'_>, id: Uuid, input: SubmitReportInput) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReviewService::new(db.clone(), store.clone());
+        
+        match service.submit_report(id, input.into(), user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Claim a submitted report for review
+    async fn claim_report_review(&self, ctx: &Context<'_>, id: Uuid) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReviewService::new(db.clone(), store.clone());
+        
+        match service.claim_review(id, user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Record the outcome of a review
+    async fn review_report(&self, ctx: &Context<'_>, id: Uuid, input: ReviewReportInput) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReviewService::new(db.clone(), store.clone());
+        
+        match service.review_report(id, input.into(), user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Finalize an approved report
+    async fn finalize_report(&self, ctx: &Context<'_>, id: Uuid) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = ReviewService::new(db.clone(), store.clone());
+        
+        match service.finalize_report(id, user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Start an amended version of a finalized report
+    async fn amend_report(&self, ctx: &Context<'_>, id: Uuid) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = SnapshotService::new(db.clone(), store.clone());
+        
+        match service.amend_report(id, user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// Render a report to PDF and store it
+    async fn render_report_pdf(&self, ctx: &Context<'_>, id: Uuid) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>().unwrap();
+        let store = ctx.data::<Arc<dyn AttachmentStore>>().unwrap();
+        let user = ctx.data::<CurrentUser>().unwrap();
+        let service = RenderService::new(db.clone(), store.clone());
+        
+        match service.render_pdf(id, user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use actix_web::ResponseError;
+use async_graphql::{Context, Object, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use shared::db::Database;
+use shared::error::AppError;
+
+use crate::service::report_service::ReportService;
+use super::types::{Report, ReportQueryInput, ReportSummary};
+use super::CurrentUser;
+
+/// GraphQL query root
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Get a report by ID
+    async fn report(&self, ctx: &Context<'_>, id: Uuid) -> Result<Report> {
+        let db = ctx.data::<Arc<Database>>()?;
+        let user = ctx.data::<CurrentUser>()?;
+        let service = ReportService::new(db.clone());
+        
+        match service.find_report_by_id(id, user.0.clone()).await {
+            Ok(report) => Ok(report.into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    /// List report summaries
+    async fn reports(&self, ctx: &Context<'_>, query: Option<ReportQueryInput>) -> Result<Vec<ReportSummary>> {
+        let db = ctx.data::<Arc<Database>>()?;
+        let user = ctx.data::<CurrentUser>()?;
+        let service = ReportService::new(db.clone());
+        let query = query.map(|q| q.into()).unwrap_or_default();
+        
+        match service.list_reports(&query, user.0.clone()).await {
+            Ok(reports) => Ok(reports.into_iter().map(|r| r.into()).collect()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Convert application errors to GraphQL errors
+impl From<AppError> for async_graphql::Error {
+    fn from(err: AppError) -> Self {
+        async_graphql::Error::new(err.to_string())
+            .extend_with(|_, e| {
+                e.set("type", err.error_type());
+                e.set("status", err.status_code().as_u16());
+            })
+    }
+}
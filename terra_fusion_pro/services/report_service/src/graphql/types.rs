@@ -0,0 +1,268 @@
+use async_graphql::{SimpleObject, InputObject, Enum};
+use chrono::{DateTime, Utc};
+use shared::models::report::{ReportStatus as ModelReportStatus, ReportType as ModelReportType};
+use uuid::Uuid;
+
+/// GraphQL representation of a report
+#[derive(SimpleObject)]
+pub struct Report {
+    pub id: Uuid,
+    pub title: String,
+    pub property_id: Uuid,
+    pub appraiser_id: Uuid,
+    pub status: ReportStatus,
+    pub report_type: ReportType,
+    pub valuation_amount: Option<f64>,
+    pub content: String, // JSON as string
+    pub pdf_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub reviewer_id: Option<Uuid>,
+    pub review_comments: Option<String>,
+    pub submission_comments: Option<String>,
+    pub version: i32,
+    pub amends_id: Option<Uuid>,
+    pub supervisor_id: Option<Uuid>,
+}
+
+/// GraphQL representation of a report listing entry
+#[derive(SimpleObject)]
+pub struct ReportSummary {
+    pub id: Uuid,
+    pub title: String,
+    pub status: ReportStatus,
+    pub report_type: ReportType,
+    pub property_address: String,
+    pub appraiser_name: String,
+    pub valuation_amount: Option<f64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// GraphQL enum for report statuses
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ReportStatus {
+    Draft,
+    InProgress,
+    Submitted,
+    UnderReview,
+    NeedsCorrection,
+    Approved,
+    Rejected,
+    Finalized,
+}
+
+/// GraphQL enum for report types
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ReportType {
+    Form1004,
+    Form1073,
+    Form1025,
+    Form1004C,
+    Form2055,
+    CommercialForm,
+    DesktopAppraisal,
+    BPO,
+    Other,
+}
+
+/// GraphQL input for creating a report
+#[derive(InputObject)]
+pub struct ReportInput {
+    pub title: String,
+    pub property_id: Uuid,
+    pub report_type: ReportType,
+    pub content: Option<String>, // JSON as string
+}
+
+/// GraphQL input for updating a report
+#[derive(InputObject)]
+pub struct UpdateReportInput {
+    pub title: Option<String>,
+    pub status: Option<ReportStatus>,
+    pub valuation_amount: Option<f64>,
+    pub content: Option<String>, // JSON as string
+}
+
+/// GraphQL input for submitting a report for review
+#[derive(InputObject)]
+pub struct SubmitReportInput {
+    pub comments: Option<String>,
+}
+
+/// GraphQL input for recording a review outcome
+#[derive(InputObject)]
+pub struct ReviewReportInput {
+    pub status: ReportStatus,
+    pub comments: String,
+}
+
+/// GraphQL input for filtering and paging reports
+#[derive(InputObject)]
+pub struct ReportQueryInput {
+    pub property_id: Option<Uuid>,
+    pub appraiser_id: Option<Uuid>,
+    pub status: Option<ReportStatus>,
+    pub report_type: Option<ReportType>,
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+/// Conversion functions between GraphQL and domain model types
+impl From<shared::models::report::Report> for Report {
+    fn from(r: shared::models::report::Report) -> Self {
+        Self {
+            id: r.id,
+            title: r.title,
+            property_id: r.property_id,
+            appraiser_id: r.appraiser_id,
+            status: r.status.into(),
+            report_type: r.report_type.into(),
+            valuation_amount: r.valuation_amount,
+            content: r.content.to_string(),
+            pdf_url: r.pdf_url,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+            submitted_at: r.submitted_at,
+            reviewed_at: r.reviewed_at,
+            reviewer_id: r.reviewer_id,
+            review_comments: r.review_comments,
+            submission_comments: r.submission_comments,
+            version: r.version,
+            amends_id: r.amends_id,
+            supervisor_id: r.supervisor_id,
+        }
+    }
+}
+
+impl From<shared::models::report::ReportSummary> for ReportSummary {
+    fn from(r: shared::models::report::ReportSummary) -> Self {
+        Self {
+            id: r.id,
+            title: r.title,
+            status: r.status.into(),
+            report_type: r.report_type.into(),
+            property_address: r.property_address,
+            appraiser_name: r.appraiser_name,
+            valuation_amount: r.valuation_amount,
+            created_at: r.created_at,
+            updated_at: r.updated_at,
+        }
+    }
+}
+
+impl From<ModelReportStatus> for ReportStatus {
+    fn from(status: ModelReportStatus) -> Self {
+        match status {
+            ModelReportStatus::Draft => ReportStatus::Draft,
+            ModelReportStatus::InProgress => ReportStatus::InProgress,
+            ModelReportStatus::Submitted => ReportStatus::Submitted,
+            ModelReportStatus::UnderReview => ReportStatus::UnderReview,
+            ModelReportStatus::NeedsCorrection => ReportStatus::NeedsCorrection,
+            ModelReportStatus::Approved => ReportStatus::Approved,
+            ModelReportStatus::Rejected => ReportStatus::Rejected,
+            ModelReportStatus::Finalized => ReportStatus::Finalized,
+        }
+    }
+}
+
+impl From<ReportStatus> for ModelReportStatus {
+    fn from(status: ReportStatus) -> Self {
+        match status {
+            ReportStatus::Draft => ModelReportStatus::Draft,
+            ReportStatus::InProgress => ModelReportStatus::InProgress,
+            ReportStatus::Submitted => ModelReportStatus::Submitted,
+            ReportStatus::UnderReview => ModelReportStatus::UnderReview,
+            ReportStatus::NeedsCorrection => ModelReportStatus::NeedsCorrection,
+            ReportStatus::Approved => ModelReportStatus::Approved,
+            ReportStatus::Rejected => ModelReportStatus::Rejected,
+            ReportStatus::Finalized => ModelReportStatus::Finalized,
+        }
+    }
+}
+
+impl From<ModelReportType> for ReportType {
+    fn from(rt: ModelReportType) -> Self {
+        match rt {
+            ModelReportType::Form1004 => ReportType::Form1004,
+            ModelReportType::Form1073 => ReportType::Form1073,
+            ModelReportType::Form1025 => ReportType::Form1025,
+            ModelReportType::Form1004C => ReportType::Form1004C,
+            ModelReportType::Form2055 => ReportType::Form2055,
+            ModelReportType::CommercialForm => ReportType::CommercialForm,
+            ModelReportType::DesktopAppraisal => ReportType::DesktopAppraisal,
+            ModelReportType::BPO => ReportType::BPO,
+            ModelReportType::Other => ReportType::Other,
+        }
+    }
+}
+
+impl From<ReportType> for ModelReportType {
+    fn from(rt: ReportType) -> Self {
+        match rt {
+            ReportType::Form1004 => ModelReportType::Form1004,
+            ReportType::Form1073 => ModelReportType::Form1073,
+            ReportType::Form1025 => ModelReportType::Form1025,
+            ReportType::Form1004C => ModelReportType::Form1004C,
+            ReportType::Form2055 => ModelReportType::Form2055,
+            ReportType::CommercialForm => ModelReportType::CommercialForm,
+            ReportType::DesktopAppraisal => ModelReportType::DesktopAppraisal,
+            ReportType::BPO => ModelReportType::BPO,
+            ReportType::Other => ModelReportType::Other,
+        }
+    }
+}
+
+impl From<ReportInput> for shared::models::report::CreateReportRequest {
+    fn from(r: ReportInput) -> Self {
+        Self {
+            title: r.title,
+            property_id: r.property_id,
+            report_type: r.report_type.into(),
+            content: r.content.map(|c| serde_json::from_str(&c).unwrap_or_default()),
+        }
+    }
+}
+
+impl From<UpdateReportInput> for shared::models::report::UpdateReportRequest {
+    fn from(r: UpdateReportInput) -> Self {
+        Self {
+            title: r.title,
+            status: r.status.map(|s| s.into()),
+            valuation_amount: r.valuation_amount,
+            content: r.content.map(|c| serde_json::from_str(&c).unwrap_or_default()),
+        }
+    }
+}
+
+impl From<SubmitReportInput> for shared::models::report::SubmitReportRequest {
+    fn from(r: SubmitReportInput) -> Self {
+        Self {
+            comments: r.comments,
+        }
+    }
+}
+
+impl From<ReviewReportInput> for shared::models::report::ReviewReportRequest {
+    fn from(r: ReviewReportInput) -> Self {
+        Self {
+            status: r.status.into(),
+            comments: r.comments,
+        }
+    }
+}
+
+impl From<ReportQueryInput> for shared::models::report::ReportQuery {
+    fn from(q: ReportQueryInput) -> Self {
+        Self {
+            property_id: q.property_id,
+            appraiser_id: q.appraiser_id,
+            status: q.status.map(|s| s.into()),
+            report_type: q.report_type.map(|rt| rt.into()),
+            page: q.page,
+            limit: q.limit,
+        }
+    }
+}
@@ -1,3 +1,116 @@
-fn main() {
-    println!("Hello, world!");
+mod api;
+mod diff;
+mod mismo;
+mod narrative;
+mod reconciliation;
+mod repository;
+mod rendering;
+mod service;
+mod sharing;
+mod graphql;
+
+use std::sync::Arc;
+
+use actix_cors::Cors;
+use actix_session::CookieSession;
+use actix_web::{web, App, HttpResponse, HttpServer, middleware};
+
+use shared::{
+    auth::{
+        middleware::AuthenticationMiddleware,
+        replit_auth::ReplitAuth,
+    },
+    db::Database,
+    config::Config,
+    signing::KeyVault,
+    storage::{AttachmentStore, LocalAttachmentStore},
+};
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
+    
+    // Load configuration
+    let config = Config::from_env().expect("Failed to load configuration");
+    
+    // Initialize database
+    let db = Arc::new(Database::connect(&config.database_url).await.expect("Failed to connect to database"));
+    log::info!("Connected to database");
+    
+    // Initialize Replit Auth for session handling
+    let replit_auth_config = shared::auth::replit_auth::ReplitAuthConfig {
+        client_id: config.replit_auth.client_id.clone(),
+        domain: config.replit_auth.domain.clone(),
+        discovery_url: "https://replit.com/oidc".to_string(),
+    };
+    let replit_auth = Arc::new(ReplitAuth::new(replit_auth_config));
+    
+    // Initialize attachment storage for rendered reports and photos
+    let attachments: Arc<dyn AttachmentStore> = Arc::new(LocalAttachmentStore::new(&config.attachments_dir));
+    
+    // Initialize the vault holding appraisers' signing keys
+    let vault = Arc::new(KeyVault::new(&config.signing_key_secret));
+    
+    // Initialize the signer for report share links
+    let share_signer = Arc::new(sharing::ShareLinkSigner::new(&config.share_link_secret));
+    
+    // Load the MISMO schema validator used by lender exports
+    let mismo_schema = Arc::new(mismo::SchemaValidator::from_env().expect("Failed to load MISMO schema validator"));
+    
+    // Load the rounding and spread rules used when reconciling approaches to value
+    let reconciliation_rules = Arc::new(
+        reconciliation::ReconciliationRules::from_env().expect("Failed to load reconciliation rules"),
+    );
+    
+    // Set up GraphQL schema
+    let schema = web::Data::new(graphql::create_schema(db.clone(), attachments.clone()));
+    
+    // Start HTTP server
+    log::info!("Starting Report Service on {}:{}", config.host, config.port);
+    
+    HttpServer::new(move || {
+        let cors = Cors::default()
+            .allow_any_origin()
+            .allow_any_method()
+            .allow_any_header()
+            .max_age(3600);
+            
+        App::new()
+            .wrap(middleware::Logger::default())
+            .wrap(cors)
+            .wrap(middleware::NormalizePath::trim())
+            .wrap(CookieSession::signed(&[0; 32]) // In production, use a proper key
+                .secure(false)) // In production, set to true for HTTPS
+            .wrap(AuthenticationMiddleware::new(replit_auth.clone()))
+            .app_data(web::Data::new(db.clone()))
+            .app_data(web::Data::new(attachments.clone()))
+            .app_data(web::Data::new(mismo_schema.clone()))
+            .app_data(web::Data::new(reconciliation_rules.clone()))
+            .app_data(web::Data::new(vault.clone()))
+            .app_data(web::Data::new(share_signer.clone()))
+            .app_data(schema.clone())
+            // Add health check endpoint
+            .route("/health", web::get().to(health_check))
+            // Add API routes
+            .service(
+                web::scope("/api/v1")
+                    .configure(api::configure_routes)
+            )
+            // Add GraphQL endpoint
+            .service(
+                web::scope("/graphql")
+                    .configure(graphql::configure_routes)
+            )
+    })
+    .bind((config.host.as_str(), config.port))?
+    .run()
+    .await
+}
+
+/// Health check endpoint
+async fn health_check() -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "healthy",
+        "service": "report-service"
+    }))
 }
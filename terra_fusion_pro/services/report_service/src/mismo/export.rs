@@ -0,0 +1,434 @@
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use serde_json::{json, Map, Value};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::Appraisal;
+use shared::models::credential::{AppraiserLicense, LicenseLevel};
+use shared::models::property::Property;
+use shared::models::report::Report;
+use shared::utils::format::format_address_single_line;
+
+use super::mapping::{
+    AttributeMapping, ElementMapping, COMPARABLE_ADJUSTMENTS, COMPARABLE_ATTRIBUTES, COMPARABLE_ROOMS,
+    COST_ANALYSIS, INCOME_ANALYSIS, PROPERTY_ATTRIBUTES, PROPERTY_CHILDREN, PURPOSE_TYPES, RECONCILIATION,
+    SALES_COMPARISON_ATTRIBUTES, VALUATION_ATTRIBUTES,
+};
+use super::uad::{to_mismo, UadFormat};
+use super::{MismoForm, MISMO_VERSION};
+use crate::rendering::layout::{layout_for, lookup, FormLayout};
+
+/// Everything written to a MISMO export besides the form mapping
+pub struct MismoReport<'a> {
+    /// Report being exported
+    pub report: &'a Report,
+
+    /// Subject property
+    pub property: &'a Property,
+
+    /// Appraisal order the report was written for (if any)
+    pub appraisal: Option<&'a Appraisal>,
+
+    /// Name of the lender that ordered the appraisal (if known)
+    pub lender_name: Option<&'a str>,
+
+    /// Name of the signing appraiser
+    pub appraiser_name: &'a str,
+
+    /// License the appraiser signs under (if any)
+    pub license: Option<&'a AppraiserLicense>,
+
+    /// Rendered report PDF, embedded in the export
+    pub pdf: &'a [u8],
+}
+
+/// Write a report as a MISMO 2.6 GSE `VALUATION_RESPONSE` document. Values that cannot be
+/// expressed in their UAD form, and missing required values, fail the export with every
+/// problem listed by content path.
+pub fn write_mismo(document: &MismoReport, form: &MismoForm) -> AppResult<Vec<u8>> {
+    let layout = layout_for(&document.report.report_type);
+    let mut writer = MismoWriter::new(&document.report.content, layout);
+
+    writer
+        .xml
+        .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
+        .map_err(xml_error)?;
+    writer.start("VALUATION_RESPONSE", vec![("MISMOVersionID", MISMO_VERSION.to_string())])?;
+
+    write_report(&mut writer, document, form, layout)?;
+    write_parties(&mut writer, document)?;
+    write_property(&mut writer, document.property)?;
+    write_valuation_methods(&mut writer, document.property)?;
+    write_valuation(&mut writer, document.report)?;
+
+    writer.end("VALUATION_RESPONSE")?;
+    writer.finish()
+}
+
+/// `REPORT`: form identification, the order's purpose and the embedded PDF
+fn write_report(writer: &mut MismoWriter, document: &MismoReport, form: &MismoForm, layout: &FormLayout) -> AppResult<()> {
+    let report = document.report;
+    let mut attributes = vec![
+        ("AppraisalFormType", form.code.to_string()),
+        ("AppraisalFormVersionIdentifier", form.version.to_string()),
+        ("AppraiserFileIdentifier", report.id.to_string()),
+    ];
+
+    if let Some(submitted_at) = report.submitted_at {
+        attributes.push(("AppraiserReportSignedDate", submitted_at.format("%Y-%m-%d").to_string()));
+    }
+
+    if let Some(appraisal) = document.appraisal {
+        if let Some((_, purpose, other)) = PURPOSE_TYPES.iter().find(|(purpose, _, _)| *purpose == appraisal.purpose) {
+            attributes.push(("AppraisalPurposeType", purpose.to_string()));
+            if let Some(other) = other {
+                attributes.push(("AppraisalPurposeTypeOtherDescription", other.to_string()));
+            }
+        }
+        attributes.push(("AppraisalIntendedUseDescription", appraisal.purpose.intended_use().to_string()));
+        attributes.push(("AppraisalScopeOfWorkDescription", appraisal.appraisal_type.scope_of_work().to_string()));
+    }
+
+    writer.start("REPORT", attributes)?;
+
+    writer.start(
+        "EMBEDDED_FILE",
+        vec![
+            ("_Type", "PDF".to_string()),
+            ("_EncodingType", "Base64".to_string()),
+            ("_Name", "AppraisalReport".to_string()),
+            ("MIMETypeIdentifier", "application/pdf".to_string()),
+        ],
+    )?;
+    writer.text_element("DOCUMENT", &base64::encode(document.pdf))?;
+    writer.end("EMBEDDED_FILE")?;
+
+    writer.empty(
+        "FORM",
+        vec![
+            ("AppraisalReportContentType", "AppraisalForm".to_string()),
+            ("AppraisalReportContentIdentifier", form.code.to_string()),
+            ("AppraisalReportContentName", layout.title.to_string()),
+            ("AppraisalReportContentIsPrimaryFormIndicator", "Y".to_string()),
+            ("AppraisalReportContentSequenceIdentifier", "1".to_string()),
+        ],
+    )?;
+
+    writer.end("REPORT")
+}
+
+/// `PARTIES`: the signing appraiser and the ordering lender
+fn write_parties(writer: &mut MismoWriter, document: &MismoReport) -> AppResult<()> {
+    writer.start("PARTIES", Vec::new())?;
+
+    writer.start("APPRAISER", vec![("_Name", document.appraiser_name.to_string())])?;
+    if let Some(license) = document.license {
+        writer.empty(
+            "APPRAISER_LICENSE",
+            vec![
+                ("_Type", license_type(&license.level).to_string()),
+                ("_Identifier", license.license_number.clone()),
+                ("_State", license.state.clone()),
+                ("_ExpirationDate", license.expires_on.format("%Y-%m-%d").to_string()),
+            ],
+        )?;
+    }
+    writer.end("APPRAISER")?;
+
+    let lender = match document.lender_name {
+        Some(name) => Some(name.to_string()),
+        None => lookup(writer.content, "subject.lender_client")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .filter(|name| !name.trim().is_empty()),
+    };
+    if let Some(lender) = lender {
+        writer.empty("LENDER", vec![("_UnparsedName", lender)])?;
+    }
+
+    writer.end("PARTIES")
+}
+
+/// `PROPERTY`: the subject's address, identification, neighborhood, site and improvements
+fn write_property(writer: &mut MismoWriter, property: &Property) -> AppResult<()> {
+    let address = &property.address;
+    let mut attributes = vec![("_StreetAddress", address.street1.clone())];
+    if let Some(street2) = address.street2.as_ref().filter(|s| !s.trim().is_empty()) {
+        attributes.push(("_StreetAddress2", street2.clone()));
+    }
+    attributes.push(("_City", address.city.clone()));
+    attributes.push(("_State", address.state.clone()));
+    attributes.push(("_PostalCode", address.postal_code.clone()));
+    attributes.extend(writer.section_attributes("subject", PROPERTY_ATTRIBUTES));
+
+    writer.start("PROPERTY", attributes)?;
+    for child in PROPERTY_CHILDREN {
+        writer.mapped(child)?;
+    }
+    writer.end("PROPERTY")
+}
+
+/// `VALUATION_METHODS`: the approaches to value printed on the form, with the comparable grid
+fn write_valuation_methods(writer: &mut MismoWriter, property: &Property) -> AppResult<()> {
+    writer.start("VALUATION_METHODS", Vec::new())?;
+    writer.mapped(&COST_ANALYSIS)?;
+
+    if writer.includes("sales_comparison") {
+        let attributes = writer.section_attributes("sales_comparison", SALES_COMPARISON_ATTRIBUTES);
+        writer.start("SALES_COMPARISON", attributes)?;
+
+        // Sequence 0 is the subject column of the grid; its values are checked with their own sections
+        writer.quiet = true;
+        let subject = subject_column(writer.content, property);
+        writer.comparable(0, &subject)?;
+        writer.quiet = false;
+
+        match writer.content.get("comparables") {
+            Some(Value::Array(comparables)) => {
+                for (index, comparable) in comparables.iter().enumerate() {
+                    writer.comparable(index + 1, comparable)?;
+                }
+            }
+            Some(_) => writer.errors.push("comparables: must be a list".to_string()),
+            None => {}
+        }
+
+        writer.end("SALES_COMPARISON")?;
+    }
+
+    writer.mapped(&INCOME_ANALYSIS)?;
+    writer.end("VALUATION_METHODS")
+}
+
+/// `VALUATION`: the appraised value, effective date and reconciliation
+fn write_valuation(writer: &mut MismoWriter, report: &Report) -> AppResult<()> {
+    let mut attributes = Vec::new();
+    match report.valuation_amount {
+        Some(amount) => attributes.push(("_PropertyAppraisedValueAmount", format!("{:.0}", amount.round()))),
+        None => writer.errors.push("valuation_amount: is required".to_string()),
+    }
+    attributes.extend(writer.section_attributes("reconciliation", VALUATION_ATTRIBUTES));
+
+    writer.start("VALUATION", attributes)?;
+    writer.mapped(&RECONCILIATION)?;
+    writer.end("VALUATION")
+}
+
+/// Subject column of the comparable grid, assembled from the subject's own sections
+fn subject_column(content: &Value, property: &Property) -> Value {
+    let address = &property.address;
+    let mut subject = Map::new();
+    subject.insert(
+        "address".to_string(),
+        json!(format_address_single_line(
+            &address.street1,
+            address.street2.as_deref(),
+            &address.city,
+            &address.state,
+            &address.postal_code,
+        )),
+    );
+
+    for (key, path) in [
+        ("location", "neighborhood.location"),
+        ("view", "site.view"),
+        ("condition", "improvements.condition"),
+        ("quality", "improvements.quality"),
+        ("gross_living_area", "improvements.gross_living_area"),
+        ("bedrooms", "improvements.bedrooms"),
+        ("bathrooms", "improvements.bathrooms"),
+    ] {
+        if let Some(value) = lookup(content, path) {
+            subject.insert(key.to_string(), value.clone());
+        }
+    }
+
+    Value::Object(subject)
+}
+
+/// MISMO license type of a license level
+fn license_type(level: &LicenseLevel) -> &'static str {
+    match level {
+        LicenseLevel::Trainee => "Trainee",
+        LicenseLevel::Licensed => "License",
+        LicenseLevel::CertifiedResidential => "CertifiedResidential",
+        LicenseLevel::CertifiedGeneral => "CertifiedGeneral",
+    }
+}
+
+fn xml_error(err: quick_xml::Error) -> AppError {
+    AppError::General(format!("Failed to write MISMO XML: {}", err))
+}
+
+/// Streams the document while collecting every mapping problem, so one export attempt reports them all
+struct MismoWriter<'a> {
+    xml: Writer<Vec<u8>>,
+    content: &'a Value,
+    layout: &'static FormLayout,
+    errors: Vec<String>,
+    quiet: bool,
+}
+
+impl<'a> MismoWriter<'a> {
+    fn new(content: &'a Value, layout: &'static FormLayout) -> Self {
+        Self {
+            xml: Writer::new_with_indent(Vec::new(), b' ', 2),
+            content,
+            layout,
+            errors: Vec::new(),
+            quiet: false,
+        }
+    }
+
+    /// Whether the form prints the given content section
+    fn includes(&self, section: &str) -> bool {
+        self.layout.sections.iter().any(|s| s.key == section)
+    }
+
+    fn start(&mut self, name: &str, attributes: Vec<(&str, String)>) -> AppResult<()> {
+        let element = element(name, &attributes);
+        self.xml.write_event(Event::Start(element)).map_err(xml_error)
+    }
+
+    fn empty(&mut self, name: &str, attributes: Vec<(&str, String)>) -> AppResult<()> {
+        let element = element(name, &attributes);
+        self.xml.write_event(Event::Empty(element)).map_err(xml_error)
+    }
+
+    fn end(&mut self, name: &str) -> AppResult<()> {
+        self.xml.write_event(Event::End(BytesEnd::new(name))).map_err(xml_error)
+    }
+
+    fn text_element(&mut self, name: &str, text: &str) -> AppResult<()> {
+        self.start(name, Vec::new())?;
+        self.xml.write_event(Event::Text(BytesText::new(text))).map_err(xml_error)?;
+        self.end(name)
+    }
+
+    /// Attributes read from a top-level content section
+    fn section_attributes(&mut self, section: &str, mappings: &[AttributeMapping]) -> Vec<(&'static str, String)> {
+        let content = self.content;
+        let object = content.get(section).unwrap_or(&Value::Null);
+        self.attributes(section, object, mappings)
+    }
+
+    /// Attributes read from a content object; `location` prefixes reported problems
+    fn attributes(&mut self, location: &str, object: &Value, mappings: &[AttributeMapping]) -> Vec<(&'static str, String)> {
+        let mut attributes = Vec::new();
+
+        for mapping in mappings {
+            match self.value(location, object, mapping.path, mapping.format) {
+                Some(value) => attributes.push((mapping.name, value)),
+                None if mapping.required && !self.quiet => {
+                    self.errors.push(format!("{}.{}: is required", location, mapping.path));
+                }
+                None => {}
+            }
+        }
+
+        attributes
+    }
+
+    /// One value in its MISMO form, recording it as a problem if it cannot be written
+    fn value(&mut self, location: &str, object: &Value, path: &str, format: UadFormat) -> Option<String> {
+        match to_mismo(lookup(object, path), format) {
+            Ok(value) => value,
+            Err(problem) => {
+                if !self.quiet {
+                    self.errors.push(format!("{}.{}: {}", location, path, problem));
+                }
+                None
+            }
+        }
+    }
+
+    /// Write a mapped element if its section is on the form and it holds any values
+    fn mapped(&mut self, mapping: &ElementMapping) -> AppResult<()> {
+        if !self.includes(mapping.section) {
+            return Ok(());
+        }
+
+        let attributes = self.section_attributes(mapping.section, mapping.attributes);
+        let has_children = mapping.children.iter().any(|child| self.has_values(child));
+        if attributes.is_empty() && !has_children {
+            return Ok(());
+        }
+
+        if has_children {
+            self.start(mapping.name, attributes)?;
+            for child in mapping.children {
+                self.mapped(child)?;
+            }
+            self.end(mapping.name)
+        } else {
+            self.empty(mapping.name, attributes)
+        }
+    }
+
+    /// Whether a mapped element or any of its children has a value to write
+    fn has_values(&self, mapping: &ElementMapping) -> bool {
+        let object = match self.content.get(mapping.section) {
+            Some(object) if self.includes(mapping.section) => object,
+            _ => return false,
+        };
+
+        mapping
+            .attributes
+            .iter()
+            .any(|attribute| matches!(to_mismo(lookup(object, attribute.path), attribute.format), Ok(Some(_))))
+            || mapping.children.iter().any(|child| self.has_values(child))
+    }
+
+    /// One `COMPARABLE_SALE` of the sales comparison grid
+    fn comparable(&mut self, sequence: usize, comparable: &Value) -> AppResult<()> {
+        let location = format!("comparables[{}]", sequence.saturating_sub(1));
+        if !comparable.is_object() {
+            self.errors.push(format!("{}: must be an object", location));
+            return Ok(());
+        }
+
+        let mut attributes = vec![("PropertySequenceIdentifier", sequence.to_string())];
+        attributes.extend(self.attributes(&location, comparable, COMPARABLE_ATTRIBUTES));
+        self.start("COMPARABLE_SALE", attributes)?;
+
+        if let Some(address) = self.value(&location, comparable, "address", UadFormat::Text) {
+            self.empty("LOCATION", vec![("PropertyStreetAddress", address)])?;
+        }
+
+        for adjustment in COMPARABLE_ADJUSTMENTS {
+            if let Some(description) = self.value(&location, comparable, adjustment.path, adjustment.format) {
+                self.empty(
+                    "SALE_PRICE_ADJUSTMENT",
+                    vec![("_Type", adjustment.kind.to_string()), ("_Description", description)],
+                )?;
+            }
+        }
+
+        let rooms = self.attributes(&location, comparable, COMPARABLE_ROOMS);
+        if !rooms.is_empty() {
+            self.empty("ROOM_ADJUSTMENT", rooms)?;
+        }
+
+        self.end("COMPARABLE_SALE")
+    }
+
+    /// The finished document, or every problem found while writing it
+    fn finish(self) -> AppResult<Vec<u8>> {
+        if !self.errors.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Report cannot be exported as MISMO {}: {}",
+                MISMO_VERSION,
+                self.errors.join("; ")
+            )));
+        }
+
+        Ok(self.xml.into_inner())
+    }
+}
+
+fn element<'n>(name: &'n str, attributes: &[(&str, String)]) -> BytesStart<'n> {
+    let mut element = BytesStart::new(name);
+    for (key, value) in attributes {
+        element.push_attribute((*key, value.as_str()));
+    }
+    element
+}
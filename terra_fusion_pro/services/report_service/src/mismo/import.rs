@@ -0,0 +1,584 @@
+use chrono::NaiveDate;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde_json::{json, Map, Value};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::AppraisalPurpose;
+use shared::models::property::{Address, PropertyCharacteristics, PropertyType};
+use shared::models::report::{ReportType, UnmappedElement};
+
+use super::form_by_code;
+use super::mapping::{
+    AttributeMapping, ElementMapping, APPRAISER_ATTRIBUTES, APPRAISER_LICENSE_ATTRIBUTES, COMPARABLE_ADJUSTMENTS,
+    COMPARABLE_ATTRIBUTES, COMPARABLE_ROOMS, COST_ANALYSIS, INCOME_ANALYSIS, LENDER_ATTRIBUTES, PROPERTY_ATTRIBUTES,
+    PROPERTY_CHILDREN, PURPOSE_TYPES, RECONCILIATION, SALES_COMPARISON_ATTRIBUTES, VALUATION_ATTRIBUTES,
+};
+use super::uad::{from_mismo, parse_date};
+
+/// Everything read from a MISMO appraisal document
+pub struct MismoImport {
+    /// Report type of the document's primary form
+    pub report_type: ReportType,
+
+    /// Subject address
+    pub address: Address,
+
+    /// Property characteristics taken from the improvements and site sections
+    pub characteristics: PropertyCharacteristics,
+
+    /// Report content, keyed by section as the report forms expect
+    pub content: Value,
+
+    /// Appraised value
+    pub appraised_value: Option<f64>,
+
+    /// Effective date of the appraisal
+    pub effective_date: Option<NaiveDate>,
+
+    /// Date the appraiser signed the report
+    pub signed_on: Option<NaiveDate>,
+
+    /// Purpose of the appraisal
+    pub purpose: AppraisalPurpose,
+
+    /// The appraiser's file number
+    pub file_identifier: Option<String>,
+
+    /// Embedded report PDF (if any)
+    pub pdf: Option<Vec<u8>>,
+
+    /// Elements and attributes that were not imported
+    pub unmapped: Vec<UnmappedElement>,
+}
+
+/// Read a MISMO 2.6 `VALUATION_RESPONSE` document using the same mapping the export writes.
+/// Every element and attribute the mapping does not consume is listed in `unmapped`.
+pub fn read_mismo(xml: &[u8]) -> AppResult<MismoImport> {
+    let mut root = parse(xml)?;
+    if root.name != "VALUATION_RESPONSE" {
+        return Err(AppError::Validation(format!(
+            "Expected a MISMO VALUATION_RESPONSE document, found <{}>",
+            root.name
+        )));
+    }
+
+    match root.take_attribute("MISMOVersionID") {
+        Some(version) if version.starts_with("2.6") => {}
+        Some(version) => {
+            return Err(AppError::Validation(format!("Unsupported MISMO version {}; expected 2.6", version)))
+        }
+        None => return Err(AppError::Validation("Document has no MISMOVersionID".to_string())),
+    }
+
+    let mut reader = ContentReader::default();
+    let path = "VALUATION_RESPONSE";
+
+    let mut report = root
+        .take_child("REPORT")
+        .ok_or_else(|| AppError::Validation("Document has no REPORT element".to_string()))?;
+    let form_code = report
+        .take_attribute("AppraisalFormType")
+        .ok_or_else(|| AppError::Validation("REPORT has no AppraisalFormType".to_string()))?;
+    let form = form_by_code(&form_code).ok_or_else(|| {
+        AppError::Validation(format!(
+            "Unsupported appraisal form {}; expected FNM1004, FNM1073, FNM1025 or FNM2055",
+            form_code
+        ))
+    })?;
+
+    let file_identifier = report.take_attribute("AppraiserFileIdentifier");
+    let signed_on = report.take_attribute("AppraiserReportSignedDate").and_then(|date| parse_date(&date));
+    let purpose = read_purpose(&mut report);
+    // Derived from the purpose and form on export, so there is nothing further to keep
+    report.take_attribute("AppraisalFormVersionIdentifier");
+    report.take_attribute("AppraisalIntendedUseDescription");
+    report.take_attribute("AppraisalScopeOfWorkDescription");
+
+    let pdf = read_embedded_pdf(&mut report, &format!("{}/REPORT", path), &mut reader);
+    while let Some(position) = report.children.iter().position(|child| {
+        child.name == "FORM" && child.attribute("AppraisalReportContentIdentifier") == Some(form.code)
+    }) {
+        report.children.remove(position);
+    }
+    reader.leftovers(&format!("{}/REPORT", path), report);
+
+    if let Some(parties) = root.take_child("PARTIES") {
+        read_parties(parties, &format!("{}/PARTIES", path), &mut reader);
+    }
+
+    let mut property = root
+        .take_child("PROPERTY")
+        .ok_or_else(|| AppError::Validation("Document has no PROPERTY element".to_string()))?;
+    let property_path = format!("{}/PROPERTY", path);
+    let address = read_address(&mut property)?;
+    reader.attributes(&mut property, "subject", PROPERTY_ATTRIBUTES);
+    for child in PROPERTY_CHILDREN {
+        reader.mapped(&mut property, child, &property_path);
+    }
+    reader.leftovers(&property_path, property);
+
+    if let Some(mut methods) = root.take_child("VALUATION_METHODS") {
+        let methods_path = format!("{}/VALUATION_METHODS", path);
+        reader.mapped(&mut methods, &COST_ANALYSIS, &methods_path);
+        if let Some(sales) = methods.take_child("SALES_COMPARISON") {
+            read_sales_comparison(sales, &format!("{}/SALES_COMPARISON", methods_path), &mut reader);
+        }
+        reader.mapped(&mut methods, &INCOME_ANALYSIS, &methods_path);
+        reader.leftovers(&methods_path, methods);
+    }
+
+    let mut appraised_value = None;
+    if let Some(mut valuation) = root.take_child("VALUATION") {
+        let valuation_path = format!("{}/VALUATION", path);
+        appraised_value = valuation
+            .take_attribute("_PropertyAppraisedValueAmount")
+            .and_then(|amount| amount.replace(['$', ','], "").parse::<f64>().ok());
+        reader.attributes(&mut valuation, "reconciliation", VALUATION_ATTRIBUTES);
+        reader.mapped(&mut valuation, &RECONCILIATION, &valuation_path);
+        reader.leftovers(&valuation_path, valuation);
+    }
+
+    reader.leftovers(path, root);
+
+    let content = Value::Object(reader.content);
+    let effective_date = content
+        .pointer("/reconciliation/effective_date")
+        .and_then(Value::as_str)
+        .and_then(parse_date);
+
+    let characteristics = characteristics(&content, &form.report_type);
+
+    Ok(MismoImport {
+        report_type: form.report_type.clone(),
+        address,
+        characteristics,
+        content,
+        appraised_value,
+        effective_date,
+        signed_on,
+        purpose,
+        file_identifier,
+        pdf,
+        unmapped: reader.unmapped,
+    })
+}
+
+/// Property record characteristics from the imported improvements and site sections
+fn characteristics(content: &Value, report_type: &ReportType) -> PropertyCharacteristics {
+    let number = |pointer: &str| match content.pointer(pointer) {
+        Some(Value::Number(n)) => n.as_f64(),
+        Some(Value::String(s)) => s.replace(',', "").trim().parse::<f64>().ok(),
+        _ => None,
+    };
+    let (lot_size, lot_size_in_sqft) = match content.pointer("/site/area").and_then(Value::as_str) {
+        Some(area) => lot_size(area),
+        None => (None, None),
+    };
+
+    PropertyCharacteristics {
+        property_type: match report_type {
+            ReportType::Form1073 => PropertyType::Condo,
+            ReportType::Form1025 => PropertyType::MultiFamily,
+            _ => PropertyType::SingleFamily,
+        },
+        year_built: number("/improvements/year_built").map(|year| year as i32),
+        square_feet: number("/improvements/gross_living_area"),
+        bedrooms: number("/improvements/bedrooms").map(|count| count as i32),
+        bathrooms: content
+            .pointer("/improvements/bathrooms")
+            .and_then(|value| match value {
+                Value::String(s) => bathrooms(s),
+                Value::Number(n) => n.as_f64(),
+                _ => None,
+            }),
+        lot_size,
+        lot_size_in_sqft,
+        parking: None,
+        stories: number("/improvements/stories").map(|stories| stories.ceil() as i32),
+        has_basement: None,
+        has_pool: None,
+        features: None,
+    }
+}
+
+/// UAD bathroom count "full.half", e.g. "2.1" for two full baths and one half bath
+fn bathrooms(text: &str) -> Option<f64> {
+    let (full, half) = match text.trim().split_once('.') {
+        Some((full, half)) => (full.parse::<u32>().ok()?, half.parse::<u32>().ok()?),
+        None => (text.trim().parse::<u32>().ok()?, 0),
+    };
+    Some(full as f64 + half as f64 * 0.5)
+}
+
+/// Site area such as "7,500 sf" or "0.25 ac"; a bare number below 100 is taken as acres
+fn lot_size(area: &str) -> (Option<f64>, Option<bool>) {
+    let lower = area.to_lowercase();
+    let number = lower
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | ' '))
+        .filter(|c| *c != ',' && *c != ' ')
+        .collect::<String>();
+
+    match number.parse::<f64>() {
+        Ok(size) if lower.contains("ac") => (Some(size), Some(false)),
+        Ok(size) if lower.contains("sf") || lower.contains("sq") => (Some(size), Some(true)),
+        Ok(size) => (Some(size), Some(size >= 100.0)),
+        Err(_) => (None, None),
+    }
+}
+
+/// Appraisal purpose from `AppraisalPurposeType`, using the description to tell "Other" purposes apart
+fn read_purpose(report: &mut Node) -> AppraisalPurpose {
+    let purpose = report.take_attribute("AppraisalPurposeType");
+    let other = report.take_attribute("AppraisalPurposeTypeOtherDescription");
+
+    PURPOSE_TYPES
+        .iter()
+        .find(|(_, mismo, description)| {
+            purpose.as_deref() == Some(*mismo)
+                && (description.is_none()
+                    || description.map(str::to_lowercase) == other.as_deref().map(str::to_lowercase))
+        })
+        .map(|(purpose, _, _)| purpose.clone())
+        .unwrap_or(AppraisalPurpose::Other)
+}
+
+/// The first base64-encoded PDF among the report's `EMBEDDED_FILE`s; anything else is left unmapped
+fn read_embedded_pdf(report: &mut Node, path: &str, reader: &mut ContentReader) -> Option<Vec<u8>> {
+    let position = report.children.iter().position(|child| {
+        child.name == "EMBEDDED_FILE"
+            && child.attribute("_Type").is_some_and(|kind| kind.eq_ignore_ascii_case("PDF"))
+            && child.attribute("_EncodingType").is_some_and(|encoding| encoding.eq_ignore_ascii_case("Base64"))
+    })?;
+
+    let mut file = report.children.remove(position);
+    let encoded = file.take_child("DOCUMENT")?.text;
+    let encoded = encoded.chars().filter(|c| !c.is_ascii_whitespace()).collect::<String>();
+
+    match base64::decode(encoded) {
+        Ok(pdf) if pdf.starts_with(b"%PDF") => Some(pdf),
+        _ => {
+            reader.unmapped.push(UnmappedElement {
+                path: format!("{}/EMBEDDED_FILE/DOCUMENT", path),
+                value: Some("Embedded file is not a base64-encoded PDF".to_string()),
+            });
+            None
+        }
+    }
+}
+
+/// The signing appraiser goes to `content.appraiser`, the lender to `subject.lender_client`
+fn read_parties(mut parties: Node, path: &str, reader: &mut ContentReader) {
+    if let Some(mut appraiser) = parties.take_child("APPRAISER") {
+        let appraiser_path = format!("{}/APPRAISER", path);
+        reader.attributes(&mut appraiser, "appraiser", APPRAISER_ATTRIBUTES);
+        if let Some(mut license) = appraiser.take_child("APPRAISER_LICENSE") {
+            reader.attributes(&mut license, "appraiser", APPRAISER_LICENSE_ATTRIBUTES);
+            reader.leftovers(&format!("{}/APPRAISER_LICENSE", appraiser_path), license);
+        }
+        reader.leftovers(&appraiser_path, appraiser);
+    }
+
+    if let Some(mut lender) = parties.take_child("LENDER") {
+        reader.attributes(&mut lender, "subject", LENDER_ATTRIBUTES);
+        reader.leftovers(&format!("{}/LENDER", path), lender);
+    }
+
+    reader.leftovers(path, parties);
+}
+
+/// The subject address; street, city, state and postal code are required
+fn read_address(property: &mut Node) -> AppResult<Address> {
+    let mut required = |name: &str| {
+        property
+            .take_attribute(name)
+            .ok_or_else(|| AppError::Validation(format!("PROPERTY has no {}", name)))
+    };
+
+    let street1 = required("_StreetAddress")?;
+    let city = required("_City")?;
+    let state = required("_State")?;
+    let postal_code = required("_PostalCode")?;
+
+    Ok(Address {
+        street1,
+        street2: property.take_attribute("_StreetAddress2"),
+        city,
+        state,
+        postal_code,
+        country: "US".to_string(),
+        latitude: None,
+        longitude: None,
+    })
+}
+
+/// `SALES_COMPARISON` and its grid. The subject column (sequence 0) repeats the subject's own
+/// sections, so only comparables 1 and up are kept.
+fn read_sales_comparison(mut sales: Node, path: &str, reader: &mut ContentReader) {
+    reader.attributes(&mut sales, "sales_comparison", SALES_COMPARISON_ATTRIBUTES);
+
+    let mut comparables = Vec::new();
+    for (index, mut comparable) in sales.take_children("COMPARABLE_SALE").into_iter().enumerate() {
+        let comparable_path = format!("{}/COMPARABLE_SALE[{}]", path, index);
+        if comparable.take_attribute("PropertySequenceIdentifier").as_deref() == Some("0") {
+            continue;
+        }
+
+        let mut entry = Map::new();
+        read_into(&mut comparable, &mut entry, COMPARABLE_ATTRIBUTES);
+
+        if let Some(mut location) = comparable.take_child("LOCATION") {
+            if let Some(address) = location.take_attribute("PropertyStreetAddress") {
+                entry.insert("address".to_string(), json!(address));
+            }
+            reader.leftovers(&format!("{}/LOCATION", comparable_path), location);
+        }
+
+        for mut adjustment in comparable.take_children("SALE_PRICE_ADJUSTMENT") {
+            let mapping = adjustment
+                .attribute("_Type")
+                .and_then(|kind| COMPARABLE_ADJUSTMENTS.iter().find(|mapping| mapping.kind == kind));
+
+            match mapping {
+                Some(mapping) => {
+                    adjustment.take_attribute("_Type");
+                    if let Some(description) = adjustment.take_attribute("_Description") {
+                        insert_path(&mut entry, mapping.path, from_mismo(&description, mapping.format));
+                    }
+                    let kind_path = format!("{}/SALE_PRICE_ADJUSTMENT[{}]", comparable_path, mapping.kind);
+                    reader.leftovers(&kind_path, adjustment);
+                }
+                None => comparable.children.push(adjustment),
+            }
+        }
+
+        if let Some(mut rooms) = comparable.take_child("ROOM_ADJUSTMENT") {
+            read_into(&mut rooms, &mut entry, COMPARABLE_ROOMS);
+            reader.leftovers(&format!("{}/ROOM_ADJUSTMENT", comparable_path), rooms);
+        }
+
+        reader.leftovers(&comparable_path, comparable);
+        comparables.push(Value::Object(entry));
+    }
+
+    if !comparables.is_empty() {
+        reader.content.insert("comparables".to_string(), Value::Array(comparables));
+    }
+    reader.leftovers(path, sales);
+}
+
+/// Content assembled from the document, plus everything left over
+#[derive(Default)]
+struct ContentReader {
+    content: Map<String, Value>,
+    unmapped: Vec<UnmappedElement>,
+}
+
+impl ContentReader {
+    /// Move mapped attributes of an element into a content section
+    fn attributes(&mut self, node: &mut Node, section: &str, mappings: &[AttributeMapping]) {
+        let object = self
+            .content
+            .entry(section.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if let Value::Object(object) = object {
+            read_into(node, object, mappings);
+            if object.is_empty() {
+                self.content.remove(section);
+            }
+        }
+    }
+
+    /// Read a mapped element, if present, and its mapped children
+    fn mapped(&mut self, parent: &mut Node, mapping: &ElementMapping, parent_path: &str) {
+        let mut node = match parent.take_child(mapping.name) {
+            Some(node) => node,
+            None => return,
+        };
+        let path = format!("{}/{}", parent_path, mapping.name);
+
+        self.attributes(&mut node, mapping.section, mapping.attributes);
+        for child in mapping.children {
+            self.mapped(&mut node, child, &path);
+        }
+        self.leftovers(&path, node);
+    }
+
+    /// Record whatever the mapping did not consume from an element
+    fn leftovers(&mut self, path: &str, node: Node) {
+        for (name, value) in node.attributes {
+            self.unmapped.push(UnmappedElement {
+                path: format!("{}/@{}", path, name),
+                value: Some(value),
+            });
+        }
+
+        for mut child in node.children {
+            let child_path = format!("{}/{}", path, child.name);
+            let text = std::mem::take(&mut child.text);
+            let text = text.trim();
+            self.unmapped.push(UnmappedElement {
+                path: child_path.clone(),
+                value: if text.is_empty() { None } else { Some(text.to_string()) },
+            });
+            self.leftovers(&child_path, child);
+        }
+    }
+}
+
+/// Move mapped attributes of an element into a content object
+fn read_into(node: &mut Node, object: &mut Map<String, Value>, mappings: &[AttributeMapping]) {
+    for mapping in mappings {
+        if let Some(text) = node.take_attribute(mapping.name) {
+            insert_path(object, mapping.path, from_mismo(&text, mapping.format));
+        }
+    }
+}
+
+/// Insert a value at a dotted path, creating intermediate objects
+fn insert_path(object: &mut Map<String, Value>, path: &str, value: Value) {
+    match path.split_once('.') {
+        Some((key, rest)) => {
+            let child = object.entry(key.to_string()).or_insert_with(|| Value::Object(Map::new()));
+            if !child.is_object() {
+                *child = Value::Object(Map::new());
+            }
+            if let Value::Object(child) = child {
+                insert_path(child, rest, value);
+            }
+        }
+        None => {
+            object.insert(path.to_string(), value);
+        }
+    }
+}
+
+/// A parsed element; the reader takes attributes and children out as it maps them
+struct Node {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+    text: String,
+}
+
+impl Node {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Remove an attribute, returning its value when it is not blank
+    fn take_attribute(&mut self, name: &str) -> Option<String> {
+        let position = self.attributes.iter().position(|(key, _)| key == name)?;
+        let (_, value) = self.attributes.remove(position);
+        let value = value.trim();
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_string())
+        }
+    }
+
+    /// Remove the first child with the given name
+    fn take_child(&mut self, name: &str) -> Option<Node> {
+        let position = self.children.iter().position(|child| child.name == name)?;
+        Some(self.children.remove(position))
+    }
+
+    /// Remove every child with the given name, in document order
+    fn take_children(&mut self, name: &str) -> Vec<Node> {
+        let (taken, kept) = std::mem::take(&mut self.children)
+            .into_iter()
+            .partition(|child| child.name == name);
+        self.children = kept;
+        taken
+    }
+}
+
+/// Parse a document into a tree of elements. Namespace prefixes are dropped and namespace
+/// declarations ignored, since MISMO 2.6 element names are unique without them.
+fn parse(xml: &[u8]) -> AppResult<Node> {
+    let mut reader = Reader::from_reader(xml);
+    reader.trim_text(true);
+
+    let mut buffer = Vec::new();
+    let mut stack: Vec<Node> = Vec::new();
+    let mut root = None;
+
+    loop {
+        let event = reader.read_event_into(&mut buffer).map_err(|e| {
+            AppError::Validation(format!("Invalid XML at byte {}: {}", reader.buffer_position(), e))
+        })?;
+
+        match event {
+            Event::Start(element) => stack.push(node(&element)?),
+            Event::Empty(element) => {
+                let node = node(&element)?;
+                attach(&mut stack, &mut root, node)?;
+            }
+            Event::End(_) => {
+                if let Some(node) = stack.pop() {
+                    attach(&mut stack, &mut root, node)?;
+                }
+            }
+            Event::Text(text) => {
+                if let Some(current) = stack.last_mut() {
+                    let text = text
+                        .unescape()
+                        .map_err(|e| AppError::Validation(format!("Invalid XML text: {}", e)))?;
+                    current.text.push_str(&text);
+                }
+            }
+            Event::CData(data) => {
+                if let Some(current) = stack.last_mut() {
+                    current.text.push_str(&String::from_utf8_lossy(&data));
+                }
+            }
+            Event::Eof => {
+                if let Some(open) = stack.last() {
+                    return Err(AppError::Validation(format!("Invalid XML: <{}> is never closed", open.name)));
+                }
+                break;
+            }
+            _ => {}
+        }
+        buffer.clear();
+    }
+
+    root.ok_or_else(|| AppError::Validation("Document has no root element".to_string()))
+}
+
+fn node(element: &BytesStart) -> AppResult<Node> {
+    let mut attributes = Vec::new();
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|e| AppError::Validation(format!("Invalid XML attribute: {}", e)))?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        if key == "xmlns" || key.starts_with("xmlns:") || key.starts_with("xsi:") {
+            continue;
+        }
+
+        let value = attribute
+            .unescape_value()
+            .map_err(|e| AppError::Validation(format!("Invalid XML attribute {}: {}", key, e)))?;
+        attributes.push((key, value.into_owned()));
+    }
+
+    Ok(Node {
+        name: String::from_utf8_lossy(element.local_name().as_ref()).into_owned(),
+        attributes,
+        children: Vec::new(),
+        text: String::new(),
+    })
+}
+
+fn attach(stack: &mut [Node], root: &mut Option<Node>, node: Node) -> AppResult<()> {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None if root.is_none() => *root = Some(node),
+        None => return Err(AppError::Validation("Document has more than one root element".to_string())),
+    }
+    Ok(())
+}
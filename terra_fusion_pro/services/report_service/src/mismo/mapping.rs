@@ -0,0 +1,310 @@
+use shared::models::appraisal::AppraisalPurpose;
+
+use super::uad::{
+    UadFormat, APPRAISAL_CONDITION, BUILT_UP, DEMAND_SUPPLY, GROWTH, LOCATION_TYPE, MARKETING_TIME,
+    OCCUPANCY, PROPERTY_RIGHTS, VALUE_TREND, ZONING_COMPLIANCE,
+};
+
+/// A MISMO element whose attributes are read from one object of `Report.content`
+pub struct ElementMapping {
+    /// Element name
+    pub name: &'static str,
+
+    /// Top-level content key the attributes are read from
+    pub section: &'static str,
+
+    /// Attributes, in document order
+    pub attributes: &'static [AttributeMapping],
+
+    /// Nested elements, in document order
+    pub children: &'static [ElementMapping],
+}
+
+/// A MISMO attribute holding one content value
+pub struct AttributeMapping {
+    /// Attribute name
+    pub name: &'static str,
+
+    /// Dotted path of the value within the section object
+    pub path: &'static str,
+
+    /// How the value is written
+    pub format: UadFormat,
+
+    /// Whether GSE delivery requires the value
+    pub required: bool,
+}
+
+/// A sales comparison adjustment line, written as a typed `SALE_PRICE_ADJUSTMENT` element
+pub struct AdjustmentMapping {
+    /// Value of the `_Type` attribute
+    pub kind: &'static str,
+
+    /// Dotted path of the description within a comparable
+    pub path: &'static str,
+
+    /// How the description is written
+    pub format: UadFormat,
+}
+
+const fn attr(name: &'static str, path: &'static str, format: UadFormat) -> AttributeMapping {
+    AttributeMapping { name, path, format, required: false }
+}
+
+const fn required(name: &'static str, path: &'static str, format: UadFormat) -> AttributeMapping {
+    AttributeMapping { name, path, format, required: true }
+}
+
+const fn adjustment(kind: &'static str, path: &'static str, format: UadFormat) -> AdjustmentMapping {
+    AdjustmentMapping { kind, path, format }
+}
+
+/// MISMO purpose type of each appraisal purpose, with a description for purposes MISMO lists as "Other"
+pub const PURPOSE_TYPES: &[(AppraisalPurpose, &str, Option<&str>)] = &[
+    (AppraisalPurpose::Purchase, "Purchase", None),
+    (AppraisalPurpose::Refinance, "Refinance", None),
+    (AppraisalPurpose::HomeEquity, "Other", Some("Home Equity")),
+    (AppraisalPurpose::PMI, "Other", Some("PMI Removal")),
+    (AppraisalPurpose::PreListing, "Other", Some("Pre-Listing")),
+    (AppraisalPurpose::Estate, "Other", Some("Estate")),
+    (AppraisalPurpose::Divorce, "Other", Some("Divorce")),
+    (AppraisalPurpose::TaxAppeal, "Other", Some("Tax Appeal")),
+    (AppraisalPurpose::Bankruptcy, "Other", Some("Bankruptcy")),
+    (AppraisalPurpose::RelocationEstimate, "Other", Some("Relocation")),
+    (AppraisalPurpose::Insurance, "Other", Some("Insurance")),
+    (AppraisalPurpose::Other, "Other", Some("Other")),
+];
+
+/// Attributes of the `APPRAISER` party, read back into `content.appraiser` on import; export
+/// writes them from the signer's user and license records
+pub const APPRAISER_ATTRIBUTES: &[AttributeMapping] = &[attr("_Name", "name", UadFormat::Text)];
+
+/// Attributes of the appraiser's `APPRAISER_LICENSE`, read back into `content.appraiser` on import
+pub const APPRAISER_LICENSE_ATTRIBUTES: &[AttributeMapping] = &[
+    attr("_Type", "license_type", UadFormat::Text),
+    attr("_Identifier", "license_number", UadFormat::Text),
+    attr("_State", "license_state", UadFormat::Text),
+    attr("_ExpirationDate", "license_expires_on", UadFormat::Date),
+];
+
+/// Attributes of the `LENDER` party, read back into the subject section on import
+pub const LENDER_ATTRIBUTES: &[AttributeMapping] = &[attr("_UnparsedName", "lender_client", UadFormat::Text)];
+
+/// Attributes of the `PROPERTY` element taken from the subject section; the address comes from the property record
+pub const PROPERTY_ATTRIBUTES: &[AttributeMapping] = &[
+    attr("_CurrentOccupancyType", "occupant", OCCUPANCY),
+    attr("_RightsType", "property_rights", PROPERTY_RIGHTS),
+];
+
+/// Elements nested in `PROPERTY`, in document order
+pub const PROPERTY_CHILDREN: &[ElementMapping] = &[
+    ElementMapping {
+        name: "_IDENTIFICATION",
+        section: "subject",
+        attributes: &[attr("AssessorsParcelIdentifier", "parcel_number", UadFormat::Text)],
+        children: &[],
+    },
+    ElementMapping {
+        name: "_LEGAL_DESCRIPTION",
+        section: "subject",
+        attributes: &[attr("_TextDescription", "legal_description", UadFormat::Text)],
+        children: &[],
+    },
+    ElementMapping {
+        name: "_OWNER",
+        section: "subject",
+        attributes: &[attr("_Name", "owner_of_record", UadFormat::Text)],
+        children: &[],
+    },
+    ElementMapping {
+        name: "_TAX",
+        section: "subject",
+        attributes: &[
+            attr("_YearIdentifier", "tax_year", UadFormat::Count),
+            attr("_TotalTaxAmount", "real_estate_taxes", UadFormat::Amount),
+        ],
+        children: &[],
+    },
+    ElementMapping {
+        name: "SALES_CONTRACT",
+        section: "contract",
+        attributes: &[
+            attr("_Amount", "contract_price", UadFormat::Amount),
+            attr("_Date", "contract_date", UadFormat::Date),
+            attr("_SaleType", "sale_type", UadFormat::Text),
+            attr("SellerConcessionAmount", "financial_assistance", UadFormat::Amount),
+            attr("_ReviewComment", "analysis", UadFormat::Text),
+        ],
+        children: &[],
+    },
+    ElementMapping {
+        name: "NEIGHBORHOOD",
+        section: "neighborhood",
+        attributes: &[
+            attr("_Name", "neighborhood_name", UadFormat::Text),
+            attr("_LocationType", "location", LOCATION_TYPE),
+            attr("_BuiltupRangeType", "built_up", BUILT_UP),
+            attr("_GrowthPaceType", "growth", GROWTH),
+            attr("_PropertyValueTrendType", "property_values", VALUE_TREND),
+            attr("_DemandSupplyType", "demand_supply", DEMAND_SUPPLY),
+            attr("_TypicalMarketingTimeDurationType", "marketing_time", MARKETING_TIME),
+            attr("_BoundaryAndCharacteristicsDescription", "boundaries", UadFormat::Text),
+            attr("_Description", "description", UadFormat::Text),
+            attr("_MarketConditionsDescription", "market_conditions", UadFormat::Text),
+        ],
+        children: &[],
+    },
+    ElementMapping {
+        name: "SITE",
+        section: "site",
+        attributes: &[
+            attr("_DimensionsDescription", "dimensions", UadFormat::Text),
+            attr("_AreaDescription", "area", UadFormat::Text),
+            attr("_HighestBestUseDescription", "highest_and_best_use", UadFormat::Text),
+            attr("_UtilitiesDescription", "utilities", UadFormat::Text),
+            attr("_ViewDescription", "view", UadFormat::Text),
+            attr("_Comment", "comments", UadFormat::Text),
+        ],
+        children: &[
+            ElementMapping {
+                name: "_ZONING",
+                section: "site",
+                attributes: &[
+                    attr("_ClassificationIdentifier", "zoning_classification", UadFormat::Text),
+                    attr("_ComplianceType", "zoning_compliance", ZONING_COMPLIANCE),
+                ],
+                children: &[],
+            },
+            ElementMapping {
+                name: "FLOOD_ZONE",
+                section: "site",
+                attributes: &[attr("NFIPFloodZoneIdentifier", "flood_zone", UadFormat::Text)],
+                children: &[],
+            },
+        ],
+    },
+    ElementMapping {
+        name: "PROJECT",
+        section: "project",
+        attributes: &[
+            attr("_Name", "name", UadFormat::Text),
+            attr("_PhaseIdentifier", "phase", UadFormat::Text),
+            attr("_TotalUnitCount", "units_total", UadFormat::Count),
+            attr("_UnitsSoldCount", "units_sold", UadFormat::Count),
+            attr("_UnitsRentedCount", "units_rented", UadFormat::Count),
+            attr("_AssociationDuesAmount", "hoa_fee", UadFormat::Amount),
+            attr("_Description", "description", UadFormat::Text),
+        ],
+        children: &[],
+    },
+    ElementMapping {
+        name: "STRUCTURE",
+        section: "improvements",
+        attributes: &[
+            attr("LivingUnitCount", "units", UadFormat::Count),
+            attr("StoriesCount", "stories", UadFormat::Decimal),
+            attr("PropertyStructureBuiltYear", "year_built", UadFormat::Count),
+            required("GrossLivingAreaSquareFeetCount", "gross_living_area", UadFormat::Count),
+            attr("TotalRoomCount", "rooms", UadFormat::Count),
+            attr("TotalBedroomCount", "bedrooms", UadFormat::Count),
+            attr("TotalBathroomCount", "bathrooms", UadFormat::Text),
+        ],
+        children: &[
+            ElementMapping {
+                name: "ARCHITECTURAL_DESIGN",
+                section: "improvements",
+                attributes: &[attr("_Description", "design_style", UadFormat::Text)],
+                children: &[],
+            },
+            ElementMapping {
+                name: "STRUCTURE_ANALYSIS",
+                section: "improvements",
+                attributes: &[
+                    required("PropertyConditionType", "condition", UadFormat::Condition),
+                    required("PropertyQualityType", "quality", UadFormat::Quality),
+                    attr("EffectiveAgeYearsCount", "effective_age", UadFormat::Count),
+                    attr("PropertyConditionDescription", "description", UadFormat::Text),
+                    attr("InteriorDataSourceDescription", "interior_data_source", UadFormat::Text),
+                ],
+                children: &[],
+            },
+        ],
+    },
+];
+
+/// Approach elements nested in `VALUATION_METHODS`, in document order. The sales comparison
+/// approach is written separately because it carries the comparable grid.
+pub const COST_ANALYSIS: ElementMapping = ElementMapping {
+    name: "COST_ANALYSIS",
+    section: "cost",
+    attributes: &[
+        attr("SiteEstimatedValueAmount", "site_value", UadFormat::Amount),
+        attr("NewImprovementTotalCostAmount", "cost_new", UadFormat::Amount),
+        attr("DepreciationTotalAmount", "depreciation", UadFormat::Amount),
+        attr("ValueIndicatedByCostApproachAmount", "indicated_value", UadFormat::Amount),
+        attr("_Comment", "comments", UadFormat::Text),
+    ],
+    children: &[],
+};
+
+pub const SALES_COMPARISON_ATTRIBUTES: &[AttributeMapping] = &[
+    attr("ComparableListingsCount", "listings_count", UadFormat::Count),
+    attr("ComparableSalesCount", "sales_count", UadFormat::Count),
+    required("ValueIndicatedBySalesComparisonApproachAmount", "indicated_value", UadFormat::Amount),
+    attr("_Comment", "summary", UadFormat::Text),
+];
+
+pub const INCOME_ANALYSIS: ElementMapping = ElementMapping {
+    name: "INCOME_ANALYSIS",
+    section: "income",
+    attributes: &[
+        attr("EstimatedMarketMonthlyRentAmount", "gross_monthly_rent", UadFormat::Amount),
+        attr("GrossRentMultiplierFactor", "gross_rent_multiplier", UadFormat::Decimal),
+        attr("ValueIndicatedByIncomeApproachAmount", "indicated_value", UadFormat::Amount),
+        attr("_Comment", "rent_schedule_summary", UadFormat::Text),
+    ],
+    children: &[],
+};
+
+/// Attributes of each `COMPARABLE_SALE`, read from one entry of `content.comparables`
+pub const COMPARABLE_ATTRIBUTES: &[AttributeMapping] = &[
+    attr("ProximityToSubjectDescription", "proximity", UadFormat::Text),
+    attr("PropertySalesAmount", "sale_price", UadFormat::Amount),
+    attr("DataSourceDescription", "data_source", UadFormat::Text),
+    attr("SalesPriceTotalAdjustmentAmount", "net_adjustment", UadFormat::Amount),
+    attr("AdjustedSalesPriceAmount", "adjusted_price", UadFormat::Amount),
+];
+
+/// Adjustment lines of each `COMPARABLE_SALE`
+pub const COMPARABLE_ADJUSTMENTS: &[AdjustmentMapping] = &[
+    adjustment("DateOfSale", "sale_date", UadFormat::Text),
+    adjustment("Location", "location", UadFormat::Text),
+    adjustment("View", "view", UadFormat::Text),
+    adjustment("Condition", "condition", UadFormat::Condition),
+    adjustment("Quality", "quality", UadFormat::Quality),
+    adjustment("GrossLivingArea", "gross_living_area", UadFormat::Count),
+];
+
+/// Room counts of each `COMPARABLE_SALE`, written as its `ROOM_ADJUSTMENT` element
+pub const COMPARABLE_ROOMS: &[AttributeMapping] = &[
+    attr("TotalBedroomCount", "bedrooms", UadFormat::Count),
+    attr("TotalBathroomCount", "bathrooms", UadFormat::Text),
+];
+
+/// Attributes of the `VALUATION` element besides the appraised value
+pub const VALUATION_ATTRIBUTES: &[AttributeMapping] = &[
+    required("AppraisalEffectiveDate", "effective_date", UadFormat::Date),
+];
+
+pub const RECONCILIATION: ElementMapping = ElementMapping {
+    name: "_RECONCILIATION",
+    section: "reconciliation",
+    attributes: &[
+        attr("_ConditionOfAppraisalType", "condition", APPRAISAL_CONDITION),
+        attr("SalesComparisonApproachValueAmount", "sales_comparison_value", UadFormat::Amount),
+        attr("CostApproachValueAmount", "cost_value", UadFormat::Amount),
+        attr("IncomeApproachValueAmount", "income_value", UadFormat::Amount),
+        attr("_SummaryComment", "comments", UadFormat::Text),
+    ],
+    children: &[],
+};
@@ -0,0 +1,44 @@
+pub mod export;
+pub mod import;
+pub mod mapping;
+pub mod schema;
+pub mod uad;
+
+use shared::models::report::ReportType;
+
+pub use export::{write_mismo, MismoReport};
+pub use import::read_mismo;
+pub use schema::SchemaValidator;
+
+/// MISMO version of exported and imported documents
+pub const MISMO_VERSION: &str = "2.6";
+
+/// A GSE form that can be delivered as MISMO XML
+pub struct MismoForm {
+    /// Report type the form is written from
+    pub report_type: ReportType,
+
+    /// Form identifier, e.g. "FNM1004"
+    pub code: &'static str,
+
+    /// Form edition the mapping follows
+    pub version: &'static str,
+}
+
+/// Forms with a MISMO mapping
+static FORMS: [MismoForm; 4] = [
+    MismoForm { report_type: ReportType::Form1004, code: "FNM1004", version: "March 2005" },
+    MismoForm { report_type: ReportType::Form1073, code: "FNM1073", version: "March 2005" },
+    MismoForm { report_type: ReportType::Form1025, code: "FNM1025", version: "March 2005" },
+    MismoForm { report_type: ReportType::Form2055, code: "FNM2055", version: "March 2005" },
+];
+
+/// GSE form a report type is delivered as; `None` for report types without a MISMO mapping
+pub fn form_for(report_type: &ReportType) -> Option<&'static MismoForm> {
+    FORMS.iter().find(|form| form.report_type == *report_type)
+}
+
+/// GSE form with the given identifier, e.g. "FNM1004"
+pub fn form_by_code(code: &str) -> Option<&'static MismoForm> {
+    FORMS.iter().find(|form| form.code.eq_ignore_ascii_case(code.trim()))
+}
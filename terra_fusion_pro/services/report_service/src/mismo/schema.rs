@@ -0,0 +1,122 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use shared::error::{AppError, AppResult};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// Validates exports against the MISMO 2.6 GSE XML schema with a local `xmllint`
+pub struct SchemaValidator {
+    /// Root XSD of the schema set
+    schema_path: PathBuf,
+
+    /// xmllint executable
+    xmllint: String,
+}
+
+impl SchemaValidator {
+    /// Load validator settings from environment variables. The MISMO schema is distributed
+    /// separately, so `MISMO_SCHEMA_PATH` points at its root XSD. Fails when the schema or
+    /// xmllint is missing, so a misconfigured service stops at startup rather than at export.
+    pub fn from_env() -> AppResult<Self> {
+        let validator = Self {
+            schema_path: env::var("MISMO_SCHEMA_PATH")
+                .unwrap_or_else(|_| "./config/mismo/AppraisalXML_2_6_GSE.xsd".to_string())
+                .into(),
+            xmllint: env::var("XMLLINT_PATH").unwrap_or_else(|_| "xmllint".to_string()),
+        };
+
+        if !validator.schema_path.is_file() {
+            return Err(AppError::Configuration(format!(
+                "MISMO schema not found at {}; set MISMO_SCHEMA_PATH to its root XSD",
+                validator.schema_path.display()
+            )));
+        }
+
+        std::process::Command::new(&validator.xmllint)
+            .arg("--version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map_err(|e| AppError::Configuration(format!("Failed to run {}: {}", validator.xmllint, e)))?;
+
+        Ok(validator)
+    }
+
+    /// Validate a document, returning the schema violations as a validation error
+    pub async fn validate(&self, xml: &[u8]) -> AppResult<()> {
+        if !self.schema_path.is_file() {
+            return Err(AppError::Configuration(format!(
+                "MISMO schema not found at {}",
+                self.schema_path.display()
+            )));
+        }
+
+        let mut child = Command::new(&self.xmllint)
+            .arg("--noout")
+            .arg("--nonet")
+            .arg("--schema")
+            .arg(&self.schema_path)
+            .arg("-")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| AppError::Configuration(format!("Failed to run {}: {}", self.xmllint, e)))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(xml)
+                .await
+                .map_err(|e| AppError::General(format!("Failed to send document to xmllint: {}", e)))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|e| AppError::General(format!("Failed to run xmllint: {}", e)))?;
+
+        if output.status.success() {
+            return Ok(());
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let problems = stderr
+            .lines()
+            .filter(|line| !line.trim().is_empty() && line.trim() != "- fails to validate")
+            .map(problem)
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        // xmllint exits with 3 or 4 for validation failures and 5 when the schema itself cannot be compiled
+        match output.status.code() {
+            Some(3) | Some(4) => Err(AppError::Validation(format!(
+                "Export does not conform to the MISMO schema: {}",
+                problems
+            ))),
+            Some(5) => Err(AppError::Configuration(format!("Invalid MISMO schema: {}", problems))),
+            _ => Err(AppError::General(format!("xmllint failed: {}", problems))),
+        }
+    }
+}
+
+/// An xmllint message such as "-:12: Schemas validity error : Element 'X': ..." as "line 12: Element 'X': ..."
+fn problem(line: &str) -> String {
+    let located = line
+        .strip_prefix("-:")
+        .and_then(|rest| rest.split_once(':'))
+        .filter(|(number, _)| number.chars().all(|c| c.is_ascii_digit()));
+
+    match located {
+        Some((number, message)) => {
+            let message = message.trim();
+            let message = message
+                .strip_prefix("Schemas validity error :")
+                .or_else(|| message.strip_prefix("parser error :"))
+                .unwrap_or(message);
+            format!("line {}: {}", number, message.trim())
+        }
+        None => line.trim().to_string(),
+    }
+}
@@ -0,0 +1,226 @@
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+
+use crate::rendering::layout::display_value;
+
+/// How a content value is written as a MISMO attribute, following the UAD value conventions
+#[derive(Clone, Copy)]
+pub enum UadFormat {
+    /// Free text, written as stored
+    Text,
+    /// Dollar amount, rounded to whole dollars
+    Amount,
+    /// Whole number
+    Count,
+    /// Decimal number, e.g. a rent multiplier
+    Decimal,
+    /// Calendar date, written as YYYY-MM-DD
+    Date,
+    /// UAD condition rating, C1 through C6
+    Condition,
+    /// UAD quality rating, Q1 through Q6
+    Quality,
+    /// Yes/no indicator, written as Y or N
+    Indicator,
+    /// Enumerated value; each pair maps an accepted spelling to its MISMO value, and the first
+    /// spelling of a value is the one imports store
+    Enumerated(&'static [(&'static str, &'static str)]),
+}
+
+pub const BUILT_UP: UadFormat = UadFormat::Enumerated(&[
+    ("Over 75%", "Over75Percent"),
+    ("25-75%", "25To75Percent"),
+    ("Under 25%", "Under25Percent"),
+]);
+
+pub const GROWTH: UadFormat = UadFormat::Enumerated(&[
+    ("Rapid", "Rapid"),
+    ("Stable", "Stable"),
+    ("Slow", "Slow"),
+]);
+
+pub const VALUE_TREND: UadFormat = UadFormat::Enumerated(&[
+    ("Increasing", "Increasing"),
+    ("Stable", "Stable"),
+    ("Declining", "Declining"),
+]);
+
+pub const DEMAND_SUPPLY: UadFormat = UadFormat::Enumerated(&[
+    ("Shortage", "Shortage"),
+    ("In Balance", "InBalance"),
+    ("Over Supply", "OverSupply"),
+]);
+
+pub const MARKETING_TIME: UadFormat = UadFormat::Enumerated(&[
+    ("Under 3 mths", "UnderThreeMonths"),
+    ("Under 3 months", "UnderThreeMonths"),
+    ("3-6 mths", "ThreeToSixMonths"),
+    ("3-6 months", "ThreeToSixMonths"),
+    ("Over 6 mths", "OverSixMonths"),
+    ("Over 6 months", "OverSixMonths"),
+]);
+
+pub const LOCATION_TYPE: UadFormat = UadFormat::Enumerated(&[
+    ("Urban", "Urban"),
+    ("Suburban", "Suburban"),
+    ("Rural", "Rural"),
+]);
+
+pub const OCCUPANCY: UadFormat = UadFormat::Enumerated(&[
+    ("Owner", "Owner"),
+    ("Tenant", "Tenant"),
+    ("Vacant", "Vacant"),
+]);
+
+pub const PROPERTY_RIGHTS: UadFormat = UadFormat::Enumerated(&[
+    ("Fee Simple", "FeeSimple"),
+    ("Leasehold", "Leasehold"),
+    ("Other", "Other"),
+]);
+
+pub const ZONING_COMPLIANCE: UadFormat = UadFormat::Enumerated(&[
+    ("Legal", "Legal"),
+    ("Legal Nonconforming", "LegalNonconforming"),
+    ("No Zoning", "NoZoning"),
+    ("Illegal", "Illegal"),
+]);
+
+pub const APPRAISAL_CONDITION: UadFormat = UadFormat::Enumerated(&[
+    ("As Is", "AsIs"),
+    ("Subject to completion", "SubjectToCompletionPerPlans"),
+    ("Subject to repairs", "SubjectToRepairsOrAlterations"),
+    ("Subject to inspection", "SubjectToInspection"),
+]);
+
+/// Write a content value in its MISMO form. Missing and empty values yield `Ok(None)`;
+/// values that cannot be expressed in the format yield a description of the problem.
+pub fn to_mismo(value: Option<&Value>, format: UadFormat) -> Result<Option<String>, String> {
+    let value = match value {
+        None | Some(Value::Null) => return Ok(None),
+        Some(value) => value,
+    };
+
+    let text = display_value(value);
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(None);
+    }
+
+    let written = match format {
+        UadFormat::Text => text.to_string(),
+        UadFormat::Amount => match parse_number(value) {
+            Some(amount) => format!("{:.0}", amount.round()),
+            None => return Err(format!("\"{}\" is not a dollar amount", text)),
+        },
+        UadFormat::Count => match parse_number(value) {
+            Some(count) if count >= 0.0 && count.fract() == 0.0 => format!("{:.0}", count),
+            _ => return Err(format!("\"{}\" is not a whole number", text)),
+        },
+        UadFormat::Decimal => match parse_number(value) {
+            Some(number) => number.to_string(),
+            None => return Err(format!("\"{}\" is not a number", text)),
+        },
+        UadFormat::Date => match parse_date(text) {
+            Some(date) => date.format("%Y-%m-%d").to_string(),
+            None => return Err(format!("\"{}\" is not a date (YYYY-MM-DD or MM/DD/YYYY)", text)),
+        },
+        UadFormat::Condition => rating(text, 'C')
+            .ok_or_else(|| format!("\"{}\" is not a UAD condition rating (C1-C6)", text))?,
+        UadFormat::Quality => rating(text, 'Q')
+            .ok_or_else(|| format!("\"{}\" is not a UAD quality rating (Q1-Q6)", text))?,
+        UadFormat::Indicator => match value {
+            Value::Bool(true) => "Y".to_string(),
+            Value::Bool(false) => "N".to_string(),
+            _ => match normalize(text).as_str() {
+                "y" | "yes" | "true" => "Y".to_string(),
+                "n" | "no" | "false" => "N".to_string(),
+                _ => return Err(format!("\"{}\" is not yes or no", text)),
+            },
+        },
+        UadFormat::Enumerated(options) => {
+            let key = normalize(text);
+            let matched = options
+                .iter()
+                .find(|(alias, mismo)| normalize(alias) == key || normalize(mismo) == key)
+                .map(|(_, mismo)| mismo.to_string());
+
+            match matched {
+                Some(mismo) => mismo,
+                None => {
+                    let accepted = options.iter().map(|(alias, _)| *alias).collect::<Vec<_>>().join(", ");
+                    return Err(format!("\"{}\" must be one of: {}", text, accepted));
+                }
+            }
+        }
+    };
+
+    Ok(Some(written))
+}
+
+/// Read a MISMO attribute back into the content value it was written from. Values that do not
+/// parse in their format are kept as text rather than dropped.
+pub fn from_mismo(text: &str, format: UadFormat) -> Value {
+    match format {
+        UadFormat::Amount | UadFormat::Count | UadFormat::Decimal => match parse_number(&json!(text)) {
+            Some(n) if n.fract() == 0.0 && n.abs() < 1e15 => json!(n as i64),
+            Some(n) => json!(n),
+            None => json!(text),
+        },
+        UadFormat::Indicator => match text {
+            "Y" => json!(true),
+            "N" => json!(false),
+            _ => json!(text),
+        },
+        UadFormat::Enumerated(options) => {
+            let key = normalize(text);
+            match options.iter().find(|(_, mismo)| normalize(mismo) == key) {
+                Some((spelling, _)) => json!(spelling),
+                None => json!(text),
+            }
+        }
+        UadFormat::Text | UadFormat::Date | UadFormat::Condition | UadFormat::Quality => json!(text),
+    }
+}
+
+/// A number stored as a JSON number or as text such as "$350,000"
+fn parse_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s
+            .chars()
+            .filter(|c| !matches!(c, '$' | ',' | ' '))
+            .collect::<String>()
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite()),
+        _ => None,
+    }
+}
+
+/// A date stored as YYYY-MM-DD or MM/DD/YYYY
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(text, "%m/%d/%Y"))
+        .ok()
+}
+
+/// A UAD rating such as "C3" or "q2" with the given prefix, or a bare digit
+fn rating(text: &str, prefix: char) -> Option<String> {
+    let digits = match text.strip_prefix(prefix).or_else(|| text.strip_prefix(prefix.to_ascii_lowercase())) {
+        Some(rest) => rest,
+        None => text,
+    };
+
+    match digits.parse::<u8>() {
+        Ok(level @ 1..=6) => Some(format!("{}{}", prefix, level)),
+        _ => None,
+    }
+}
+
+/// Lower-cased alphanumerics, so "Over 75%", "over75" and "Over75Percent" compare sensibly
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, NaiveDate};
+use handlebars::{handlebars_helper, no_escape, Handlebars, Template};
+use serde::Serialize;
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::Appraisal;
+use shared::models::property::Property;
+use shared::models::report::Report;
+use shared::utils::format::{
+    format_address_single_line, format_currency, format_date_long, format_date_short, format_percentage,
+    format_price_per_sqft, format_square_feet,
+};
+
+/// What a narrative template can refer to, e.g. `{{property.address.city}}`,
+/// `{{appraisal.purpose}}` or `{{report.content.site.zoning_classification}}`
+#[derive(Serialize)]
+pub struct NarrativeContext<'a> {
+    /// Subject property
+    pub property: &'a Property,
+
+    /// Appraisal the report was ordered under (null if there is none)
+    pub appraisal: Option<&'a Appraisal>,
+
+    /// Report the narrative is written for
+    pub report: &'a Report,
+}
+
+handlebars_helper!(currency_helper: |amount: f64| format_currency(amount));
+handlebars_helper!(square_feet_helper: |area: f64| format_square_feet(area));
+handlebars_helper!(price_per_sqft_helper: |price: f64, area: f64| format_price_per_sqft(price, area));
+handlebars_helper!(percentage_helper: |value: f64| format_percentage(value));
+handlebars_helper!(date_helper: |value: str| format_date(value, false));
+handlebars_helper!(short_date_helper: |value: str| format_date(value, true));
+handlebars_helper!(address_helper: |address: object| {
+    let part = |key: &str| address.get(key).and_then(|value| value.as_str()).unwrap_or_default();
+    format_address_single_line(
+        part("street1"),
+        Some(part("street2")).filter(|street2| !street2.is_empty()),
+        part("city"),
+        part("state"),
+        part("postal_code"),
+    )
+});
+
+/// Check that a template parses, so a broken snippet is caught when it is saved
+pub fn check_template(template: &str) -> AppResult<()> {
+    Template::compile(template)
+        .map(|_| ())
+        .map_err(|e| AppError::Validation(format!("Template is invalid: {}", e)))
+}
+
+/// Render a narrative template as plain text. Rendering is strict: a reference to a field that
+/// does not exist is an error rather than a silent blank, so guard optional fields with `{{#if}}`.
+pub fn render_template(template: &str, context: &NarrativeContext<'_>) -> AppResult<String> {
+    registry()
+        .render_template(template, context)
+        .map(|text| text.trim().to_string())
+        .map_err(|e| AppError::Validation(format!("Template could not be rendered: {}", e)))
+}
+
+/// Template registry with the formatting helpers of `shared::utils::format`
+fn registry() -> Handlebars<'static> {
+    let mut registry = Handlebars::new();
+    registry.set_strict_mode(true);
+    registry.register_escape_fn(no_escape);
+
+    registry.register_helper("currency", Box::new(currency_helper));
+    registry.register_helper("square_feet", Box::new(square_feet_helper));
+    registry.register_helper("price_per_sqft", Box::new(price_per_sqft_helper));
+    registry.register_helper("percentage", Box::new(percentage_helper));
+    registry.register_helper("date", Box::new(date_helper));
+    registry.register_helper("short_date", Box::new(short_date_helper));
+    registry.register_helper("address", Box::new(address_helper));
+
+    registry
+}
+
+/// Format a timestamp or date as "June 1, 2023", or as "06/01/2023" when `short`. Text that is not
+/// a date is printed as written.
+fn format_date(value: &str, short: bool) -> String {
+    let date = DateTime::parse_from_rfc3339(value)
+        .map(|datetime| datetime.date_naive())
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%m/%d/%Y"));
+
+    match date {
+        Ok(date) if short => format_date_short(date),
+        Ok(date) => format_date_long(date),
+        Err(_) => value.to_string(),
+    }
+}
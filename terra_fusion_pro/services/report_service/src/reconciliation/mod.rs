@@ -0,0 +1,368 @@
+use std::env;
+
+use serde_json::Value;
+use shared::error::{AppError, AppResult};
+use shared::models::property::ValuationMethod;
+use shared::models::reconciliation::{ApproachIndication, ApproachWeight, ReconcileReportRequest};
+
+/// Approaches to value that can be reconciled, with the content holding each one's indication
+const APPROACHES: [(ValuationMethod, &str); 3] = [
+    (ValuationMethod::SalesComparison, "/sales_comparison/indicated_value"),
+    (ValuationMethod::CostApproach, "/cost/indicated_value"),
+    (ValuationMethod::IncomeApproach, "/income/indicated_value"),
+];
+
+/// Tolerance on the sum of the weights, so 33.33 + 33.33 + 33.34 adds up to 100
+const WEIGHT_TOLERANCE: f64 = 0.01;
+
+/// Rounding increment applied to values up to a ceiling
+struct RoundingBand {
+    /// Largest value the band applies to; `None` for the last band
+    up_to: Option<f64>,
+
+    /// Increment values are rounded to
+    increment: f64,
+}
+
+/// How reconciled values are rounded and how far apart the approaches may be
+pub struct ReconciliationRules {
+    /// Rounding bands, by ascending ceiling
+    rounding: Vec<RoundingBand>,
+
+    /// Largest spread between indications accepted without an explanation, as a percentage
+    max_spread_percent: f64,
+}
+
+/// Weighted and rounded value of a report's approaches to value
+pub struct Reconciliation {
+    /// Each approach with its weight and contribution
+    pub indications: Vec<ApproachIndication>,
+
+    /// Weighted value before rounding
+    pub weighted_value: f64,
+
+    /// Increment the weighted value was rounded to
+    pub rounding_increment: f64,
+
+    /// Reconciled value after rounding
+    pub final_value: f64,
+
+    /// Gap between the highest and lowest indications as a percentage of the weighted value
+    pub spread_percent: f64,
+}
+
+impl ReconciliationRules {
+    /// Load reconciliation rules from environment variables. `RECONCILIATION_ROUNDING` lists
+    /// `ceiling:increment` bands in ascending order followed by the increment for larger values;
+    /// the default rounds to $500 up to $100,000, to $1,000 up to $1,000,000 and to $5,000 above.
+    pub fn from_env() -> AppResult<Self> {
+        let rounding = env::var("RECONCILIATION_ROUNDING")
+            .unwrap_or_else(|_| "100000:500,1000000:1000,5000".to_string());
+        let rounding = parse_rounding(&rounding)?;
+
+        let max_spread_percent = env::var("RECONCILIATION_MAX_SPREAD_PERCENT")
+            .unwrap_or_else(|_| "15".to_string())
+            .parse::<f64>()
+            .ok()
+            .filter(|percent| percent.is_finite() && *percent >= 0.0)
+            .ok_or_else(|| {
+                AppError::Configuration("RECONCILIATION_MAX_SPREAD_PERCENT must be a non-negative number".to_string())
+            })?;
+
+        Ok(Self { rounding, max_spread_percent })
+    }
+
+    /// Largest spread between indications accepted without an explanation, as a percentage
+    pub fn max_spread_percent(&self) -> f64 {
+        self.max_spread_percent
+    }
+
+    /// Weigh the approaches developed in a report's content and round the result
+    pub fn reconcile(&self, content: &Value, request: &ReconcileReportRequest) -> AppResult<Reconciliation> {
+        for weight in &request.weights {
+            if !APPROACHES.iter().any(|(method, _)| *method == weight.method) {
+                return Err(AppError::Validation(format!(
+                    "{:?} is not an approach to value that can be reconciled",
+                    weight.method
+                )));
+            }
+            if request.weights.iter().filter(|other| other.method == weight.method).count() > 1 {
+                return Err(AppError::Validation(format!("{:?} is weighted more than once", weight.method)));
+            }
+            if !weight.weight.is_finite() || !(0.0..=100.0).contains(&weight.weight) {
+                return Err(AppError::Validation(format!(
+                    "Weight of {:?} must be between 0 and 100",
+                    weight.method
+                )));
+            }
+            if weight.justification.trim().is_empty() {
+                return Err(AppError::Validation(format!(
+                    "Weight of {:?} needs a justification",
+                    weight.method
+                )));
+            }
+        }
+
+        let total = request.weights.iter().map(|weight| weight.weight).sum::<f64>();
+        if (total - 100.0).abs() > WEIGHT_TOLERANCE {
+            return Err(AppError::Validation(format!("Weights must add up to 100, not {}", total)));
+        }
+
+        let mut indications = Vec::new();
+        for (method, pointer) in &APPROACHES {
+            let indicated_value = content.pointer(pointer).and_then(Value::as_f64);
+            let weight = request.weights.iter().find(|weight| weight.method == *method);
+
+            match (indicated_value, weight) {
+                (Some(value), _) if value <= 0.0 => {
+                    return Err(AppError::Validation(format!(
+                        "Value indicated by {:?} must be positive",
+                        method
+                    )))
+                }
+                (Some(_), None) => {
+                    return Err(AppError::Validation(format!(
+                        "{:?} was developed and needs a weight, even if it is 0",
+                        method
+                    )))
+                }
+                (None, Some(ApproachWeight { weight, .. })) if *weight > 0.0 => {
+                    return Err(AppError::Validation(format!(
+                        "{:?} has no indicated value and cannot be given weight",
+                        method
+                    )))
+                }
+                (None, None) => continue,
+                _ => {}
+            }
+
+            let weight = weight.map_or(0.0, |weight| weight.weight);
+            indications.push(ApproachIndication {
+                method: method.clone(),
+                indicated_value,
+                weight,
+                contribution: indicated_value.unwrap_or(0.0) * weight / 100.0,
+            });
+        }
+
+        let weighted_value = indications.iter().map(|indication| indication.contribution).sum::<f64>();
+
+        let values = indications.iter().filter_map(|indication| indication.indicated_value);
+        let highest = values.clone().fold(f64::MIN, f64::max);
+        let lowest = values.fold(f64::MAX, f64::min);
+        let spread_percent = (highest - lowest) / weighted_value * 100.0;
+
+        let explained = request
+            .spread_explanation
+            .as_deref()
+            .is_some_and(|explanation| !explanation.trim().is_empty());
+        if spread_percent > self.max_spread_percent && !explained {
+            return Err(AppError::Validation(format!(
+                "Indications are {:.1}% apart, more than the {}% allowed without a spread explanation",
+                spread_percent, self.max_spread_percent
+            )));
+        }
+
+        let rounding_increment = self.increment_for(weighted_value);
+        let final_value = (weighted_value / rounding_increment).round() * rounding_increment;
+
+        Ok(Reconciliation {
+            indications,
+            weighted_value,
+            rounding_increment,
+            final_value,
+            spread_percent,
+        })
+    }
+
+    /// Rounding increment of the band a value falls in
+    fn increment_for(&self, value: f64) -> f64 {
+        self.rounding
+            .iter()
+            .find(|band| band.up_to.unwrap_or(f64::INFINITY) >= value)
+            .map_or(1.0, |band| band.increment)
+    }
+}
+
+/// Parse rounding bands such as "100000:500,1000000:1000,5000"
+fn parse_rounding(rules: &str) -> AppResult<Vec<RoundingBand>> {
+    let invalid = || {
+        AppError::Configuration(
+            "RECONCILIATION_ROUNDING must list ascending ceiling:increment bands followed by an increment"
+                .to_string(),
+        )
+    };
+    let amount = |text: &str| {
+        text.trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|amount| amount.is_finite() && *amount > 0.0)
+            .ok_or_else(invalid)
+    };
+
+    let entries = rules.split(',').collect::<Vec<_>>();
+    let (last, bands) = entries.split_last().ok_or_else(invalid)?;
+
+    let mut rounding = Vec::new();
+    for band in bands {
+        let (up_to, increment) = band.split_once(':').ok_or_else(invalid)?;
+        let up_to = amount(up_to)?;
+        if rounding.last().is_some_and(|previous: &RoundingBand| previous.up_to >= Some(up_to)) {
+            return Err(invalid());
+        }
+        rounding.push(RoundingBand { up_to: Some(up_to), increment: amount(increment)? });
+    }
+    rounding.push(RoundingBand { up_to: None, increment: amount(last)? });
+
+    Ok(rounding)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn rules() -> ReconciliationRules {
+        ReconciliationRules {
+            rounding: parse_rounding("100000:500,1000000:1000,5000").unwrap(),
+            max_spread_percent: 15.0,
+        }
+    }
+
+    fn weight(method: ValuationMethod, weight: f64) -> ApproachWeight {
+        ApproachWeight { method, weight, justification: "Best supported by the market".to_string() }
+    }
+
+    fn request(weights: Vec<ApproachWeight>) -> ReconcileReportRequest {
+        ReconcileReportRequest { weights, spread_explanation: None }
+    }
+
+    fn content(sales: f64, cost: f64) -> Value {
+        json!({
+            "sales_comparison": {"indicated_value": sales},
+            "cost": {"indicated_value": cost},
+        })
+    }
+
+    fn is_validation_error(result: AppResult<Reconciliation>) -> bool {
+        matches!(result, Err(AppError::Validation(_)))
+    }
+
+    #[test]
+    fn weighs_the_approaches_and_rounds_the_result() {
+        let request = request(vec![
+            weight(ValuationMethod::SalesComparison, 60.0),
+            weight(ValuationMethod::CostApproach, 40.0),
+        ]);
+
+        let reconciliation = rules().reconcile(&content(412300.0, 418900.0), &request).unwrap();
+
+        assert_eq!(reconciliation.weighted_value, 414940.0);
+        assert_eq!(reconciliation.rounding_increment, 1000.0);
+        assert_eq!(reconciliation.final_value, 415000.0);
+        assert_eq!(reconciliation.indications.len(), 2);
+        assert_eq!(reconciliation.indications[0].contribution, 247380.0);
+        assert!((reconciliation.spread_percent - 1.5906).abs() < 0.001);
+    }
+
+    #[test]
+    fn rounds_by_the_band_the_value_falls_in() {
+        let rules = rules();
+        let only_sales = |value: f64| {
+            let content = json!({"sales_comparison": {"indicated_value": value}});
+            let request = request(vec![weight(ValuationMethod::SalesComparison, 100.0)]);
+            rules.reconcile(&content, &request).unwrap().final_value
+        };
+
+        assert_eq!(only_sales(85_100.0), 85_000.0);
+        assert_eq!(only_sales(100_000.0), 100_000.0);
+        assert_eq!(only_sales(412_600.0), 413_000.0);
+        assert_eq!(only_sales(1_234_000.0), 1_235_000.0);
+    }
+
+    #[test]
+    fn weights_must_add_up_to_100_within_the_tolerance() {
+        let content = json!({
+            "sales_comparison": {"indicated_value": 400000},
+            "cost": {"indicated_value": 410000},
+            "income": {"indicated_value": 405000},
+        });
+        let thirds = request(vec![
+            weight(ValuationMethod::SalesComparison, 33.33),
+            weight(ValuationMethod::CostApproach, 33.33),
+            weight(ValuationMethod::IncomeApproach, 33.34),
+        ]);
+        let short = request(vec![
+            weight(ValuationMethod::SalesComparison, 50.0),
+            weight(ValuationMethod::CostApproach, 30.0),
+            weight(ValuationMethod::IncomeApproach, 10.0),
+        ]);
+
+        assert!(rules().reconcile(&content, &thirds).is_ok());
+        assert!(is_validation_error(rules().reconcile(&content, &short)));
+    }
+
+    #[test]
+    fn refuses_weights_that_are_unsupported_repeated_out_of_range_or_unjustified() {
+        let content = content(400000.0, 410000.0);
+        let rules = rules();
+        let mut unjustified = weight(ValuationMethod::CostApproach, 50.0);
+        unjustified.justification = "  ".to_string();
+
+        for weights in [
+            vec![weight(ValuationMethod::SalesComparison, 50.0), weight(ValuationMethod::Automated, 50.0)],
+            vec![weight(ValuationMethod::SalesComparison, 50.0), weight(ValuationMethod::SalesComparison, 50.0)],
+            vec![weight(ValuationMethod::SalesComparison, 120.0), weight(ValuationMethod::CostApproach, -20.0)],
+            vec![weight(ValuationMethod::SalesComparison, 50.0), unjustified],
+        ] {
+            assert!(is_validation_error(rules.reconcile(&content, &request(weights))));
+        }
+    }
+
+    #[test]
+    fn developed_approaches_need_a_weight_and_only_developed_ones_may_carry_weight() {
+        let rules = rules();
+        let sales_only = json!({"sales_comparison": {"indicated_value": 400000}});
+
+        let unweighted = request(vec![weight(ValuationMethod::SalesComparison, 100.0)]);
+        assert!(is_validation_error(rules.reconcile(&content(400000.0, 410000.0), &unweighted)));
+
+        let undeveloped = request(vec![
+            weight(ValuationMethod::SalesComparison, 80.0),
+            weight(ValuationMethod::IncomeApproach, 20.0),
+        ]);
+        assert!(is_validation_error(rules.reconcile(&sales_only, &undeveloped)));
+
+        let zero_weight = request(vec![
+            weight(ValuationMethod::SalesComparison, 100.0),
+            weight(ValuationMethod::IncomeApproach, 0.0),
+        ]);
+        assert_eq!(rules.reconcile(&sales_only, &zero_weight).unwrap().final_value, 400000.0);
+    }
+
+    #[test]
+    fn a_wide_spread_needs_an_explanation() {
+        let content = content(400000.0, 480000.0);
+        let mut request = request(vec![
+            weight(ValuationMethod::SalesComparison, 90.0),
+            weight(ValuationMethod::CostApproach, 10.0),
+        ]);
+
+        assert!(is_validation_error(rules().reconcile(&content, &request)));
+
+        request.spread_explanation = Some("Cost approach overstates value for homes of this age".to_string());
+        assert_eq!(rules().reconcile(&content, &request).unwrap().final_value, 408000.0);
+    }
+
+    #[test]
+    fn rounding_bands_must_ascend_and_be_positive() {
+        for rules in ["500", "100000:500,5000", "100000:500,1000000:1000,5000"] {
+            assert!(parse_rounding(rules).is_ok(), "{}", rules);
+        }
+
+        for rules in ["", "1000000:1000,100000:500,5000", "100000:0,5000", "100000:500,", "100000,5000", "abc"] {
+            assert!(matches!(parse_rounding(rules), Err(AppError::Configuration(_))), "{}", rules);
+        }
+    }
+}
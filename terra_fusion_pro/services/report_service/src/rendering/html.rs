@@ -0,0 +1,396 @@
+use std::fmt::Write;
+
+use serde_json::Value;
+use shared::db::encode_enum;
+use shared::utils::format::format_currency;
+
+use super::layout::{
+    self, FieldFormat, FieldLayout, FormLayout, SectionLayout, COMPARABLES_PER_GRID, RESERVED_KEYS,
+    SALES_COMPARISON_KEY,
+};
+use super::{property_address, property_facts, ExhibitContent, JpegImage, ReportDocument, SignatureBlock};
+
+/// Print-friendly styles: US Letter pages, sections kept whole where they fit
+const STYLES: &str = r#"
+@page { size: letter; margin: 0.55in; }
+* { box-sizing: border-box; }
+body { font: 10pt/1.35 Helvetica, Arial, sans-serif; color: #111; margin: 0; background: #e8e8e8; }
+main { max-width: 8.5in; margin: 0 auto; padding: 0.55in; background: #fff; }
+header.form { display: flex; gap: 0.75em; border-bottom: 1px solid #111; padding-bottom: 4pt; margin-bottom: 12pt; }
+header.form strong { white-space: nowrap; }
+h1 { font-size: 16pt; margin: 0 0 6pt; }
+h2 { font-size: 11pt; margin: 14pt 0 4pt; padding-bottom: 2pt; border-bottom: 1px solid #999; break-after: avoid; }
+p.subtitle { font-size: 11pt; margin: 0 0 2pt; }
+p.note { color: #666; font-style: italic; margin: 2pt 0; }
+p.preview { background: #fff4ce; border: 1px solid #e0c060; padding: 4pt 8pt; margin: 0 0 12pt; }
+dl { display: grid; grid-template-columns: 2.35in 1fr; gap: 2pt 8pt; margin: 0; }
+dt { font-weight: bold; }
+dd { margin: 0; }
+dd.narrative { grid-column: 1 / -1; white-space: pre-wrap; margin-bottom: 4pt; }
+section { break-inside: avoid-page; }
+table.grid { width: 100%; border-collapse: collapse; margin: 6pt 0; table-layout: fixed; break-inside: avoid; }
+table.grid th, table.grid td { border: 1px solid #bbb; padding: 2pt 4pt; text-align: left; vertical-align: top; }
+table.grid th:first-child { width: 1.65in; }
+figure { margin: 0 0 12pt; break-inside: avoid; text-align: center; }
+figure img { max-width: 100%; max-height: 2.8in; width: auto; height: auto; }
+figure figcaption { text-align: left; }
+section.exhibit figure img { max-height: 8in; }
+.signature-line { width: 3in; min-height: 0.5in; border-bottom: 1px solid #111; display: flex; align-items: flex-end; }
+.signature-line img { max-width: 100%; max-height: 0.5in; }
+footer { margin-top: 18pt; padding-top: 4pt; border-top: 1px solid #111; font-size: 8pt; }
+@media print {
+  body { background: none; }
+  main { max-width: none; padding: 0; }
+  p.preview { display: none; }
+  .photos, .exhibit { break-before: page; }
+}
+"#;
+
+/// Render a report to a standalone HTML page laid out like its PDF. Photos, exhibit images and
+/// signature images are embedded, so the page needs nothing else to display or print. When
+/// `events_path` is given the page reloads itself, keeping its scroll position, whenever that
+/// event stream reports an update.
+pub fn render_html(document: &ReportDocument<'_>, layout: &FormLayout, events_path: Option<&str>) -> String {
+    let report = document.report;
+    let comparables = report
+        .content
+        .get("comparables")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    let _ = writeln!(html, "<title>{} - {}</title>", escape(&report.title), escape(layout.title));
+    let _ = writeln!(html, "<style>{}</style>", STYLES);
+    html.push_str("</head>\n<body>\n<main>\n");
+
+    let _ = writeln!(
+        html,
+        "<header class=\"form\"><strong>{}</strong><span>{}</span></header>",
+        escape(layout.form_name),
+        escape(layout.title)
+    );
+    html.push_str("<p class=\"preview\">Preview of the report as it stands; the PDF remains the report of record.</p>\n");
+
+    write_title(&mut html, document, layout);
+    write_property(&mut html, document);
+
+    for section in layout.sections {
+        write_section(&mut html, section, &report.content);
+        if section.key == SALES_COMPARISON_KEY {
+            write_comparables(&mut html, layout.comparable_rows, comparables);
+        }
+    }
+
+    write_additional_sections(&mut html, layout, &report.content);
+    write_photos(&mut html, document);
+    for signature in &document.signatures {
+        write_signature(&mut html, signature);
+    }
+    write_exhibits(&mut html, document);
+
+    let _ = writeln!(html, "<footer>{}</footer>", escape(&property_address(document.property)));
+    html.push_str("</main>\n");
+
+    if let Some(events_path) = events_path {
+        write_live_refresh(&mut html, events_path);
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Title block with the report, subject address and value conclusion
+fn write_title(html: &mut String, document: &ReportDocument<'_>, layout: &FormLayout) {
+    let report = document.report;
+
+    let _ = writeln!(html, "<h1>{}</h1>", escape(layout.title));
+    let _ = writeln!(html, "<p class=\"subtitle\">{}</p>", escape(&report.title));
+    let _ = writeln!(html, "<p class=\"subtitle\">{}</p>", escape(&property_address(document.property)));
+
+    let value = report
+        .valuation_amount
+        .map(format_currency)
+        .unwrap_or_else(|| "Not yet concluded".to_string());
+    let effective_date = layout::format_field(
+        layout::lookup(&report.content, "reconciliation.effective_date"),
+        FieldFormat::Text,
+    );
+
+    html.push_str("<section>\n<dl>\n");
+    write_field_row(html, "Opinion of Market Value", &value);
+    if let Some(date) = effective_date {
+        write_field_row(html, "Effective Date", &date);
+    }
+    write_field_row(html, "Report Status", &layout::humanize_key(&encode_enum(&report.status)));
+    html.push_str("</dl>\n</section>\n");
+}
+
+/// Subject property characteristics from the property record
+fn write_property(html: &mut String, document: &ReportDocument<'_>) {
+    html.push_str("<section>\n<h2>Subject Property</h2>\n<dl>\n");
+    for (label, value) in property_facts(document.property) {
+        write_field_row(html, label, &value);
+    }
+    html.push_str("</dl>\n</section>\n");
+}
+
+/// A content section printed through its layout
+fn write_section(html: &mut String, section: &SectionLayout, content: &Value) {
+    let _ = writeln!(html, "<section>\n<h2>{}</h2>", escape(section.heading));
+
+    let values = content.get(section.key);
+    let mut rows = String::new();
+    if let Some(values) = values {
+        for field in section.fields {
+            write_field(&mut rows, field, values);
+        }
+    }
+
+    if rows.is_empty() {
+        html.push_str("<p class=\"note\">Not provided</p>\n");
+    } else {
+        let _ = write!(html, "<dl>\n{}</dl>\n", rows);
+    }
+    html.push_str("</section>\n");
+}
+
+/// One field, skipped when it has no value
+fn write_field(html: &mut String, field: &FieldLayout, values: &Value) {
+    let value = match layout::format_field(layout::lookup(values, field.path), field.format) {
+        Some(value) => value,
+        None => return,
+    };
+
+    match field.format {
+        FieldFormat::Narrative => {
+            let _ = writeln!(
+                html,
+                "<dt>{}</dt>\n<dd class=\"narrative\">{}</dd>",
+                escape(field.label),
+                escape(&value)
+            );
+        }
+        _ => write_field_row(html, field.label, &value),
+    }
+}
+
+fn write_field_row(html: &mut String, label: &str, value: &str) {
+    let _ = writeln!(html, "<dt>{}</dt><dd>{}</dd>", escape(label), escape(value));
+}
+
+/// Sales comparison grid, in blocks of comparables as on the GSE forms
+fn write_comparables(html: &mut String, rows: &[FieldLayout], comparables: &[Value]) {
+    for (block, group) in comparables.chunks(COMPARABLES_PER_GRID).enumerate() {
+        let first = block * COMPARABLES_PER_GRID + 1;
+
+        html.push_str("<table class=\"grid\">\n<thead><tr><th>Feature</th>");
+        for number in first..first + group.len() {
+            let _ = write!(html, "<th>Comparable {}</th>", number);
+        }
+        for _ in group.len()..COMPARABLES_PER_GRID {
+            html.push_str("<th></th>");
+        }
+        html.push_str("</tr></thead>\n<tbody>\n");
+
+        for row in rows {
+            let _ = write!(html, "<tr><th>{}</th>", escape(row.label));
+            for comparable in group {
+                let cell = layout::format_field(layout::lookup(comparable, row.path), row.format).unwrap_or_default();
+                let _ = write!(html, "<td>{}</td>", escape(&cell));
+            }
+            for _ in group.len()..COMPARABLES_PER_GRID {
+                html.push_str("<td></td>");
+            }
+            html.push_str("</tr>\n");
+        }
+        html.push_str("</tbody>\n</table>\n");
+    }
+}
+
+/// Content sections that the layout does not cover, printed generically so nothing entered is lost
+fn write_additional_sections(html: &mut String, layout: &FormLayout, content: &Value) {
+    let content = match content.as_object() {
+        Some(content) => content,
+        None => return,
+    };
+
+    let mut keys: Vec<&String> = content
+        .keys()
+        .filter(|key| !RESERVED_KEYS.contains(&key.as_str()))
+        .filter(|key| !layout.sections.iter().any(|section| section.key == key.as_str()))
+        .collect();
+    keys.sort();
+
+    for key in keys {
+        let heading = escape(&layout::humanize_key(key));
+
+        match &content[key] {
+            Value::Object(fields) => {
+                let _ = writeln!(html, "<section>\n<h2>{}</h2>\n<dl>", heading);
+
+                let mut names: Vec<&String> = fields.keys().collect();
+                names.sort();
+                for name in names {
+                    if let Some(value) = layout::format_field(Some(&fields[name]), FieldFormat::Text) {
+                        write_field_row(html, &layout::humanize_key(name), &value);
+                    }
+                }
+                html.push_str("</dl>\n</section>\n");
+            }
+            Value::String(text) if !text.trim().is_empty() => {
+                let _ = writeln!(
+                    html,
+                    "<section>\n<h2>{}</h2>\n<dl><dd class=\"narrative\">{}</dd></dl>\n</section>",
+                    heading,
+                    escape(text)
+                );
+            }
+            value => {
+                if let Some(value) = layout::format_field(Some(value), FieldFormat::Text) {
+                    let _ = writeln!(html, "<section>\n<h2>{}</h2>\n<dl>", heading);
+                    write_field_row(html, &layout::humanize_key(key), &value);
+                    html.push_str("</dl>\n</section>\n");
+                }
+            }
+        }
+    }
+}
+
+/// Photo addendum, starting on a new page when printed
+fn write_photos(html: &mut String, document: &ReportDocument<'_>) {
+    if document.photos.is_empty() {
+        return;
+    }
+
+    html.push_str("<section class=\"photos\">\n<h2>Photo Addendum</h2>\n");
+    for photo in &document.photos {
+        let label = escape(&photo.label);
+        let _ = write!(
+            html,
+            "<figure>\n<p><strong>{}</strong></p>\n<img src=\"{}\" alt=\"{}\" width=\"{}\" height=\"{}\">\n",
+            label,
+            data_uri(&photo.image),
+            label,
+            photo.image.width,
+            photo.image.height
+        );
+        if let Some(caption) = &photo.caption {
+            let _ = writeln!(html, "<figcaption>{}</figcaption>", escape(caption));
+        }
+        html.push_str("</figure>\n");
+    }
+    html.push_str("</section>\n");
+}
+
+/// Exhibits, each starting on a new page when printed. Uploaded PDFs are only noted here; their
+/// pages are merged into the rendered PDF.
+fn write_exhibits(html: &mut String, document: &ReportDocument<'_>) {
+    for exhibit in &document.exhibits {
+        let title = escape(&exhibit.title);
+        let _ = writeln!(html, "<section class=\"exhibit\">\n<h2>{}</h2>", title);
+
+        match &exhibit.content {
+            ExhibitContent::Image(image) => {
+                let _ = writeln!(
+                    html,
+                    "<figure>\n<img src=\"{}\" alt=\"{}\" width=\"{}\" height=\"{}\">",
+                    data_uri(image),
+                    title,
+                    image.width,
+                    image.height
+                );
+                if let Some(caption) = &exhibit.caption {
+                    let _ = writeln!(html, "<figcaption>{}</figcaption>", escape(caption));
+                }
+                html.push_str("</figure>\n");
+            }
+            ExhibitContent::Pdf { page_count, .. } => {
+                if let Some(caption) = &exhibit.caption {
+                    let _ = writeln!(html, "<p>{}</p>", escape(caption));
+                }
+                let _ = writeln!(
+                    html,
+                    "<p class=\"note\">Uploaded PDF of {} page(s), merged into the rendered PDF as uploaded.</p>",
+                    page_count
+                );
+            }
+        }
+        html.push_str("</section>\n");
+    }
+}
+
+/// Signature block of an appraiser, with their signature image on the line if they have one
+fn write_signature(html: &mut String, signature: &SignatureBlock) {
+    let _ = writeln!(html, "<section>\n<h2>{}</h2>\n<dl>", escape(&signature.title));
+
+    html.push_str("<dt>Signature</dt><dd><div class=\"signature-line\">");
+    if let Some(image) = &signature.image {
+        let _ = write!(html, "<img src=\"{}\" alt=\"Signature\">", data_uri(image));
+    }
+    html.push_str("</div></dd>\n");
+
+    write_field_row(html, "Name", &signature.appraiser_name);
+    write_field_row(
+        html,
+        "State License/Certification #",
+        signature.license.as_deref().unwrap_or("Not on file"),
+    );
+    write_field_row(
+        html,
+        "Date of Signature and Report",
+        &signature
+            .signed_on
+            .map(|date| date.format("%m/%d/%Y").to_string())
+            .unwrap_or_else(|| "Unsigned".to_string()),
+    );
+    html.push_str("</dl>\n</section>\n");
+}
+
+/// Script that reloads the page when the report changes, restoring where the reader was
+fn write_live_refresh(html: &mut String, events_path: &str) {
+    let _ = write!(
+        html,
+        r#"<script>
+(function () {{
+  var key = "preview-scroll:" + location.pathname;
+  var saved = sessionStorage.getItem(key);
+  if (saved !== null) {{
+    sessionStorage.removeItem(key);
+    window.scrollTo(0, parseInt(saved, 10));
+  }}
+  var events = new EventSource({});
+  events.addEventListener("updated", function () {{
+    sessionStorage.setItem(key, String(window.scrollY));
+    location.reload();
+  }});
+  events.addEventListener("closed", function () {{ events.close(); }});
+}})();
+</script>
+"#,
+        serde_json::Value::from(events_path)
+    );
+}
+
+/// Inline image source for a JPEG
+fn data_uri(image: &JpegImage) -> String {
+    format!("data:image/jpeg;base64,{}", base64::encode(&image.bytes))
+}
+
+/// Escape text for HTML element content and quoted attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
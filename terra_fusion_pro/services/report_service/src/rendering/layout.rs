@@ -0,0 +1,454 @@
+use serde_json::Value;
+use shared::models::report::ReportType;
+use shared::utils::format::{format_currency, format_square_feet};
+
+/// Page layout of a report form: which `Report.content` sections are printed, in order
+pub struct FormLayout {
+    /// Form number printed in the page header
+    pub form_name: &'static str,
+
+    /// Full form title
+    pub title: &'static str,
+
+    /// Content sections, in print order
+    pub sections: &'static [SectionLayout],
+
+    /// Rows of the sales comparison grid, read from each entry of `content.comparables`
+    pub comparable_rows: &'static [FieldLayout],
+}
+
+/// A titled block of fields read from one object of `Report.content`
+pub struct SectionLayout {
+    /// Heading printed above the section
+    pub heading: &'static str,
+
+    /// Top-level content key the fields are read from
+    pub key: &'static str,
+
+    /// Fields, in print order
+    pub fields: &'static [FieldLayout],
+}
+
+/// A labelled value read from a content object
+pub struct FieldLayout {
+    /// Label printed next to the value
+    pub label: &'static str,
+
+    /// Dotted path of the value within the section object
+    pub path: &'static str,
+
+    /// How the value is formatted
+    pub format: FieldFormat,
+}
+
+/// Formatting applied to a field value
+#[derive(Clone, Copy)]
+pub enum FieldFormat {
+    /// Printed as stored
+    Text,
+    /// Dollar amount
+    Currency,
+    /// Area in square feet
+    SquareFeet,
+    /// Long narrative, printed as wrapped paragraphs below the label
+    Narrative,
+}
+
+/// Content keys with dedicated rendering rather than a generic section
+pub const RESERVED_KEYS: &[&str] = &["comparables", "photos"];
+
+/// Section the comparable grid is printed after
+pub const SALES_COMPARISON_KEY: &str = "sales_comparison";
+
+/// Comparables per block of the sales comparison grid, as on the GSE forms
+pub const COMPARABLES_PER_GRID: usize = 3;
+
+const fn text(label: &'static str, path: &'static str) -> FieldLayout {
+    FieldLayout { label, path, format: FieldFormat::Text }
+}
+
+const fn currency(label: &'static str, path: &'static str) -> FieldLayout {
+    FieldLayout { label, path, format: FieldFormat::Currency }
+}
+
+const fn square_feet(label: &'static str, path: &'static str) -> FieldLayout {
+    FieldLayout { label, path, format: FieldFormat::SquareFeet }
+}
+
+const fn narrative(label: &'static str, path: &'static str) -> FieldLayout {
+    FieldLayout { label, path, format: FieldFormat::Narrative }
+}
+
+const SUBJECT: SectionLayout = SectionLayout {
+    heading: "Subject",
+    key: "subject",
+    fields: &[
+        text("Borrower", "borrower"),
+        text("Owner of Public Record", "owner_of_record"),
+        text("Legal Description", "legal_description"),
+        text("Assessor's Parcel #", "parcel_number"),
+        text("Tax Year", "tax_year"),
+        currency("R.E. Taxes", "real_estate_taxes"),
+        text("Neighborhood Name", "neighborhood_name"),
+        text("Occupant", "occupant"),
+        text("Property Rights Appraised", "property_rights"),
+        text("Assignment Type", "assignment_type"),
+        text("Lender/Client", "lender_client"),
+    ],
+};
+
+const CONTRACT: SectionLayout = SectionLayout {
+    heading: "Contract",
+    key: "contract",
+    fields: &[
+        currency("Contract Price", "contract_price"),
+        text("Date of Contract", "contract_date"),
+        text("Sale Type", "sale_type"),
+        currency("Financial Assistance", "financial_assistance"),
+        narrative("Contract Analysis", "analysis"),
+    ],
+};
+
+const NEIGHBORHOOD: SectionLayout = SectionLayout {
+    heading: "Neighborhood",
+    key: "neighborhood",
+    fields: &[
+        text("Location", "location"),
+        text("Built-Up", "built_up"),
+        text("Growth", "growth"),
+        text("Property Values", "property_values"),
+        text("Demand/Supply", "demand_supply"),
+        text("Marketing Time", "marketing_time"),
+        narrative("Neighborhood Boundaries", "boundaries"),
+        narrative("Neighborhood Description", "description"),
+        narrative("Market Conditions", "market_conditions"),
+    ],
+};
+
+const SITE: SectionLayout = SectionLayout {
+    heading: "Site",
+    key: "site",
+    fields: &[
+        text("Dimensions", "dimensions"),
+        text("Area", "area"),
+        text("Zoning Classification", "zoning_classification"),
+        text("Zoning Compliance", "zoning_compliance"),
+        text("Highest & Best Use as Improved", "highest_and_best_use"),
+        text("Utilities", "utilities"),
+        text("FEMA Flood Zone", "flood_zone"),
+        text("View", "view"),
+        narrative("Site Comments", "comments"),
+    ],
+};
+
+const IMPROVEMENTS: SectionLayout = SectionLayout {
+    heading: "Improvements",
+    key: "improvements",
+    fields: &[
+        text("Units", "units"),
+        text("Stories", "stories"),
+        text("Design (Style)", "design_style"),
+        text("Year Built", "year_built"),
+        text("Effective Age (Yrs)", "effective_age"),
+        text("Condition", "condition"),
+        text("Quality", "quality"),
+        square_feet("Gross Living Area", "gross_living_area"),
+        text("Rooms", "rooms"),
+        text("Bedrooms", "bedrooms"),
+        text("Bath(s)", "bathrooms"),
+        narrative("Condition of the Property", "description"),
+    ],
+};
+
+const EXTERIOR_IMPROVEMENTS: SectionLayout = SectionLayout {
+    heading: "Improvements (Exterior Inspection)",
+    key: "improvements",
+    fields: &[
+        text("Units", "units"),
+        text("Stories", "stories"),
+        text("Design (Style)", "design_style"),
+        text("Year Built", "year_built"),
+        text("Condition", "condition"),
+        text("Quality", "quality"),
+        square_feet("Gross Living Area", "gross_living_area"),
+        text("Source of Interior Data", "interior_data_source"),
+        narrative("Condition of the Property", "description"),
+    ],
+};
+
+const PROJECT: SectionLayout = SectionLayout {
+    heading: "Project Information",
+    key: "project",
+    fields: &[
+        text("Project Name", "name"),
+        text("Phase", "phase"),
+        text("Total Units", "units_total"),
+        text("Units Sold", "units_sold"),
+        text("Units Rented", "units_rented"),
+        currency("HOA Fee (per month)", "hoa_fee"),
+        narrative("Project Description", "description"),
+    ],
+};
+
+const MANUFACTURED_HOME: SectionLayout = SectionLayout {
+    heading: "Manufactured Home",
+    key: "manufactured_home",
+    fields: &[
+        text("Manufacturer", "manufacturer"),
+        text("Model", "model"),
+        text("Serial Number", "serial_number"),
+        text("HUD Data Plate", "hud_data_plate"),
+        text("HUD Label Numbers", "hud_label_numbers"),
+        text("Year Manufactured", "year_manufactured"),
+        text("Foundation", "foundation"),
+        narrative("Installation Comments", "installation_comments"),
+    ],
+};
+
+const INCOME: SectionLayout = SectionLayout {
+    heading: "Income Approach",
+    key: "income",
+    fields: &[
+        currency("Estimated Monthly Market Rent", "gross_monthly_rent"),
+        text("Gross Rent Multiplier", "gross_rent_multiplier"),
+        currency("Indicated Value by Income Approach", "indicated_value"),
+        narrative("Rent Schedule Summary", "rent_schedule_summary"),
+    ],
+};
+
+const COST: SectionLayout = SectionLayout {
+    heading: "Cost Approach",
+    key: "cost",
+    fields: &[
+        currency("Site Value", "site_value"),
+        currency("Replacement Cost New", "cost_new"),
+        currency("Depreciation", "depreciation"),
+        currency("Indicated Value by Cost Approach", "indicated_value"),
+        narrative("Cost Approach Comments", "comments"),
+    ],
+};
+
+const SALES_COMPARISON: SectionLayout = SectionLayout {
+    heading: "Sales Comparison Approach",
+    key: "sales_comparison",
+    fields: &[
+        text("Comparable Listings", "listings_count"),
+        text("Comparable Sales (12 months)", "sales_count"),
+        currency("Indicated Value by Sales Comparison", "indicated_value"),
+        narrative("Summary of Sales Comparison Approach", "summary"),
+    ],
+};
+
+const RECONCILIATION: SectionLayout = SectionLayout {
+    heading: "Reconciliation",
+    key: "reconciliation",
+    fields: &[
+        currency("Sales Comparison Approach", "sales_comparison_value"),
+        currency("Cost Approach", "cost_value"),
+        currency("Income Approach", "income_value"),
+        text("Appraisal Made", "condition"),
+        text("Effective Date of Appraisal", "effective_date"),
+        narrative("Reconciliation Comments", "comments"),
+        narrative("Spread Between Approaches", "spread_explanation"),
+        currency("Final Reconciled Value", "final_value"),
+    ],
+};
+
+const MARKET_ANALYSIS: SectionLayout = SectionLayout {
+    heading: "Market Analysis",
+    key: "market_analysis",
+    fields: &[
+        text("Market Area", "market_area"),
+        text("Vacancy Rate", "vacancy_rate"),
+        text("Capitalization Rate", "cap_rate"),
+        narrative("Market Overview", "overview"),
+        narrative("Highest and Best Use", "highest_and_best_use"),
+    ],
+};
+
+const BPO_MARKET: SectionLayout = SectionLayout {
+    heading: "Market Conditions",
+    key: "market_conditions",
+    fields: &[
+        text("Market Trend", "trend"),
+        text("Average Days on Market", "days_on_market"),
+        text("Supply", "supply"),
+        currency("Estimated Repairs", "estimated_repairs"),
+        narrative("Comments", "comments"),
+    ],
+};
+
+const DESKTOP_SCOPE: SectionLayout = SectionLayout {
+    heading: "Scope of Work",
+    key: "scope",
+    fields: &[
+        text("Data Sources", "data_sources"),
+        text("Floor Plan Source", "floor_plan_source"),
+        narrative("Scope of Work", "description"),
+    ],
+};
+
+/// Rows of the residential sales comparison grid
+const COMPARABLE_ROWS: &[FieldLayout] = &[
+    text("Address", "address"),
+    text("Proximity to Subject", "proximity"),
+    currency("Sale Price", "sale_price"),
+    text("Data Source(s)", "data_source"),
+    text("Date of Sale", "sale_date"),
+    text("Location", "location"),
+    text("View", "view"),
+    text("Condition", "condition"),
+    text("Quality", "quality"),
+    square_feet("Gross Living Area", "gross_living_area"),
+    text("Bedrooms", "bedrooms"),
+    text("Bathrooms", "bathrooms"),
+    currency("Net Adjustment", "net_adjustment"),
+    currency("Adjusted Sale Price", "adjusted_price"),
+];
+
+/// Rows of the broker price opinion comparable grid
+const BPO_COMPARABLE_ROWS: &[FieldLayout] = &[
+    text("Address", "address"),
+    text("Status", "status"),
+    currency("List Price", "list_price"),
+    currency("Sale Price", "sale_price"),
+    text("Date of Sale", "sale_date"),
+    text("Days on Market", "days_on_market"),
+    square_feet("Living Area", "gross_living_area"),
+    text("Bedrooms", "bedrooms"),
+    text("Bathrooms", "bathrooms"),
+    currency("Adjusted Price", "adjusted_price"),
+];
+
+static FORM_1004: FormLayout = FormLayout {
+    form_name: "Form 1004",
+    title: "Uniform Residential Appraisal Report",
+    sections: &[SUBJECT, CONTRACT, NEIGHBORHOOD, SITE, IMPROVEMENTS, SALES_COMPARISON, COST, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static FORM_1073: FormLayout = FormLayout {
+    form_name: "Form 1073",
+    title: "Individual Condominium Unit Appraisal Report",
+    sections: &[SUBJECT, CONTRACT, NEIGHBORHOOD, PROJECT, IMPROVEMENTS, SALES_COMPARISON, INCOME, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static FORM_1025: FormLayout = FormLayout {
+    form_name: "Form 1025",
+    title: "Small Residential Income Property Appraisal Report",
+    sections: &[SUBJECT, CONTRACT, NEIGHBORHOOD, SITE, IMPROVEMENTS, INCOME, SALES_COMPARISON, COST, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static FORM_1004C: FormLayout = FormLayout {
+    form_name: "Form 1004C",
+    title: "Manufactured Home Appraisal Report",
+    sections: &[SUBJECT, CONTRACT, NEIGHBORHOOD, SITE, MANUFACTURED_HOME, IMPROVEMENTS, SALES_COMPARISON, COST, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static FORM_2055: FormLayout = FormLayout {
+    form_name: "Form 2055",
+    title: "Exterior-Only Inspection Residential Appraisal Report",
+    sections: &[SUBJECT, CONTRACT, NEIGHBORHOOD, SITE, EXTERIOR_IMPROVEMENTS, SALES_COMPARISON, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static COMMERCIAL: FormLayout = FormLayout {
+    form_name: "Commercial",
+    title: "Commercial Property Appraisal Report",
+    sections: &[SUBJECT, SITE, IMPROVEMENTS, MARKET_ANALYSIS, SALES_COMPARISON, INCOME, COST, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static DESKTOP: FormLayout = FormLayout {
+    form_name: "Desktop",
+    title: "Desktop Appraisal Report",
+    sections: &[SUBJECT, DESKTOP_SCOPE, NEIGHBORHOOD, SITE, IMPROVEMENTS, SALES_COMPARISON, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+static BPO: FormLayout = FormLayout {
+    form_name: "BPO",
+    title: "Broker Price Opinion",
+    sections: &[SUBJECT, BPO_MARKET, EXTERIOR_IMPROVEMENTS, SALES_COMPARISON, RECONCILIATION],
+    comparable_rows: BPO_COMPARABLE_ROWS,
+};
+
+static OTHER: FormLayout = FormLayout {
+    form_name: "Report",
+    title: "Appraisal Report",
+    sections: &[SUBJECT, NEIGHBORHOOD, SITE, IMPROVEMENTS, SALES_COMPARISON, RECONCILIATION],
+    comparable_rows: COMPARABLE_ROWS,
+};
+
+/// Layout used to print a report of the given type
+pub fn layout_for(report_type: &ReportType) -> &'static FormLayout {
+    match report_type {
+        ReportType::Form1004 => &FORM_1004,
+        ReportType::Form1073 => &FORM_1073,
+        ReportType::Form1025 => &FORM_1025,
+        ReportType::Form1004C => &FORM_1004C,
+        ReportType::Form2055 => &FORM_2055,
+        ReportType::CommercialForm => &COMMERCIAL,
+        ReportType::DesktopAppraisal => &DESKTOP,
+        ReportType::BPO => &BPO,
+        ReportType::Other => &OTHER,
+    }
+}
+
+/// Look up a dotted path within a content object
+pub fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |current, key| current.get(key))
+}
+
+/// Format a field value for print; missing and empty values yield `None`
+pub fn format_field(value: Option<&Value>, format: FieldFormat) -> Option<String> {
+    let value = value?;
+
+    let formatted = match (format, value) {
+        (FieldFormat::Currency, Value::Number(n)) => n.as_f64().map(format_currency)?,
+        (FieldFormat::SquareFeet, Value::Number(n)) => n.as_f64().map(format_square_feet)?,
+        _ => display_value(value),
+    };
+
+    if formatted.trim().is_empty() {
+        None
+    } else {
+        Some(formatted)
+    }
+}
+
+/// Plain text form of a JSON value
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(true) => "Yes".to_string(),
+        Value::Bool(false) => "No".to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .map(display_value)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(_) => value.to_string(),
+    }
+}
+
+/// Human-readable heading for a content key without a layout, e.g. `market_trends` → "Market Trends"
+pub fn humanize_key(key: &str) -> String {
+    key.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
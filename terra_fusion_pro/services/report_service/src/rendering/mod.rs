@@ -0,0 +1,192 @@
+pub mod html;
+pub mod layout;
+pub mod pdf;
+
+use chrono::NaiveDate;
+use shared::db::encode_enum;
+use shared::models::property::Property;
+use shared::models::report::Report;
+use shared::utils::format::{format_address_single_line, format_square_feet};
+
+pub use html::render_html;
+pub use pdf::render_pdf;
+
+/// Everything printed in a report besides its layout
+pub struct ReportDocument<'a> {
+    /// Report being printed
+    pub report: &'a Report,
+
+    /// Subject property
+    pub property: &'a Property,
+
+    /// Exhibit photos, in print order
+    pub photos: Vec<Photo>,
+
+    /// Signature blocks: the appraiser's, then the supervisory appraiser's (if any)
+    pub signatures: Vec<SignatureBlock>,
+
+    /// Exhibits printed after the signature blocks, in the report's exhibit order
+    pub exhibits: Vec<Exhibit>,
+}
+
+/// A photo exhibit
+pub struct Photo {
+    /// Short label, e.g. "Subject Front"
+    pub label: String,
+
+    /// Optional caption printed below the photo
+    pub caption: Option<String>,
+
+    /// Decoded image header plus the original JPEG bytes
+    pub image: JpegImage,
+}
+
+/// A photo, sketch, map or uploaded document from the report's exhibits
+pub struct Exhibit {
+    /// Title printed above the exhibit, e.g. "Subject Front" or "Comparable 2"
+    pub title: String,
+
+    /// Optional caption printed below the exhibit
+    pub caption: Option<String>,
+
+    /// The exhibit itself
+    pub content: ExhibitContent,
+}
+
+/// What an exhibit prints
+pub enum ExhibitContent {
+    /// An image printed on a page of its own
+    Image(JpegImage),
+
+    /// An uploaded PDF whose pages are merged into the report as they are
+    Pdf {
+        /// Original file content
+        bytes: Vec<u8>,
+
+        /// Number of pages in the file
+        page_count: usize,
+    },
+}
+
+/// A baseline or progressive JPEG, embedded without re-encoding
+pub struct JpegImage {
+    /// Width in pixels
+    pub width: u32,
+
+    /// Height in pixels
+    pub height: u32,
+
+    /// Number of color components (1 = gray, 3 = RGB, 4 = CMYK)
+    pub components: u8,
+
+    /// Original file content
+    pub bytes: Vec<u8>,
+}
+
+impl JpegImage {
+    /// Read the frame header of a JPEG file, returning `None` if it is not a JPEG
+    pub fn parse(bytes: Vec<u8>) -> Option<Self> {
+        if bytes.len() < 4 || bytes[0] != 0xFF || bytes[1] != 0xD8 {
+            return None;
+        }
+
+        let mut pos = 2;
+        while pos + 4 <= bytes.len() {
+            if bytes[pos] != 0xFF {
+                return None;
+            }
+
+            let marker = bytes[pos + 1];
+            if marker == 0xFF {
+                pos += 1;
+                continue;
+            }
+
+            let length = u16::from_be_bytes([bytes[pos + 2], bytes[pos + 3]]) as usize;
+            let is_frame_header = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+
+            if is_frame_header {
+                let header = bytes.get(pos + 4..pos + 10)?;
+                let height = u16::from_be_bytes([header[1], header[2]]) as u32;
+                let width = u16::from_be_bytes([header[3], header[4]]) as u32;
+                let components = header[5];
+
+                if width == 0 || height == 0 || !matches!(components, 1 | 3 | 4) {
+                    return None;
+                }
+
+                return Some(Self { width, height, components, bytes });
+            }
+
+            pos += 2 + length;
+        }
+
+        None
+    }
+}
+
+/// Appraiser identification printed at the end of the report. The handwritten image is only a
+/// likeness; the report's cryptographic signatures are what prove who signed it.
+pub struct SignatureBlock {
+    /// Block heading, e.g. "Supervisory Appraiser"
+    pub title: String,
+
+    /// Appraiser's name
+    pub appraiser_name: String,
+
+    /// Handwritten signature printed on the signature line (if on file)
+    pub image: Option<JpegImage>,
+
+    /// State license or certification, e.g. "CA AR012345"
+    pub license: Option<String>,
+
+    /// Date the report was signed (its submission date)
+    pub signed_on: Option<NaiveDate>,
+}
+
+/// Single-line subject address
+pub fn property_address(property: &Property) -> String {
+    let address = &property.address;
+    format_address_single_line(
+        &address.street1,
+        address.street2.as_deref(),
+        &address.city,
+        &address.state,
+        &address.postal_code,
+    )
+}
+
+/// Labelled subject characteristics from the property record, skipping those not on file
+pub fn property_facts(property: &Property) -> Vec<(&'static str, String)> {
+    let characteristics = &property.characteristics;
+
+    let lot_size = characteristics.lot_size.map(|size| match characteristics.lot_size_in_sqft {
+        Some(true) => format_square_feet(size),
+        _ => format!("{} acres", size),
+    });
+    let fields = [
+        ("Address", Some(property_address(property))),
+        (
+            "Property Type",
+            Some(layout::humanize_key(&encode_enum(&characteristics.property_type))),
+        ),
+        ("Year Built", characteristics.year_built.map(|v| v.to_string())),
+        ("Gross Living Area", characteristics.square_feet.map(format_square_feet)),
+        ("Bedrooms", characteristics.bedrooms.map(|v| v.to_string())),
+        ("Bathrooms", characteristics.bathrooms.map(|v| v.to_string())),
+        ("Stories", characteristics.stories.map(|v| v.to_string())),
+        ("Lot Size", lot_size),
+        ("Parking Spaces", characteristics.parking.map(|v| v.to_string())),
+        ("Basement", characteristics.has_basement.map(yes_no)),
+        ("Pool", characteristics.has_pool.map(yes_no)),
+    ];
+
+    fields
+        .into_iter()
+        .filter_map(|(label, value)| value.map(|value| (label, value)))
+        .collect()
+}
+
+fn yes_no(value: bool) -> String {
+    String::from(if value { "Yes" } else { "No" })
+}
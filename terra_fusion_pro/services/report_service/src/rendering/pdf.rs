@@ -0,0 +1,1123 @@
+use chrono::{DateTime, Utc};
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Dictionary, Document, Object, ObjectId, Stream, StringFormat};
+use serde_json::Value;
+use shared::db::encode_enum;
+use shared::error::{AppError, AppResult};
+use shared::models::property::Property;
+use shared::utils::format::format_currency;
+
+use super::layout::{
+    self, FieldFormat, FieldLayout, FormLayout, SectionLayout, COMPARABLES_PER_GRID, RESERVED_KEYS,
+    SALES_COMPARISON_KEY,
+};
+use super::{
+    property_address, property_facts, Exhibit, ExhibitContent, JpegImage, Photo, ReportDocument, SignatureBlock,
+};
+
+/// US Letter, in points
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+
+const MARGIN: f32 = 40.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const CONTENT_TOP: f32 = PAGE_HEIGHT - MARGIN - 30.0;
+const CONTENT_BOTTOM: f32 = MARGIN + 24.0;
+
+const BODY_SIZE: f32 = 9.0;
+const LEADING: f32 = 12.0;
+const LABEL_WIDTH: f32 = 170.0;
+const GRID_LABEL_WIDTH: f32 = 120.0;
+const PHOTO_HEIGHT: f32 = 200.0;
+const SIGNATURE_WIDTH: f32 = 220.0;
+const SIGNATURE_HEIGHT: f32 = 36.0;
+
+/// Page attributes a page may inherit from its page tree, which merged pages must carry themselves
+const INHERITED_PAGE_KEYS: [&str; 4] = ["Resources", "MediaBox", "CropBox", "Rotate"];
+
+/// A page of the rendered report
+enum Page<'a> {
+    /// A page laid out by the renderer, numbered in its footer
+    Drawn(Vec<Operation>),
+
+    /// The pages of an uploaded PDF, merged as they are
+    Uploaded(&'a [u8]),
+}
+
+/// Render a report to PDF.
+/// The output depends only on the document, so the same report always renders to the same bytes.
+pub fn render_pdf(document: &ReportDocument<'_>, layout: &FormLayout) -> AppResult<Vec<u8>> {
+    let report = document.report;
+    let comparables = report
+        .content
+        .get("comparables")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut canvas = Canvas::new();
+    write_title(&mut canvas, document, layout);
+    write_property(&mut canvas, document.property);
+
+    for section in layout.sections {
+        write_section(&mut canvas, section, &report.content);
+        if section.key == SALES_COMPARISON_KEY {
+            write_comparables(&mut canvas, layout.comparable_rows, comparables);
+        }
+    }
+
+    write_additional_sections(&mut canvas, layout, &report.content);
+    write_photos(&mut canvas, &document.photos);
+    for (index, signature) in document.signatures.iter().enumerate() {
+        write_signature(&mut canvas, index, signature);
+    }
+
+    let mut pages: Vec<Page<'_>> = canvas.finish().into_iter().map(Page::Drawn).collect();
+    for (index, exhibit) in document.exhibits.iter().enumerate() {
+        match &exhibit.content {
+            ExhibitContent::Image(image) => {
+                let mut canvas = Canvas::new();
+                write_exhibit(&mut canvas, index, exhibit, image);
+                pages.extend(canvas.finish().into_iter().map(Page::Drawn));
+            }
+            ExhibitContent::Pdf { bytes, .. } => pages.push(Page::Uploaded(bytes)),
+        }
+    }
+
+    assemble(document, layout, pages)
+}
+
+/// Number of pages in an uploaded PDF, failing if it cannot be merged into a report
+pub fn pdf_page_count(bytes: &[u8]) -> AppResult<usize> {
+    let upload = Document::load_mem(bytes)
+        .map_err(|e| AppError::Validation(format!("File is not a readable PDF: {}", e)))?;
+
+    if upload.is_encrypted() {
+        return Err(AppError::Validation("Encrypted PDFs cannot be merged into a report".to_string()));
+    }
+
+    match upload.get_pages().len() {
+        0 => Err(AppError::Validation("PDF has no pages".to_string())),
+        count => Ok(count),
+    }
+}
+
+/// Title block with the form, subject address and value conclusion
+fn write_title(canvas: &mut Canvas, document: &ReportDocument<'_>, layout: &FormLayout) {
+    let report = document.report;
+
+    canvas.text(Font::Bold, 16.0, MARGIN, canvas.y - 16.0, layout.title);
+    canvas.y -= 24.0;
+    canvas.text(Font::Regular, 11.0, MARGIN, canvas.y - 11.0, &report.title);
+    canvas.y -= 16.0;
+    canvas.text(Font::Regular, 11.0, MARGIN, canvas.y - 11.0, &property_address(document.property));
+    canvas.y -= 24.0;
+
+    let value = report
+        .valuation_amount
+        .map(format_currency)
+        .unwrap_or_else(|| "Not yet concluded".to_string());
+    let effective_date = layout::format_field(
+        layout::lookup(&report.content, "reconciliation.effective_date"),
+        FieldFormat::Text,
+    );
+
+    canvas.field("Opinion of Market Value", &value);
+    if let Some(date) = effective_date {
+        canvas.field("Effective Date", &date);
+    }
+    canvas.field("Report Status", &layout::humanize_key(&encode_enum(&report.status)));
+    canvas.y -= 6.0;
+}
+
+/// Subject property characteristics from the property record
+fn write_property(canvas: &mut Canvas, property: &Property) {
+    canvas.heading("Subject Property");
+    for (label, value) in property_facts(property) {
+        canvas.field(label, &value);
+    }
+}
+
+/// A content section printed through its layout
+fn write_section(canvas: &mut Canvas, section: &SectionLayout, content: &Value) {
+    canvas.heading(section.heading);
+
+    let values = match content.get(section.key) {
+        Some(values) => values,
+        None => {
+            canvas.note("Not provided");
+            return;
+        }
+    };
+
+    let mut printed = false;
+    for field in section.fields {
+        printed |= write_field(canvas, field, values);
+    }
+
+    if !printed {
+        canvas.note("Not provided");
+    }
+}
+
+/// Print one field, returning whether it had a value
+fn write_field(canvas: &mut Canvas, field: &FieldLayout, values: &Value) -> bool {
+    let value = match layout::format_field(layout::lookup(values, field.path), field.format) {
+        Some(value) => value,
+        None => return false,
+    };
+
+    match field.format {
+        FieldFormat::Narrative => canvas.narrative(field.label, &value),
+        _ => canvas.field(field.label, &value),
+    }
+    true
+}
+
+/// Sales comparison grid, three comparables per block as on the GSE forms
+fn write_comparables(canvas: &mut Canvas, rows: &[FieldLayout], comparables: &[Value]) {
+    let column_width = (CONTENT_WIDTH - GRID_LABEL_WIDTH) / COMPARABLES_PER_GRID as f32;
+
+    for (block, group) in comparables.chunks(COMPARABLES_PER_GRID).enumerate() {
+        let first = block * COMPARABLES_PER_GRID + 1;
+        let headers: Vec<String> = (first..first + group.len())
+            .map(|number| format!("Comparable {}", number))
+            .collect();
+
+        canvas.y -= 4.0;
+        canvas.grid_row("Feature", &headers, Font::Bold, column_width);
+
+        for row in rows {
+            let cells: Vec<String> = group
+                .iter()
+                .map(|comparable| {
+                    layout::format_field(layout::lookup(comparable, row.path), row.format).unwrap_or_default()
+                })
+                .collect();
+            canvas.grid_row(row.label, &cells, Font::Regular, column_width);
+        }
+    }
+}
+
+/// Content sections that the layout does not cover, printed generically so nothing entered is lost
+fn write_additional_sections(canvas: &mut Canvas, layout: &FormLayout, content: &Value) {
+    let content = match content.as_object() {
+        Some(content) => content,
+        None => return,
+    };
+
+    let mut keys: Vec<&String> = content
+        .keys()
+        .filter(|key| !RESERVED_KEYS.contains(&key.as_str()))
+        .filter(|key| !layout.sections.iter().any(|section| section.key == key.as_str()))
+        .collect();
+    keys.sort();
+
+    for key in keys {
+        let heading = layout::humanize_key(key);
+
+        match &content[key] {
+            Value::Object(fields) => {
+                canvas.heading(&heading);
+
+                let mut names: Vec<&String> = fields.keys().collect();
+                names.sort();
+                for name in names {
+                    if let Some(value) = layout::format_field(Some(&fields[name]), FieldFormat::Text) {
+                        canvas.field(&layout::humanize_key(name), &value);
+                    }
+                }
+            }
+            Value::String(text) if !text.trim().is_empty() => {
+                canvas.heading(&heading);
+                canvas.paragraph(text);
+            }
+            value => {
+                if let Some(value) = layout::format_field(Some(value), FieldFormat::Text) {
+                    canvas.heading(&heading);
+                    canvas.field(&heading, &value);
+                }
+            }
+        }
+    }
+}
+
+/// Photo addendum, each photo scaled to the page width
+fn write_photos(canvas: &mut Canvas, photos: &[Photo]) {
+    if photos.is_empty() {
+        return;
+    }
+
+    canvas.start_page();
+    canvas.heading("Photo Addendum");
+
+    for (index, photo) in photos.iter().enumerate() {
+        let image = &photo.image;
+        let scale = (CONTENT_WIDTH / image.width as f32).min(PHOTO_HEIGHT / image.height as f32);
+        let width = image.width as f32 * scale;
+        let height = image.height as f32 * scale;
+
+        let caption = photo
+            .caption
+            .as_deref()
+            .map(|caption| wrap(caption, Font::Regular, BODY_SIZE, CONTENT_WIDTH))
+            .unwrap_or_default();
+
+        canvas.ensure_space(LEADING + height + 6.0 + caption.len() as f32 * LEADING + 12.0);
+        canvas.text(Font::Bold, BODY_SIZE, MARGIN, canvas.y - BODY_SIZE, &photo.label);
+        canvas.y -= LEADING;
+
+        canvas.image(&image_name(index), MARGIN + (CONTENT_WIDTH - width) / 2.0, canvas.y - height, width, height);
+        canvas.y -= height + 6.0;
+
+        for line in caption {
+            canvas.text(Font::Regular, BODY_SIZE, MARGIN, canvas.y - BODY_SIZE, &line);
+            canvas.y -= LEADING;
+        }
+        canvas.y -= 12.0;
+    }
+}
+
+/// Image exhibit on a page of its own, scaled to fit below its title and above its caption
+fn write_exhibit(canvas: &mut Canvas, index: usize, exhibit: &Exhibit, image: &JpegImage) {
+    canvas.heading(&exhibit.title);
+
+    let caption = exhibit
+        .caption
+        .as_deref()
+        .map(|caption| wrap(caption, Font::Regular, BODY_SIZE, CONTENT_WIDTH))
+        .unwrap_or_default();
+
+    let available = canvas.y - CONTENT_BOTTOM - 6.0 - caption.len() as f32 * LEADING;
+    let scale = (CONTENT_WIDTH / image.width as f32).min(available.max(LEADING) / image.height as f32);
+    let width = image.width as f32 * scale;
+    let height = image.height as f32 * scale;
+
+    canvas.image(
+        &exhibit_image_name(index),
+        MARGIN + (CONTENT_WIDTH - width) / 2.0,
+        canvas.y - height,
+        width,
+        height,
+    );
+    canvas.y -= height + 6.0;
+
+    for line in caption {
+        canvas.text(Font::Regular, BODY_SIZE, MARGIN, canvas.y - BODY_SIZE, &line);
+        canvas.y -= LEADING;
+    }
+}
+
+/// Signature block of an appraiser, with their signature image on the line if they have one
+fn write_signature(canvas: &mut Canvas, index: usize, signature: &SignatureBlock) {
+    canvas.heading(&signature.title);
+    canvas.ensure_space(LEADING * 6.0 + SIGNATURE_HEIGHT);
+
+    let line_x = MARGIN + LABEL_WIDTH;
+    match &signature.image {
+        Some(image) => {
+            let scale = (SIGNATURE_WIDTH / image.width as f32).min(SIGNATURE_HEIGHT / image.height as f32);
+            canvas.y -= SIGNATURE_HEIGHT + 4.0;
+            canvas.image(
+                &signature_image_name(index),
+                line_x,
+                canvas.y + 2.0,
+                image.width as f32 * scale,
+                image.height as f32 * scale,
+            );
+        }
+        None => canvas.y -= 24.0,
+    }
+    canvas.line(line_x, canvas.y, line_x + SIGNATURE_WIDTH, canvas.y);
+    canvas.text(Font::Bold, BODY_SIZE, MARGIN, canvas.y + 2.0, "Signature");
+    canvas.y -= 8.0;
+
+    canvas.field("Name", &signature.appraiser_name);
+    canvas.field(
+        "State License/Certification #",
+        signature.license.as_deref().unwrap_or("Not on file"),
+    );
+    canvas.field(
+        "Date of Signature and Report",
+        &signature
+            .signed_on
+            .map(|date| date.format("%m/%d/%Y").to_string())
+            .unwrap_or_else(|| "Unsigned".to_string()),
+    );
+}
+
+/// Build the PDF document from the laid out pages, merging in the pages of uploaded PDFs
+fn assemble(document: &ReportDocument<'_>, layout: &FormLayout, pages: Vec<Page<'_>>) -> AppResult<Vec<u8>> {
+    let mut pdf = Document::with_version("1.5");
+    let pages_id = pdf.new_object_id();
+
+    let mut fonts = Dictionary::new();
+    for font in [Font::Regular, Font::Bold] {
+        let font_id = pdf.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => font.base_font(),
+            "Encoding" => "WinAnsiEncoding",
+        });
+        fonts.set(font.resource(), font_id);
+    }
+
+    let mut images = Dictionary::new();
+    for (index, photo) in document.photos.iter().enumerate() {
+        let image_id = pdf.add_object(image_stream(&photo.image));
+        images.set(image_name(index), image_id);
+    }
+    for (index, signature) in document.signatures.iter().enumerate() {
+        if let Some(image) = &signature.image {
+            let image_id = pdf.add_object(image_stream(image));
+            images.set(signature_image_name(index), image_id);
+        }
+    }
+    for (index, exhibit) in document.exhibits.iter().enumerate() {
+        if let ExhibitContent::Image(image) = &exhibit.content {
+            let image_id = pdf.add_object(image_stream(image));
+            images.set(exhibit_image_name(index), image_id);
+        }
+    }
+
+    // Uploaded pages are imported first so drawn pages can be numbered out of the full total
+    let mut imported = Vec::new();
+    for page in &pages {
+        if let Page::Uploaded(bytes) = page {
+            imported.push(import_pages(&mut pdf, bytes, pages_id)?);
+        }
+    }
+
+    let drawn = pages.iter().filter(|page| matches!(page, Page::Drawn(_))).count();
+    let total = drawn + imported.iter().map(Vec::len).sum::<usize>();
+    let footer = property_address(document.property);
+    let mut imported = imported.into_iter();
+    let mut kids: Vec<Object> = Vec::with_capacity(total);
+
+    for page in pages {
+        let mut operations = match page {
+            Page::Drawn(operations) => operations,
+            Page::Uploaded(_) => {
+                kids.extend(imported.next().unwrap_or_default().into_iter().map(Object::from));
+                continue;
+            }
+        };
+        operations.extend(page_chrome(layout, &footer, kids.len() + 1, total));
+
+        let content = Content { operations }
+            .encode()
+            .map_err(|e| AppError::General(format!("Failed to encode PDF page: {}", e)))?;
+        let content_id = pdf.add_object(Stream::new(Dictionary::new(), content));
+        let page_id = pdf.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+        });
+        kids.push(page_id.into());
+    }
+
+    pdf.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => total as i64,
+            "MediaBox" => vec![num(0.0), num(0.0), num(PAGE_WIDTH), num(PAGE_HEIGHT)],
+            "Resources" => dictionary! {
+                "Font" => fonts,
+                "XObject" => images,
+            },
+        }),
+    );
+
+    let catalog_id = pdf.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+    });
+    let info_id = pdf.add_object(dictionary! {
+        "Title" => Object::string_literal(encode_text(&document.report.title)),
+        "Subject" => Object::string_literal(layout.title),
+        "Producer" => Object::string_literal("TerraFusionPro"),
+        "CreationDate" => Object::string_literal(pdf_date(document.report.updated_at)),
+    });
+    pdf.trailer.set("Root", catalog_id);
+    pdf.trailer.set("Info", info_id);
+    pdf.compress();
+
+    let mut bytes = Vec::new();
+    pdf.save_to(&mut bytes)
+        .map_err(|e| AppError::General(format!("Failed to write PDF: {}", e)))?;
+
+    Ok(bytes)
+}
+
+/// Copy the pages of an uploaded PDF into the document under the given page tree node,
+/// returning their IDs in page order. Each page takes along the attributes it inherited from
+/// its own page tree; the upload's page tree and catalog are left behind.
+fn import_pages(pdf: &mut Document, bytes: &[u8], parent: ObjectId) -> AppResult<Vec<ObjectId>> {
+    let mut upload = Document::load_mem(bytes)
+        .map_err(|e| AppError::Validation(format!("Uploaded PDF could not be read: {}", e)))?;
+    if upload.is_encrypted() {
+        return Err(AppError::Validation("Encrypted PDFs cannot be merged into a report".to_string()));
+    }
+
+    upload.renumber_objects_with(pdf.max_id + 1);
+    let page_ids: Vec<ObjectId> = upload.get_pages().into_values().collect();
+
+    for &page_id in &page_ids {
+        let inherited = inherited_attributes(&upload, page_id);
+        let page = upload
+            .get_dictionary_mut(page_id)
+            .map_err(|e| AppError::Validation(format!("Uploaded PDF has a malformed page: {}", e)))?;
+
+        for (key, value) in inherited {
+            page.set(key, value);
+        }
+        if !page.has(b"Resources") {
+            // Without resources of its own the page would inherit the report's fonts and images
+            page.set("Resources", Dictionary::new());
+        }
+        page.set("Parent", parent);
+    }
+
+    upload
+        .objects
+        .retain(|_, object| !matches!(object.type_name(), Ok("Pages" | "Catalog")));
+    pdf.max_id = pdf.max_id.max(upload.max_id);
+    pdf.objects.extend(upload.objects);
+
+    Ok(page_ids)
+}
+
+/// Inheritable attributes a page takes from its ancestors in the page tree
+fn inherited_attributes(upload: &Document, page_id: ObjectId) -> Vec<(&'static str, Object)> {
+    let mut found = Vec::new();
+    let page = match upload.get_dictionary(page_id) {
+        Ok(page) => page,
+        Err(_) => return found,
+    };
+
+    let mut missing: Vec<&'static str> = INHERITED_PAGE_KEYS
+        .into_iter()
+        .filter(|key| !page.has(key.as_bytes()))
+        .collect();
+    let mut parent = page.get(b"Parent").and_then(Object::as_reference).ok();
+
+    // The depth is bounded in case a malformed tree loops back on itself
+    for _ in 0..32 {
+        let node = match parent.map(|id| upload.get_dictionary(id)) {
+            Some(Ok(node)) if !missing.is_empty() => node,
+            _ => break,
+        };
+
+        missing.retain(|&key| match node.get(key.as_bytes()) {
+            Ok(value) => {
+                found.push((key, value.clone()));
+                false
+            }
+            Err(_) => true,
+        });
+        parent = node.get(b"Parent").and_then(Object::as_reference).ok();
+    }
+
+    found
+}
+
+/// Header and footer drawn on every page the renderer lays out
+fn page_chrome(layout: &FormLayout, footer: &str, page: usize, total: usize) -> Vec<Operation> {
+    let mut canvas = Canvas::new();
+    let header_y = PAGE_HEIGHT - MARGIN - 10.0;
+    let page_label = format!("Page {} of {}", page, total);
+
+    canvas.text(Font::Bold, BODY_SIZE, MARGIN, header_y, layout.form_name);
+    let title_x = MARGIN + text_width(layout.form_name, Font::Bold, BODY_SIZE) + 8.0;
+    canvas.text(Font::Regular, BODY_SIZE, title_x, header_y, layout.title);
+    canvas.line(MARGIN, header_y - 6.0, PAGE_WIDTH - MARGIN, header_y - 6.0);
+
+    let footer_y = MARGIN;
+    let page_width = text_width(&page_label, Font::Regular, 8.0);
+    canvas.line(MARGIN, footer_y + 12.0, PAGE_WIDTH - MARGIN, footer_y + 12.0);
+    canvas.text(
+        Font::Regular,
+        8.0,
+        MARGIN,
+        footer_y,
+        &fit(footer, Font::Regular, 8.0, CONTENT_WIDTH - page_width - 16.0),
+    );
+    canvas.text(Font::Regular, 8.0, PAGE_WIDTH - MARGIN - page_width, footer_y, &page_label);
+
+    canvas.ops
+}
+
+/// Image XObject embedding a JPEG as-is
+fn image_stream(image: &JpegImage) -> Stream {
+    let color_space = match image.components {
+        1 => "DeviceGray",
+        4 => "DeviceCMYK",
+        _ => "DeviceRGB",
+    };
+
+    let mut dict = dictionary! {
+        "Type" => "XObject",
+        "Subtype" => "Image",
+        "Width" => image.width as i64,
+        "Height" => image.height as i64,
+        "ColorSpace" => color_space,
+        "BitsPerComponent" => 8,
+        "Filter" => "DCTDecode",
+    };
+    if image.components == 4 {
+        // CMYK JPEGs written by Adobe software store inverted samples
+        dict.set("Decode", [1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0].map(num).to_vec());
+    }
+
+    Stream::new(dict, image.bytes.clone())
+}
+
+/// Resource name of the nth photo
+fn image_name(index: usize) -> String {
+    format!("Im{}", index + 1)
+}
+
+/// Resource name of the image of the nth exhibit
+fn exhibit_image_name(index: usize) -> String {
+    format!("Ex{}", index + 1)
+}
+
+/// Resource name of the signature image in the nth signature block
+fn signature_image_name(index: usize) -> String {
+    format!("Sig{}", index + 1)
+}
+
+/// PDF date string, e.g. `D:20230601120000Z`
+fn pdf_date(at: DateTime<Utc>) -> String {
+    at.format("D:%Y%m%d%H%M%SZ").to_string()
+}
+
+/// A real number operand, rounded to keep content streams compact
+fn num(value: f32) -> Object {
+    Object::Real((value * 100.0).round() / 100.0)
+}
+
+/// The two standard fonts used, so nothing needs embedding
+#[derive(Clone, Copy)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    fn base_font(self) -> &'static str {
+        match self {
+            Font::Regular => "Helvetica",
+            Font::Bold => "Helvetica-Bold",
+        }
+    }
+
+    /// Advance widths of ASCII 32..=126 in thousandths of the font size
+    fn widths(self) -> &'static [u16; 95] {
+        match self {
+            Font::Regular => &HELVETICA_WIDTHS,
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+        }
+    }
+}
+
+#[rustfmt::skip]
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+/// Width of a string in points
+fn text_width(text: &str, font: Font, size: f32) -> f32 {
+    let widths = font.widths();
+    let units: u32 = text
+        .chars()
+        .map(|c| match c as u32 {
+            code @ 32..=126 => widths[(code - 32) as usize] as u32,
+            _ => 556,
+        })
+        .sum();
+
+    units as f32 * size / 1000.0
+}
+
+/// Break text into lines no wider than `width`, keeping explicit line breaks
+fn wrap(text: &str, font: Font, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+
+            if text_width(&candidate, font, size) <= width {
+                line = candidate;
+                continue;
+            }
+
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            // Words wider than a whole line are split wherever they overflow
+            for c in word.chars() {
+                line.push(c);
+                if line.chars().count() > 1 && text_width(&line, font, size) > width {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, c.to_string()));
+                }
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Shorten text with an ellipsis until it fits `width`
+fn fit(text: &str, font: Font, size: f32, width: f32) -> String {
+    if text_width(text, font, size) <= width {
+        return text.to_string();
+    }
+
+    let mut fitted: String = text.to_string();
+    while !fitted.is_empty() && text_width(&format!("{}...", fitted), font, size) > width {
+        fitted.pop();
+    }
+    format!("{}...", fitted.trim_end())
+}
+
+/// Encode text in WinAnsiEncoding, the encoding of the standard fonts.
+/// Characters outside it are replaced with `?`.
+fn encode_text(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            '\u{20}'..='\u{7E}' | '\u{A0}'..='\u{FF}' => c as u8,
+            '\u{20AC}' => 0x80,
+            '\u{2026}' => 0x85,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201C}' => 0x93,
+            '\u{201D}' => 0x94,
+            '\t' => b' ',
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Page content under construction; `y` is the top of the free space on the current page
+struct Canvas {
+    pages: Vec<Vec<Operation>>,
+    ops: Vec<Operation>,
+    y: f32,
+}
+
+impl Canvas {
+    fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            ops: Vec::new(),
+            y: CONTENT_TOP,
+        }
+    }
+
+    /// Finish the current page and start a new one
+    fn new_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.ops));
+        self.y = CONTENT_TOP;
+    }
+
+    /// Start a new page unless the current one is still empty
+    fn start_page(&mut self) {
+        if !self.ops.is_empty() {
+            self.new_page();
+        }
+    }
+
+    /// Move to a new page if less than `height` is left on this one
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < CONTENT_BOTTOM && !self.ops.is_empty() {
+            self.new_page();
+        }
+    }
+
+    fn finish(mut self) -> Vec<Vec<Operation>> {
+        if !self.ops.is_empty() || self.pages.is_empty() {
+            self.new_page();
+        }
+        self.pages
+    }
+
+    /// Section heading on a shaded bar
+    fn heading(&mut self, text: &str) {
+        self.ensure_space(20.0 + 2.0 * LEADING);
+        self.y -= 6.0;
+        self.fill_rect(MARGIN, self.y - 14.0, CONTENT_WIDTH, 14.0, 0.85);
+        self.text(Font::Bold, 10.0, MARGIN + 4.0, self.y - 10.5, text);
+        self.y -= 20.0;
+    }
+
+    /// Label and value side by side, the value wrapping within its column
+    fn field(&mut self, label: &str, value: &str) {
+        let labels = wrap(label, Font::Bold, BODY_SIZE, LABEL_WIDTH - 8.0);
+        let values = wrap(value, Font::Regular, BODY_SIZE, CONTENT_WIDTH - LABEL_WIDTH);
+
+        for row in 0..labels.len().max(values.len()) {
+            self.ensure_space(LEADING);
+            let baseline = self.y - BODY_SIZE;
+
+            if let Some(line) = labels.get(row) {
+                self.text(Font::Bold, BODY_SIZE, MARGIN, baseline, line);
+            }
+            if let Some(line) = values.get(row) {
+                self.text(Font::Regular, BODY_SIZE, MARGIN + LABEL_WIDTH, baseline, line);
+            }
+            self.y -= LEADING;
+        }
+    }
+
+    /// Label on its own line followed by full-width paragraphs
+    fn narrative(&mut self, label: &str, text: &str) {
+        self.ensure_space(2.0 * LEADING);
+        self.text(Font::Bold, BODY_SIZE, MARGIN, self.y - BODY_SIZE, label);
+        self.y -= LEADING;
+        self.paragraph(text);
+    }
+
+    /// Full-width wrapped text
+    fn paragraph(&mut self, text: &str) {
+        for line in wrap(text, Font::Regular, BODY_SIZE, CONTENT_WIDTH) {
+            self.ensure_space(LEADING);
+            self.text(Font::Regular, BODY_SIZE, MARGIN, self.y - BODY_SIZE, &line);
+            self.y -= LEADING;
+        }
+        self.y -= 4.0;
+    }
+
+    /// Muted single-line remark
+    fn note(&mut self, text: &str) {
+        self.ensure_space(LEADING);
+        self.ops.push(Operation::new("g", vec![num(0.4)]));
+        self.text(Font::Regular, BODY_SIZE, MARGIN, self.y - BODY_SIZE, text);
+        self.ops.push(Operation::new("g", vec![num(0.0)]));
+        self.y -= LEADING;
+    }
+
+    /// One row of the comparable grid, ruled underneath
+    fn grid_row(&mut self, label: &str, cells: &[String], font: Font, column_width: f32) {
+        let columns: Vec<Vec<String>> = cells
+            .iter()
+            .map(|cell| wrap(cell, font, BODY_SIZE, column_width - 6.0))
+            .collect();
+        let lines = columns.iter().map(Vec::len).max().unwrap_or(1).max(1);
+
+        self.ensure_space(lines as f32 * LEADING + 2.0);
+        self.text(
+            Font::Bold,
+            BODY_SIZE,
+            MARGIN,
+            self.y - BODY_SIZE,
+            &fit(label, Font::Bold, BODY_SIZE, GRID_LABEL_WIDTH - 6.0),
+        );
+
+        for (column, cell) in columns.iter().enumerate() {
+            let x = MARGIN + GRID_LABEL_WIDTH + column as f32 * column_width;
+            for (line_index, line) in cell.iter().enumerate() {
+                let baseline = self.y - BODY_SIZE - line_index as f32 * LEADING;
+                self.text(font, BODY_SIZE, x, baseline, line);
+            }
+        }
+
+        self.y -= lines as f32 * LEADING;
+        self.line(MARGIN, self.y + 1.0, PAGE_WIDTH - MARGIN, self.y + 1.0);
+        self.y -= 2.0;
+    }
+
+    fn text(&mut self, font: Font, size: f32, x: f32, y: f32, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        self.ops.extend([
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec![font.resource().into(), num(size)]),
+            Operation::new("Td", vec![num(x), num(y)]),
+            Operation::new("Tj", vec![Object::String(encode_text(text), StringFormat::Hexadecimal)]),
+            Operation::new("ET", vec![]),
+        ]);
+    }
+
+    fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32) {
+        self.ops.extend([
+            Operation::new("q", vec![]),
+            Operation::new("w", vec![num(0.5)]),
+            Operation::new("m", vec![num(x1), num(y1)]),
+            Operation::new("l", vec![num(x2), num(y2)]),
+            Operation::new("S", vec![]),
+            Operation::new("Q", vec![]),
+        ]);
+    }
+
+    fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        self.ops.extend([
+            Operation::new("q", vec![]),
+            Operation::new("g", vec![num(gray)]),
+            Operation::new("re", vec![num(x), num(y), num(width), num(height)]),
+            Operation::new("f", vec![]),
+            Operation::new("Q", vec![]),
+        ]);
+    }
+
+    fn image(&mut self, name: &str, x: f32, y: f32, width: f32, height: f32) {
+        self.ops.extend([
+            Operation::new("q", vec![]),
+            Operation::new("cm", vec![num(width), num(0.0), num(0.0), num(height), num(x), num(y)]),
+            Operation::new("Do", vec![name.into()]),
+            Operation::new("Q", vec![]),
+        ]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use serde_json::json;
+    use sha2::{Digest, Sha256};
+    use shared::models::property::{Address, PropertyCharacteristics, PropertyType};
+    use shared::models::report::{Report, ReportStatus, ReportType};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::rendering::layout::layout_for;
+
+    /// SHA-256 of the fixture report rendered to PDF. A change to the renderer that alters its
+    /// output must update this, after checking the new output by eye.
+    const GOLDEN_SHA256: &str = "63e051e86d1f7950fd6b0ccf8a13e6ff5173cf0a37bd35ee677e1a4bc40f7dd5";
+
+    /// Header-only 16x16 RGB JPEG; the renderer embeds JPEG bytes without decoding them
+    const JPEG: &[u8] = &[
+        0xFF, 0xD8, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x10, 0x00, 0x10, 0x03, 0x01, 0x22, 0x00, 0x02, 0x11,
+        0x01, 0x03, 0x11, 0x01, 0xFF, 0xD9,
+    ];
+
+    fn report() -> Report {
+        let at = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+
+        Report {
+            id: Uuid::nil(),
+            title: "123 Main St".to_string(),
+            property_id: Uuid::nil(),
+            appraiser_id: Uuid::nil(),
+            status: ReportStatus::InProgress,
+            report_type: ReportType::Form1004,
+            valuation_amount: Some(415000.0),
+            content: json!({
+                "subject": {"borrower": "Jane Q. Public", "parcel_number": "123-456-789"},
+                "improvements": {"gross_living_area": 1850, "condition": "C3", "quality": "Q4", "year_built": 1995},
+                "comparables": [
+                    {"address": "1 Elm St", "sale_price": 400000, "sale_date": "s03/23;c02/23"},
+                    {"address": "2 Oak Ave", "sale_price": 410000},
+                ],
+                "reconciliation": {"effective_date": "06/01/2023", "comments": "Weighted toward sales comparison"},
+            }),
+            pdf_url: None,
+            created_at: at,
+            updated_at: at,
+            submitted_at: None,
+            reviewed_at: None,
+            reviewer_id: None,
+            review_comments: None,
+            submission_comments: None,
+            version: 0,
+            amends_id: None,
+            supervisor_id: None,
+        }
+    }
+
+    fn property() -> Property {
+        let at = Utc.with_ymd_and_hms(2023, 5, 1, 9, 0, 0).unwrap();
+
+        Property {
+            id: Uuid::nil(),
+            address: Address {
+                street1: "123 Main St".to_string(),
+                street2: None,
+                city: "Springfield".to_string(),
+                state: "IL".to_string(),
+                postal_code: "62701".to_string(),
+                country: "US".to_string(),
+                latitude: None,
+                longitude: None,
+            },
+            characteristics: PropertyCharacteristics {
+                property_type: PropertyType::SingleFamily,
+                year_built: Some(1995),
+                square_feet: Some(1850.0),
+                bedrooms: Some(3),
+                bathrooms: Some(2.5),
+                lot_size: Some(0.25),
+                lot_size_in_sqft: Some(false),
+                parking: None,
+                stories: Some(2),
+                has_basement: Some(true),
+                has_pool: None,
+                features: None,
+            },
+            valuation: None,
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    /// A two-page A4 upload whose pages inherit their MediaBox and fonts from the page tree
+    fn upload() -> Vec<u8> {
+        let mut pdf = Document::with_version("1.4");
+        let root = pdf.new_object_id();
+        let parent = pdf.new_object_id();
+        let font = pdf.add_object(dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Courier" });
+
+        let mut kids = Vec::new();
+        for number in 1..=2 {
+            let operations = vec![
+                Operation::new("BT", vec![]),
+                Operation::new("Tf", vec!["F1".into(), 24.into()]),
+                Operation::new("Td", vec![100.into(), 700.into()]),
+                Operation::new("Tj", vec![Object::string_literal(format!("Uploaded page {}", number))]),
+                Operation::new("ET", vec![]),
+            ];
+            let contents = pdf.add_object(Stream::new(dictionary! {}, Content { operations }.encode().unwrap()));
+            kids.push(pdf.add_object(dictionary! { "Type" => "Page", "Parent" => parent, "Contents" => contents }).into());
+        }
+
+        pdf.objects.insert(parent, Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Parent" => root,
+            "Kids" => kids,
+            "Count" => 2,
+            "Resources" => dictionary! { "Font" => dictionary! { "F1" => font } },
+        }));
+        pdf.objects.insert(root, Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![parent.into()],
+            "Count" => 2,
+            "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+        }));
+        let catalog = pdf.add_object(dictionary! { "Type" => "Catalog", "Pages" => root });
+        pdf.trailer.set("Root", catalog);
+
+        let mut bytes = Vec::new();
+        pdf.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn jpeg() -> JpegImage {
+        JpegImage::parse(JPEG.to_vec()).unwrap()
+    }
+
+    fn render(report: &Report, property: &Property) -> Vec<u8> {
+        let document = ReportDocument {
+            report,
+            property,
+            photos: vec![Photo {
+                label: "Subject Front".to_string(),
+                caption: Some("Front view".to_string()),
+                image: jpeg(),
+            }],
+            signatures: vec![SignatureBlock {
+                title: "Appraiser".to_string(),
+                appraiser_name: "Sam Appraiser".to_string(),
+                image: Some(jpeg()),
+                license: Some("IL 553.0012345".to_string()),
+                signed_on: None,
+            }],
+            exhibits: vec![
+                Exhibit {
+                    title: "Subject Rear".to_string(),
+                    caption: Some("Rear view from the yard".to_string()),
+                    content: ExhibitContent::Image(jpeg()),
+                },
+                Exhibit {
+                    title: "Plat Map".to_string(),
+                    caption: None,
+                    content: ExhibitContent::Pdf { bytes: upload(), page_count: 2 },
+                },
+                Exhibit {
+                    title: "Comparable 1".to_string(),
+                    caption: None,
+                    content: ExhibitContent::Image(jpeg()),
+                },
+            ],
+        };
+
+        render_pdf(&document, layout_for(&report.report_type)).unwrap()
+    }
+
+    #[test]
+    fn renders_the_same_bytes_every_time() {
+        let (report, property) = (report(), property());
+        let first = render(&report, &property);
+        let second = render(&report, &property);
+
+        assert_eq!(first, second);
+        assert_eq!(hex::encode(Sha256::digest(&first)), GOLDEN_SHA256);
+    }
+
+    #[test]
+    fn merges_uploaded_pages_in_exhibit_order() {
+        let (report, property) = (report(), property());
+        let pdf = Document::load_mem(&render(&report, &property)).unwrap();
+        let pages: Vec<(u32, ObjectId)> = pdf.get_pages().into_iter().collect();
+        let text = |number: u32| pdf.extract_text(&[number]).unwrap_or_default();
+
+        // The last page mentioning each title, since the sales grid also names the comparables
+        let position = |needle: &str| {
+            pages
+                .iter()
+                .rposition(|(number, _)| text(*number).contains(needle))
+                .unwrap_or_else(|| panic!("no page contains {:?}", needle))
+        };
+        let rear = position("Subject Rear");
+        let first_upload = position("Uploaded page 1");
+        let second_upload = position("Uploaded page 2");
+        let comparable = position("Comparable 1");
+
+        assert_eq!((first_upload, second_upload, comparable), (rear + 1, rear + 2, rear + 3));
+        assert_eq!(comparable, pages.len() - 1);
+
+        // Merged pages keep the A4 size they inherited in the upload
+        let media_box = pdf.get_dictionary(pages[first_upload].1).unwrap().get(b"MediaBox").unwrap();
+        assert_eq!(media_box.as_array().unwrap()[2].as_i64().unwrap(), 595);
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use shared::db::{column, decode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::Appraisal;
+use uuid::Uuid;
+
+/// Read-only access to appraisal orders owned by the appraisal service
+#[derive(Clone)]
+pub struct AppraisalRepository {
+    db: Arc<Database>,
+}
+
+impl AppraisalRepository {
+    /// Create a new appraisal repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get the appraisal a report was written for, if the report is linked to one
+    pub async fn find_by_report(&self, report_id: Uuid) -> AppResult<Option<Appraisal>> {
+        let row = sqlx::query("SELECT * FROM appraisals WHERE report_id = $1 ORDER BY created_at DESC LIMIT 1")
+            .bind(report_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch appraisal: {}", e)))?;
+
+        let row = match row {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+
+        Ok(Some(Appraisal {
+            id: column(&row, "id")?,
+            reference_number: column(&row, "reference_number")?,
+            client_id: column(&row, "client_id")?,
+            property_id: column(&row, "property_id")?,
+            appraiser_id: column(&row, "appraiser_id")?,
+            report_id: column(&row, "report_id")?,
+            status: decode_enum(&column::<String>(&row, "status")?)?,
+            due_date: column(&row, "due_date")?,
+            purpose: decode_enum(&column::<String>(&row, "purpose")?)?,
+            appraisal_type: decode_enum(&column::<String>(&row, "appraisal_type")?)?,
+            instructions: column(&row, "instructions")?,
+            fee: column(&row, "fee")?,
+            created_at: column(&row, "created_at")?,
+            updated_at: column(&row, "updated_at")?,
+            completed_at: column(&row, "completed_at")?,
+        }))
+    }
+
+    /// Name of the client that placed an appraisal order
+    pub async fn client_name(&self, client_id: Uuid) -> AppResult<Option<String>> {
+        let row = sqlx::query("SELECT name FROM clients WHERE id = $1")
+            .bind(client_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch client: {}", e)))?;
+
+        match row {
+            Some(row) => Ok(Some(column(&row, "name")?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::exhibit::ReportExhibit;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+/// Marks a report as changed so previews and renders pick up its exhibits
+const TOUCH_REPORT: &str = "UPDATE reports SET updated_at = $2 WHERE id = $1";
+
+/// Repository for report exhibits. Every change also bumps the report's `updated_at`, since
+/// exhibits are printed as part of the report.
+pub struct ExhibitRepository {
+    db: Arc<Database>,
+}
+
+impl ExhibitRepository {
+    /// Create a new exhibit repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get an exhibit by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<ReportExhibit> {
+        let row = sqlx::query("SELECT * FROM report_exhibits WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch exhibit: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_exhibit(&row),
+            None => Err(AppError::NotFound(format!("Exhibit not found with ID: {}", id))),
+        }
+    }
+
+    /// List the exhibits of a report in print order
+    pub async fn for_report(&self, report_id: Uuid) -> AppResult<Vec<ReportExhibit>> {
+        let rows = sqlx::query("SELECT * FROM report_exhibits WHERE report_id = $1 ORDER BY position")
+            .bind(report_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch exhibits: {}", e)))?;
+
+        rows.iter().map(row_to_exhibit).collect()
+    }
+
+    /// Insert a new exhibit after the report's last one; its `position` is assigned here
+    pub async fn create(&self, exhibit: &ReportExhibit) -> AppResult<ReportExhibit> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query(
+            "INSERT INTO report_exhibits
+                (id, report_id, kind, caption, position, comparable_number, media_type, storage_key,
+                 page_count, byte_size, uploaded_by, created_at)
+             SELECT $1, $2, $3, $4, COALESCE(MAX(position), 0) + 1, $5, $6, $7, $8, $9, $10, $11
+             FROM report_exhibits WHERE report_id = $2
+             RETURNING *"
+        )
+        .bind(exhibit.id)
+        .bind(exhibit.report_id)
+        .bind(encode_enum(&exhibit.kind))
+        .bind(&exhibit.caption)
+        .bind(exhibit.comparable_number)
+        .bind(&exhibit.media_type)
+        .bind(&exhibit.storage_key)
+        .bind(exhibit.page_count)
+        .bind(exhibit.byte_size)
+        .bind(exhibit.uploaded_by)
+        .bind(exhibit.created_at)
+        .fetch_one(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create exhibit: {}", e)))?;
+
+        sqlx::query(TOUCH_REPORT)
+            .bind(exhibit.report_id)
+            .bind(exhibit.created_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_exhibit(&row)
+    }
+
+    /// Change the caption of an exhibit
+    pub async fn set_caption(&self, exhibit: &ReportExhibit, updated_at: DateTime<Utc>) -> AppResult<ReportExhibit> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query("UPDATE report_exhibits SET caption = $2 WHERE id = $1 RETURNING *")
+            .bind(exhibit.id)
+            .bind(&exhibit.caption)
+            .fetch_optional(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update exhibit: {}", e)))?
+            .ok_or_else(|| AppError::NotFound(format!("Exhibit not found with ID: {}", exhibit.id)))?;
+
+        sqlx::query(TOUCH_REPORT)
+            .bind(exhibit.report_id)
+            .bind(updated_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_exhibit(&row)
+    }
+
+    /// Number a report's exhibits in the given order. The IDs must be exactly the report's
+    /// exhibits; positions are checked for uniqueness when the transaction commits.
+    pub async fn reorder(
+        &self,
+        report_id: Uuid,
+        exhibit_ids: &[Uuid],
+        updated_at: DateTime<Utc>,
+    ) -> AppResult<Vec<ReportExhibit>> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        for (index, id) in exhibit_ids.iter().enumerate() {
+            let updated = sqlx::query("UPDATE report_exhibits SET position = $3 WHERE id = $1 AND report_id = $2")
+                .bind(id)
+                .bind(report_id)
+                .bind(index as i32 + 1)
+                .execute(&mut tx)
+                .await
+                .map_err(|e| AppError::Database(format!("Failed to reorder exhibits: {}", e)))?;
+
+            if updated.rows_affected() == 0 {
+                return Err(AppError::Conflict(format!("Exhibit {} was removed from report {}", id, report_id)));
+            }
+        }
+
+        sqlx::query(TOUCH_REPORT)
+            .bind(report_id)
+            .bind(updated_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        self.for_report(report_id).await
+    }
+
+    /// Delete an exhibit and close the gap it leaves in the print order
+    pub async fn delete(&self, exhibit: &ReportExhibit, updated_at: DateTime<Utc>) -> AppResult<()> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query("DELETE FROM report_exhibits WHERE id = $1 RETURNING position")
+            .bind(exhibit.id)
+            .fetch_optional(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete exhibit: {}", e)))?;
+
+        // Use the position as deleted, in case the exhibits were reordered since they were read
+        let position: i32 = match row {
+            Some(row) => column(&row, "position")?,
+            None => return Err(AppError::NotFound(format!("Exhibit not found with ID: {}", exhibit.id))),
+        };
+
+        sqlx::query("UPDATE report_exhibits SET position = position - 1 WHERE report_id = $1 AND position > $2")
+            .bind(exhibit.report_id)
+            .bind(position)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to reorder exhibits: {}", e)))?;
+
+        sqlx::query(TOUCH_REPORT)
+            .bind(exhibit.report_id)
+            .bind(updated_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))
+    }
+}
+
+/// Convert a database row to an exhibit model
+fn row_to_exhibit(row: &PgRow) -> AppResult<ReportExhibit> {
+    Ok(ReportExhibit {
+        id: column(row, "id")?,
+        report_id: column(row, "report_id")?,
+        kind: decode_enum(&column::<String>(row, "kind")?)?,
+        caption: column(row, "caption")?,
+        position: column(row, "position")?,
+        comparable_number: column(row, "comparable_number")?,
+        media_type: column(row, "media_type")?,
+        storage_key: column(row, "storage_key")?,
+        page_count: column(row, "page_count")?,
+        byte_size: column(row, "byte_size")?,
+        uploaded_by: column(row, "uploaded_by")?,
+        created_at: column(row, "created_at")?,
+    })
+}
@@ -0,0 +1,9 @@
+pub mod appraisal_repository;
+pub mod exhibit_repository;
+pub mod property_repository;
+pub mod report_repository;
+pub mod revision_repository;
+pub mod share_link_repository;
+pub mod signature_repository;
+pub mod snapshot_repository;
+pub mod snippet_repository;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use shared::db::{column, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::property::Property;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Read-only access to properties owned by the property service
+#[derive(Clone)]
+pub struct PropertyRepository {
+    db: Arc<Database>,
+}
+
+impl PropertyRepository {
+    /// Create a new property repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a property by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<Property> {
+        let row = sqlx::query("SELECT * FROM properties WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch property: {}", e)))?;
+
+        let row = row.ok_or_else(|| AppError::NotFound(format!("Property not found with ID: {}", id)))?;
+
+        Ok(Property {
+            id: column(&row, "id")?,
+            address: column::<Json<_>>(&row, "address")?.0,
+            characteristics: column::<Json<_>>(&row, "characteristics")?.0,
+            valuation: column::<Option<Json<_>>>(&row, "valuation")?.map(|v| v.0),
+            created_at: column(&row, "created_at")?,
+            updated_at: column(&row, "updated_at")?,
+        })
+    }
+}
@@ -0,0 +1,548 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::Appraisal;
+use shared::models::exhibit::ReportExhibit;
+use shared::models::property::{Address, Property, PropertyValuation};
+use shared::models::report::{Report, ReportQuery, ReportSnapshot, ReportStatus, ReportSummary};
+use shared::utils::format::{format_address_single_line, format_name};
+use sqlx::postgres::{PgArguments, PgRow};
+use sqlx::query::Query;
+use sqlx::types::Json;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+/// INSERT statement shared by report creation; parameters are bound by `bind_new_report`
+const INSERT_REPORT: &str = "
+    INSERT INTO reports
+        (id, title, property_id, appraiser_id, status, report_type, valuation_amount, content,
+         pdf_url, created_at, updated_at, submitted_at, reviewed_at, reviewer_id, review_comments,
+         submission_comments, version, amends_id)
+    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+    RETURNING *";
+
+/// UPDATE statement shared by report writes; parameters are bound by `bind_report`
+const UPDATE_REPORT: &str = "
+    UPDATE reports
+    SET title = $2, status = $3, valuation_amount = $4, content = $5, pdf_url = $6, updated_at = $7,
+        submitted_at = $8, reviewed_at = $9, reviewer_id = $10, review_comments = $11,
+        submission_comments = $12";
+
+/// Repository for report operations
+#[derive(Clone)]
+pub struct ReportRepository {
+    db: Arc<Database>,
+}
+
+impl ReportRepository {
+    /// Create a new report repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a report by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<Report> {
+        let row = sqlx::query("SELECT * FROM reports WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch report: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_report(&row),
+            None => Err(AppError::NotFound(format!("Report not found with ID: {}", id))),
+        }
+    }
+
+    /// List report summaries matching a query, most recently updated first
+    pub async fn find_summaries(
+        &self,
+        query: &ReportQuery,
+        participant: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> AppResult<Vec<ReportSummary>> {
+        let rows = sqlx::query(
+            "SELECT r.id, r.title, r.status, r.report_type, r.appraiser_id, p.address,
+                    u.first_name, u.last_name, r.valuation_amount, r.created_at, r.updated_at
+             FROM reports r
+             JOIN properties p ON p.id = r.property_id
+             LEFT JOIN users u ON u.id = r.appraiser_id::text
+             WHERE ($1::uuid IS NULL OR r.property_id = $1)
+               AND ($2::uuid IS NULL OR r.appraiser_id = $2)
+               AND ($3::text IS NULL OR r.status = $3)
+               AND ($4::text IS NULL OR r.report_type = $4)
+               AND ($7::text IS NULL OR r.appraiser_id::text = $7 OR r.reviewer_id::text = $7)
+             ORDER BY r.updated_at DESC
+             LIMIT $5 OFFSET $6"
+        )
+        .bind(query.property_id)
+        .bind(query.appraiser_id)
+        .bind(query.status.as_ref().map(encode_enum))
+        .bind(query.report_type.as_ref().map(encode_enum))
+        .bind(limit)
+        .bind(offset)
+        .bind(participant)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch reports: {}", e)))?;
+
+        rows.iter().map(row_to_summary).collect()
+    }
+
+    /// Insert a new report
+    pub async fn create(&self, report: &Report) -> AppResult<Report> {
+        let row = bind_new_report(sqlx::query(INSERT_REPORT), report)
+            .fetch_one(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to create report: {}", e)))?;
+
+        row_to_report(&row)
+    }
+
+    /// Insert the property, completed appraisal and report read from an imported document,
+    /// all or nothing
+    pub async fn create_imported(&self, property: &Property, appraisal: &Appraisal, report: &Report) -> AppResult<Report> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO properties (id, address, characteristics, valuation, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6)"
+        )
+        .bind(property.id)
+        .bind(Json(&property.address))
+        .bind(Json(&property.characteristics))
+        .bind(property.valuation.as_ref().map(Json))
+        .bind(property.created_at)
+        .bind(property.updated_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create property: {}", e)))?;
+
+        let row = bind_new_report(sqlx::query(INSERT_REPORT), report)
+            .fetch_one(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to create report: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO appraisals
+                (id, reference_number, client_id, property_id, appraiser_id, report_id, status, due_date,
+                 purpose, appraisal_type, instructions, fee, created_at, updated_at, completed_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)"
+        )
+        .bind(appraisal.id)
+        .bind(&appraisal.reference_number)
+        .bind(appraisal.client_id)
+        .bind(appraisal.property_id)
+        .bind(appraisal.appraiser_id)
+        .bind(appraisal.report_id)
+        .bind(encode_enum(&appraisal.status))
+        .bind(appraisal.due_date)
+        .bind(encode_enum(&appraisal.purpose))
+        .bind(encode_enum(&appraisal.appraisal_type))
+        .bind(&appraisal.instructions)
+        .bind(appraisal.fee)
+        .bind(appraisal.created_at)
+        .bind(appraisal.updated_at)
+        .bind(appraisal.completed_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create appraisal: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_report(&row)
+    }
+
+    /// Insert an amendment together with the exhibits copied from the report it amends,
+    /// all or nothing. The exhibits keep their positions.
+    pub async fn create_amendment(&self, report: &Report, exhibits: &[ReportExhibit]) -> AppResult<Report> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = bind_new_report(sqlx::query(INSERT_REPORT), report)
+            .fetch_one(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to create report: {}", e)))?;
+
+        for exhibit in exhibits {
+            sqlx::query(
+                "INSERT INTO report_exhibits
+                    (id, report_id, kind, caption, position, comparable_number, media_type, storage_key,
+                     page_count, byte_size, uploaded_by, created_at)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)"
+            )
+            .bind(exhibit.id)
+            .bind(exhibit.report_id)
+            .bind(encode_enum(&exhibit.kind))
+            .bind(&exhibit.caption)
+            .bind(exhibit.position)
+            .bind(exhibit.comparable_number)
+            .bind(&exhibit.media_type)
+            .bind(&exhibit.storage_key)
+            .bind(exhibit.page_count)
+            .bind(exhibit.byte_size)
+            .bind(exhibit.uploaded_by)
+            .bind(exhibit.created_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to copy exhibit: {}", e)))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_report(&row)
+    }
+
+    /// Assign the supervisory appraiser who co-signs a report
+    pub async fn assign_supervisor(&self, id: Uuid, supervisor_id: Uuid) -> AppResult<Report> {
+        let row = sqlx::query(
+            "UPDATE reports SET supervisor_id = $2, updated_at = $3 WHERE id = $1 RETURNING *"
+        )
+        .bind(id)
+        .bind(supervisor_id)
+        .bind(Utc::now())
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to assign supervisor: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_report(&row),
+            None => Err(AppError::NotFound(format!("Report not found with ID: {}", id))),
+        }
+    }
+
+    /// Persist changes to a report, failing with a conflict if the stored report
+    /// has left the `expected` status since it was read
+    pub async fn update_if_status(&self, report: &Report, expected: &ReportStatus) -> AppResult<Report> {
+        let sql = format!("{} WHERE id = $1 AND status = $13 RETURNING *", UPDATE_REPORT);
+
+        let row = bind_report(sqlx::query(&sql), report)
+            .bind(encode_enum(expected))
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_report(&row),
+            None => Err(AppError::Conflict(format!(
+                "Report {} is no longer {:?}",
+                report.id, expected
+            ))),
+        }
+    }
+
+    /// Submit a report for review together with a revision recording its content as submitted,
+    /// failing with a conflict if the stored report has left the `expected` status since it was read
+    pub async fn submit(&self, report: &Report, expected: &ReportStatus) -> AppResult<Report> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let sql = format!("{} WHERE id = $1 AND status = $13 RETURNING *", UPDATE_REPORT);
+        let row = bind_report(sqlx::query(&sql), report)
+            .bind(encode_enum(expected))
+            .fetch_optional(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to submit report: {}", e)))?;
+
+        let row = match row {
+            Some(row) => row,
+            None => {
+                return Err(AppError::Conflict(format!(
+                    "Report {} is no longer {:?}",
+                    report.id, expected
+                )))
+            }
+        };
+
+        sqlx::query(
+            "INSERT INTO report_revisions
+                (id, report_id, revision, valuation_amount, content, submission_comments, submitted_at)
+             SELECT $1, $2, COALESCE(MAX(revision), 0) + 1, $3, $4, $5, $6
+             FROM report_revisions WHERE report_id = $2"
+        )
+        .bind(Uuid::new_v4())
+        .bind(report.id)
+        .bind(report.valuation_amount)
+        .bind(Json(&report.content))
+        .bind(&report.submission_comments)
+        .bind(report.submitted_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to store report revision: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_report(&row)
+    }
+
+    /// Write a report's reconciled value together with the valuation of its property, failing
+    /// with a conflict if the stored report has left the `expected` status since it was read
+    pub async fn reconcile(&self, report: &Report, expected: &ReportStatus, valuation: &PropertyValuation) -> AppResult<Report> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let sql = format!("{} WHERE id = $1 AND status = $13 RETURNING *", UPDATE_REPORT);
+        let row = bind_report(sqlx::query(&sql), report)
+            .bind(encode_enum(expected))
+            .fetch_optional(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report: {}", e)))?;
+
+        let row = match row {
+            Some(row) => row,
+            None => {
+                return Err(AppError::Conflict(format!(
+                    "Report {} is no longer {:?}",
+                    report.id, expected
+                )))
+            }
+        };
+
+        sqlx::query("UPDATE properties SET valuation = $2, updated_at = $3 WHERE id = $1")
+            .bind(report.property_id)
+            .bind(Json(valuation))
+            .bind(report.updated_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update property valuation: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_report(&row)
+    }
+
+    /// Finalize an approved report together with the snapshot of what was delivered, failing
+    /// with a conflict if the stored report has left the `expected` status since it was read
+    pub async fn finalize(&self, report: &Report, expected: &ReportStatus, snapshot: &ReportSnapshot) -> AppResult<Report> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let sql = format!("{} WHERE id = $1 AND status = $13 RETURNING *", UPDATE_REPORT);
+        let row = bind_report(sqlx::query(&sql), report)
+            .bind(encode_enum(expected))
+            .fetch_optional(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to finalize report: {}", e)))?;
+
+        let row = match row {
+            Some(row) => row,
+            None => {
+                return Err(AppError::Conflict(format!(
+                    "Report {} is no longer {:?}",
+                    report.id, expected
+                )))
+            }
+        };
+
+        sqlx::query(
+            "INSERT INTO report_snapshots
+                (id, report_id, version, manifest, manifest_sha256, finalized_by, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)"
+        )
+        .bind(snapshot.id)
+        .bind(snapshot.report_id)
+        .bind(snapshot.version)
+        .bind(Json(&snapshot.manifest))
+        .bind(&snapshot.manifest_sha256)
+        .bind(snapshot.finalized_by)
+        .bind(snapshot.created_at)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to store report snapshot: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_report(&row)
+    }
+
+    /// ID of the report version amending a finalized report (if any)
+    pub async fn find_amendment(&self, id: Uuid) -> AppResult<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM reports WHERE amends_id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch report amendment: {}", e)))?;
+
+        match row {
+            Some(row) => Ok(Some(column(&row, "id")?)),
+            None => Ok(None),
+        }
+    }
+
+    /// When a report was last changed, without loading it
+    pub async fn updated_at(&self, id: Uuid) -> AppResult<DateTime<Utc>> {
+        let row = sqlx::query("SELECT updated_at FROM reports WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch report: {}", e)))?;
+
+        match row {
+            Some(row) => column(&row, "updated_at"),
+            None => Err(AppError::NotFound(format!("Report not found with ID: {}", id))),
+        }
+    }
+
+    /// Record where the rendered PDF of a report can be fetched
+    pub async fn set_pdf_url(&self, id: Uuid, pdf_url: &str) -> AppResult<Report> {
+        let row = sqlx::query("UPDATE reports SET pdf_url = $2 WHERE id = $1 RETURNING *")
+            .bind(id)
+            .bind(pdf_url)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to update report PDF: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_report(&row),
+            None => Err(AppError::NotFound(format!("Report not found with ID: {}", id))),
+        }
+    }
+
+    /// Delete a report
+    pub async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM reports WHERE id = $1")
+            .bind(id)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete report: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Report not found with ID: {}", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Whether a property exists
+    pub async fn property_exists(&self, property_id: Uuid) -> AppResult<bool> {
+        let row = sqlx::query("SELECT EXISTS (SELECT 1 FROM properties WHERE id = $1) AS present")
+            .bind(property_id)
+            .fetch_one(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to check property: {}", e)))?;
+
+        column(&row, "present")
+    }
+}
+
+/// Bind the parameters referenced by `INSERT_REPORT`
+fn bind_new_report<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    report: &'q Report,
+) -> Query<'q, Postgres, PgArguments> {
+    query
+        .bind(report.id)
+        .bind(&report.title)
+        .bind(report.property_id)
+        .bind(report.appraiser_id)
+        .bind(encode_enum(&report.status))
+        .bind(encode_enum(&report.report_type))
+        .bind(report.valuation_amount)
+        .bind(Json(&report.content))
+        .bind(&report.pdf_url)
+        .bind(report.created_at)
+        .bind(report.updated_at)
+        .bind(report.submitted_at)
+        .bind(report.reviewed_at)
+        .bind(report.reviewer_id)
+        .bind(&report.review_comments)
+        .bind(&report.submission_comments)
+        .bind(report.version)
+        .bind(report.amends_id)
+}
+
+/// Bind the parameters referenced by `UPDATE_REPORT`
+fn bind_report<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    report: &'q Report,
+) -> Query<'q, Postgres, PgArguments> {
+    query
+        .bind(report.id)
+        .bind(&report.title)
+        .bind(encode_enum(&report.status))
+        .bind(report.valuation_amount)
+        .bind(Json(&report.content))
+        .bind(&report.pdf_url)
+        .bind(report.updated_at)
+        .bind(report.submitted_at)
+        .bind(report.reviewed_at)
+        .bind(report.reviewer_id)
+        .bind(&report.review_comments)
+        .bind(&report.submission_comments)
+}
+
+/// Convert a listing query row to a report summary
+fn row_to_summary(row: &PgRow) -> AppResult<ReportSummary> {
+    let address = column::<Json<Address>>(row, "address")?.0;
+    let first_name: Option<String> = column(row, "first_name")?;
+    let last_name: Option<String> = column(row, "last_name")?;
+    let appraiser_id: Uuid = column(row, "appraiser_id")?;
+
+    let appraiser_name = match (first_name, last_name) {
+        (Some(first), Some(last)) => format_name(&first, &last),
+        (Some(name), None) | (None, Some(name)) => name,
+        (None, None) => appraiser_id.to_string(),
+    };
+
+    Ok(ReportSummary {
+        id: column(row, "id")?,
+        title: column(row, "title")?,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        report_type: decode_enum(&column::<String>(row, "report_type")?)?,
+        property_address: format_address_single_line(
+            &address.street1,
+            address.street2.as_deref(),
+            &address.city,
+            &address.state,
+            &address.postal_code,
+        ),
+        appraiser_name,
+        valuation_amount: column(row, "valuation_amount")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to a report model
+pub fn row_to_report(row: &PgRow) -> AppResult<Report> {
+    Ok(Report {
+        id: column(row, "id")?,
+        title: column(row, "title")?,
+        property_id: column(row, "property_id")?,
+        appraiser_id: column(row, "appraiser_id")?,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        report_type: decode_enum(&column::<String>(row, "report_type")?)?,
+        valuation_amount: column(row, "valuation_amount")?,
+        content: column::<Json<_>>(row, "content")?.0,
+        pdf_url: column(row, "pdf_url")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+        submitted_at: column(row, "submitted_at")?,
+        reviewed_at: column(row, "reviewed_at")?,
+        reviewer_id: column(row, "reviewer_id")?,
+        review_comments: column(row, "review_comments")?,
+        submission_comments: column(row, "submission_comments")?,
+        version: column(row, "version")?,
+        amends_id: column(row, "amends_id")?,
+        supervisor_id: column(row, "supervisor_id")?,
+    })
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use shared::db::{column, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::report::ReportRevision;
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Read access to the revisions recorded when reports are submitted; they are written by
+/// `ReportRepository::submit`
+#[derive(Clone)]
+pub struct RevisionRepository {
+    db: Arc<Database>,
+}
+
+impl RevisionRepository {
+    /// Create a new revision repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Revisions of a report, oldest first
+    pub async fn for_report(&self, report_id: Uuid) -> AppResult<Vec<ReportRevision>> {
+        let rows = sqlx::query("SELECT * FROM report_revisions WHERE report_id = $1 ORDER BY revision")
+            .bind(report_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch report revisions: {}", e)))?;
+
+        rows.iter().map(row_to_revision).collect()
+    }
+}
+
+/// Convert a database row to a ReportRevision
+fn row_to_revision(row: &PgRow) -> AppResult<ReportRevision> {
+    Ok(ReportRevision {
+        id: column(row, "id")?,
+        report_id: column(row, "report_id")?,
+        revision: column(row, "revision")?,
+        valuation_amount: column(row, "valuation_amount")?,
+        content: column::<Json<_>>(row, "content")?.0,
+        submission_comments: column(row, "submission_comments")?,
+        submitted_at: column(row, "submitted_at")?,
+    })
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::share::{ShareAccessOutcome, ShareLink, ShareLinkAccess};
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+/// INSERT statement for the access log; parameters are bound in field order
+const INSERT_ACCESS: &str = "
+    INSERT INTO share_link_accesses (id, link_id, outcome, ip_address, user_agent, accessed_at)
+    VALUES ($1, $2, $3, $4, $5, $6)";
+
+/// Repository for report share links and their access log
+pub struct ShareLinkRepository {
+    db: Arc<Database>,
+}
+
+impl ShareLinkRepository {
+    /// Create a new share link repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a share link by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<ShareLink> {
+        let row = sqlx::query("SELECT * FROM share_links WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch share link: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_link(&row),
+            None => Err(AppError::NotFound(format!("Share link not found with ID: {}", id))),
+        }
+    }
+
+    /// List the share links of a report, newest first
+    pub async fn for_report(&self, report_id: Uuid) -> AppResult<Vec<ShareLink>> {
+        let rows = sqlx::query("SELECT * FROM share_links WHERE report_id = $1 ORDER BY created_at DESC")
+            .bind(report_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch share links: {}", e)))?;
+
+        rows.iter().map(row_to_link).collect()
+    }
+
+    /// Insert a new share link
+    pub async fn create(&self, link: &ShareLink) -> AppResult<ShareLink> {
+        let row = sqlx::query(
+            "INSERT INTO share_links
+                (id, report_id, artifact, recipient_role, recipient_name, recipient_email, password_hash,
+                 expires_at, max_downloads, download_count, first_downloaded_at, created_by, created_at, revoked_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+             RETURNING *"
+        )
+        .bind(link.id)
+        .bind(link.report_id)
+        .bind(&link.artifact)
+        .bind(encode_enum(&link.recipient_role))
+        .bind(&link.recipient_name)
+        .bind(&link.recipient_email)
+        .bind(&link.password_hash)
+        .bind(link.expires_at)
+        .bind(link.max_downloads)
+        .bind(link.download_count)
+        .bind(link.first_downloaded_at)
+        .bind(link.created_by)
+        .bind(link.created_at)
+        .bind(link.revoked_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create share link: {}", e)))?;
+
+        row_to_link(&row)
+    }
+
+    /// Revoke a share link, failing with a conflict if it was already revoked
+    pub async fn revoke(&self, id: Uuid, revoked_at: DateTime<Utc>) -> AppResult<ShareLink> {
+        let row = sqlx::query(
+            "UPDATE share_links SET revoked_at = $2 WHERE id = $1 AND revoked_at IS NULL RETURNING *"
+        )
+        .bind(id)
+        .bind(revoked_at)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to revoke share link: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_link(&row),
+            None => Err(AppError::Conflict(format!("Share link {} is already revoked", id))),
+        }
+    }
+
+    /// Count a download against a link and log it, provided the link is still unrevoked,
+    /// unexpired and under its download limit when the download is counted. Returns `None`
+    /// without logging anything when the link no longer allows downloads.
+    pub async fn claim_download(&self, access: &ShareLinkAccess) -> AppResult<Option<ShareLink>> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query(
+            "UPDATE share_links
+             SET download_count = download_count + 1,
+                 first_downloaded_at = COALESCE(first_downloaded_at, $2)
+             WHERE id = $1 AND revoked_at IS NULL AND expires_at > $2
+               AND (max_downloads IS NULL OR download_count < max_downloads)
+             RETURNING *"
+        )
+        .bind(access.link_id)
+        .bind(access.accessed_at)
+        .fetch_optional(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to count share link download: {}", e)))?;
+
+        let row = match row {
+            Some(row) => row,
+            None => return Ok(None),
+        };
+
+        sqlx::query(INSERT_ACCESS)
+            .bind(access.id)
+            .bind(access.link_id)
+            .bind(encode_enum(&access.outcome))
+            .bind(&access.ip_address)
+            .bind(&access.user_agent)
+            .bind(access.accessed_at)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to log share link access: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        row_to_link(&row).map(Some)
+    }
+
+    /// Log an attempt to open a link that did not lead to a download
+    pub async fn record_access(&self, access: &ShareLinkAccess) -> AppResult<()> {
+        sqlx::query(INSERT_ACCESS)
+            .bind(access.id)
+            .bind(access.link_id)
+            .bind(encode_enum(&access.outcome))
+            .bind(&access.ip_address)
+            .bind(&access.user_agent)
+            .bind(access.accessed_at)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to log share link access: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Count the wrong passwords given for a link since a point in time
+    pub async fn count_password_rejections(&self, link_id: Uuid, since: DateTime<Utc>) -> AppResult<i64> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) AS count FROM share_link_accesses
+             WHERE link_id = $1 AND outcome = $2 AND accessed_at > $3"
+        )
+        .bind(link_id)
+        .bind(encode_enum(&ShareAccessOutcome::PasswordRejected))
+        .bind(since)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to count share link password rejections: {}", e)))?;
+
+        column(&row, "count")
+    }
+
+    /// List the attempts to open a link, oldest first
+    pub async fn accesses(&self, link_id: Uuid) -> AppResult<Vec<ShareLinkAccess>> {
+        let rows = sqlx::query("SELECT * FROM share_link_accesses WHERE link_id = $1 ORDER BY accessed_at")
+            .bind(link_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch share link accesses: {}", e)))?;
+
+        rows.iter().map(row_to_access).collect()
+    }
+}
+
+/// Convert a database row to a share link model
+fn row_to_link(row: &PgRow) -> AppResult<ShareLink> {
+    let password_hash: Option<String> = column(row, "password_hash")?;
+
+    Ok(ShareLink {
+        id: column(row, "id")?,
+        report_id: column(row, "report_id")?,
+        artifact: column(row, "artifact")?,
+        recipient_role: decode_enum(&column::<String>(row, "recipient_role")?)?,
+        recipient_name: column(row, "recipient_name")?,
+        recipient_email: column(row, "recipient_email")?,
+        password_protected: password_hash.is_some(),
+        password_hash,
+        expires_at: column(row, "expires_at")?,
+        max_downloads: column(row, "max_downloads")?,
+        download_count: column(row, "download_count")?,
+        first_downloaded_at: column(row, "first_downloaded_at")?,
+        created_by: column(row, "created_by")?,
+        created_at: column(row, "created_at")?,
+        revoked_at: column(row, "revoked_at")?,
+    })
+}
+
+/// Convert a database row to a share link access model
+fn row_to_access(row: &PgRow) -> AppResult<ShareLinkAccess> {
+    Ok(ShareLinkAccess {
+        id: column(row, "id")?,
+        link_id: column(row, "link_id")?,
+        outcome: decode_enum(&column::<String>(row, "outcome")?)?,
+        ip_address: column(row, "ip_address")?,
+        user_agent: column(row, "user_agent")?,
+        accessed_at: column(row, "accessed_at")?,
+    })
+}
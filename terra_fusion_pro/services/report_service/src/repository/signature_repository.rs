@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use shared::db::{column, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::signature::ReportSignature;
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Repository for signatures on finalized reports; signatures are never changed once made
+pub struct SignatureRepository {
+    db: Arc<Database>,
+}
+
+impl SignatureRepository {
+    /// Create a new signature repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get the signatures on a report, in the order they were made
+    pub async fn for_report(&self, report_id: Uuid) -> AppResult<Vec<ReportSignature>> {
+        let rows = sqlx::query(
+            "SELECT s.*, k.public_key FROM report_signatures s
+             JOIN signing_keys k ON k.id = s.key_id
+             WHERE s.report_id = $1
+             ORDER BY s.created_at"
+        )
+        .bind(report_id)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch report signatures: {}", e)))?;
+
+        rows.iter().map(row_to_signature).collect()
+    }
+
+    /// Record a signature over a report's snapshot
+    pub async fn create(&self, snapshot_id: Uuid, signature: &ReportSignature) -> AppResult<()> {
+        sqlx::query(
+            "INSERT INTO report_signatures
+                (id, report_id, snapshot_id, key_id, role, payload, signature, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
+        )
+        .bind(signature.id)
+        .bind(signature.payload.report_id)
+        .bind(snapshot_id)
+        .bind(signature.key_id)
+        .bind(encode_enum(&signature.payload.role))
+        .bind(Json(&signature.payload))
+        .bind(&signature.signature)
+        .bind(signature.payload.signed_at)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to store report signature: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// Convert a database row to a signature model
+fn row_to_signature(row: &PgRow) -> AppResult<ReportSignature> {
+    Ok(ReportSignature {
+        id: column(row, "id")?,
+        key_id: column(row, "key_id")?,
+        public_key: column(row, "public_key")?,
+        payload: column::<Json<_>>(row, "payload")?.0,
+        signature: column(row, "signature")?,
+    })
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use shared::db::{column, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::report::ReportSnapshot;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// Read access to the snapshots taken when reports are finalized; they are written by
+/// `ReportRepository::finalize` and never change afterwards
+#[derive(Clone)]
+pub struct SnapshotRepository {
+    db: Arc<Database>,
+}
+
+impl SnapshotRepository {
+    /// Create a new snapshot repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get the snapshot of a finalized report
+    pub async fn get_by_report(&self, report_id: Uuid) -> AppResult<ReportSnapshot> {
+        let row = sqlx::query("SELECT * FROM report_snapshots WHERE report_id = $1")
+            .bind(report_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch report snapshot: {}", e)))?;
+
+        let row = row.ok_or_else(|| {
+            AppError::NotFound(format!("Report {} has not been finalized", report_id))
+        })?;
+
+        Ok(ReportSnapshot {
+            id: column(&row, "id")?,
+            report_id: column(&row, "report_id")?,
+            version: column(&row, "version")?,
+            manifest: column::<Json<_>>(&row, "manifest")?.0,
+            manifest_sha256: column(&row, "manifest_sha256")?,
+            finalized_by: column(&row, "finalized_by")?,
+            created_at: column(&row, "created_at")?,
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use shared::db::{column, decode_enum, encode_enum, Database};
+use shared::error::{AppError, AppResult};
+use shared::models::snippet::{NarrativeSnippet, SnippetQuery};
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+/// Repository for narrative snippet libraries
+pub struct SnippetRepository {
+    db: Arc<Database>,
+}
+
+impl SnippetRepository {
+    /// Create a new snippet repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a snippet by ID
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<NarrativeSnippet> {
+        let row = sqlx::query("SELECT * FROM narrative_snippets WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch snippet: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_snippet(&row),
+            None => Err(AppError::NotFound(format!("Snippet not found with ID: {}", id))),
+        }
+    }
+
+    /// List the organization's snippets and an appraiser's personal snippets matching a query
+    pub async fn find_visible(&self, appraiser_id: Uuid, query: &SnippetQuery) -> AppResult<Vec<NarrativeSnippet>> {
+        let rows = sqlx::query(
+            "SELECT * FROM narrative_snippets
+             WHERE (scope = 'organization' OR owner_id = $1)
+               AND ($2::text IS NULL OR section = $2)
+               AND ($3::text IS NULL OR scope = $3)
+             ORDER BY section, field, name"
+        )
+        .bind(appraiser_id)
+        .bind(&query.section)
+        .bind(query.scope.as_ref().map(encode_enum))
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch snippets: {}", e)))?;
+
+        rows.iter().map(row_to_snippet).collect()
+    }
+
+    /// Insert a new snippet
+    pub async fn create(&self, snippet: &NarrativeSnippet) -> AppResult<NarrativeSnippet> {
+        let row = sqlx::query(
+            "INSERT INTO narrative_snippets
+                (id, scope, owner_id, name, section, field, template, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING *"
+        )
+        .bind(snippet.id)
+        .bind(encode_enum(&snippet.scope))
+        .bind(snippet.owner_id)
+        .bind(&snippet.name)
+        .bind(&snippet.section)
+        .bind(&snippet.field)
+        .bind(&snippet.template)
+        .bind(snippet.created_at)
+        .bind(snippet.updated_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create snippet: {}", e)))?;
+
+        row_to_snippet(&row)
+    }
+
+    /// Persist changes to a snippet
+    pub async fn update(&self, snippet: &NarrativeSnippet) -> AppResult<NarrativeSnippet> {
+        let row = sqlx::query(
+            "UPDATE narrative_snippets
+             SET scope = $2, name = $3, section = $4, field = $5, template = $6, updated_at = $7
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(snippet.id)
+        .bind(encode_enum(&snippet.scope))
+        .bind(&snippet.name)
+        .bind(&snippet.section)
+        .bind(&snippet.field)
+        .bind(&snippet.template)
+        .bind(snippet.updated_at)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to update snippet: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_snippet(&row),
+            None => Err(AppError::NotFound(format!("Snippet not found with ID: {}", snippet.id))),
+        }
+    }
+
+    /// Delete a snippet
+    pub async fn delete(&self, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM narrative_snippets WHERE id = $1")
+            .bind(id)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete snippet: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Snippet not found with ID: {}", id)));
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert a database row to a snippet model
+fn row_to_snippet(row: &PgRow) -> AppResult<NarrativeSnippet> {
+    Ok(NarrativeSnippet {
+        id: column(row, "id")?,
+        scope: decode_enum(&column::<String>(row, "scope")?)?,
+        owner_id: column(row, "owner_id")?,
+        name: column(row, "name")?,
+        section: column(row, "section")?,
+        field: column(row, "field")?,
+        template: column(row, "template")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
@@ -0,0 +1,282 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::exhibit::{
+    ExhibitCheck, ExhibitKind, ReorderExhibitsRequest, ReportExhibit, UpdateExhibitRequest, UploadExhibitQuery,
+    MAX_EXHIBIT_BYTES, MAX_EXHIBIT_PDF_PAGES,
+};
+use shared::models::report::{Report, ReportStatus};
+use shared::storage::AttachmentStore;
+use uuid::Uuid;
+
+use super::{ensure_author, ensure_participant, parse_appraiser_id, require_user};
+use crate::rendering::pdf::pdf_page_count;
+use crate::rendering::JpegImage;
+use crate::repository::exhibit_repository::ExhibitRepository;
+use crate::repository::report_repository::ReportRepository;
+
+/// MIME type of image exhibits
+pub const JPEG_MEDIA_TYPE: &str = "image/jpeg";
+
+/// MIME type of uploaded documents
+pub const PDF_MEDIA_TYPE: &str = "application/pdf";
+
+/// Service for the photos, sketches, maps and documents printed after a report's body
+pub struct ExhibitService {
+    reports: ReportRepository,
+    exhibits: ExhibitRepository,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl ExhibitService {
+    /// Create a new exhibit service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            exhibits: ExhibitRepository::new(db),
+            store,
+        }
+    }
+
+    /// Add a JPEG or PDF exhibit to the end of a report the current user is editing
+    pub async fn upload_exhibit(
+        &self,
+        report_id: Uuid,
+        query: UploadExhibitQuery,
+        bytes: &[u8],
+        user_id: Option<String>,
+    ) -> AppResult<ReportExhibit> {
+        let user_id = require_user(user_id)?;
+        let uploaded_by = parse_appraiser_id(&user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+        ensure_editable(&report)?;
+
+        if bytes.is_empty() {
+            return Err(AppError::Validation("Exhibit file is empty".to_string()));
+        }
+        if bytes.len() > MAX_EXHIBIT_BYTES {
+            return Err(AppError::Validation(format!(
+                "Exhibit files must be at most {} bytes",
+                MAX_EXHIBIT_BYTES
+            )));
+        }
+
+        match (query.kind, query.comparable_number) {
+            (ExhibitKind::ComparablePhoto, Some(number)) if number >= 1 => {}
+            (ExhibitKind::ComparablePhoto, _) => {
+                return Err(AppError::Validation(
+                    "Comparable photos need the number of the comparable sale they picture".to_string(),
+                ))
+            }
+            (_, Some(_)) => {
+                return Err(AppError::Validation(
+                    "Only comparable photos are numbered by comparable sale".to_string(),
+                ))
+            }
+            (_, None) => {}
+        }
+
+        let (media_type, page_count) = if JpegImage::parse(bytes.to_vec()).is_some() {
+            (JPEG_MEDIA_TYPE, None)
+        } else if bytes.starts_with(b"%PDF-") && !query.kind.is_photo() {
+            let pages = pdf_page_count(bytes)?;
+            if pages > MAX_EXHIBIT_PDF_PAGES {
+                return Err(AppError::Validation(format!(
+                    "PDF exhibits may add at most {} pages to a report",
+                    MAX_EXHIBIT_PDF_PAGES
+                )));
+            }
+            (PDF_MEDIA_TYPE, Some(pages as i32))
+        } else if query.kind.is_photo() {
+            return Err(AppError::Validation(format!(
+                "{} exhibits must be JPEG photos",
+                query.kind.title()
+            )));
+        } else {
+            return Err(AppError::Validation("Exhibits must be JPEG images or PDF files".to_string()));
+        };
+
+        let id = Uuid::new_v4();
+        let storage_key = exhibit_key(report_id, id, media_type);
+        self.store.put(&storage_key, bytes).await?;
+
+        let exhibit = ReportExhibit {
+            id,
+            report_id,
+            kind: query.kind,
+            caption: non_empty(query.caption),
+            position: 0,
+            comparable_number: query.comparable_number,
+            media_type: media_type.to_string(),
+            storage_key,
+            page_count,
+            byte_size: bytes.len() as i64,
+            uploaded_by,
+            created_at: Utc::now(),
+        };
+
+        match self.exhibits.create(&exhibit).await {
+            Ok(exhibit) => Ok(exhibit),
+            Err(err) => {
+                self.discard(&exhibit.storage_key).await;
+                Err(err)
+            }
+        }
+    }
+
+    /// List the exhibits of a report in print order
+    pub async fn list_exhibits(&self, report_id: Uuid, user_id: Option<String>) -> AppResult<Vec<ReportExhibit>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.exhibits.for_report(report_id).await
+    }
+
+    /// Load the uploaded file of an exhibit
+    pub async fn download_exhibit(
+        &self,
+        report_id: Uuid,
+        exhibit_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<(ReportExhibit, Vec<u8>)> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        let exhibit = self.exhibit_of(report_id, exhibit_id).await?;
+        let bytes = self.store.get(&exhibit.storage_key).await?;
+        Ok((exhibit, bytes))
+    }
+
+    /// Change the caption of an exhibit
+    pub async fn update_exhibit(
+        &self,
+        report_id: Uuid,
+        exhibit_id: Uuid,
+        request: UpdateExhibitRequest,
+        user_id: Option<String>,
+    ) -> AppResult<ReportExhibit> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+        ensure_editable(&report)?;
+
+        let mut exhibit = self.exhibit_of(report_id, exhibit_id).await?;
+        exhibit.caption = non_empty(request.caption);
+
+        self.exhibits.set_caption(&exhibit, Utc::now()).await
+    }
+
+    /// Put a report's exhibits in a new print order. Every exhibit must be listed exactly once,
+    /// so an order built from a stale list is refused rather than applied in part.
+    pub async fn reorder_exhibits(
+        &self,
+        report_id: Uuid,
+        request: ReorderExhibitsRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Vec<ReportExhibit>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+        ensure_editable(&report)?;
+
+        let current: HashSet<Uuid> = self
+            .exhibits
+            .for_report(report_id)
+            .await?
+            .into_iter()
+            .map(|exhibit| exhibit.id)
+            .collect();
+        let requested: HashSet<Uuid> = request.exhibit_ids.iter().copied().collect();
+
+        if requested.len() != request.exhibit_ids.len() {
+            return Err(AppError::Validation("Each exhibit may only be listed once".to_string()));
+        }
+        if requested != current {
+            return Err(AppError::Validation(format!(
+                "The new order must list all {} exhibits of report {} and no others",
+                current.len(),
+                report_id
+            )));
+        }
+
+        self.exhibits.reorder(report_id, &request.exhibit_ids, Utc::now()).await
+    }
+
+    /// Remove an exhibit and its uploaded file
+    pub async fn delete_exhibit(&self, report_id: Uuid, exhibit_id: Uuid, user_id: Option<String>) -> AppResult<()> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+        ensure_editable(&report)?;
+
+        let exhibit = self.exhibit_of(report_id, exhibit_id).await?;
+        self.exhibits.delete(&exhibit, Utc::now()).await?;
+        self.discard(&exhibit.storage_key).await;
+
+        Ok(())
+    }
+
+    /// Check whether a report has the exhibits its type requires
+    pub async fn check_exhibits(&self, report_id: Uuid, user_id: Option<String>) -> AppResult<ExhibitCheck> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        let exhibits = self.exhibits.for_report(report_id).await?;
+        Ok(ExhibitCheck::evaluate(&report.report_type, &exhibits))
+    }
+
+    /// Get an exhibit, checking it belongs to the report
+    async fn exhibit_of(&self, report_id: Uuid, exhibit_id: Uuid) -> AppResult<ReportExhibit> {
+        let exhibit = self.exhibits.get_by_id(exhibit_id).await?;
+        if exhibit.report_id != report_id {
+            return Err(AppError::NotFound(format!(
+                "Report {} has no exhibit with ID: {}",
+                report_id, exhibit_id
+            )));
+        }
+        Ok(exhibit)
+    }
+
+    /// Remove a file no exhibit refers to any more
+    async fn discard(&self, storage_key: &str) {
+        if let Err(err) = self.store.delete(storage_key).await {
+            log::warn!("Failed to remove orphaned exhibit {}: {:?}", storage_key, err);
+        }
+    }
+}
+
+/// Exhibits are part of the report, so they change only while the report can be edited
+fn ensure_editable(report: &Report) -> AppResult<()> {
+    if report.status == ReportStatus::Finalized {
+        return Err(AppError::Validation(format!(
+            "Report {} is finalized and cannot be changed; amend it instead",
+            report.id
+        )));
+    }
+
+    if !report.status.is_editable() {
+        return Err(AppError::Validation(format!(
+            "Cannot change the exhibits of a report with status {:?}",
+            report.status
+        )));
+    }
+    Ok(())
+}
+
+/// Storage key of an exhibit's file, under the report's own prefix
+pub(super) fn exhibit_key(report_id: Uuid, exhibit_id: Uuid, media_type: &str) -> String {
+    let extension = if media_type == PDF_MEDIA_TYPE { "pdf" } else { "jpg" };
+    format!("reports/{}/exhibits/{}.{}", report_id, exhibit_id, extension)
+}
+
+/// Trimmed text, or `None` when blank
+fn non_empty(text: Option<String>) -> Option<String> {
+    text.map(|text| text.trim().to_string()).filter(|text| !text.is_empty())
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::report::ReportStatus;
+use shared::storage::AttachmentStore;
+use uuid::Uuid;
+
+use super::render_service::RenderService;
+use super::{ensure_participant, require_user};
+use crate::mismo::{form_for, write_mismo, MismoReport, SchemaValidator};
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+
+/// Service for exporting reports to lender delivery formats
+pub struct ExportService {
+    reports: ReportRepository,
+    properties: PropertyRepository,
+    appraisals: AppraisalRepository,
+    renderer: RenderService,
+    schema: Arc<SchemaValidator>,
+}
+
+impl ExportService {
+    /// Create a new export service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>, schema: Arc<SchemaValidator>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            appraisals: AppraisalRepository::new(db.clone()),
+            renderer: RenderService::new(db, store),
+            schema,
+        }
+    }
+
+    /// Export a residential report as MISMO 2.6 GSE XML with the report PDF embedded.
+    /// The PDF is rendered from the same data as the XML (finalized reports embed the PDF they
+    /// were delivered with), and the document is validated against the schema before it is returned.
+    pub async fn export_mismo(&self, id: Uuid, user_id: Option<String>) -> AppResult<Vec<u8>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        let form = form_for(&report.report_type).ok_or_else(|| {
+            AppError::Validation(
+                "Only Form 1004, 1073, 1025 and 2055 reports can be exported as MISMO XML".to_string(),
+            )
+        })?;
+
+        let property = self.properties.get_by_id(report.property_id).await?;
+        let appraisal = self.appraisals.find_by_report(id).await?;
+        let lender_name = match &appraisal {
+            Some(appraisal) => self.appraisals.client_name(appraisal.client_id).await?,
+            None => None,
+        };
+        let signer = self.renderer.signer(&report, &property).await?;
+        let pdf = match report.status {
+            ReportStatus::Finalized => self.renderer.delivered_pdf(id).await?,
+            _ => self.renderer.render(&report, &property).await?,
+        };
+
+        let xml = write_mismo(
+            &MismoReport {
+                report: &report,
+                property: &property,
+                appraisal: appraisal.as_ref(),
+                lender_name: lender_name.as_deref(),
+                appraiser_name: &signer.name,
+                license: signer.license.as_ref(),
+                pdf: &pdf,
+            },
+            form,
+        )?;
+
+        self.schema.validate(&xml).await?;
+        Ok(xml)
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::appraisal::{Appraisal, AppraisalStatus, AppraisalType};
+use shared::models::property::{Property, PropertyValuation, ValuationMethod};
+use shared::models::report::{ImportReportQuery, ImportReportResult, Report, ReportStatus, ReportType};
+use shared::storage::AttachmentStore;
+use shared::utils::format::format_address_single_line;
+use uuid::Uuid;
+
+use super::render_service::pdf_key;
+use super::{parse_appraiser_id, require_user, start_of_day};
+use crate::mismo::read_mismo;
+use crate::rendering::layout::layout_for;
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::report_repository::ReportRepository;
+
+/// Service for importing appraisals delivered by outside vendors
+pub struct ImportService {
+    reports: ReportRepository,
+    appraisals: AppraisalRepository,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl ImportService {
+    /// Create a new import service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            appraisals: AppraisalRepository::new(db),
+            store,
+        }
+    }
+
+    /// Import a MISMO 2.6 appraisal as a new property, a completed appraisal for the client and a
+    /// draft report owned by the importing appraiser. Unless `commit` is set nothing is stored, so
+    /// the result can be reviewed, unmapped elements included, before committing.
+    pub async fn import_mismo(
+        &self,
+        xml: &[u8],
+        query: ImportReportQuery,
+        user_id: Option<String>,
+    ) -> AppResult<ImportReportResult> {
+        let appraiser_id = parse_appraiser_id(&require_user(user_id)?)?;
+
+        if self.appraisals.client_name(query.client_id).await?.is_none() {
+            return Err(AppError::NotFound(format!("Client not found with ID: {}", query.client_id)));
+        }
+
+        let import = read_mismo(xml)?;
+        let now = Utc::now();
+
+        let property = Property {
+            id: Uuid::new_v4(),
+            address: import.address,
+            characteristics: import.characteristics,
+            valuation: import.appraised_value.map(|market_value| PropertyValuation {
+                market_value,
+                confidence: None,
+                valuation_method: ValuationMethod::SalesComparison,
+                valuation_date: import.effective_date.map_or(now, start_of_day),
+                appraiser_id: None,
+            }),
+            created_at: now,
+            updated_at: now,
+        };
+
+        let address = &property.address;
+        let report = Report {
+            id: Uuid::new_v4(),
+            title: format!(
+                "{} - {}",
+                format_address_single_line(
+                    &address.street1,
+                    address.street2.as_deref(),
+                    &address.city,
+                    &address.state,
+                    &address.postal_code,
+                ),
+                layout_for(&import.report_type).title
+            ),
+            property_id: property.id,
+            appraiser_id,
+            status: ReportStatus::Draft,
+            report_type: import.report_type.clone(),
+            valuation_amount: import.appraised_value,
+            content: import.content,
+            pdf_url: None,
+            created_at: now,
+            updated_at: now,
+            submitted_at: None,
+            reviewed_at: None,
+            reviewer_id: None,
+            review_comments: None,
+            submission_comments: None,
+            version: 1,
+            amends_id: None,
+            supervisor_id: None,
+        };
+
+        let reference_number = query
+            .reference_number
+            .map(|reference| reference.trim().to_string())
+            .filter(|reference| !reference.is_empty())
+            .or(import.file_identifier);
+
+        let appraisal = Appraisal {
+            id: Uuid::new_v4(),
+            reference_number,
+            client_id: query.client_id,
+            property_id: property.id,
+            appraiser_id: None,
+            report_id: Some(report.id),
+            status: AppraisalStatus::Completed,
+            due_date: None,
+            purpose: import.purpose,
+            appraisal_type: match import.report_type {
+                ReportType::Form2055 => AppraisalType::DriveBy,
+                _ => AppraisalType::FullAppraisal,
+            },
+            instructions: None,
+            fee: None,
+            created_at: now,
+            updated_at: now,
+            completed_at: Some(import.signed_on.map_or(now, start_of_day)),
+        };
+
+        if !query.commit.unwrap_or(false) {
+            return Ok(ImportReportResult {
+                committed: false,
+                property,
+                appraisal,
+                report,
+                unmapped: import.unmapped,
+            });
+        }
+
+        let mut report = report;
+        if let Some(pdf) = &import.pdf {
+            self.store.put(&pdf_key(report.id), pdf).await?;
+            report.pdf_url = Some(format!("/api/v1/reports/{}/pdf", report.id));
+        }
+
+        let report = match self.reports.create_imported(&property, &appraisal, &report).await {
+            Ok(report) => report,
+            Err(err) => {
+                if import.pdf.is_some() {
+                    if let Err(cleanup) = self.store.delete(&pdf_key(report.id)).await {
+                        log::warn!("Failed to remove PDF of failed import {}: {:?}", report.id, cleanup);
+                    }
+                }
+                return Err(err);
+            }
+        };
+
+        Ok(ImportReportResult {
+            committed: true,
+            property,
+            appraisal,
+            report,
+            unmapped: import.unmapped,
+        })
+    }
+}
@@ -0,0 +1,58 @@
+pub mod exhibit_service;
+pub mod export_service;
+pub mod import_service;
+pub mod photo_service;
+pub mod preview_service;
+pub mod reconciliation_service;
+pub mod render_service;
+pub mod report_service;
+pub mod review_service;
+pub mod revision_service;
+pub mod share_service;
+pub mod signature_service;
+pub mod snapshot_service;
+pub mod snippet_service;
+
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use shared::error::{AppError, AppResult};
+use shared::models::report::Report;
+use uuid::Uuid;
+
+/// Require an authenticated user
+fn require_user(user_id: Option<String>) -> AppResult<String> {
+    user_id.ok_or_else(|| AppError::Authentication("Authentication required".to_string()))
+}
+
+/// Parse the ID of a user acting as an appraiser; appraiser IDs are UUIDs
+fn parse_appraiser_id(user_id: &str) -> AppResult<Uuid> {
+    Uuid::parse_str(user_id)
+        .map_err(|_| AppError::Validation("Only appraisers can author or review reports".to_string()))
+}
+
+/// Ensure the user authored the report
+fn ensure_author(report: &Report, user_id: &str) -> AppResult<()> {
+    if report.appraiser_id.to_string() != user_id {
+        return Err(AppError::Authorization(
+            "Only the report's appraiser can change it".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Ensure the user is the report's appraiser or its reviewer
+fn ensure_participant(report: &Report, user_id: &str) -> AppResult<()> {
+    let is_appraiser = report.appraiser_id.to_string() == user_id;
+    let is_reviewer = report.reviewer_id.map_or(false, |id| id.to_string() == user_id);
+
+    if !is_appraiser && !is_reviewer {
+        return Err(AppError::Authorization(
+            "Only the report's appraiser or reviewer can access it".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Midnight UTC at the start of a date
+fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap())
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::content::{ReportPhoto, UploadReportPhotoQuery, MAX_REPORT_PHOTO_BYTES};
+use shared::storage::AttachmentStore;
+use uuid::Uuid;
+
+use super::{ensure_author, ensure_participant, require_user};
+use crate::rendering::JpegImage;
+use crate::repository::report_repository::ReportRepository;
+
+/// Service for the photos listed under a report's `content.photos`. Files are stored under the
+/// report's own prefix, the only place renders read photos from.
+pub struct PhotoService {
+    reports: ReportRepository,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl PhotoService {
+    /// Create a new photo service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db),
+            store,
+        }
+    }
+
+    /// Store a JPEG for a report the current user is editing, returning the entry to add to
+    /// its `content.photos`
+    pub async fn upload_photo(
+        &self,
+        report_id: Uuid,
+        query: UploadReportPhotoQuery,
+        bytes: &[u8],
+        user_id: Option<String>,
+    ) -> AppResult<ReportPhoto> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+
+        if !report.status.is_editable() {
+            return Err(AppError::Validation(format!(
+                "Cannot add photos to a report with status {:?}",
+                report.status
+            )));
+        }
+        if bytes.len() > MAX_REPORT_PHOTO_BYTES {
+            return Err(AppError::Validation(format!(
+                "Report photos must be at most {} bytes",
+                MAX_REPORT_PHOTO_BYTES
+            )));
+        }
+        if JpegImage::parse(bytes.to_vec()).is_none() {
+            return Err(AppError::Validation("Report photos must be JPEG images".to_string()));
+        }
+
+        let storage_key = photo_key(report_id, Uuid::new_v4());
+        self.store.put(&storage_key, bytes).await?;
+
+        Ok(ReportPhoto {
+            storage_key: Some(storage_key),
+            label: query.label,
+            caption: query.caption,
+        })
+    }
+
+    /// Load a photo uploaded for a report
+    pub async fn download_photo(&self, report_id: Uuid, photo_id: Uuid, user_id: Option<String>) -> AppResult<Vec<u8>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.store.get(&photo_key(report_id, photo_id)).await
+    }
+}
+
+/// Storage key of a report photo, under the report's own prefix
+fn photo_key(report_id: Uuid, photo_id: Uuid) -> String {
+    format!("reports/{}/photos/{}.jpg", report_id, photo_id)
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream};
+use shared::db::Database;
+use shared::error::AppResult;
+use shared::storage::AttachmentStore;
+use uuid::Uuid;
+
+use super::render_service::RenderService;
+use super::{ensure_participant, require_user};
+use crate::rendering::layout::layout_for;
+use crate::rendering::render_html;
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+
+/// How often a watched report is checked for changes
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// What a watched report's event stream reports after each check
+pub enum PreviewEvent {
+    /// The report was saved at this time
+    Updated(DateTime<Utc>),
+    /// Nothing changed; sent so idle connections stay open and closed ones are noticed
+    Unchanged,
+}
+
+/// Service for previewing reports in the browser while they are edited
+pub struct PreviewService {
+    db: Arc<Database>,
+    reports: ReportRepository,
+    properties: PropertyRepository,
+    renderer: RenderService,
+}
+
+impl PreviewService {
+    /// Create a new preview service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            renderer: RenderService::new(db.clone(), store),
+            db,
+        }
+    }
+
+    /// Render a report to standalone HTML with the layout of its PDF. A live preview reloads
+    /// itself from the report's event stream whenever the report is saved.
+    pub async fn render_preview(&self, id: Uuid, live: bool, user_id: Option<String>) -> AppResult<String> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        let property = self.properties.get_by_id(report.property_id).await?;
+        let document = self.renderer.document(&report, &property).await?;
+        let events_path = format!("/api/v1/reports/{}/preview/events", id);
+
+        Ok(render_html(
+            &document,
+            layout_for(&report.report_type),
+            live.then_some(events_path.as_str()),
+        ))
+    }
+
+    /// Watch a report for saves. The stream checks the report every couple of seconds and ends
+    /// when the report is deleted or can no longer be read.
+    pub async fn watch(&self, id: Uuid, user_id: Option<String>) -> AppResult<impl Stream<Item = PreviewEvent>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        let reports = ReportRepository::new(self.db.clone());
+        Ok(stream::unfold((reports, report.updated_at), move |(reports, seen)| async move {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            match reports.updated_at(id).await {
+                Ok(updated_at) if updated_at != seen => {
+                    Some((PreviewEvent::Updated(updated_at), (reports, updated_at)))
+                }
+                Ok(_) => Some((PreviewEvent::Unchanged, (reports, seen))),
+                Err(err) => {
+                    log::warn!("Stopped watching report {} for preview: {:?}", id, err);
+                    None
+                }
+            }
+        }))
+    }
+}
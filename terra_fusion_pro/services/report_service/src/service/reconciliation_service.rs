@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::{json, Value};
+use shared::content::validate_content;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::property::{PropertyValuation, ValuationMethod};
+use shared::models::reconciliation::{ReconcileReportQuery, ReconcileReportRequest, ReconciliationResult};
+use shared::models::report::ReportStatus;
+use uuid::Uuid;
+
+use super::{ensure_author, require_user, start_of_day};
+use crate::mismo::uad::parse_date;
+use crate::reconciliation::ReconciliationRules;
+use crate::repository::report_repository::ReportRepository;
+
+/// Service for reconciling a report's approaches to value into its final value
+pub struct ReconciliationService {
+    reports: ReportRepository,
+    rules: Arc<ReconciliationRules>,
+}
+
+impl ReconciliationService {
+    /// Create a new reconciliation service
+    pub fn new(db: Arc<Database>, rules: Arc<ReconciliationRules>) -> Self {
+        Self {
+            reports: ReportRepository::new(db),
+            rules,
+        }
+    }
+
+    /// Weigh the approaches developed in a report into a rounded final value. The weights and
+    /// result are recorded in the reconciliation section, the final value becomes the report's
+    /// valuation amount and the property's combined valuation. Nothing is saved unless
+    /// `query.commit` is set.
+    pub async fn reconcile_report(
+        &self,
+        id: Uuid,
+        query: &ReconcileReportQuery,
+        request: ReconcileReportRequest,
+        user_id: Option<String>,
+    ) -> AppResult<ReconciliationResult> {
+        let user_id = require_user(user_id)?;
+        let mut report = self.reports.get_by_id(id).await?;
+        ensure_author(&report, &user_id)?;
+
+        if report.status == ReportStatus::Finalized {
+            return Err(AppError::Validation(format!(
+                "Report {} is finalized and cannot be changed; amend it instead",
+                report.id
+            )));
+        }
+
+        if !report.status.is_editable() {
+            return Err(AppError::Validation(format!(
+                "Cannot edit a report with status {:?}",
+                report.status
+            )));
+        }
+        let current = report.status.clone();
+
+        let reconciliation = self.rules.reconcile(&report.content, &request)?;
+
+        let mut content = report.content.clone();
+        let section = content
+            .as_object_mut()
+            .ok_or_else(|| AppError::Validation("Report content must be a JSON object".to_string()))?
+            .entry("reconciliation")
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .ok_or_else(|| AppError::Validation("Reconciliation section must be a JSON object".to_string()))?;
+
+        for indication in &reconciliation.indications {
+            let key = match indication.method {
+                ValuationMethod::SalesComparison => "sales_comparison_value",
+                ValuationMethod::CostApproach => "cost_value",
+                ValuationMethod::IncomeApproach => "income_value",
+                _ => continue,
+            };
+            if let Some(value) = indication.indicated_value {
+                section.insert(key.to_string(), json!(value));
+            }
+        }
+        section.insert("weights".to_string(), json!(request.weights));
+        section.insert("weighted_value".to_string(), json!(reconciliation.weighted_value));
+        section.insert("final_value".to_string(), json!(reconciliation.final_value));
+        section.insert("spread_percent".to_string(), json!(reconciliation.spread_percent));
+        match request.spread_explanation.as_deref().map(str::trim) {
+            Some(explanation) if !explanation.is_empty() => {
+                section.insert("spread_explanation".to_string(), json!(explanation));
+            }
+            _ => {
+                section.remove("spread_explanation");
+            }
+        }
+
+        let effective_date = section
+            .get("effective_date")
+            .and_then(Value::as_str)
+            .and_then(|date| parse_date(date.trim()));
+
+        validate_content(&report.report_type, &content)?;
+
+        let now = Utc::now();
+        report.content = content;
+        report.valuation_amount = Some(reconciliation.final_value);
+        report.updated_at = now;
+
+        let valuation = PropertyValuation {
+            market_value: reconciliation.final_value,
+            confidence: None,
+            valuation_method: ValuationMethod::Combined,
+            valuation_date: effective_date.map_or(now, start_of_day),
+            appraiser_id: Some(report.appraiser_id),
+        };
+
+        let committed = query.commit.unwrap_or(false);
+        if committed {
+            report = self.reports.reconcile(&report, &current, &valuation).await?;
+        }
+
+        Ok(ReconciliationResult {
+            committed,
+            indications: reconciliation.indications,
+            weighted_value: reconciliation.weighted_value,
+            rounding_increment: reconciliation.rounding_increment,
+            final_value: reconciliation.final_value,
+            spread_percent: reconciliation.spread_percent,
+            max_spread_percent: self.rules.max_spread_percent(),
+            valuation,
+            report,
+        })
+    }
+}
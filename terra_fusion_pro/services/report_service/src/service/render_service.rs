@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use serde_json::Value;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::credential::AppraiserLicense;
+use shared::models::exhibit::ExhibitKind;
+use shared::models::property::Property;
+use shared::models::report::{Report, ReportStatus};
+use shared::models::signature::signature_image_key;
+use shared::repository::credential_repository::CredentialRepository;
+use shared::repository::user_repository::UserRepository;
+use shared::storage::AttachmentStore;
+use shared::utils::format::format_name;
+use uuid::Uuid;
+
+use super::exhibit_service::PDF_MEDIA_TYPE;
+use super::snapshot_service::{snapshot_key, SNAPSHOT_PDF};
+use super::{ensure_participant, require_user};
+use crate::rendering::layout::layout_for;
+use crate::rendering::{render_pdf, Exhibit, ExhibitContent, JpegImage, Photo, ReportDocument, SignatureBlock};
+use crate::repository::exhibit_repository::ExhibitRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+use crate::repository::snapshot_repository::SnapshotRepository;
+
+/// An appraiser signing a report
+pub struct Signer {
+    /// ID of the signing user
+    pub user_id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// License the report is signed under (if any)
+    pub license: Option<AppraiserLicense>,
+
+    /// Date printed with the signature
+    pub signed_on: Option<NaiveDate>,
+}
+
+/// Service for rendering reports to PDF
+pub struct RenderService {
+    reports: ReportRepository,
+    properties: PropertyRepository,
+    snapshots: SnapshotRepository,
+    exhibits: ExhibitRepository,
+    users: UserRepository,
+    credentials: CredentialRepository,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl RenderService {
+    /// Create a new render service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            snapshots: SnapshotRepository::new(db.clone()),
+            exhibits: ExhibitRepository::new(db.clone()),
+            users: UserRepository::new(db.clone()),
+            credentials: CredentialRepository::new(db),
+            store,
+        }
+    }
+
+    /// Render a report to PDF, store it and record its URL on the report
+    pub async fn render_pdf(&self, id: Uuid, user_id: Option<String>) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        if report.status == ReportStatus::Finalized {
+            return Err(AppError::Validation(
+                "A finalized report keeps the PDF it was delivered with; amend it to make changes".to_string(),
+            ));
+        }
+
+        let property = self.properties.get_by_id(report.property_id).await?;
+        let pdf = self.render(&report, &property).await?;
+
+        self.store.put(&pdf_key(id), &pdf).await?;
+        self.reports.set_pdf_url(id, &format!("/api/v1/reports/{}/pdf", id)).await
+    }
+
+    /// Load the most recently rendered PDF of a report, or the delivered PDF once it is finalized
+    pub async fn download_pdf(&self, id: Uuid, user_id: Option<String>) -> AppResult<Vec<u8>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        if report.status == ReportStatus::Finalized {
+            return self.delivered_pdf(id).await;
+        }
+
+        if report.pdf_url.is_none() {
+            return Err(AppError::NotFound(format!("Report {} has not been rendered", id)));
+        }
+
+        self.store.get(&pdf_key(id)).await
+    }
+
+    /// The PDF stored in a finalized report's snapshot
+    pub async fn delivered_pdf(&self, id: Uuid) -> AppResult<Vec<u8>> {
+        let snapshot = self.snapshots.get_by_report(id).await?;
+        self.store.get(&snapshot_key(id, snapshot.id, SNAPSHOT_PDF)).await
+    }
+
+    /// Render a report to PDF without storing it
+    pub async fn render(&self, report: &Report, property: &Property) -> AppResult<Vec<u8>> {
+        let document = self.document(report, property).await?;
+        render_pdf(&document, layout_for(&report.report_type))
+    }
+
+    /// Gather everything printed in a report: its photos, signature blocks and exhibits
+    pub async fn document<'a>(&self, report: &'a Report, property: &'a Property) -> AppResult<ReportDocument<'a>> {
+        let mut signatures = vec![self.signature_block("Appraiser", self.signer(report, property).await?).await?];
+        if let Some(supervisor) = self.supervisor(report, property).await? {
+            signatures.push(self.signature_block("Supervisory Appraiser", supervisor).await?);
+        }
+
+        Ok(ReportDocument {
+            report,
+            property,
+            photos: self.load_photos(report).await?,
+            signatures,
+            exhibits: self.load_exhibits(report).await?,
+        })
+    }
+
+    /// Signature block of a signer, with their signature image if they have uploaded one
+    async fn signature_block(&self, title: &str, signer: Signer) -> AppResult<SignatureBlock> {
+        let image = match self.store.get(&signature_image_key(&signer.user_id)).await {
+            Ok(bytes) => JpegImage::parse(bytes).or_else(|| {
+                log::warn!("Signature image of user {} is not a JPEG; leaving the line blank", signer.user_id);
+                None
+            }),
+            Err(AppError::NotFound(_)) => None,
+            Err(err) => return Err(err),
+        };
+
+        Ok(SignatureBlock {
+            title: title.to_string(),
+            appraiser_name: signer.name,
+            image,
+            license: signer.license.map(|license| format!("{} {}", license.state, license.license_number)),
+            signed_on: signer.signed_on,
+        })
+    }
+
+    /// Load the photos listed under `content.photos`. Photos must be JPEGs stored under the report's
+    /// own storage prefix, so a report cannot pull in files attached elsewhere.
+    async fn load_photos(&self, report: &Report) -> AppResult<Vec<Photo>> {
+        let entries = match report.content.get("photos") {
+            Some(Value::Array(entries)) => entries.as_slice(),
+            Some(_) => return Err(AppError::Validation("Report photos must be a list".to_string())),
+            None => return Ok(Vec::new()),
+        };
+
+        let prefix = format!("reports/{}/", report.id);
+        let mut photos = Vec::with_capacity(entries.len());
+
+        for (index, entry) in entries.iter().enumerate() {
+            let storage_key = entry
+                .get("storage_key")
+                .and_then(Value::as_str)
+                .filter(|key| key.starts_with(&prefix))
+                .ok_or_else(|| {
+                    AppError::Validation(format!("Photo {} must reference a file stored under {}", index + 1, prefix))
+                })?;
+
+            let label = entry
+                .get("label")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("Photo {}", index + 1));
+
+            let bytes = self.store.get(storage_key).await?;
+            let image = JpegImage::parse(bytes)
+                .ok_or_else(|| AppError::Validation(format!("Photo \"{}\" must be a JPEG image", label)))?;
+
+            photos.push(Photo {
+                label,
+                caption: entry.get("caption").and_then(Value::as_str).map(str::to_string),
+                image,
+            });
+        }
+
+        Ok(photos)
+    }
+
+    /// Load a report's exhibits in print order, titling comparable photos by comparable sale
+    async fn load_exhibits(&self, report: &Report) -> AppResult<Vec<Exhibit>> {
+        let exhibits = self.exhibits.for_report(report.id).await?;
+        let mut loaded = Vec::with_capacity(exhibits.len());
+
+        for exhibit in exhibits {
+            let title = match (exhibit.kind, exhibit.comparable_number) {
+                (ExhibitKind::ComparablePhoto, Some(number)) => format!("Comparable {}", number),
+                (kind, _) => kind.title().to_string(),
+            };
+
+            let bytes = self.store.get(&exhibit.storage_key).await?;
+            let content = if exhibit.media_type == PDF_MEDIA_TYPE {
+                ExhibitContent::Pdf {
+                    page_count: exhibit.page_count.unwrap_or_default() as usize,
+                    bytes,
+                }
+            } else {
+                let image = JpegImage::parse(bytes).ok_or_else(|| {
+                    AppError::Validation(format!("Exhibit \"{}\" is not a readable JPEG image", title))
+                })?;
+                ExhibitContent::Image(image)
+            };
+
+            loaded.push(Exhibit {
+                title,
+                caption: exhibit.caption,
+                content,
+            });
+        }
+
+        Ok(loaded)
+    }
+
+    /// The report's appraiser, with the license held in the subject's state on the date the
+    /// report was submitted
+    pub async fn signer(&self, report: &Report, property: &Property) -> AppResult<Signer> {
+        let appraiser_id = report.appraiser_id.to_string();
+        let license = match report.submitted_at {
+            Some(submitted_at) => self
+                .credentials
+                .licenses_for_user(&appraiser_id)
+                .await?
+                .into_iter()
+                .find(|license| {
+                    license.state.eq_ignore_ascii_case(&property.address.state)
+                        && license.is_active_on(submitted_at.date_naive())
+                }),
+            None => None,
+        };
+
+        Ok(Signer {
+            name: self.display_name(&appraiser_id).await?,
+            user_id: appraiser_id,
+            license,
+            signed_on: report.submitted_at.map(|at| at.date_naive()),
+        })
+    }
+
+    /// The report's reviewer, when they held a license in the subject's state on the date of the
+    /// review that qualifies them to supervise and co-sign it
+    pub async fn supervisor(&self, report: &Report, property: &Property) -> AppResult<Option<Signer>> {
+        let (reviewer_id, reviewed_at) = match (report.reviewer_id, report.reviewed_at) {
+            (Some(reviewer_id), Some(reviewed_at)) => (reviewer_id.to_string(), reviewed_at),
+            _ => return Ok(None),
+        };
+
+        let license = self
+            .credentials
+            .licenses_for_user(&reviewer_id)
+            .await?
+            .into_iter()
+            .find(|license| {
+                license.level.can_supervise()
+                    && license.state.eq_ignore_ascii_case(&property.address.state)
+                    && license.is_active_on(reviewed_at.date_naive())
+            });
+
+        match license {
+            Some(license) => Ok(Some(Signer {
+                name: self.display_name(&reviewer_id).await?,
+                user_id: reviewer_id,
+                license: Some(license),
+                signed_on: Some(reviewed_at.date_naive()),
+            })),
+            None => Ok(None),
+        }
+    }
+
+    /// Name printed for a user, falling back to their email and then their ID
+    async fn display_name(&self, user_id: &str) -> AppResult<String> {
+        match self.users.get_by_id(user_id.to_string()).await {
+            Ok(user) => Ok(match (user.first_name, user.last_name) {
+                (Some(first), Some(last)) => format_name(&first, &last),
+                (Some(name), None) | (None, Some(name)) => name,
+                (None, None) => user.email.unwrap_or_else(|| user_id.to_string()),
+            }),
+            Err(AppError::NotFound(_)) => Ok(user_id.to_string()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Storage key of a report's rendered PDF
+pub(super) fn pdf_key(id: Uuid) -> String {
+    format!("reports/{}/report.pdf", id)
+}
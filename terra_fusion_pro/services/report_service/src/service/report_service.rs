@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::auth::access::AccessControl;
+use shared::content::validate_content;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::report::{
+    CreateReportRequest, Report, ReportQuery, ReportStatus, ReportSummary, UpdateReportRequest,
+};
+use uuid::Uuid;
+
+use super::{ensure_author, ensure_participant, parse_appraiser_id, require_user};
+use crate::repository::report_repository::ReportRepository;
+
+/// Default number of reports per page
+const DEFAULT_PAGE_SIZE: i64 = 20;
+
+/// Largest page of reports a caller may request
+const MAX_PAGE_SIZE: i64 = 100;
+
+/// Service for drafting and managing appraisal reports
+pub struct ReportService {
+    reports: ReportRepository,
+    access: AccessControl,
+}
+
+impl ReportService {
+    /// Create a new report service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            access: AccessControl::new(db),
+        }
+    }
+
+    /// List report summaries matching a query. Staff see every report; anyone else only the
+    /// reports they authored or review.
+    pub async fn list_reports(&self, query: &ReportQuery, user_id: Option<String>) -> AppResult<Vec<ReportSummary>> {
+        let user_id = require_user(user_id)?;
+        let participant = if self.access.is_staff(&user_id).await? {
+            None
+        } else {
+            Some(user_id.as_str())
+        };
+
+        let page = query.page.unwrap_or(1);
+        if page < 1 {
+            return Err(AppError::Validation("Page must be at least 1".to_string()));
+        }
+
+        let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+            return Err(AppError::Validation(format!(
+                "Limit must be between 1 and {}",
+                MAX_PAGE_SIZE
+            )));
+        }
+
+        self.reports.find_summaries(query, participant, limit, (page - 1) * limit).await
+    }
+
+    /// Find a report by ID, visible to its appraiser, its reviewer and staff
+    pub async fn find_report_by_id(&self, id: Uuid, user_id: Option<String>) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+
+        if ensure_participant(&report, &user_id).is_err() && !self.access.is_staff(&user_id).await? {
+            return Err(AppError::Authorization(
+                "Only the report's appraiser, its reviewer or staff can view it".to_string(),
+            ));
+        }
+
+        Ok(report)
+    }
+
+    /// Start a draft report authored by the current user
+    pub async fn create_report(&self, request: CreateReportRequest, user_id: Option<String>) -> AppResult<Report> {
+        let appraiser_id = parse_appraiser_id(&require_user(user_id)?)?;
+
+        let title = request.title.trim();
+        if title.is_empty() {
+            return Err(AppError::Validation("Report title is required".to_string()));
+        }
+
+        if !self.reports.property_exists(request.property_id).await? {
+            return Err(AppError::NotFound(format!(
+                "Property not found with ID: {}",
+                request.property_id
+            )));
+        }
+
+        let content = request.content.unwrap_or_else(|| serde_json::json!({}));
+        validate_content(&request.report_type, &content)?;
+
+        let now = Utc::now();
+        let report = Report {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            property_id: request.property_id,
+            appraiser_id,
+            status: ReportStatus::Draft,
+            report_type: request.report_type,
+            valuation_amount: None,
+            content,
+            pdf_url: None,
+            created_at: now,
+            updated_at: now,
+            submitted_at: None,
+            reviewed_at: None,
+            reviewer_id: None,
+            review_comments: None,
+            submission_comments: None,
+            version: 1,
+            amends_id: None,
+            supervisor_id: None,
+        };
+
+        self.reports.create(&report).await
+    }
+
+    /// Update a report the current user authored while it is still editable
+    pub async fn update_report(
+        &self,
+        id: Uuid,
+        request: UpdateReportRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let mut report = self.reports.get_by_id(id).await?;
+        ensure_author(&report, &user_id)?;
+
+        if report.status == ReportStatus::Finalized {
+            return Err(AppError::Validation(format!(
+                "Report {} is finalized and cannot be changed; amend it instead",
+                report.id
+            )));
+        }
+
+        if !report.status.is_editable() {
+            return Err(AppError::Validation(format!(
+                "Cannot edit a report with status {:?}",
+                report.status
+            )));
+        }
+        let current = report.status.clone();
+
+        if let Some(title) = request.title {
+            let title = title.trim();
+            if title.is_empty() {
+                return Err(AppError::Validation("Report title is required".to_string()));
+            }
+            report.title = title.to_string();
+        }
+
+        if let Some(status) = request.status {
+            let editable = status.is_editable() && report.status.can_transition_to(&status);
+            if status != report.status && !editable {
+                return Err(AppError::Validation(format!(
+                    "Report status cannot be changed from {:?} to {:?} by editing",
+                    report.status, status
+                )));
+            }
+            report.status = status;
+        }
+
+        if let Some(amount) = request.valuation_amount {
+            if !amount.is_finite() || amount <= 0.0 {
+                return Err(AppError::Validation("Valuation amount must be positive".to_string()));
+            }
+            report.valuation_amount = Some(amount);
+        }
+
+        if let Some(content) = request.content {
+            validate_content(&report.report_type, &content)?;
+            report.content = content;
+        }
+
+        report.updated_at = Utc::now();
+        self.reports.update_if_status(&report, &current).await
+    }
+
+    /// Delete a draft report the current user authored
+    pub async fn delete_report(&self, id: Uuid, user_id: Option<String>) -> AppResult<()> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_author(&report, &user_id)?;
+
+        if report.status != ReportStatus::Draft {
+            return Err(AppError::Validation("Only draft reports can be deleted".to_string()));
+        }
+
+        self.reports.delete(id).await
+    }
+}
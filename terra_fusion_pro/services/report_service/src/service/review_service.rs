@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::exhibit::ExhibitCheck;
+use shared::models::report::{Report, ReportStatus, ReviewReportRequest, SubmitReportRequest};
+use shared::models::uad::UadValidation;
+use shared::storage::AttachmentStore;
+use shared::uad::validate_report;
+use uuid::Uuid;
+
+use super::snapshot_service::SnapshotService;
+use super::{ensure_author, ensure_participant, parse_appraiser_id, require_user};
+use crate::repository::exhibit_repository::ExhibitRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+
+/// Service for moving reports through submission, review and finalization
+pub struct ReviewService {
+    reports: ReportRepository,
+    properties: PropertyRepository,
+    exhibits: ExhibitRepository,
+    snapshots: SnapshotService,
+}
+
+impl ReviewService {
+    /// Create a new review service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            exhibits: ExhibitRepository::new(db.clone()),
+            snapshots: SnapshotService::new(db, store),
+        }
+    }
+
+    /// Check a report against the UAD specification without changing it
+    pub async fn validate_uad(&self, id: Uuid, user_id: Option<String>) -> AppResult<UadValidation> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.uad_validation(&report).await
+    }
+
+    /// Submit a report for review on behalf of its appraiser, recording the submitted content as
+    /// a new revision
+    pub async fn submit_report(
+        &self,
+        id: Uuid,
+        request: SubmitReportRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let mut report = self.reports.get_by_id(id).await?;
+        ensure_author(&report, &user_id)?;
+
+        let current = transition(&mut report, ReportStatus::Submitted)?;
+        if report.valuation_amount.is_none() {
+            return Err(AppError::Validation(
+                "A valuation amount is required before submitting".to_string(),
+            ));
+        }
+
+        let uad = self.uad_validation(&report).await?;
+        if !uad.is_compliant() {
+            let problems = uad
+                .errors
+                .iter()
+                .map(|finding| format!("{}: {}", finding.path, finding.message))
+                .collect::<Vec<_>>();
+            return Err(AppError::Validation(format!(
+                "Report has {} UAD error(s) to correct before submitting: {}",
+                problems.len(),
+                problems.join("; ")
+            )));
+        }
+
+        let exhibits = ExhibitCheck::evaluate(&report.report_type, &self.exhibits.for_report(id).await?);
+        if !exhibits.complete {
+            let missing = exhibits
+                .missing
+                .iter()
+                .map(|shortfall| format!("{} ({} of {})", shortfall.kind.title(), shortfall.present, shortfall.required))
+                .collect::<Vec<_>>();
+            return Err(AppError::Validation(format!(
+                "Report is missing exhibits required before submitting: {}",
+                missing.join(", ")
+            )));
+        }
+
+        let now = Utc::now();
+        report.submitted_at = Some(now);
+        report.submission_comments = request
+            .comments
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        report.reviewer_id = None;
+        report.reviewed_at = None;
+        report.updated_at = now;
+
+        self.reports.submit(&report, &current).await
+    }
+
+    /// Claim a submitted report for review by the current user
+    pub async fn claim_review(&self, id: Uuid, user_id: Option<String>) -> AppResult<Report> {
+        let reviewer_id = parse_appraiser_id(&require_user(user_id)?)?;
+        let mut report = self.reports.get_by_id(id).await?;
+
+        if report.appraiser_id == reviewer_id {
+            return Err(AppError::Authorization(
+                "Appraisers cannot review their own reports".to_string(),
+            ));
+        }
+
+        let current = transition(&mut report, ReportStatus::UnderReview)?;
+        report.reviewer_id = Some(reviewer_id);
+        report.updated_at = Utc::now();
+
+        self.reports.update_if_status(&report, &current).await
+    }
+
+    /// Record the outcome of a review the current user claimed
+    pub async fn review_report(
+        &self,
+        id: Uuid,
+        request: ReviewReportRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let mut report = self.reports.get_by_id(id).await?;
+
+        if report.reviewer_id.map_or(true, |reviewer| reviewer.to_string() != user_id) {
+            return Err(AppError::Authorization(
+                "Only the reviewer who claimed the report can review it".to_string(),
+            ));
+        }
+
+        if !request.status.is_review_outcome() {
+            return Err(AppError::Validation(format!(
+                "{:?} is not a review outcome",
+                request.status
+            )));
+        }
+
+        let comments = request.comments.trim().to_string();
+        if comments.is_empty() && request.status != ReportStatus::Approved {
+            return Err(AppError::Validation(
+                "Comments are required when a report is not approved".to_string(),
+            ));
+        }
+
+        let current = transition(&mut report, request.status)?;
+        let now = Utc::now();
+        report.reviewed_at = Some(now);
+        report.review_comments = Some(comments).filter(|c| !c.is_empty());
+        report.updated_at = now;
+
+        self.reports.update_if_status(&report, &current).await
+    }
+
+    /// Finalize an approved report on behalf of its appraiser, freezing it in a snapshot
+    pub async fn finalize_report(&self, id: Uuid, user_id: Option<String>) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let mut report = self.reports.get_by_id(id).await?;
+        ensure_author(&report, &user_id)?;
+
+        let current = transition(&mut report, ReportStatus::Finalized)?;
+        report.updated_at = Utc::now();
+        report.pdf_url = Some(format!("/api/v1/reports/{}/pdf", report.id));
+
+        self.snapshots.finalize(&report, &current, report.appraiser_id).await
+    }
+
+    /// UAD findings for a report's content and the property record it describes
+    async fn uad_validation(&self, report: &Report) -> AppResult<UadValidation> {
+        let property = self.properties.get_by_id(report.property_id).await?;
+        Ok(validate_report(&report.report_type, &report.content, Some(&property.characteristics)))
+    }
+}
+
+/// Move a report to `next`, returning the status it left
+fn transition(report: &mut Report, next: ReportStatus) -> AppResult<ReportStatus> {
+    if !report.status.can_transition_to(&next) {
+        return Err(AppError::Validation(format!(
+            "Cannot move a report from {:?} to {:?}",
+            report.status, next
+        )));
+    }
+
+    Ok(std::mem::replace(&mut report.status, next))
+}
@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::report::{ReportRevision, RevisionDiff, RevisionDiffQuery};
+use uuid::Uuid;
+
+use super::{ensure_participant, require_user};
+use crate::diff::{diff_adjustments, diff_amount, diff_content};
+use crate::rendering::layout::layout_for;
+use crate::repository::report_repository::ReportRepository;
+use crate::repository::revision_repository::RevisionRepository;
+
+/// Service for the content revisions recorded at each submission and what changed between them
+pub struct RevisionService {
+    reports: ReportRepository,
+    revisions: RevisionRepository,
+}
+
+impl RevisionService {
+    /// Create a new revision service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            revisions: RevisionRepository::new(db),
+        }
+    }
+
+    /// List the submitted revisions of a report, oldest first
+    pub async fn list_revisions(&self, id: Uuid, user_id: Option<String>) -> AppResult<Vec<ReportRevision>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.revisions.for_report(id).await
+    }
+
+    /// Compare two submitted revisions of a report. By default the latest submission is compared
+    /// with the one before it, which after a resubmission is what the reviewer sent back.
+    pub async fn diff_revisions(
+        &self,
+        id: Uuid,
+        query: &RevisionDiffQuery,
+        user_id: Option<String>,
+    ) -> AppResult<RevisionDiff> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        let revisions = self.revisions.for_report(id).await?;
+
+        let latest = revisions
+            .last()
+            .ok_or_else(|| AppError::NotFound(format!("Report {} has not been submitted", id)))?;
+        let to = query.to.unwrap_or(latest.revision);
+        let from = query.from.unwrap_or(to - 1);
+        if from >= to {
+            return Err(AppError::Validation(
+                "The revision to compare from must be earlier than the revision to compare to".to_string(),
+            ));
+        }
+
+        let find = |number: i32| {
+            revisions
+                .iter()
+                .find(|revision| revision.revision == number)
+                .ok_or_else(|| AppError::NotFound(format!("Report {} has no revision {}", id, number)))
+        };
+        let before = find(from)?;
+        let after = find(to)?;
+
+        Ok(RevisionDiff {
+            report_id: id,
+            from_revision: before.revision,
+            to_revision: after.revision,
+            from_submitted_at: before.submitted_at,
+            to_submitted_at: after.submitted_at,
+            valuation_amount: diff_amount(before.valuation_amount, after.valuation_amount),
+            adjustments: diff_adjustments(&before.content, &after.content),
+            changes: diff_content(layout_for(&report.report_type), &before.content, &after.content),
+        })
+    }
+}
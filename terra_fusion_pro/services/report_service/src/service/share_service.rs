@@ -0,0 +1,307 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::report::ReportStatus;
+use shared::models::share::{
+    CreateShareLinkRequest, CreatedShareLink, ShareAccessOutcome, ShareLink, ShareLinkAccess,
+};
+use shared::storage::AttachmentStore;
+use uuid::Uuid;
+
+use super::snapshot_service::{SnapshotService, SNAPSHOT_PDF};
+use super::{ensure_author, ensure_participant, parse_appraiser_id, require_user};
+use crate::repository::report_repository::ReportRepository;
+use crate::repository::share_link_repository::ShareLinkRepository;
+use crate::repository::snapshot_repository::SnapshotRepository;
+use crate::sharing::{hash_password, token_link_id, verify_password, ShareLinkSigner};
+
+/// Lifetime of a share link when none is requested
+const DEFAULT_EXPIRY_HOURS: i64 = 7 * 24;
+
+/// Longest lifetime a share link may be given
+const MAX_EXPIRY_HOURS: i64 = 30 * 24;
+
+/// Shortest password accepted for a protected link
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Wrong passwords a protected link accepts within the lockout window before refusing all tries
+const MAX_PASSWORD_REJECTIONS: i64 = 5;
+
+/// Window over which wrong passwords are counted, in minutes
+const PASSWORD_LOCKOUT_MINUTES: i64 = 15;
+
+/// A request to open a share link, as recorded in its access log
+pub struct ShareLinkVisit {
+    /// IP address the request came from (optional)
+    pub ip_address: Option<String>,
+
+    /// User agent of the request (optional)
+    pub user_agent: Option<String>,
+
+    /// Password entered by the recipient (optional)
+    pub password: Option<String>,
+}
+
+/// An artifact downloaded through a share link
+pub struct SharedArtifact {
+    /// ID of the shared report
+    pub report_id: Uuid,
+
+    /// Artifact name, e.g. "report.pdf"
+    pub name: String,
+
+    /// MIME type
+    pub media_type: String,
+
+    /// File contents
+    pub bytes: Vec<u8>,
+}
+
+/// Service for delivering finalized reports through signed, expiring share links
+pub struct ShareService {
+    reports: ReportRepository,
+    snapshots: SnapshotRepository,
+    links: ShareLinkRepository,
+    artifacts: SnapshotService,
+    signer: Arc<ShareLinkSigner>,
+}
+
+impl ShareService {
+    /// Create a new share service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>, signer: Arc<ShareLinkSigner>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            snapshots: SnapshotRepository::new(db.clone()),
+            links: ShareLinkRepository::new(db.clone()),
+            artifacts: SnapshotService::new(db, store),
+            signer,
+        }
+    }
+
+    /// Share an artifact of a finalized report the current user authored
+    pub async fn create_link(
+        &self,
+        report_id: Uuid,
+        request: CreateShareLinkRequest,
+        user_id: Option<String>,
+    ) -> AppResult<CreatedShareLink> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+
+        if report.status != ReportStatus::Finalized {
+            return Err(AppError::Validation("Only finalized reports can be shared".to_string()));
+        }
+
+        let snapshot = self.snapshots.get_by_report(report_id).await?;
+        let artifact = request.artifact.unwrap_or_else(|| SNAPSHOT_PDF.to_string());
+        if !snapshot.manifest.artifacts.iter().any(|delivered| delivered.name == artifact) {
+            return Err(AppError::Validation(format!(
+                "Report {} has no artifact named {}",
+                report_id, artifact
+            )));
+        }
+
+        let hours = request.expires_in_hours.unwrap_or(DEFAULT_EXPIRY_HOURS);
+        if !(1..=MAX_EXPIRY_HOURS).contains(&hours) {
+            return Err(AppError::Validation(format!(
+                "Share links must expire within 1 to {} hours",
+                MAX_EXPIRY_HOURS
+            )));
+        }
+
+        if request.max_downloads.is_some_and(|max| max < 1) {
+            return Err(AppError::Validation("Download limit must be at least 1".to_string()));
+        }
+
+        let password_hash = match request.password {
+            Some(password) if password.chars().count() < MIN_PASSWORD_LENGTH => {
+                return Err(AppError::Validation(format!(
+                    "Share link passwords must be at least {} characters",
+                    MIN_PASSWORD_LENGTH
+                )))
+            }
+            Some(password) => Some(hash_password(&password)?),
+            None => None,
+        };
+
+        let recipient_email = non_empty(request.recipient_email);
+        if recipient_email.as_deref().is_some_and(|email| !email.contains('@')) {
+            return Err(AppError::Validation("Recipient email address is not valid".to_string()));
+        }
+
+        let now = Utc::now();
+        let link = ShareLink {
+            id: Uuid::new_v4(),
+            report_id,
+            artifact,
+            recipient_role: request.recipient_role,
+            recipient_name: non_empty(request.recipient_name),
+            recipient_email,
+            password_protected: password_hash.is_some(),
+            password_hash,
+            expires_at: now + Duration::hours(hours),
+            max_downloads: request.max_downloads,
+            download_count: 0,
+            first_downloaded_at: None,
+            created_by: parse_appraiser_id(&user_id)?,
+            created_at: now,
+            revoked_at: None,
+        };
+
+        let link = self.links.create(&link).await?;
+        let path = format!("/api/v1/shared/{}", self.signer.token(&link));
+
+        Ok(CreatedShareLink { link, path })
+    }
+
+    /// List the share links of a report
+    pub async fn list_links(&self, report_id: Uuid, user_id: Option<String>) -> AppResult<Vec<ShareLink>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.links.for_report(report_id).await
+    }
+
+    /// Revoke a share link of a report the current user authored
+    pub async fn revoke_link(&self, report_id: Uuid, link_id: Uuid, user_id: Option<String>) -> AppResult<ShareLink> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_author(&report, &user_id)?;
+
+        self.link_of(report_id, link_id).await?;
+        self.links.revoke(link_id, Utc::now()).await
+    }
+
+    /// List every attempt to open a share link, including refused ones
+    pub async fn list_accesses(
+        &self,
+        report_id: Uuid,
+        link_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<Vec<ShareLinkAccess>> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.link_of(report_id, link_id).await?;
+        self.links.accesses(link_id).await
+    }
+
+    /// Download the artifact behind a share link token. Every attempt on a genuine link is
+    /// logged, whether or not it is allowed; tokens that do not verify are refused as unknown.
+    pub async fn open_link(&self, token: &str, visit: ShareLinkVisit) -> AppResult<SharedArtifact> {
+        let unknown = || AppError::NotFound("Share link not found".to_string());
+
+        let link_id = token_link_id(token).ok_or_else(unknown)?;
+        let link = match self.links.get_by_id(link_id).await {
+            Ok(link) => link,
+            Err(AppError::NotFound(_)) => return Err(unknown()),
+            Err(err) => return Err(err),
+        };
+        if !self.signer.verify(&link, token) {
+            return Err(unknown());
+        }
+
+        let now = Utc::now();
+        let access = |outcome| ShareLinkAccess {
+            id: Uuid::new_v4(),
+            link_id: link.id,
+            outcome,
+            ip_address: visit.ip_address.clone(),
+            user_agent: visit.user_agent.clone(),
+            accessed_at: now,
+        };
+
+        if let Some(outcome) = refusal(&link, now) {
+            return self.refuse(&access(outcome)).await;
+        }
+
+        if let Some(hash) = &link.password_hash {
+            // Refuse before checking the password, so a locked link gives a guesser nothing
+            let since = now - Duration::minutes(PASSWORD_LOCKOUT_MINUTES);
+            if self.links.count_password_rejections(link.id, since).await? >= MAX_PASSWORD_REJECTIONS {
+                return self.refuse(&access(ShareAccessOutcome::Locked)).await;
+            }
+
+            let matches = visit
+                .password
+                .as_deref()
+                .is_some_and(|password| verify_password(password, hash));
+            if !matches {
+                return self.refuse(&access(ShareAccessOutcome::PasswordRejected)).await;
+            }
+        }
+
+        let snapshot = self.snapshots.get_by_report(link.report_id).await?;
+        let (bytes, media_type) = self.artifacts.load_artifact(&snapshot, &link.artifact).await?;
+
+        if self.links.claim_download(&access(ShareAccessOutcome::Downloaded)).await?.is_none() {
+            // The link was revoked, expired or used up since it was read
+            let current = self.links.get_by_id(link.id).await?;
+            let outcome = refusal(&current, now).unwrap_or(ShareAccessOutcome::LimitReached);
+            return self.refuse(&access(outcome)).await;
+        }
+
+        Ok(SharedArtifact {
+            report_id: link.report_id,
+            name: link.artifact,
+            media_type,
+            bytes,
+        })
+    }
+
+    /// Get a share link, checking it belongs to the report
+    async fn link_of(&self, report_id: Uuid, link_id: Uuid) -> AppResult<ShareLink> {
+        let link = self.links.get_by_id(link_id).await?;
+        if link.report_id != report_id {
+            return Err(AppError::NotFound(format!(
+                "Report {} has no share link with ID: {}",
+                report_id, link_id
+            )));
+        }
+        Ok(link)
+    }
+
+    /// Log a refused attempt to open a link and return the error the recipient sees
+    async fn refuse<T>(&self, access: &ShareLinkAccess) -> AppResult<T> {
+        self.links.record_access(access).await?;
+
+        Err(match access.outcome {
+            ShareAccessOutcome::PasswordRejected => {
+                AppError::Authentication("This share link needs the correct password".to_string())
+            }
+            ShareAccessOutcome::Expired => AppError::Authorization("This share link has expired".to_string()),
+            ShareAccessOutcome::Revoked => AppError::Authorization("This share link has been revoked".to_string()),
+            ShareAccessOutcome::LimitReached => {
+                AppError::Authorization("This share link has reached its download limit".to_string())
+            }
+            ShareAccessOutcome::Locked => AppError::Authorization(format!(
+                "Too many wrong passwords for this share link; try again in {} minutes",
+                PASSWORD_LOCKOUT_MINUTES
+            )),
+            ShareAccessOutcome::Downloaded => AppError::General("A download is not a refusal".to_string()),
+        })
+    }
+}
+
+/// Why a link no longer allows downloads, if it does not
+fn refusal(link: &ShareLink, now: DateTime<Utc>) -> Option<ShareAccessOutcome> {
+    if link.revoked_at.is_some() {
+        Some(ShareAccessOutcome::Revoked)
+    } else if link.expires_at <= now {
+        Some(ShareAccessOutcome::Expired)
+    } else if link.max_downloads.is_some_and(|max| link.download_count >= max) {
+        Some(ShareAccessOutcome::LimitReached)
+    } else {
+        None
+    }
+}
+
+/// Trimmed text, or `None` when blank
+fn non_empty(text: Option<String>) -> Option<String> {
+    text.map(|text| text.trim().to_string()).filter(|text| !text.is_empty())
+}
@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::credential::LicenseLevel;
+use shared::models::report::{Report, ReportStatus};
+use shared::models::signature::{
+    AssignSupervisorRequest, ReportSignature, SignReportRequest, SignatureCheck, SignaturePayload,
+    SignatureVerification, SignerRole,
+};
+use shared::repository::credential_repository::CredentialRepository;
+use shared::repository::signing_key_repository::SigningKeyRepository;
+use shared::signing::{self, KeyVault};
+use shared::utils::canonical_json::to_canonical_json;
+use uuid::Uuid;
+
+use super::snapshot_service::sha256_hex;
+use super::{ensure_author, require_user};
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+use crate::repository::signature_repository::SignatureRepository;
+use crate::repository::snapshot_repository::SnapshotRepository;
+
+/// Service for signing finalized reports and verifying their signatures
+pub struct SignatureService {
+    reports: ReportRepository,
+    snapshots: SnapshotRepository,
+    properties: PropertyRepository,
+    signatures: SignatureRepository,
+    keys: SigningKeyRepository,
+    credentials: CredentialRepository,
+    vault: Arc<KeyVault>,
+}
+
+impl SignatureService {
+    /// Create a new signature service
+    pub fn new(db: Arc<Database>, vault: Arc<KeyVault>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            snapshots: SnapshotRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            signatures: SignatureRepository::new(db.clone()),
+            keys: SigningKeyRepository::new(db.clone()),
+            credentials: CredentialRepository::new(db),
+            vault,
+        }
+    }
+
+    /// Name the supervisory appraiser who co-signs a report. Only the report's appraiser may,
+    /// and only until the report has a supervisor signature.
+    pub async fn assign_supervisor(
+        &self,
+        id: Uuid,
+        request: AssignSupervisorRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_author(&report, &user_id)?;
+
+        if request.supervisor_id == report.appraiser_id {
+            return Err(AppError::Validation(
+                "The report's appraiser cannot supervise their own report".to_string(),
+            ));
+        }
+
+        if self.signatures.for_report(id).await?.iter().any(|s| s.payload.role == SignerRole::Supervisor) {
+            return Err(AppError::Conflict(format!(
+                "Report {} has already been co-signed by its supervisor",
+                id
+            )));
+        }
+
+        self.reports.assign_supervisor(id, request.supervisor_id).await
+    }
+
+    /// Sign a finalized report with the current user's key. The signature covers the snapshot's
+    /// manifest hash, the signer, the license they sign under and the time of signing. The
+    /// appraiser signs as `Appraiser`; the assigned supervisor co-signs as `Supervisor`.
+    pub async fn sign_report(
+        &self,
+        id: Uuid,
+        request: SignReportRequest,
+        user_id: Option<String>,
+    ) -> AppResult<ReportSignature> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+
+        match request.role {
+            SignerRole::Appraiser if report.appraiser_id.to_string() != user_id => {
+                return Err(AppError::Authorization(
+                    "Only the report's appraiser can sign it as the appraiser".to_string(),
+                ));
+            }
+            SignerRole::Supervisor
+                if !report.supervisor_id.is_some_and(|supervisor| supervisor.to_string() == user_id) =>
+            {
+                return Err(AppError::Authorization(
+                    "Only the report's assigned supervisor can co-sign it as supervisor".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        if report.status != ReportStatus::Finalized {
+            return Err(AppError::Validation("Only finalized reports can be signed".to_string()));
+        }
+
+        if self.signatures.for_report(id).await?.iter().any(|s| s.payload.role == request.role) {
+            return Err(AppError::Conflict(format!(
+                "Report {} already has a {:?} signature",
+                id, request.role
+            )));
+        }
+
+        let key = self.keys.active_for_user(&user_id).await?.ok_or_else(|| {
+            AppError::Validation("Create a signing key before signing reports".to_string())
+        })?;
+
+        let snapshot = self.snapshots.get_by_report(id).await?;
+        let property = self.properties.get_by_id(report.property_id).await?;
+        let signed_at = Utc::now();
+
+        let license = self
+            .credentials
+            .licenses_for_user(&user_id)
+            .await?
+            .into_iter()
+            .find(|license| {
+                license.state.eq_ignore_ascii_case(&property.address.state)
+                    && license.is_active_on(signed_at.date_naive())
+                    && (request.role == SignerRole::Appraiser || license.level.can_supervise())
+            })
+            .ok_or_else(|| {
+                AppError::Validation(match request.role {
+                    SignerRole::Appraiser => format!(
+                        "Signing requires a license active in {} today",
+                        property.address.state
+                    ),
+                    SignerRole::Supervisor => format!(
+                        "Co-signing requires a certified license active in {} today",
+                        property.address.state
+                    ),
+                })
+            })?;
+
+        let payload = SignaturePayload {
+            report_id: id,
+            version: snapshot.version,
+            manifest_sha256: snapshot.manifest_sha256,
+            signer_id: user_id,
+            role: request.role,
+            license_state: license.state,
+            license_number: license.license_number,
+            license_level: license.level,
+            signed_at,
+        };
+
+        let signature = ReportSignature {
+            id: Uuid::new_v4(),
+            key_id: key.id,
+            signature: self.vault.sign(&key.sealed_private_key, &to_canonical_json(&payload)?)?,
+            public_key: key.public_key,
+            payload,
+        };
+
+        self.signatures.create(snapshot.id, &signature).await?;
+        Ok(signature)
+    }
+
+    /// Check every signature on a finalized report. Open to anyone holding a copy of the report,
+    /// so it needs no authentication.
+    pub async fn verify_signatures(&self, id: Uuid) -> AppResult<SignatureVerification> {
+        let snapshot = self.snapshots.get_by_report(id).await?;
+        let manifest_intact = sha256_hex(&to_canonical_json(&snapshot.manifest)?) == snapshot.manifest_sha256;
+
+        let mut checks = Vec::new();
+        for signature in self.signatures.for_report(id).await? {
+            let key = self.keys.get_by_id(signature.key_id).await?;
+            let payload = &signature.payload;
+
+            let valid = manifest_intact
+                && payload.report_id == id
+                && payload.version == snapshot.version
+                && payload.manifest_sha256 == snapshot.manifest_sha256
+                && !key.revoked_at.is_some_and(|revoked_at| payload.signed_at >= revoked_at)
+                && signing::verify(&key.public_key, &to_canonical_json(payload)?, &signature.signature);
+
+            checks.push(SignatureCheck {
+                signature,
+                valid,
+                key_revoked_at: key.revoked_at,
+            });
+        }
+
+        let valid_role = |role: SignerRole| {
+            checks
+                .iter()
+                .find(|check| check.valid && check.signature.payload.role == role)
+        };
+        let complete = checks.iter().all(|check| check.valid)
+            && match valid_role(SignerRole::Appraiser) {
+                Some(check) if check.signature.payload.license_level == LicenseLevel::Trainee => {
+                    valid_role(SignerRole::Supervisor).is_some()
+                }
+                Some(_) => true,
+                None => false,
+            };
+
+        Ok(SignatureVerification {
+            report_id: id,
+            version: snapshot.version,
+            manifest: snapshot.manifest,
+            manifest_sha256: snapshot.manifest_sha256,
+            signatures: checks,
+            complete,
+            amended_by: self.reports.find_amendment(id).await?,
+        })
+    }
+}
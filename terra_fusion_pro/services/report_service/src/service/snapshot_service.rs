@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::exhibit::ReportExhibit;
+use shared::models::report::{
+    Report, ReportSnapshot, ReportStatus, SnapshotArtifact, SnapshotManifest, SnapshotVerification,
+};
+use shared::storage::AttachmentStore;
+use shared::utils::canonical_json::to_canonical_json;
+use uuid::Uuid;
+
+use super::exhibit_service::exhibit_key;
+use super::render_service::RenderService;
+use super::{ensure_author, ensure_participant, require_user};
+use crate::repository::exhibit_repository::ExhibitRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+use crate::repository::snapshot_repository::SnapshotRepository;
+
+/// Canonical JSON of the report and property as finalized
+pub const SNAPSHOT_JSON: &str = "report.json";
+
+/// The report PDF as delivered
+pub const SNAPSHOT_PDF: &str = "report.pdf";
+
+/// Service for freezing finalized reports and proving what was delivered
+pub struct SnapshotService {
+    reports: ReportRepository,
+    snapshots: SnapshotRepository,
+    properties: PropertyRepository,
+    exhibits: ExhibitRepository,
+    renderer: RenderService,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl SnapshotService {
+    /// Create a new snapshot service
+    pub fn new(db: Arc<Database>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            reports: ReportRepository::new(db.clone()),
+            snapshots: SnapshotRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            exhibits: ExhibitRepository::new(db.clone()),
+            renderer: RenderService::new(db, store.clone()),
+            store,
+        }
+    }
+
+    /// Persist a report moving to `Finalized` together with its snapshot: the canonical JSON of
+    /// the report and its property, the rendered PDF, and a manifest of their SHA-256 hashes.
+    /// Artifacts are stored under the snapshot's own key, so a failed attempt never disturbs
+    /// the artifacts of one that succeeded.
+    pub async fn finalize(&self, report: &Report, expected: &ReportStatus, finalized_by: Uuid) -> AppResult<Report> {
+        let property = self.properties.get_by_id(report.property_id).await?;
+        let json = to_canonical_json(&json!({ "report": report, "property": property }))?;
+        let pdf = self.renderer.render(report, &property).await?;
+
+        let files = [
+            (SNAPSHOT_JSON, "application/json", json),
+            (SNAPSHOT_PDF, "application/pdf", pdf),
+        ];
+
+        let manifest = SnapshotManifest {
+            report_id: report.id,
+            version: report.version,
+            amends_id: report.amends_id,
+            finalized_at: report.updated_at,
+            artifacts: files
+                .iter()
+                .map(|(name, media_type, bytes)| SnapshotArtifact {
+                    name: name.to_string(),
+                    media_type: media_type.to_string(),
+                    size: bytes.len() as i64,
+                    sha256: sha256_hex(bytes),
+                })
+                .collect(),
+        };
+
+        let snapshot = ReportSnapshot {
+            id: Uuid::new_v4(),
+            report_id: report.id,
+            version: report.version,
+            manifest_sha256: sha256_hex(&to_canonical_json(&manifest)?),
+            manifest,
+            finalized_by,
+            created_at: report.updated_at,
+        };
+
+        let mut stored = Vec::with_capacity(files.len());
+        let mut result = Ok(());
+        for (name, _, bytes) in &files {
+            let key = snapshot_key(report.id, snapshot.id, name);
+            result = self.store.put(&key, bytes).await;
+            if result.is_err() {
+                break;
+            }
+            stored.push(key);
+        }
+
+        let result = match result {
+            Ok(()) => self.reports.finalize(report, expected, &snapshot).await,
+            Err(err) => Err(err),
+        };
+
+        if result.is_err() {
+            for key in &stored {
+                if let Err(err) = self.store.delete(key).await {
+                    log::warn!("Failed to remove snapshot artifact {}: {:?}", key, err);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Get the snapshot of a finalized report
+    pub async fn get_snapshot(&self, id: Uuid, user_id: Option<String>) -> AppResult<ReportSnapshot> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.snapshots.get_by_report(id).await
+    }
+
+    /// Load an artifact of a finalized report, after checking it still matches the manifest
+    pub async fn download_artifact(
+        &self,
+        id: Uuid,
+        name: &str,
+        user_id: Option<String>,
+    ) -> AppResult<(Vec<u8>, String)> {
+        let snapshot = self.get_snapshot(id, user_id).await?;
+        self.load_artifact(&snapshot, name).await
+    }
+
+    /// Load an artifact of a snapshot, after checking it still matches the manifest. Callers
+    /// are responsible for checking access to the report.
+    pub async fn load_artifact(&self, snapshot: &ReportSnapshot, name: &str) -> AppResult<(Vec<u8>, String)> {
+        let id = snapshot.report_id;
+        let artifact = snapshot
+            .manifest
+            .artifacts
+            .iter()
+            .find(|artifact| artifact.name == name)
+            .ok_or_else(|| AppError::NotFound(format!("Report {} has no artifact named {}", id, name)))?;
+
+        let bytes = self.store.get(&snapshot_key(id, snapshot.id, &artifact.name)).await?;
+        if sha256_hex(&bytes) != artifact.sha256 {
+            return Err(AppError::General(format!(
+                "Stored artifact {} of report {} no longer matches its manifest",
+                artifact.name, id
+            )));
+        }
+
+        Ok((bytes, artifact.media_type.clone()))
+    }
+
+    /// Check whether a file is byte-for-byte one of the artifacts delivered with a finalized report
+    pub async fn verify(&self, id: Uuid, file: &[u8], user_id: Option<String>) -> AppResult<SnapshotVerification> {
+        require_user(user_id)?;
+        let snapshot = self.snapshots.get_by_report(id).await?;
+        let sha256 = sha256_hex(file);
+
+        let artifact = snapshot
+            .manifest
+            .artifacts
+            .iter()
+            .find(|artifact| artifact.sha256 == sha256 && artifact.size == file.len() as i64)
+            .map(|artifact| artifact.name.clone());
+
+        Ok(SnapshotVerification {
+            report_id: id,
+            version: snapshot.version,
+            verified: artifact.is_some(),
+            artifact,
+            sha256,
+            manifest_sha256: snapshot.manifest_sha256,
+            finalized_at: snapshot.manifest.finalized_at,
+            amended_by: self.reports.find_amendment(id).await?,
+        })
+    }
+
+    /// Start an amended version of a finalized report. The amendment is a new draft with the
+    /// original's content, photos and exhibits that references the original; the original stays
+    /// frozen.
+    pub async fn amend_report(&self, id: Uuid, user_id: Option<String>) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let original = self.reports.get_by_id(id).await?;
+        ensure_author(&original, &user_id)?;
+
+        if original.status != ReportStatus::Finalized {
+            return Err(AppError::Validation(
+                "Only finalized reports can be amended; edit the report instead".to_string(),
+            ));
+        }
+
+        if let Some(amendment) = self.reports.find_amendment(id).await? {
+            return Err(AppError::Conflict(format!(
+                "Report {} has already been amended by report {}",
+                id, amendment
+            )));
+        }
+
+        let amendment_id = Uuid::new_v4();
+        let content = self.copy_photos(&original, amendment_id).await?;
+
+        let now = Utc::now();
+        let amendment = Report {
+            id: amendment_id,
+            title: original.title.clone(),
+            property_id: original.property_id,
+            appraiser_id: original.appraiser_id,
+            status: ReportStatus::Draft,
+            report_type: original.report_type.clone(),
+            valuation_amount: original.valuation_amount,
+            content,
+            pdf_url: None,
+            created_at: now,
+            updated_at: now,
+            submitted_at: None,
+            reviewed_at: None,
+            reviewer_id: None,
+            review_comments: None,
+            submission_comments: None,
+            version: original.version + 1,
+            amends_id: Some(original.id),
+            supervisor_id: None,
+        };
+
+        let exhibits = self.copy_exhibits(&original, &amendment).await?;
+
+        self.reports.create_amendment(&amendment, &exhibits).await
+    }
+
+    /// Copy the original's photos under the amendment's storage prefix, returning the content
+    /// with their storage keys rewritten. Photos may only be read from a report's own prefix.
+    async fn copy_photos(&self, original: &Report, amendment_id: Uuid) -> AppResult<Value> {
+        let mut content = original.content.clone();
+        let entries = match content.get_mut("photos").and_then(Value::as_array_mut) {
+            Some(entries) => entries,
+            None => return Ok(content),
+        };
+
+        let prefix = format!("reports/{}/", original.id);
+        for entry in entries.iter_mut() {
+            let key = entry
+                .get("storage_key")
+                .and_then(Value::as_str)
+                .and_then(|key| key.strip_prefix(&prefix))
+                .map(str::to_string);
+
+            if let Some(rest) = key {
+                let copied = format!("reports/{}/{}", amendment_id, rest);
+                let bytes = self.store.get(&format!("{}{}", prefix, rest)).await?;
+                self.store.put(&copied, &bytes).await?;
+                entry["storage_key"] = json!(copied);
+            }
+        }
+
+        Ok(content)
+    }
+
+    /// Copy the files of the original's exhibits under the amendment's storage prefix,
+    /// returning the amendment's exhibit records, in the same order, for it to be created with
+    async fn copy_exhibits(&self, original: &Report, amendment: &Report) -> AppResult<Vec<ReportExhibit>> {
+        let mut copies = Vec::new();
+
+        for exhibit in self.exhibits.for_report(original.id).await? {
+            let id = Uuid::new_v4();
+            let storage_key = exhibit_key(amendment.id, id, &exhibit.media_type);
+            let bytes = self.store.get(&exhibit.storage_key).await?;
+            self.store.put(&storage_key, &bytes).await?;
+
+            copies.push(ReportExhibit {
+                id,
+                report_id: amendment.id,
+                storage_key,
+                created_at: amendment.created_at,
+                ..exhibit
+            });
+        }
+
+        Ok(copies)
+    }
+}
+
+/// Storage key of an artifact of a report snapshot
+pub(super) fn snapshot_key(report_id: Uuid, snapshot_id: Uuid, name: &str) -> String {
+    format!("reports/{}/snapshots/{}/{}", report_id, snapshot_id, name)
+}
+
+/// SHA-256 of bytes, hex encoded
+pub(super) fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
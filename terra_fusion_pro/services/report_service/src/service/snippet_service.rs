@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::{Map, Value};
+use shared::db::Database;
+use shared::error::{AppError, AppResult};
+use shared::models::report::{Report, UpdateReportRequest};
+use shared::models::snippet::{
+    ApplySnippetRequest, NarrativeSnippet, RenderedSnippet, SnippetMode, SnippetQuery, SnippetRequest, SnippetScope,
+};
+use uuid::Uuid;
+
+use super::report_service::ReportService;
+use super::{ensure_participant, parse_appraiser_id, require_user};
+use crate::narrative::{check_template, render_template, NarrativeContext};
+use crate::rendering::layout::RESERVED_KEYS;
+use crate::repository::appraisal_repository::AppraisalRepository;
+use crate::repository::property_repository::PropertyRepository;
+use crate::repository::report_repository::ReportRepository;
+use crate::repository::snippet_repository::SnippetRepository;
+
+/// Service for narrative snippet libraries and writing snippets into reports
+pub struct SnippetService {
+    snippets: SnippetRepository,
+    reports: ReportRepository,
+    properties: PropertyRepository,
+    appraisals: AppraisalRepository,
+    editor: ReportService,
+}
+
+impl SnippetService {
+    /// Create a new snippet service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            snippets: SnippetRepository::new(db.clone()),
+            reports: ReportRepository::new(db.clone()),
+            properties: PropertyRepository::new(db.clone()),
+            appraisals: AppraisalRepository::new(db.clone()),
+            editor: ReportService::new(db),
+        }
+    }
+
+    /// List the organization's snippets and the current user's personal snippets
+    pub async fn list_snippets(&self, query: &SnippetQuery, user_id: Option<String>) -> AppResult<Vec<NarrativeSnippet>> {
+        let appraiser_id = parse_appraiser_id(&require_user(user_id)?)?;
+        self.snippets.find_visible(appraiser_id, query).await
+    }
+
+    /// Add a snippet owned by the current user
+    pub async fn create_snippet(&self, request: SnippetRequest, user_id: Option<String>) -> AppResult<NarrativeSnippet> {
+        let owner_id = parse_appraiser_id(&require_user(user_id)?)?;
+        let request = validate_snippet(request)?;
+
+        let now = Utc::now();
+        let snippet = NarrativeSnippet {
+            id: Uuid::new_v4(),
+            scope: request.scope,
+            owner_id,
+            name: request.name,
+            section: request.section,
+            field: request.field,
+            template: request.template,
+            created_at: now,
+            updated_at: now,
+        };
+
+        self.snippets.create(&snippet).await
+    }
+
+    /// Replace a snippet the current user owns
+    pub async fn update_snippet(
+        &self,
+        id: Uuid,
+        request: SnippetRequest,
+        user_id: Option<String>,
+    ) -> AppResult<NarrativeSnippet> {
+        let mut snippet = self.owned_snippet(id, user_id).await?;
+        let request = validate_snippet(request)?;
+
+        snippet.scope = request.scope;
+        snippet.name = request.name;
+        snippet.section = request.section;
+        snippet.field = request.field;
+        snippet.template = request.template;
+        snippet.updated_at = Utc::now();
+
+        self.snippets.update(&snippet).await
+    }
+
+    /// Delete a snippet the current user owns
+    pub async fn delete_snippet(&self, id: Uuid, user_id: Option<String>) -> AppResult<()> {
+        let snippet = self.owned_snippet(id, user_id).await?;
+        self.snippets.delete(snippet.id).await
+    }
+
+    /// Render a snippet for a report without changing the report
+    pub async fn preview_snippet(
+        &self,
+        report_id: Uuid,
+        snippet_id: Uuid,
+        user_id: Option<String>,
+    ) -> AppResult<RenderedSnippet> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        ensure_participant(&report, &user_id)?;
+
+        self.render(&report, snippet_id, &user_id).await
+    }
+
+    /// Render a snippet for a report and write the text into the snippet's content field
+    pub async fn apply_snippet(
+        &self,
+        report_id: Uuid,
+        snippet_id: Uuid,
+        request: ApplySnippetRequest,
+        user_id: Option<String>,
+    ) -> AppResult<Report> {
+        let user_id = require_user(user_id)?;
+        let report = self.reports.get_by_id(report_id).await?;
+        let rendered = self.render(&report, snippet_id, &user_id).await?;
+
+        let mut content = report.content;
+        let section = match content.as_object_mut() {
+            Some(sections) => sections
+                .entry(rendered.section.clone())
+                .or_insert_with(|| Value::Object(Map::new())),
+            None => return Err(AppError::Validation("Report content must be a JSON object".to_string())),
+        };
+        let fields = section.as_object_mut().ok_or_else(|| {
+            AppError::Validation(format!("Report section \"{}\" is not an object", rendered.section))
+        })?;
+
+        let text = match (request.mode.unwrap_or(SnippetMode::Replace), fields.get(&rendered.field)) {
+            (SnippetMode::Append, Some(Value::String(existing))) if !existing.trim().is_empty() => {
+                format!("{}\n\n{}", existing.trim_end(), rendered.text)
+            }
+            _ => rendered.text,
+        };
+        fields.insert(rendered.field, Value::String(text));
+
+        let update = UpdateReportRequest {
+            title: None,
+            status: None,
+            valuation_amount: None,
+            content: Some(content),
+        };
+        self.editor.update_report(report_id, update, Some(user_id)).await
+    }
+
+    /// Render a snippet visible to the user against a report
+    async fn render(&self, report: &Report, snippet_id: Uuid, user_id: &str) -> AppResult<RenderedSnippet> {
+        let snippet = self.snippets.get_by_id(snippet_id).await?;
+        let visible = snippet.scope == SnippetScope::Organization || snippet.owner_id.to_string() == user_id;
+        if !visible {
+            return Err(AppError::NotFound(format!("Snippet not found with ID: {}", snippet_id)));
+        }
+
+        let property = self.properties.get_by_id(report.property_id).await?;
+        let appraisal = self.appraisals.find_by_report(report.id).await?;
+        let context = NarrativeContext {
+            property: &property,
+            appraisal: appraisal.as_ref(),
+            report,
+        };
+
+        Ok(RenderedSnippet {
+            snippet_id,
+            text: render_template(&snippet.template, &context)?,
+            section: snippet.section,
+            field: snippet.field,
+        })
+    }
+
+    /// Load a snippet, ensuring the current user owns it
+    async fn owned_snippet(&self, id: Uuid, user_id: Option<String>) -> AppResult<NarrativeSnippet> {
+        let user_id = require_user(user_id)?;
+        let snippet = self.snippets.get_by_id(id).await?;
+
+        if snippet.owner_id.to_string() != user_id {
+            return Err(AppError::Authorization(
+                "Only the appraiser who wrote a snippet can change it".to_string(),
+            ));
+        }
+        Ok(snippet)
+    }
+}
+
+/// Validate and normalize a snippet request
+fn validate_snippet(mut request: SnippetRequest) -> AppResult<SnippetRequest> {
+    request.name = request.name.trim().to_string();
+    if request.name.is_empty() {
+        return Err(AppError::Validation("Snippet name is required".to_string()));
+    }
+
+    request.section = request.section.trim().to_string();
+    request.field = request.field.trim().to_string();
+    for (label, key) in [("Section", &request.section), ("Field", &request.field)] {
+        let mut chars = key.chars();
+        let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid {
+            return Err(AppError::Validation(format!(
+                "{} must be a content key such as \"neighborhood\" or \"description\"",
+                label
+            )));
+        }
+    }
+    if RESERVED_KEYS.contains(&request.section.as_str()) {
+        return Err(AppError::Validation(format!(
+            "Snippets cannot be written to the \"{}\" section",
+            request.section
+        )));
+    }
+
+    if request.template.trim().is_empty() {
+        return Err(AppError::Validation("Snippet template is required".to_string()));
+    }
+    check_template(&request.template)?;
+
+    Ok(request)
+}
@@ -0,0 +1,167 @@
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use hmac::{Hmac, Mac};
+use rand::rngs::OsRng;
+use sha2::Sha256;
+use shared::error::{AppError, AppResult};
+use shared::models::share::ShareLink;
+use uuid::Uuid;
+
+/// Signs share link tokens. A token is the link ID and an HMAC-SHA256 of the link's report,
+/// artifact and expiry, so a token cannot be forged or pointed at anything else, and changing
+/// the link's terms invalidates it.
+pub struct ShareLinkSigner {
+    secret: Vec<u8>,
+}
+
+impl ShareLinkSigner {
+    /// Create a signer keyed with the configured secret
+    pub fn new(secret: &str) -> Self {
+        Self {
+            secret: secret.as_bytes().to_vec(),
+        }
+    }
+
+    /// Token identifying and authorizing a link, `<link id>.<hex signature>`
+    pub fn token(&self, link: &ShareLink) -> String {
+        let signature = self.mac(link).finalize().into_bytes();
+        format!("{}.{}", link.id.simple(), hex::encode(signature))
+    }
+
+    /// Whether a token was issued for the link as it stands
+    pub fn verify(&self, link: &ShareLink, token: &str) -> bool {
+        let signature = match token.split_once('.').and_then(|(_, signature)| hex::decode(signature).ok()) {
+            Some(signature) => signature,
+            None => return false,
+        };
+
+        self.mac(link).verify_slice(&signature).is_ok()
+    }
+
+    fn mac(&self, link: &ShareLink) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(
+            format!("{}.{}.{}.{}", link.id, link.report_id, link.artifact, link.expires_at.timestamp()).as_bytes(),
+        );
+        mac
+    }
+}
+
+/// ID of the link a token claims to be for; the claim is only trusted once `verify` passes
+pub fn token_link_id(token: &str) -> Option<Uuid> {
+    let (id, _) = token.split_once('.')?;
+    Uuid::parse_str(id).ok()
+}
+
+/// Argon2 hash of a link password, in PHC string format
+pub fn hash_password(password: &str) -> AppResult<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|e| AppError::General(format!("Failed to hash share link password: {}", e)))
+}
+
+/// Whether a password matches a stored hash. Malformed hashes never match.
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    match PasswordHash::new(hash) {
+        Ok(hash) => Argon2::default().verify_password(password.as_bytes(), &hash).is_ok(),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+    use shared::models::share::ShareRecipientRole;
+
+    use super::*;
+
+    fn link() -> ShareLink {
+        let created_at = Utc.with_ymd_and_hms(2023, 6, 1, 12, 0, 0).unwrap();
+
+        ShareLink {
+            id: Uuid::new_v4(),
+            report_id: Uuid::new_v4(),
+            artifact: "report.pdf".to_string(),
+            recipient_role: ShareRecipientRole::Client,
+            recipient_name: Some("First National Lending".to_string()),
+            recipient_email: None,
+            password_hash: None,
+            password_protected: false,
+            expires_at: created_at + Duration::days(30),
+            max_downloads: None,
+            download_count: 0,
+            first_downloaded_at: None,
+            created_by: Uuid::new_v4(),
+            created_at,
+            revoked_at: None,
+        }
+    }
+
+    #[test]
+    fn verifies_the_tokens_it_issues() {
+        let signer = ShareLinkSigner::new("share-secret");
+        let link = link();
+        let token = signer.token(&link);
+
+        assert!(signer.verify(&link, &token));
+        assert_eq!(token_link_id(&token), Some(link.id));
+    }
+
+    #[test]
+    fn refuses_tokens_signed_with_another_secret() {
+        let link = link();
+        let token = ShareLinkSigner::new("other-secret").token(&link);
+
+        assert!(!ShareLinkSigner::new("share-secret").verify(&link, &token));
+    }
+
+    #[test]
+    fn refuses_tokens_once_the_terms_of_the_link_change() {
+        let signer = ShareLinkSigner::new("share-secret");
+        let link = link();
+        let token = signer.token(&link);
+
+        let mut extended = link.clone();
+        extended.expires_at += Duration::days(1);
+        let mut other_artifact = link.clone();
+        other_artifact.artifact = "report.xml".to_string();
+        let mut other_report = link.clone();
+        other_report.report_id = Uuid::new_v4();
+        let mut other_link = link;
+        other_link.id = Uuid::new_v4();
+
+        for changed in [extended, other_artifact, other_report, other_link] {
+            assert!(!signer.verify(&changed, &token));
+        }
+    }
+
+    #[test]
+    fn refuses_tampered_and_malformed_tokens() {
+        let signer = ShareLinkSigner::new("share-secret");
+        let link = link();
+        let token = signer.token(&link);
+
+        let last = token.chars().last().unwrap();
+        let tampered = format!("{}{}", &token[..token.len() - 1], if last == '0' { '1' } else { '0' });
+        let id = link.id.simple().to_string();
+
+        for token in [tampered, String::new(), id.clone(), format!("{}.", id), format!("{}.not-hex", id)] {
+            assert!(!signer.verify(&link, &token), "{}", token);
+        }
+        assert_eq!(token_link_id("not-a-uuid.abc"), None);
+        assert_eq!(token_link_id("no-separator"), None);
+    }
+
+    #[test]
+    fn passwords_match_only_their_own_hash() {
+        let hash = hash_password("correct horse").unwrap();
+
+        assert!(hash.starts_with("$argon2"));
+        assert!(verify_password("correct horse", &hash));
+        assert!(!verify_password("wrong horse", &hash));
+        assert!(!verify_password("correct horse", "not a hash"));
+        assert_ne!(hash_password("correct horse").unwrap(), hash);
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse, Responder, ResponseError, get, post, put, delete};
+use serde::Deserialize;
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::credential::{CREDENTIAL_EXPIRY_WARNING_DAYS, InsurancePolicyRequest, LicenseRequest},
+};
+use uuid::Uuid;
+
+use crate::service::credential_service::CredentialService;
+
+/// Query parameters for the expiring credentials report
+#[derive(Debug, Deserialize)]
+struct ExpiringQuery {
+    within_days: Option<i64>,
+}
+
+/// Configure credential routes
+///
+/// Registered ahead of the `/users` scope so the nested paths are not swallowed by it.
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_credentials)
+        .service(add_license)
+        .service(update_license)
+        .service(delete_license)
+        .service(add_policy)
+        .service(delete_policy)
+        .service(get_expiring_credentials);
+}
+
+/// Get all licenses and E&O policies for a user, with expiry warnings
+#[get("/users/{id}/credentials")]
+async fn get_credentials(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.credential_summary(&path.into_inner(), session.user_id()).await {
+        Ok(summary) => HttpResponse::Ok().json(summary),
+        Err(e) => {
+            log::error!("Error fetching credentials: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Add a license for a user
+#[post("/users/{id}/licenses")]
+async fn add_license(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+    request: web::Json<LicenseRequest>,
+) -> impl Responder {
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.add_license(&path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(license) => HttpResponse::Created().json(license),
+        Err(e) => {
+            log::error!("Error adding license: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Replace a user's license record
+#[put("/users/{id}/licenses/{license_id}")]
+async fn update_license(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(String, Uuid)>,
+    request: web::Json<LicenseRequest>,
+) -> impl Responder {
+    let (user_id, license_id) = path.into_inner();
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.update_license(&user_id, license_id, request.into_inner(), session.user_id()).await {
+        Ok(license) => HttpResponse::Ok().json(license),
+        Err(e) => {
+            log::error!("Error updating license: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Remove a user's license record
+#[delete("/users/{id}/licenses/{license_id}")]
+async fn delete_license(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(String, Uuid)>,
+) -> impl Responder {
+    let (user_id, license_id) = path.into_inner();
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.delete_license(&user_id, license_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => {
+            log::error!("Error deleting license: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Add an E&O insurance policy for a user
+#[post("/users/{id}/insurance")]
+async fn add_policy(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+    request: web::Json<InsurancePolicyRequest>,
+) -> impl Responder {
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.add_policy(&path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(policy) => HttpResponse::Created().json(policy),
+        Err(e) => {
+            log::error!("Error adding insurance policy: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Remove a user's E&O insurance policy
+#[delete("/users/{id}/insurance/{policy_id}")]
+async fn delete_policy(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(String, Uuid)>,
+) -> impl Responder {
+    let (user_id, policy_id) = path.into_inner();
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.delete_policy(&user_id, policy_id, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => {
+            log::error!("Error deleting insurance policy: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// List credentials across all users that have expired or expire soon
+#[get("/credentials/expiring")]
+async fn get_expiring_credentials(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    query: web::Query<ExpiringQuery>,
+) -> impl Responder {
+    let within_days = query.within_days.unwrap_or(CREDENTIAL_EXPIRY_WARNING_DAYS);
+    let service = CredentialService::new(db.get_ref().clone());
+
+    match service.expiring_credentials(within_days, session.user_id()).await {
+        Ok(warnings) => HttpResponse::Ok().json(warnings),
+        Err(e) => {
+            log::error!("Error fetching expiring credentials: {:?}", e);
+            e.error_response()
+        }
+    }
+}
@@ -1,8 +1,14 @@
+mod credential_controller;
+mod notification_controller;
+mod signing_key_controller;
 mod user_controller;
 
 use actix_web::web;
 
 /// Configure all routes for the API
 pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    credential_controller::configure_routes(cfg);
+    notification_controller::configure_routes(cfg);
+    signing_key_controller::configure_routes(cfg);
     user_controller::configure_routes(cfg);
-}
\ No newline at end of file
+}
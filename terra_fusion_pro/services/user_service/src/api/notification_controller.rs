@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse, Responder, ResponseError, get, put};
+use serde::Deserialize;
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    models::notification::UpdateNotificationPreferencesRequest,
+};
+
+use crate::service::notification_service::NotificationService;
+
+/// Query parameters for the notification log
+#[derive(Debug, Deserialize)]
+struct LogQuery {
+    limit: Option<i64>,
+}
+
+/// Configure notification routes
+///
+/// Registered ahead of the `/users` scope so the nested paths are not swallowed by it.
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_preferences)
+        .service(update_preferences)
+        .service(get_notification_log);
+}
+
+/// Get a user's notification preferences
+#[get("/users/{id}/notification-preferences")]
+async fn get_preferences(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let service = NotificationService::new(db.get_ref().clone());
+
+    match service.preferences(&path.into_inner(), session.user_id()).await {
+        Ok(preferences) => HttpResponse::Ok().json(preferences),
+        Err(e) => {
+            log::error!("Error fetching notification preferences: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Update a user's notification preferences
+#[put("/users/{id}/notification-preferences")]
+async fn update_preferences(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+    request: web::Json<UpdateNotificationPreferencesRequest>,
+) -> impl Responder {
+    let service = NotificationService::new(db.get_ref().clone());
+
+    match service.update_preferences(&path.into_inner(), request.into_inner(), session.user_id()).await {
+        Ok(preferences) => HttpResponse::Ok().json(preferences),
+        Err(e) => {
+            log::error!("Error updating notification preferences: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Get a user's notification delivery log
+#[get("/users/{id}/notifications")]
+async fn get_notification_log(
+    db: web::Data<Arc<Database>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+    query: web::Query<LogQuery>,
+) -> impl Responder {
+    let service = NotificationService::new(db.get_ref().clone());
+
+    match service.delivery_log(&path.into_inner(), query.limit, session.user_id()).await {
+        Ok(log) => HttpResponse::Ok().json(log),
+        Err(e) => {
+            log::error!("Error fetching notification log: {:?}", e);
+            e.error_response()
+        }
+    }
+}
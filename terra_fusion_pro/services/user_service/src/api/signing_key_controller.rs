@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use actix_web::{web, HttpResponse, Responder, ResponseError, get, post, delete};
+use shared::{
+    auth::session::SessionData,
+    db::Database,
+    signing::KeyVault,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+use crate::service::signing_key_service::{SigningKeyService, MAX_SIGNATURE_IMAGE_BYTES};
+
+/// Configure signing key and signature image routes
+///
+/// Registered ahead of the `/users` scope so the nested paths are not swallowed by it.
+pub fn configure_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_signing_keys)
+        .service(create_signing_key)
+        .service(revoke_signing_key)
+        .service(
+            web::resource("/users/{id}/signature-image")
+                .app_data(web::PayloadConfig::new(MAX_SIGNATURE_IMAGE_BYTES))
+                .route(web::put().to(upload_signature_image))
+                .route(web::delete().to(delete_signature_image))
+        );
+}
+
+/// List the signing keys a user has held
+#[get("/users/{id}/signing-keys")]
+async fn get_signing_keys(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let service = SigningKeyService::new(db.get_ref().clone(), vault.get_ref().clone(), store.get_ref().clone());
+
+    match service.list_keys(&path.into_inner(), session.user_id()).await {
+        Ok(keys) => HttpResponse::Ok().json(keys),
+        Err(e) => {
+            log::error!("Error fetching signing keys: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Issue a new signing key, retiring the current one
+#[post("/users/{id}/signing-keys")]
+async fn create_signing_key(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let service = SigningKeyService::new(db.get_ref().clone(), vault.get_ref().clone(), store.get_ref().clone());
+
+    match service.create_key(&path.into_inner(), session.user_id()).await {
+        Ok(key) => HttpResponse::Created().json(key),
+        Err(e) => {
+            log::error!("Error creating signing key: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Retire a signing key
+#[delete("/users/{id}/signing-keys/{key_id}")]
+async fn revoke_signing_key(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<(String, Uuid)>,
+) -> impl Responder {
+    let (user_id, key_id) = path.into_inner();
+    let service = SigningKeyService::new(db.get_ref().clone(), vault.get_ref().clone(), store.get_ref().clone());
+
+    match service.revoke_key(&user_id, key_id, session.user_id()).await {
+        Ok(key) => HttpResponse::Ok().json(key),
+        Err(e) => {
+            log::error!("Error revoking signing key: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Upload the JPEG signature image printed on a user's reports
+async fn upload_signature_image(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+    body: web::Bytes,
+) -> impl Responder {
+    let service = SigningKeyService::new(db.get_ref().clone(), vault.get_ref().clone(), store.get_ref().clone());
+
+    match service.upload_signature_image(&path.into_inner(), &body, session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => {
+            log::error!("Error uploading signature image: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+/// Remove a user's signature image
+async fn delete_signature_image(
+    db: web::Data<Arc<Database>>,
+    vault: web::Data<Arc<KeyVault>>,
+    store: web::Data<Arc<dyn AttachmentStore>>,
+    session: web::ReqData<SessionData>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let service = SigningKeyService::new(db.get_ref().clone(), vault.get_ref().clone(), store.get_ref().clone());
+
+    match service.delete_signature_image(&path.into_inner(), session.user_id()).await {
+        Ok(_) => HttpResponse::NoContent().finish(),
+        Err(e) => {
+            log::error!("Error deleting signature image: {:?}", e);
+            e.error_response()
+        }
+    }
+}
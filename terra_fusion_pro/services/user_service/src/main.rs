@@ -17,6 +17,8 @@ use shared::{
     },
     db::Database,
     config::Config,
+    signing::KeyVault,
+    storage::{AttachmentStore, LocalAttachmentStore},
 };
 
 #[actix_web::main]
@@ -30,6 +32,10 @@ async fn main() -> std::io::Result<()> {
     let db = Arc::new(Database::connect(&config.database_url).await.expect("Failed to connect to database"));
     log::info!("Connected to database");
     
+    // Initialize signing key vault and the store signature images are kept in
+    let vault = Arc::new(KeyVault::new(&config.signing_key_secret));
+    let attachments: Arc<dyn AttachmentStore> = Arc::new(LocalAttachmentStore::new(&config.attachments_dir));
+    
     // Initialize Replit Auth
     let replit_auth_config = shared::auth::replit_auth::ReplitAuthConfig {
         client_id: config.replit_auth.client_id.clone(),
@@ -61,6 +67,8 @@ async fn main() -> std::io::Result<()> {
             .wrap(AuthenticationMiddleware::new(replit_auth.clone()))
             .app_data(web::Data::new(db.clone()))
             .app_data(web::Data::new(replit_auth.clone()))
+            .app_data(web::Data::new(vault.clone()))
+            .app_data(web::Data::new(attachments.clone()))
             .app_data(schema.clone())
             .service(
                 web::scope("/auth")
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDate, Utc};
+use shared::{
+    auth::access::AccessControl,
+    db::Database,
+    error::{AppError, AppResult},
+    models::{
+        credential::{
+            expiry_warning, AppraiserLicense, CredentialKind, CredentialSummary, CredentialWarning,
+            InsurancePolicy, InsurancePolicyRequest, LicenseRequest, CREDENTIAL_EXPIRY_WARNING_DAYS,
+        },
+        user::UserRole,
+    },
+    repository::{credential_repository::CredentialRepository, user_repository::UserRepository},
+};
+use uuid::Uuid;
+
+/// Service for managing appraiser licenses and E&O insurance
+pub struct CredentialService {
+    users: UserRepository,
+    credentials: CredentialRepository,
+    access: AccessControl,
+}
+
+impl CredentialService {
+    /// Create a new credential service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            users: UserRepository::new(db.clone()),
+            credentials: CredentialRepository::new(db.clone()),
+            access: AccessControl::new(db),
+        }
+    }
+
+    /// Get all credentials for a user, with expiry warnings
+    pub async fn credential_summary(
+        &self,
+        user_id: &str,
+        current_user_id: Option<String>,
+    ) -> AppResult<CredentialSummary> {
+        self.access.require_self_or(current_user_id, user_id, UserRole::Admin).await?;
+        self.users.get_by_id(user_id.to_string()).await?;
+
+        let licenses = self.credentials.licenses_for_user(user_id).await?;
+        let insurance_policies = self.credentials.policies_for_user(user_id).await?;
+
+        let today = Utc::now().date_naive();
+        let window = CREDENTIAL_EXPIRY_WARNING_DAYS;
+        let mut warnings: Vec<CredentialWarning> = licenses
+            .iter()
+            .filter_map(|l| license_warning(l, today, window))
+            .collect();
+        warnings.extend(insurance_policies.iter().filter_map(|p| policy_warning(p, today, window)));
+
+        Ok(CredentialSummary {
+            user_id: user_id.to_string(),
+            licenses,
+            insurance_policies,
+            warnings,
+        })
+    }
+
+    /// Add a license for a user
+    pub async fn add_license(
+        &self,
+        user_id: &str,
+        request: LicenseRequest,
+        current_user_id: Option<String>,
+    ) -> AppResult<AppraiserLicense> {
+        self.access.require_self_or(current_user_id, user_id, UserRole::Admin).await?;
+        self.users.get_by_id(user_id.to_string()).await?;
+        validate_license(&request)?;
+        self.credentials.create_license(user_id, &request).await
+    }
+
+    /// Replace a user's license record
+    pub async fn update_license(
+        &self,
+        user_id: &str,
+        id: Uuid,
+        request: LicenseRequest,
+        current_user_id: Option<String>,
+    ) -> AppResult<AppraiserLicense> {
+        self.access.require_self_or(current_user_id, user_id, UserRole::Admin).await?;
+        validate_license(&request)?;
+        self.credentials.update_license(user_id, id, &request).await
+    }
+
+    /// Remove a user's license record
+    pub async fn delete_license(&self, user_id: &str, id: Uuid, current_user_id: Option<String>) -> AppResult<()> {
+        self.access.require_self_or(current_user_id, user_id, UserRole::Admin).await?;
+        self.credentials.delete_license(user_id, id).await
+    }
+
+    /// Add an E&O insurance policy for a user
+    pub async fn add_policy(
+        &self,
+        user_id: &str,
+        request: InsurancePolicyRequest,
+        current_user_id: Option<String>,
+    ) -> AppResult<InsurancePolicy> {
+        self.access.require_self_or(current_user_id, user_id, UserRole::Admin).await?;
+        self.users.get_by_id(user_id.to_string()).await?;
+
+        if request.expires_on <= request.effective_on {
+            return Err(AppError::Validation("Policy must expire after it becomes effective".to_string()));
+        }
+        if request.coverage_amount <= 0.0 {
+            return Err(AppError::Validation("Coverage amount must be positive".to_string()));
+        }
+
+        self.credentials.create_policy(user_id, &request).await
+    }
+
+    /// Remove a user's E&O insurance policy
+    pub async fn delete_policy(&self, user_id: &str, id: Uuid, current_user_id: Option<String>) -> AppResult<()> {
+        self.access.require_self_or(current_user_id, user_id, UserRole::Admin).await?;
+        self.credentials.delete_policy(user_id, id).await
+    }
+
+    /// List warnings for all credentials expiring within the given number of days (admins only)
+    pub async fn expiring_credentials(
+        &self,
+        within_days: i64,
+        current_user_id: Option<String>,
+    ) -> AppResult<Vec<CredentialWarning>> {
+        self.access.require_role(current_user_id, UserRole::Admin).await?;
+
+        let today = Utc::now().date_naive();
+        let horizon = today + Duration::days(within_days);
+
+        let licenses = self.credentials.licenses_expiring_by(horizon).await?;
+        let policies = self.credentials.policies_expiring_by(horizon).await?;
+
+        let mut warnings: Vec<CredentialWarning> = licenses
+            .iter()
+            .filter_map(|l| license_warning(l, today, within_days))
+            .collect();
+        warnings.extend(policies.iter().filter_map(|p| policy_warning(p, today, within_days)));
+        warnings.sort_by_key(|w| w.expires_on);
+
+        Ok(warnings)
+    }
+}
+
+/// Validate the fields of a license request
+fn validate_license(request: &LicenseRequest) -> AppResult<()> {
+    let state = request.state.trim();
+    if state.len() != 2 || !state.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(AppError::Validation("State must be a two-letter code".to_string()));
+    }
+    if request.license_number.trim().is_empty() {
+        return Err(AppError::Validation("License number is required".to_string()));
+    }
+    if let Some(issued_on) = request.issued_on {
+        if request.expires_on <= issued_on {
+            return Err(AppError::Validation("License must expire after it is issued".to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Build an expiry warning for a license
+fn license_warning(license: &AppraiserLicense, today: NaiveDate, window_days: i64) -> Option<CredentialWarning> {
+    expiry_warning(
+        &license.user_id,
+        CredentialKind::License,
+        license.id,
+        &format!("{} license {}", license.state, license.license_number),
+        license.expires_on,
+        today,
+        window_days,
+    )
+}
+
+/// Build an expiry warning for an insurance policy
+fn policy_warning(policy: &InsurancePolicy, today: NaiveDate, window_days: i64) -> Option<CredentialWarning> {
+    expiry_warning(
+        &policy.user_id,
+        CredentialKind::Insurance,
+        policy.id,
+        &format!("E&O policy {} ({})", policy.policy_number, policy.carrier),
+        policy.expires_on,
+        today,
+        window_days,
+    )
+}
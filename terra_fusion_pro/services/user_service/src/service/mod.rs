@@ -1 +1,4 @@
-pub mod user_service;
\ No newline at end of file
+pub mod user_service;
+pub mod credential_service;
+pub mod notification_service;
+pub mod signing_key_service;
\ No newline at end of file
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use shared::{
+    db::Database,
+    error::{AppError, AppResult},
+    models::notification::{
+        EventPreference, NotificationDelivery, NotificationPreferences,
+        UpdateNotificationPreferencesRequest,
+    },
+    repository::{notification_repository::NotificationRepository, user_repository::UserRepository},
+};
+
+/// Largest number of delivery log entries returned at once
+const MAX_LOG_ENTRIES: i64 = 200;
+
+/// Service for users' notification preferences and delivery log
+pub struct NotificationService {
+    users: UserRepository,
+    notifications: NotificationRepository,
+}
+
+impl NotificationService {
+    /// Create a new notification service
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            users: UserRepository::new(db.clone()),
+            notifications: NotificationRepository::new(db),
+        }
+    }
+
+    /// Get a user's notification preferences, falling back to the defaults
+    pub async fn preferences(
+        &self,
+        user_id: &str,
+        current_user_id: Option<String>,
+    ) -> AppResult<NotificationPreferences> {
+        require_self(current_user_id, user_id)?;
+        self.load_preferences(user_id).await
+    }
+
+    /// Update a user's notification preferences
+    pub async fn update_preferences(
+        &self,
+        user_id: &str,
+        request: UpdateNotificationPreferencesRequest,
+        current_user_id: Option<String>,
+    ) -> AppResult<NotificationPreferences> {
+        require_self(current_user_id, user_id)?;
+        let mut preferences = self.load_preferences(user_id).await?;
+
+        if let Some(email_enabled) = request.email_enabled {
+            preferences.email_enabled = email_enabled;
+        }
+        if let Some(events) = request.events {
+            validate_event_preferences(&events)?;
+            preferences.events = events;
+        }
+        if let Some(hour) = request.digest_hour_utc {
+            if !(0..=23).contains(&hour) {
+                return Err(AppError::Validation("digest_hour_utc must be between 0 and 23".to_string()));
+            }
+            preferences.digest_hour_utc = hour;
+        }
+        preferences.updated_at = Utc::now();
+
+        self.notifications.save_preferences(&preferences).await
+    }
+
+    /// Get a user's most recent notifications, newest first
+    pub async fn delivery_log(
+        &self,
+        user_id: &str,
+        limit: Option<i64>,
+        current_user_id: Option<String>,
+    ) -> AppResult<Vec<NotificationDelivery>> {
+        require_self(current_user_id, user_id)?;
+        self.users.get_by_id(user_id.to_string()).await?;
+
+        let limit = limit.unwrap_or(50).clamp(1, MAX_LOG_ENTRIES);
+        self.notifications.list_for_user(user_id, limit).await
+    }
+
+    /// Load a user's stored preferences, falling back to the defaults
+    async fn load_preferences(&self, user_id: &str) -> AppResult<NotificationPreferences> {
+        self.users.get_by_id(user_id.to_string()).await?;
+
+        Ok(self
+            .notifications
+            .get_preferences(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::defaults_for(user_id)))
+    }
+}
+
+/// Require the signed-in user to be the user whose notifications are being accessed
+fn require_self(current_user_id: Option<String>, user_id: &str) -> AppResult<()> {
+    let current_user_id =
+        current_user_id.ok_or_else(|| AppError::Authentication("Authentication required".to_string()))?;
+
+    if current_user_id != user_id {
+        return Err(AppError::Authorization(
+            "Users can only access their own notifications".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reject event preference lists that mention an event kind twice
+fn validate_event_preferences(events: &[EventPreference]) -> AppResult<()> {
+    for (index, preference) in events.iter().enumerate() {
+        if events[..index].iter().any(|p| p.event == preference.event) {
+            return Err(AppError::Validation(format!(
+                "Duplicate preference for event {:?}",
+                preference.event
+            )));
+        }
+    }
+
+    Ok(())
+}
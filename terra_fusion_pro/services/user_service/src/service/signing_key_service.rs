@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use shared::{
+    db::Database,
+    error::{AppError, AppResult},
+    models::signature::{signature_image_key, SigningKey},
+    repository::{signing_key_repository::SigningKeyRepository, user_repository::UserRepository},
+    signing::KeyVault,
+    storage::AttachmentStore,
+};
+use uuid::Uuid;
+
+/// Largest signature image accepted
+pub const MAX_SIGNATURE_IMAGE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Service for managing the keys and signature images users sign reports with
+pub struct SigningKeyService {
+    users: UserRepository,
+    keys: SigningKeyRepository,
+    vault: Arc<KeyVault>,
+    store: Arc<dyn AttachmentStore>,
+}
+
+impl SigningKeyService {
+    /// Create a new signing key service
+    pub fn new(db: Arc<Database>, vault: Arc<KeyVault>, store: Arc<dyn AttachmentStore>) -> Self {
+        Self {
+            users: UserRepository::new(db.clone()),
+            keys: SigningKeyRepository::new(db),
+            vault,
+            store,
+        }
+    }
+
+    /// List the keys a user has held, newest first
+    pub async fn list_keys(&self, user_id: &str, current_user: Option<String>) -> AppResult<Vec<SigningKey>> {
+        ensure_self(user_id, current_user)?;
+        self.keys.keys_for_user(user_id).await
+    }
+
+    /// Issue a new signing key for a user, retiring the key they signed with until now
+    pub async fn create_key(&self, user_id: &str, current_user: Option<String>) -> AppResult<SigningKey> {
+        ensure_self(user_id, current_user)?;
+        self.users.get_by_id(user_id.to_string()).await?;
+
+        let (public_key, sealed_private_key) = self.vault.generate()?;
+        self.keys.rotate(user_id, &public_key, &sealed_private_key).await
+    }
+
+    /// Retire a user's signing key, e.g. when it may have been misused
+    pub async fn revoke_key(&self, user_id: &str, id: Uuid, current_user: Option<String>) -> AppResult<SigningKey> {
+        ensure_self(user_id, current_user)?;
+        self.keys.revoke(user_id, id).await
+    }
+
+    /// Store the handwritten signature printed on a user's reports
+    pub async fn upload_signature_image(
+        &self,
+        user_id: &str,
+        image: &[u8],
+        current_user: Option<String>,
+    ) -> AppResult<()> {
+        ensure_self(user_id, current_user)?;
+        self.users.get_by_id(user_id.to_string()).await?;
+
+        if image.len() > MAX_SIGNATURE_IMAGE_BYTES {
+            return Err(AppError::Validation(format!(
+                "Signature image must be at most {} bytes",
+                MAX_SIGNATURE_IMAGE_BYTES
+            )));
+        }
+        if !image.starts_with(&[0xFF, 0xD8]) {
+            return Err(AppError::Validation("Signature image must be a JPEG".to_string()));
+        }
+
+        self.store.put(&signature_image_key(user_id), image).await
+    }
+
+    /// Remove a user's signature image
+    pub async fn delete_signature_image(&self, user_id: &str, current_user: Option<String>) -> AppResult<()> {
+        ensure_self(user_id, current_user)?;
+        self.store.delete(&signature_image_key(user_id)).await
+    }
+}
+
+/// Ensure users only manage their own signing credentials
+fn ensure_self(user_id: &str, current_user: Option<String>) -> AppResult<()> {
+    match current_user {
+        None => Err(AppError::Authentication("Authentication required".to_string())),
+        Some(current) if current != user_id => Err(AppError::Authorization(
+            "Users can only manage their own signing keys".to_string(),
+        )),
+        Some(_) => Ok(()),
+    }
+}
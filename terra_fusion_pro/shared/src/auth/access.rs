@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use crate::{
+    db::Database,
+    error::{AppError, AppResult},
+    models::user::UserRole,
+    repository::user_repository::UserRepository,
+};
+
+/// Checks the platform role of the signed-in user before privileged actions
+pub struct AccessControl {
+    users: UserRepository,
+}
+
+impl AccessControl {
+    /// Create a new access control
+    pub fn new(db: Arc<Database>) -> Self {
+        Self {
+            users: UserRepository::new(db),
+        }
+    }
+
+    /// Get the platform role of a user
+    pub async fn role(&self, user_id: &str) -> AppResult<UserRole> {
+        self.users.role_of(user_id).await
+    }
+
+    /// Whether a user is staff or an admin
+    pub async fn is_staff(&self, user_id: &str) -> AppResult<bool> {
+        Ok(self.role(user_id).await? >= UserRole::Staff)
+    }
+
+    /// Require a signed-in user with at least the given role, returning their ID
+    pub async fn require_role(&self, user_id: Option<String>, minimum: UserRole) -> AppResult<String> {
+        let user_id = require_user(user_id)?;
+
+        if self.role(&user_id).await? < minimum {
+            return Err(AppError::Authorization(format!(
+                "This action requires the {:?} role",
+                minimum
+            )));
+        }
+
+        Ok(user_id)
+    }
+
+    /// Require the signed-in user to be `subject`, or to hold at least the given role when acting
+    /// on someone else's records, returning their ID
+    pub async fn require_self_or(
+        &self,
+        user_id: Option<String>,
+        subject: &str,
+        minimum: UserRole,
+    ) -> AppResult<String> {
+        let user_id = require_user(user_id)?;
+
+        if user_id != subject && self.role(&user_id).await? < minimum {
+            return Err(AppError::Authorization("Permission denied".to_string()));
+        }
+
+        Ok(user_id)
+    }
+}
+
+/// Require an authenticated user
+fn require_user(user_id: Option<String>) -> AppResult<String> {
+    user_id.ok_or_else(|| AppError::Authentication("Authentication required".to_string()))
+}
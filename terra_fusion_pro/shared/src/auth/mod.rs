@@ -1,3 +1,4 @@
 pub mod replit_auth;
 pub mod middleware;
-pub mod session;
\ No newline at end of file
+pub mod session;
+pub mod access;
\ No newline at end of file
@@ -28,6 +28,12 @@ pub struct Config {
     pub jwt_secret: String,
     /// Environment (development, production)
     pub environment: String,
+    /// Directory uploaded attachments are stored in
+    pub attachments_dir: String,
+    /// Secret that seals appraisers' private signing keys
+    pub signing_key_secret: String,
+    /// Secret that signs report share links
+    pub share_link_secret: String,
 }
 
 impl Config {
@@ -66,6 +72,15 @@ impl Config {
         let environment = env::var("ENVIRONMENT")
             .unwrap_or_else(|_| "development".to_string());
             
+        let attachments_dir = env::var("ATTACHMENTS_DIR")
+            .unwrap_or_else(|_| "./data/attachments".to_string());
+            
+        // Changing this secret makes existing signing keys unusable
+        let signing_key_secret = secret_var("SIGNING_KEY_SECRET", "development_signing_key_secret", &environment)?;
+            
+        // Changing this secret invalidates every share link already sent
+        let share_link_secret = secret_var("SHARE_LINK_SECRET", "development_share_link_secret", &environment)?;
+            
         Ok(Self {
             database_url,
             host,
@@ -77,6 +92,9 @@ impl Config {
             },
             jwt_secret,
             environment,
+            attachments_dir,
+            signing_key_secret,
+            share_link_secret,
         })
     }
     
@@ -89,4 +107,17 @@ impl Config {
     pub fn is_production(&self) -> bool {
         self.environment == "production"
     }
-}
\ No newline at end of file
+}
+
+/// Read a secret from the environment. The development default is public, so production
+/// refuses to start without the real secret rather than falling back to it.
+fn secret_var(name: &str, development_default: &str, environment: &str) -> AppResult<String> {
+    match env::var(name) {
+        Ok(secret) if !secret.trim().is_empty() => Ok(secret),
+        _ if environment == "production" => Err(AppError::Configuration(format!(
+            "{} must be set in production",
+            name
+        ))),
+        _ => Ok(development_default.to_string()),
+    }
+}
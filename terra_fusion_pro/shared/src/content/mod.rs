@@ -0,0 +1,122 @@
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use crate::error::{AppError, AppResult};
+use crate::models::content::{
+    BpoContent, CommercialContent, DesktopContent, Form1004CContent, Form1004Content, Form1025Content,
+    Form1073Content, Form2055Content, OtherContent,
+};
+use crate::models::report::ReportType;
+
+/// A content value that does not match the schema of its report type
+struct ContentError {
+    /// Location of the value, e.g. "improvements.bedrooms" or "comparables[2].sale_price"
+    path: String,
+
+    /// What is wrong with it
+    message: String,
+}
+
+/// Check report content against the typed schema of its report type
+pub fn validate_content(report_type: &ReportType, content: &Value) -> AppResult<()> {
+    if !content.is_object() {
+        return Err(AppError::Validation("Report content must be a JSON object".to_string()));
+    }
+
+    let errors = match report_type {
+        ReportType::Form1004 => content_errors::<Form1004Content>(content),
+        ReportType::Form1073 => content_errors::<Form1073Content>(content),
+        ReportType::Form1025 => content_errors::<Form1025Content>(content),
+        ReportType::Form1004C => content_errors::<Form1004CContent>(content),
+        ReportType::Form2055 => content_errors::<Form2055Content>(content),
+        ReportType::CommercialForm => content_errors::<CommercialContent>(content),
+        ReportType::DesktopAppraisal => content_errors::<DesktopContent>(content),
+        ReportType::BPO => content_errors::<BpoContent>(content),
+        ReportType::Other => content_errors::<OtherContent>(content),
+    };
+
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let problems = errors
+        .iter()
+        .map(|error| format!("{}: {}", error.path, error.message))
+        .collect::<Vec<_>>();
+    Err(AppError::Validation(format!(
+        "Report content has {} value(s) that do not match the {:?} schema: {}",
+        problems.len(),
+        report_type,
+        problems.join("; ")
+    )))
+}
+
+/// JSON Schema of the content of a report type
+pub fn content_schema(report_type: &ReportType) -> RootSchema {
+    match report_type {
+        ReportType::Form1004 => schema_for!(Form1004Content),
+        ReportType::Form1073 => schema_for!(Form1073Content),
+        ReportType::Form1025 => schema_for!(Form1025Content),
+        ReportType::Form1004C => schema_for!(Form1004CContent),
+        ReportType::Form2055 => schema_for!(Form2055Content),
+        ReportType::CommercialForm => schema_for!(CommercialContent),
+        ReportType::DesktopAppraisal => schema_for!(DesktopContent),
+        ReportType::BPO => schema_for!(BpoContent),
+        ReportType::Other => schema_for!(OtherContent),
+    }
+}
+
+/// Every value of the content that `T` rejects. Each top-level section is checked on its own,
+/// and a rejected section is narrowed to the fields or entries at fault by checking them one at
+/// a time; every field of the content types is optional, so a lone field is a valid section.
+fn content_errors<T: DeserializeOwned>(content: &Value) -> Vec<ContentError> {
+    let sections = match content.as_object() {
+        Some(sections) => sections,
+        None => return Vec::new(),
+    };
+
+    sections
+        .iter()
+        .flat_map(|(key, value)| {
+            narrow::<T>(key.clone(), value, &|value| json!({ key.as_str(): value }))
+        })
+        .collect()
+}
+
+/// Errors of one value, wrapped by `wrap` into content `T` can check. When the value is a
+/// container of the right kind the errors are those of its fields or entries.
+fn narrow<T: DeserializeOwned>(path: String, value: &Value, wrap: &dyn Fn(Value) -> Value) -> Vec<ContentError> {
+    let error = match T::deserialize(&wrap(value.clone())) {
+        Ok(_) => return Vec::new(),
+        Err(error) => error,
+    };
+
+    let accepts = |empty: Value| T::deserialize(&wrap(empty)).is_ok();
+    let found = match value {
+        Value::Object(fields) if accepts(json!({})) => fields
+            .iter()
+            .flat_map(|(key, field)| {
+                narrow::<T>(format!("{}.{}", path, key), field, &|value| wrap(json!({ key.as_str(): value })))
+            })
+            .collect(),
+        Value::Array(entries) if accepts(json!([])) => entries
+            .iter()
+            .enumerate()
+            .flat_map(|(index, entry)| {
+                narrow::<T>(format!("{}[{}]", path, index), entry, &|value| wrap(json!([value])))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    if found.is_empty() {
+        vec![ContentError {
+            path,
+            message: error.to_string(),
+        }]
+    } else {
+        found
+    }
+}
@@ -1,4 +1,6 @@
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::postgres::{PgPool, PgPoolOptions, PgRow};
+use sqlx::{Postgres, Row};
 
 use crate::error::{AppError, AppResult};
 
@@ -34,4 +36,34 @@ impl Database {
             .map(|r| r.rows_affected())
             .map_err(|e| AppError::Database(format!("Failed to execute query: {}", e)))
     }
-}
\ No newline at end of file
+}
+
+/// Read a typed column from a row, mapping decode failures to a database error
+pub fn column<'r, T>(row: &'r PgRow, name: &str) -> AppResult<T>
+where
+    T: sqlx::Decode<'r, Postgres> + sqlx::Type<Postgres>,
+{
+    row.try_get(name)
+        .map_err(|e| AppError::Database(format!("Failed to read column {}: {}", name, e)))
+}
+
+/// Encode a unit enum as the snake_case text stored in VARCHAR columns
+pub fn encode_enum<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        _ => String::new(),
+    }
+}
+
+/// Decode a unit enum from the snake_case text stored in VARCHAR columns
+pub fn decode_enum<T: DeserializeOwned>(value: &str) -> AppResult<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|e| AppError::Database(format!("Unknown enum value '{}': {}", value, e)))
+}
+
+/// Whether a query failed because it would break the named constraint
+pub fn violates_constraint(err: &sqlx::Error, constraint: &str) -> bool {
+    err.as_database_error()
+        .and_then(|e| e.constraint())
+        .is_some_and(|name| name == constraint)
+}
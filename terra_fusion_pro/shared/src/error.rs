@@ -16,6 +16,8 @@ pub enum AppError {
     Validation(String),
     /// Not found error
     NotFound(String),
+    /// Conflict with existing state (e.g. overlapping bookings)
+    Conflict(String),
     /// External service error
     ExternalService(String),
     /// Configuration error
@@ -35,6 +37,7 @@ impl AppError {
             AppError::Authorization(_) => "authorization_error",
             AppError::Validation(_) => "validation_error",
             AppError::NotFound(_) => "not_found_error",
+            AppError::Conflict(_) => "conflict_error",
             AppError::ExternalService(_) => "external_service_error",
             AppError::Configuration(_) => "configuration_error",
             AppError::Deserialization(_) => "deserialization_error",
@@ -50,6 +53,7 @@ impl AppError {
             AppError::Authorization(msg) => msg,
             AppError::Validation(msg) => msg,
             AppError::NotFound(msg) => msg,
+            AppError::Conflict(msg) => msg,
             AppError::ExternalService(msg) => msg,
             AppError::Configuration(msg) => msg,
             AppError::Deserialization(msg) => msg,
@@ -77,6 +81,7 @@ impl actix_web::ResponseError for AppError {
             AppError::Authorization(_) => StatusCode::FORBIDDEN,
             AppError::Validation(_) => StatusCode::BAD_REQUEST,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
             AppError::ExternalService(_) => StatusCode::BAD_GATEWAY,
             AppError::Configuration(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::Deserialization(_) => StatusCode::BAD_REQUEST,
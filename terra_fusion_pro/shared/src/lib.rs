@@ -4,6 +4,12 @@ pub mod error;
 pub mod db;
 pub mod config;
 pub mod repository;
+pub mod notifications;
+pub mod webhooks;
+pub mod content;
+pub mod signing;
+pub mod storage;
+pub mod uad;
 pub mod utils;
 
 // Re-export common types for convenience
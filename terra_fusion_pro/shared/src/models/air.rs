@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// A blocked attempt by loan-production staff to influence an appraisal,
+/// kept for Appraiser Independence Requirements (AIR) audits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AirViolation {
+    /// Unique identifier for the violation
+    pub id: Uuid,
+
+    /// ID of the client the user belongs to
+    pub client_id: Uuid,
+
+    /// ID of the user who attempted the action
+    pub user_id: String,
+
+    /// ID of the appraisal the attempt concerned (optional)
+    pub appraisal_id: Option<Uuid>,
+
+    /// What was attempted
+    pub action: AirAction,
+
+    /// Details of the attempt
+    pub detail: String,
+
+    /// When the attempt was made
+    pub created_at: DateTime<Utc>,
+}
+
+/// Enumeration of actions loan-production staff may not take
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AirAction {
+    /// Messaging the appraiser directly
+    DirectMessage,
+    /// Requesting revisions to value-related parts of a report
+    ValueRelatedRevision,
+    /// Selecting or assigning the appraiser
+    AppraiserSelection,
+    /// Negotiating the appraiser's fee or due date
+    FeeNegotiation,
+}
+
+/// How a client's appraisals are distributed across its appraiser panel
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RotationMode {
+    /// Any qualified appraiser may be assigned
+    Open,
+    /// Only active panel members may be assigned
+    PanelOnly,
+    /// Only the qualified panel member who has gone longest without an assignment may be assigned
+    RoundRobin,
+}
+
+/// A client's panel rotation policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PanelRotationPolicy {
+    /// ID of the client
+    pub client_id: Uuid,
+
+    /// How assignments rotate through the panel
+    pub mode: RotationMode,
+
+    /// When the policy was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PanelRotationPolicy {
+    /// Policy for a client that has never set one
+    pub fn defaults_for(client_id: Uuid) -> Self {
+        Self {
+            client_id,
+            mode: RotationMode::Open,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+/// An appraiser on a client's panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PanelMember {
+    /// ID of the client
+    pub client_id: Uuid,
+
+    /// ID of the appraiser
+    pub appraiser_id: Uuid,
+
+    /// Whether the appraiser currently receives assignments
+    pub is_active: bool,
+
+    /// Number of the client's appraisals assigned to the appraiser
+    pub assignment_count: i32,
+
+    /// When the appraiser was last assigned one of the client's appraisals (optional)
+    pub last_assigned_at: Option<DateTime<Utc>>,
+
+    /// When the appraiser joined the panel
+    pub added_at: DateTime<Utc>,
+}
+
+/// Request to add an appraiser to a client's panel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddPanelMemberRequest {
+    /// ID of the appraiser
+    pub appraiser_id: Uuid,
+}
+
+/// Request to change a client's panel rotation policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRotationPolicyRequest {
+    /// How assignments rotate through the panel
+    pub mode: RotationMode,
+}
@@ -68,6 +68,26 @@ pub enum AppraisalStatus {
     OnHold,
 }
 
+impl AppraisalStatus {
+    /// Every status, in pipeline order
+    pub const ALL: [AppraisalStatus; 9] = [
+        AppraisalStatus::New,
+        AppraisalStatus::Assigned,
+        AppraisalStatus::Scheduled,
+        AppraisalStatus::InProgress,
+        AppraisalStatus::PendingReview,
+        AppraisalStatus::RevisionNeeded,
+        AppraisalStatus::Completed,
+        AppraisalStatus::Cancelled,
+        AppraisalStatus::OnHold,
+    ];
+    
+    /// Whether the appraisal has left the active pipeline and can no longer be overdue
+    pub fn is_closed(&self) -> bool {
+        matches!(self, AppraisalStatus::Completed | AppraisalStatus::Cancelled)
+    }
+}
+
 /// Enumeration of appraisal purposes
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -86,6 +106,26 @@ pub enum AppraisalPurpose {
     Other,
 }
 
+impl AppraisalPurpose {
+    /// Intended use statement for engagement letters and reports
+    pub fn intended_use(&self) -> &'static str {
+        match self {
+            AppraisalPurpose::Purchase => "to assist the client in evaluating the subject property for a purchase mortgage finance transaction",
+            AppraisalPurpose::Refinance => "to assist the client in evaluating the subject property for a refinance mortgage transaction",
+            AppraisalPurpose::HomeEquity => "to assist the client in evaluating the subject property for a home equity lending decision",
+            AppraisalPurpose::PMI => "to assist the client in deciding whether private mortgage insurance may be removed",
+            AppraisalPurpose::PreListing => "to assist the client in setting a listing price for the subject property",
+            AppraisalPurpose::Estate => "to establish the market value of the subject property for estate settlement",
+            AppraisalPurpose::Divorce => "to establish the market value of the subject property for the division of marital assets",
+            AppraisalPurpose::TaxAppeal => "to support the client in an appeal of the subject property's assessed value",
+            AppraisalPurpose::Bankruptcy => "to establish the market value of the subject property for bankruptcy proceedings",
+            AppraisalPurpose::RelocationEstimate => "to assist the client in an employee relocation decision",
+            AppraisalPurpose::Insurance => "to estimate the insurable value of the subject property",
+            AppraisalPurpose::Other => "as described in the client's instructions",
+        }
+    }
+}
+
 /// Enumeration of appraisal types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -98,6 +138,20 @@ pub enum AppraisalType {
     Other,
 }
 
+impl AppraisalType {
+    /// Scope of work statement for engagement letters and reports
+    pub fn scope_of_work(&self) -> &'static str {
+        match self {
+            AppraisalType::FullAppraisal => "a full appraisal including an interior and exterior inspection of the subject property",
+            AppraisalType::DriveBy => "an exterior-only appraisal with a drive-by inspection of the subject property",
+            AppraisalType::Desktop => "a desktop appraisal without an inspection of the subject property",
+            AppraisalType::Automated => "a review of an automated valuation of the subject property",
+            AppraisalType::BPO => "a broker price opinion of the subject property",
+            AppraisalType::Other => "the scope described in the client's instructions",
+        }
+    }
+}
+
 /// Request to create a new appraisal assignment
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateAppraisalRequest {
@@ -194,6 +248,13 @@ pub struct UpdateAppraisalRequest {
     pub fee: Option<f64>,
 }
 
+/// Request to assign an appraiser to an appraisal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignAppraiserRequest {
+    /// ID of the appraiser to assign
+    pub appraiser_id: Uuid,
+}
+
 /// Appraisal summary for listing purposes
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppraisalSummary {
@@ -203,11 +264,11 @@ pub struct AppraisalSummary {
     /// Appraisal reference number (client-provided)
     pub reference_number: Option<String>,
     
-    /// Client name
-    pub client_name: String,
+    /// Client name (if the client record exists)
+    pub client_name: Option<String>,
     
-    /// Property address (formatted)
-    pub property_address: String,
+    /// Property address, formatted (if the property record exists)
+    pub property_address: Option<String>,
     
     /// Appraiser name (if assigned)
     pub appraiser_name: Option<String>,
@@ -223,4 +284,68 @@ pub struct AppraisalSummary {
     
     /// When the appraisal was created
     pub created_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+/// Filters for listing appraisals in the pipeline
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AppraisalFilter {
+    /// Only appraisals in this status (optional)
+    pub status: Option<AppraisalStatus>,
+    
+    /// Only appraisals due on or after this time (optional)
+    pub due_from: Option<DateTime<Utc>>,
+    
+    /// Only appraisals due on or before this time (optional)
+    pub due_to: Option<DateTime<Utc>>,
+    
+    /// Only appraisals ordered by this client (optional)
+    pub client_id: Option<Uuid>,
+    
+    /// Only appraisals assigned to this appraiser (optional)
+    pub appraiser_id: Option<Uuid>,
+    
+    /// Only overdue appraisals when true, only on-time ones when false (optional)
+    pub overdue: Option<bool>,
+}
+
+/// Number of appraisals in a given status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusCount {
+    /// Appraisal status
+    pub status: AppraisalStatus,
+    
+    /// Number of appraisals in the status
+    pub count: i64,
+}
+
+/// Open workload of a single appraiser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppraiserWorkload {
+    /// ID of the appraiser (none for unassigned appraisals)
+    pub appraiser_id: Option<Uuid>,
+    
+    /// Appraiser name (if assigned)
+    pub appraiser_name: Option<String>,
+    
+    /// Number of open appraisals
+    pub open: i64,
+    
+    /// Number of open appraisals past their due date
+    pub overdue: i64,
+}
+
+/// Aggregate counts for the operations dashboard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineDashboard {
+    /// Total number of matching appraisals
+    pub total: i64,
+    
+    /// Number of matching appraisals past their due date
+    pub overdue: i64,
+    
+    /// Counts per appraisal status, including empty statuses
+    pub by_status: Vec<StatusCount>,
+    
+    /// Open workload per appraiser
+    pub by_appraiser: Vec<AppraiserWorkload>,
+}
@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+use super::property::Address;
+use super::report::ReportType;
+
+/// Represents a client organization that orders appraisals
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Client {
+    /// Unique identifier for the client
+    pub id: Uuid,
+
+    /// Organization name
+    pub name: String,
+
+    /// Kind of organization
+    pub client_type: ClientType,
+
+    /// People to contact at the organization
+    pub contacts: Vec<ClientContact>,
+
+    /// Address invoices are sent to (optional)
+    pub billing_address: Option<Address>,
+
+    /// Instructions applied to new orders when none are given (optional)
+    pub default_instructions: Option<String>,
+
+    /// Report types this client accepts
+    pub required_report_types: Vec<ReportType>,
+
+    /// Whether the client can place new orders
+    pub is_active: bool,
+
+    /// When the client was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the client was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Enumeration of client organization types
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientType {
+    Lender,
+    Amc,
+    Private,
+}
+
+/// A contact person at a client organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientContact {
+    /// Contact name
+    pub name: String,
+
+    /// Job title (optional)
+    pub title: Option<String>,
+
+    /// Email address (optional)
+    pub email: Option<String>,
+
+    /// Phone number (optional)
+    pub phone: Option<String>,
+
+    /// Whether this is the primary contact
+    pub is_primary: bool,
+}
+
+/// A platform user who acts on behalf of a client through the client portal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientPortalUser {
+    /// Unique identifier for the membership
+    pub id: Uuid,
+
+    /// ID of the client organization
+    pub client_id: Uuid,
+
+    /// ID of the platform user
+    pub user_id: String,
+
+    /// Role of the user within the client organization
+    pub role: PortalRole,
+
+    /// Whether the user works in loan production and is barred from influencing appraisers
+    pub is_loan_production: bool,
+
+    /// When the membership was created
+    pub created_at: DateTime<Utc>,
+}
+
+/// Enumeration of client portal roles
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PortalRole {
+    Admin,
+    Orderer,
+    Viewer,
+}
+
+/// Request to create a new client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateClientRequest {
+    /// Organization name
+    pub name: String,
+
+    /// Kind of organization
+    pub client_type: ClientType,
+
+    /// People to contact at the organization (optional)
+    pub contacts: Option<Vec<ClientContact>>,
+
+    /// Billing address (optional)
+    pub billing_address: Option<Address>,
+
+    /// Default order instructions (optional)
+    pub default_instructions: Option<String>,
+
+    /// Report types this client accepts (optional)
+    pub required_report_types: Option<Vec<ReportType>>,
+}
+
+/// Request to update a client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateClientRequest {
+    /// Organization name (optional)
+    pub name: Option<String>,
+
+    /// Kind of organization (optional)
+    pub client_type: Option<ClientType>,
+
+    /// People to contact at the organization (optional)
+    pub contacts: Option<Vec<ClientContact>>,
+
+    /// Billing address (optional)
+    pub billing_address: Option<Address>,
+
+    /// Default order instructions (optional)
+    pub default_instructions: Option<String>,
+
+    /// Report types this client accepts (optional)
+    pub required_report_types: Option<Vec<ReportType>>,
+
+    /// Whether the client can place new orders (optional)
+    pub is_active: Option<bool>,
+}
+
+/// Request to grant a user access to a client's portal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddPortalUserRequest {
+    /// ID of the platform user
+    pub user_id: String,
+
+    /// Role of the user within the client organization
+    pub role: PortalRole,
+
+    /// Whether the user works in loan production (optional, defaults to false)
+    pub is_loan_production: Option<bool>,
+}
@@ -0,0 +1,649 @@
+//! Typed shape of `Report.content` for each report type. Every field is optional so drafts can
+//! be saved partly filled in; fields that are present must have the documented type, and
+//! sections reject fields they do not define. Sections outside a form's layout are accepted as
+//! free-form objects.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::reconciliation::ApproachWeight;
+
+/// Subject property and assignment
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubjectSection {
+    /// Borrower named on the assignment
+    pub borrower: Option<String>,
+
+    /// Owner of public record
+    pub owner_of_record: Option<String>,
+
+    /// Legal description of the property
+    pub legal_description: Option<String>,
+
+    /// Assessor's parcel number
+    pub parcel_number: Option<String>,
+
+    /// Year of the real estate tax figure
+    pub tax_year: Option<i32>,
+
+    /// Annual real estate taxes in dollars
+    pub real_estate_taxes: Option<f64>,
+
+    /// Name of the neighborhood or project
+    pub neighborhood_name: Option<String>,
+
+    /// Occupant: Owner, Tenant or Vacant
+    pub occupant: Option<String>,
+
+    /// Property rights appraised, e.g. "Fee Simple"
+    pub property_rights: Option<String>,
+
+    /// Assignment type, e.g. "Purchase Transaction"
+    pub assignment_type: Option<String>,
+
+    /// Lender or client named on the report
+    pub lender_client: Option<String>,
+}
+
+/// Sales contract of a purchase transaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ContractSection {
+    /// Contract price in dollars
+    pub contract_price: Option<f64>,
+
+    /// Date of contract, MM/DD/YYYY
+    pub contract_date: Option<String>,
+
+    /// UAD sale type, e.g. "ArmLth"
+    pub sale_type: Option<String>,
+
+    /// Financial assistance paid by the seller, in dollars
+    pub financial_assistance: Option<f64>,
+
+    /// Analysis of the contract
+    pub analysis: Option<String>,
+}
+
+/// Neighborhood characteristics and market narrative
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NeighborhoodSection {
+    /// Name of the neighborhood
+    pub neighborhood_name: Option<String>,
+
+    /// Urban, Suburban or Rural
+    pub location: Option<String>,
+
+    /// Share built up: "Over 75%", "25-75%" or "Under 25%"
+    pub built_up: Option<String>,
+
+    /// Rapid, Stable or Slow
+    pub growth: Option<String>,
+
+    /// Increasing, Stable or Declining
+    pub property_values: Option<String>,
+
+    /// Shortage, In Balance or Over Supply
+    pub demand_supply: Option<String>,
+
+    /// Typical marketing time, e.g. "3-6 mths"
+    pub marketing_time: Option<String>,
+
+    /// Neighborhood boundaries
+    pub boundaries: Option<String>,
+
+    /// Neighborhood description
+    pub description: Option<String>,
+
+    /// Market conditions narrative
+    pub market_conditions: Option<String>,
+}
+
+/// The site and its zoning
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SiteSection {
+    /// Lot dimensions
+    pub dimensions: Option<String>,
+
+    /// Site area, e.g. "0.25 ac"
+    pub area: Option<String>,
+
+    /// Zoning classification
+    pub zoning_classification: Option<String>,
+
+    /// Legal, Legal Nonconforming, No Zoning or Illegal
+    pub zoning_compliance: Option<String>,
+
+    /// Whether the present use is the highest and best use as improved
+    pub highest_and_best_use: Option<String>,
+
+    /// Public and private utilities
+    pub utilities: Option<String>,
+
+    /// FEMA flood zone
+    pub flood_zone: Option<String>,
+
+    /// UAD view, e.g. "N;Res;"
+    pub view: Option<String>,
+
+    /// Site comments
+    pub comments: Option<String>,
+}
+
+/// Description of the improvements
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ImprovementsSection {
+    /// Number of living units
+    pub units: Option<u32>,
+
+    /// Number of stories, e.g. 1.5
+    pub stories: Option<f64>,
+
+    /// Design or style
+    pub design_style: Option<String>,
+
+    /// Year built
+    pub year_built: Option<i32>,
+
+    /// Effective age in years
+    pub effective_age: Option<u32>,
+
+    /// UAD condition rating, C1 through C6
+    pub condition: Option<String>,
+
+    /// UAD quality rating, Q1 through Q6
+    pub quality: Option<String>,
+
+    /// Gross living area in square feet
+    pub gross_living_area: Option<f64>,
+
+    /// Total rooms above grade
+    pub rooms: Option<u32>,
+
+    /// Bedrooms above grade
+    pub bedrooms: Option<u32>,
+
+    /// Bathrooms in UAD full.half notation, e.g. "2.1"
+    pub bathrooms: Option<String>,
+
+    /// Condition of the property
+    pub description: Option<String>,
+
+    /// Source of interior data for an exterior-only inspection
+    pub interior_data_source: Option<String>,
+}
+
+/// Condominium project information
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectSection {
+    /// Project name
+    pub name: Option<String>,
+
+    /// Project phase
+    pub phase: Option<String>,
+
+    /// Total units in the project
+    pub units_total: Option<u32>,
+
+    /// Units sold
+    pub units_sold: Option<u32>,
+
+    /// Units rented
+    pub units_rented: Option<u32>,
+
+    /// Monthly HOA fee in dollars
+    pub hoa_fee: Option<f64>,
+
+    /// Project description
+    pub description: Option<String>,
+}
+
+/// Manufactured home identification and installation
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ManufacturedHomeSection {
+    /// Manufacturer
+    pub manufacturer: Option<String>,
+
+    /// Model
+    pub model: Option<String>,
+
+    /// Serial number
+    pub serial_number: Option<String>,
+
+    /// Whether the HUD data plate is attached
+    pub hud_data_plate: Option<bool>,
+
+    /// HUD certification label numbers
+    pub hud_label_numbers: Option<String>,
+
+    /// Year manufactured
+    pub year_manufactured: Option<i32>,
+
+    /// Foundation type
+    pub foundation: Option<String>,
+
+    /// Installation comments
+    pub installation_comments: Option<String>,
+}
+
+/// Income approach to value
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IncomeSection {
+    /// Estimated monthly market rent in dollars
+    pub gross_monthly_rent: Option<f64>,
+
+    /// Gross rent multiplier
+    pub gross_rent_multiplier: Option<f64>,
+
+    /// Value indicated by the income approach, in dollars
+    pub indicated_value: Option<f64>,
+
+    /// Summary of the rent schedule
+    pub rent_schedule_summary: Option<String>,
+}
+
+/// Cost approach to value
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CostSection {
+    /// Site value in dollars
+    pub site_value: Option<f64>,
+
+    /// Replacement cost new in dollars
+    pub cost_new: Option<f64>,
+
+    /// Total depreciation in dollars
+    pub depreciation: Option<f64>,
+
+    /// Value indicated by the cost approach, in dollars
+    pub indicated_value: Option<f64>,
+
+    /// Cost approach comments
+    pub comments: Option<String>,
+}
+
+/// Sales comparison approach summary; the grid itself is `comparables`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SalesComparisonSection {
+    /// Comparable listings currently offered
+    pub listings_count: Option<u32>,
+
+    /// Comparable sales in the past 12 months
+    pub sales_count: Option<u32>,
+
+    /// Value indicated by sales comparison, in dollars
+    pub indicated_value: Option<f64>,
+
+    /// Summary of the sales comparison approach
+    pub summary: Option<String>,
+}
+
+/// Reconciliation of the approaches to value
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconciliationSection {
+    /// Value by the sales comparison approach, in dollars
+    pub sales_comparison_value: Option<f64>,
+
+    /// Value by the cost approach, in dollars
+    pub cost_value: Option<f64>,
+
+    /// Value by the income approach, in dollars
+    pub income_value: Option<f64>,
+
+    /// Condition the appraisal is made under, e.g. "As Is"
+    pub condition: Option<String>,
+
+    /// Effective date of the appraisal, MM/DD/YYYY
+    pub effective_date: Option<String>,
+
+    /// Reconciliation comments
+    pub comments: Option<String>,
+
+    /// Weight given to each approach, with the appraiser's justification
+    pub weights: Option<Vec<ApproachWeight>>,
+
+    /// Weighted value of the approaches before rounding
+    pub weighted_value: Option<f64>,
+
+    /// Final reconciled value
+    pub final_value: Option<f64>,
+
+    /// Gap between the highest and lowest indications as a percentage of the weighted value
+    pub spread_percent: Option<f64>,
+
+    /// Why the indications differ as much as they do
+    pub spread_explanation: Option<String>,
+}
+
+/// Commercial market analysis
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MarketAnalysisSection {
+    /// Market area
+    pub market_area: Option<String>,
+
+    /// Vacancy rate as a percentage
+    pub vacancy_rate: Option<f64>,
+
+    /// Capitalization rate as a percentage
+    pub cap_rate: Option<f64>,
+
+    /// Market overview
+    pub overview: Option<String>,
+
+    /// Highest and best use analysis
+    pub highest_and_best_use: Option<String>,
+}
+
+/// Market conditions of a broker price opinion
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MarketConditionsSection {
+    /// Market trend
+    pub trend: Option<String>,
+
+    /// Average days on market
+    pub days_on_market: Option<u32>,
+
+    /// Housing supply
+    pub supply: Option<String>,
+
+    /// Estimated repairs in dollars
+    pub estimated_repairs: Option<f64>,
+
+    /// Comments
+    pub comments: Option<String>,
+}
+
+/// Scope of work of a desktop appraisal
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScopeOfWorkSection {
+    /// Data sources relied on
+    pub data_sources: Option<String>,
+
+    /// Source of the floor plan
+    pub floor_plan_source: Option<String>,
+
+    /// Scope of work
+    pub description: Option<String>,
+}
+
+/// Appraiser as named in an imported report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AppraiserSection {
+    /// Appraiser's name
+    pub name: Option<String>,
+
+    /// License or certification type
+    pub license_type: Option<String>,
+
+    /// License number
+    pub license_number: Option<String>,
+
+    /// Two-letter state of the license
+    pub license_state: Option<String>,
+
+    /// License expiration date
+    pub license_expires_on: Option<String>,
+}
+
+/// A sale in the residential sales comparison grid
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ComparableSale {
+    /// Street address
+    pub address: Option<String>,
+
+    /// Proximity to the subject, e.g. "0.42 miles NW"
+    pub proximity: Option<String>,
+
+    /// Sale price in dollars
+    pub sale_price: Option<f64>,
+
+    /// Data sources
+    pub data_source: Option<String>,
+
+    /// UAD sale type, e.g. "ArmLth"
+    pub sale_type: Option<String>,
+
+    /// UAD date of sale, e.g. "s03/23;c02/23"
+    pub sale_date: Option<String>,
+
+    /// UAD location, e.g. "N;Res;"
+    pub location: Option<String>,
+
+    /// UAD view, e.g. "N;Res;"
+    pub view: Option<String>,
+
+    /// UAD condition rating, C1 through C6
+    pub condition: Option<String>,
+
+    /// UAD quality rating, Q1 through Q6
+    pub quality: Option<String>,
+
+    /// Gross living area in square feet
+    pub gross_living_area: Option<f64>,
+
+    /// Bedrooms above grade
+    pub bedrooms: Option<u32>,
+
+    /// Bathrooms in UAD full.half notation, e.g. "2.1"
+    pub bathrooms: Option<String>,
+
+    /// Net adjustment in dollars
+    pub net_adjustment: Option<f64>,
+
+    /// Adjusted sale price in dollars
+    pub adjusted_price: Option<f64>,
+}
+
+/// A sale or listing in a broker price opinion grid
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BpoComparable {
+    /// Street address
+    pub address: Option<String>,
+
+    /// Listing status, e.g. "Sold" or "Active"
+    pub status: Option<String>,
+
+    /// List price in dollars
+    pub list_price: Option<f64>,
+
+    /// Sale price in dollars
+    pub sale_price: Option<f64>,
+
+    /// Date of sale
+    pub sale_date: Option<String>,
+
+    /// Days on market
+    pub days_on_market: Option<u32>,
+
+    /// Living area in square feet
+    pub gross_living_area: Option<f64>,
+
+    /// Bedrooms
+    pub bedrooms: Option<u32>,
+
+    /// Bathrooms in full.half notation, e.g. "2.1"
+    pub bathrooms: Option<String>,
+
+    /// Adjusted price in dollars
+    pub adjusted_price: Option<f64>,
+}
+
+/// A photo printed in the report's photo addendum
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReportPhoto {
+    /// Storage key of the JPEG, under the report's own prefix
+    pub storage_key: Option<String>,
+
+    /// Label printed above the photo, e.g. "Front"
+    pub label: Option<String>,
+
+    /// Caption printed below the photo
+    pub caption: Option<String>,
+}
+
+/// Largest accepted report photo, in bytes
+pub const MAX_REPORT_PHOTO_BYTES: usize = 10 * 1024 * 1024;
+
+/// Options for uploading a report photo; the JPEG itself is the request body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadReportPhotoQuery {
+    /// Label printed above the photo (optional)
+    pub label: Option<String>,
+
+    /// Caption printed below the photo (optional)
+    pub caption: Option<String>,
+}
+
+/// Content of a Form 1004 Uniform Residential Appraisal Report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Form1004Content {
+    pub subject: Option<SubjectSection>,
+    pub contract: Option<ContractSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub site: Option<SiteSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub cost: Option<CostSection>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a Form 1073 Individual Condominium Unit Appraisal Report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Form1073Content {
+    pub subject: Option<SubjectSection>,
+    pub contract: Option<ContractSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub project: Option<ProjectSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub income: Option<IncomeSection>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a Form 1025 Small Residential Income Property Appraisal Report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Form1025Content {
+    pub subject: Option<SubjectSection>,
+    pub contract: Option<ContractSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub site: Option<SiteSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub income: Option<IncomeSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub cost: Option<CostSection>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a Form 1004C Manufactured Home Appraisal Report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Form1004CContent {
+    pub subject: Option<SubjectSection>,
+    pub contract: Option<ContractSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub site: Option<SiteSection>,
+    pub manufactured_home: Option<ManufacturedHomeSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub cost: Option<CostSection>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a Form 2055 Exterior-Only Inspection Residential Appraisal Report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct Form2055Content {
+    pub subject: Option<SubjectSection>,
+    pub contract: Option<ContractSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub site: Option<SiteSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a commercial property appraisal report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CommercialContent {
+    pub subject: Option<SubjectSection>,
+    pub site: Option<SiteSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub market_analysis: Option<MarketAnalysisSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub income: Option<IncomeSection>,
+    pub cost: Option<CostSection>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a desktop appraisal report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DesktopContent {
+    pub subject: Option<SubjectSection>,
+    pub scope: Option<ScopeOfWorkSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub site: Option<SiteSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a broker price opinion
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BpoContent {
+    pub subject: Option<SubjectSection>,
+    pub market_conditions: Option<MarketConditionsSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<BpoComparable>>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
+
+/// Content of a report of any other type
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct OtherContent {
+    pub subject: Option<SubjectSection>,
+    pub neighborhood: Option<NeighborhoodSection>,
+    pub site: Option<SiteSection>,
+    pub improvements: Option<ImprovementsSection>,
+    pub sales_comparison: Option<SalesComparisonSection>,
+    pub comparables: Option<Vec<ComparableSale>>,
+    pub reconciliation: Option<ReconciliationSection>,
+    pub appraiser: Option<AppraiserSection>,
+    pub photos: Option<Vec<ReportPhoto>>,
+}
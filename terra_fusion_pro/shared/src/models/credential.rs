@@ -0,0 +1,276 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+use super::property::PropertyType;
+
+/// Number of days before expiry at which credentials start producing warnings
+pub const CREDENTIAL_EXPIRY_WARNING_DAYS: i64 = 60;
+
+/// A state appraiser license held by a user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppraiserLicense {
+    /// Unique identifier for the license record
+    pub id: Uuid,
+
+    /// ID of the user holding the license
+    pub user_id: String,
+
+    /// Two-letter state code the license was issued in
+    pub state: String,
+
+    /// License number as issued by the state board
+    pub license_number: String,
+
+    /// License level
+    pub level: LicenseLevel,
+
+    /// Date the license became effective (optional)
+    pub issued_on: Option<NaiveDate>,
+
+    /// Date the license expires
+    pub expires_on: NaiveDate,
+
+    /// When the record was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the record was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Enumeration of appraiser license levels, lowest to highest
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LicenseLevel {
+    Trainee,
+    Licensed,
+    CertifiedResidential,
+    CertifiedGeneral,
+}
+
+impl LicenseLevel {
+    /// Whether this level may appraise the given property type as the signing appraiser
+    pub fn permits(&self, property_type: &PropertyType) -> bool {
+        match self {
+            // Trainees may only work under a supervisory appraiser
+            LicenseLevel::Trainee => false,
+            LicenseLevel::Licensed | LicenseLevel::CertifiedResidential => matches!(
+                property_type,
+                PropertyType::SingleFamily
+                    | PropertyType::Condo
+                    | PropertyType::Townhouse
+                    | PropertyType::MultiFamily
+            ),
+            LicenseLevel::CertifiedGeneral => true,
+        }
+    }
+
+    /// Whether this level may act as a supervisory appraiser and co-sign a report
+    pub fn can_supervise(&self) -> bool {
+        matches!(self, LicenseLevel::CertifiedResidential | LicenseLevel::CertifiedGeneral)
+    }
+}
+
+impl AppraiserLicense {
+    /// Whether the license is in force on the given date
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        let started = self.issued_on.map_or(true, |issued| issued <= date);
+        started && date <= self.expires_on
+    }
+}
+
+/// An errors and omissions (E&O) insurance policy held by a user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsurancePolicy {
+    /// Unique identifier for the policy record
+    pub id: Uuid,
+
+    /// ID of the insured user
+    pub user_id: String,
+
+    /// Insurance carrier name
+    pub carrier: String,
+
+    /// Policy number
+    pub policy_number: String,
+
+    /// Per-claim coverage amount
+    pub coverage_amount: f64,
+
+    /// Date coverage begins
+    pub effective_on: NaiveDate,
+
+    /// Date coverage ends
+    pub expires_on: NaiveDate,
+
+    /// URL of the uploaded declarations page (optional)
+    pub document_url: Option<String>,
+
+    /// When the record was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the record was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl InsurancePolicy {
+    /// Whether the policy provides coverage on the given date
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.effective_on <= date && date <= self.expires_on
+    }
+}
+
+/// Request to add or replace a license record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LicenseRequest {
+    /// Two-letter state code
+    pub state: String,
+
+    /// License number
+    pub license_number: String,
+
+    /// License level
+    pub level: LicenseLevel,
+
+    /// Date the license became effective (optional)
+    pub issued_on: Option<NaiveDate>,
+
+    /// Date the license expires
+    pub expires_on: NaiveDate,
+}
+
+/// Request to add or replace an E&O insurance policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InsurancePolicyRequest {
+    /// Insurance carrier name
+    pub carrier: String,
+
+    /// Policy number
+    pub policy_number: String,
+
+    /// Per-claim coverage amount
+    pub coverage_amount: f64,
+
+    /// Date coverage begins
+    pub effective_on: NaiveDate,
+
+    /// Date coverage ends
+    pub expires_on: NaiveDate,
+
+    /// URL of the uploaded declarations page (optional)
+    pub document_url: Option<String>,
+}
+
+/// Kind of credential a warning refers to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialKind {
+    License,
+    Insurance,
+}
+
+/// Warning about a credential that has expired or expires soon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialWarning {
+    /// ID of the user holding the credential
+    pub user_id: String,
+
+    /// Kind of credential
+    pub kind: CredentialKind,
+
+    /// ID of the license or policy record
+    pub credential_id: Uuid,
+
+    /// Expiry date of the credential
+    pub expires_on: NaiveDate,
+
+    /// Days until expiry (negative once expired)
+    pub days_remaining: i64,
+
+    /// Human-readable warning
+    pub message: String,
+}
+
+/// All credentials on file for a user, with any expiry warnings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialSummary {
+    /// ID of the user
+    pub user_id: String,
+
+    /// License records
+    pub licenses: Vec<AppraiserLicense>,
+
+    /// E&O insurance policies
+    pub insurance_policies: Vec<InsurancePolicy>,
+
+    /// Expiry warnings
+    pub warnings: Vec<CredentialWarning>,
+}
+
+/// Build an expiry warning for a credential expiring within `window_days` of `today`
+pub fn expiry_warning(
+    user_id: &str,
+    kind: CredentialKind,
+    credential_id: Uuid,
+    label: &str,
+    expires_on: NaiveDate,
+    today: NaiveDate,
+    window_days: i64,
+) -> Option<CredentialWarning> {
+    let days_remaining = (expires_on - today).num_days();
+
+    if days_remaining > window_days {
+        return None;
+    }
+
+    let message = if days_remaining < 0 {
+        format!("{} expired on {}", label, expires_on)
+    } else {
+        format!("{} expires on {} ({} days)", label, expires_on, days_remaining)
+    };
+
+    Some(CredentialWarning {
+        user_id: user_id.to_string(),
+        kind,
+        credential_id,
+        expires_on,
+        days_remaining,
+        message,
+    })
+}
+
+/// Find a license qualifying its holder to appraise a property in a state on a date
+pub fn find_qualifying_license<'a>(
+    licenses: &'a [AppraiserLicense],
+    state: &str,
+    property_type: &PropertyType,
+    on_date: NaiveDate,
+) -> Result<&'a AppraiserLicense, String> {
+    let in_state: Vec<&AppraiserLicense> = licenses
+        .iter()
+        .filter(|l| l.state.eq_ignore_ascii_case(state.trim()))
+        .collect();
+
+    if in_state.is_empty() {
+        return Err(format!("Appraiser holds no license in {}", state));
+    }
+
+    let active: Vec<&AppraiserLicense> = in_state
+        .into_iter()
+        .filter(|l| l.is_active_on(on_date))
+        .collect();
+
+    if active.is_empty() {
+        return Err(format!("Appraiser's {} license is not active on {}", state, on_date));
+    }
+
+    active
+        .into_iter()
+        .find(|l| l.level.permits(property_type))
+        .ok_or_else(|| {
+            format!(
+                "Appraiser's {} license level does not permit {:?} properties",
+                state, property_type
+            )
+        })
+}
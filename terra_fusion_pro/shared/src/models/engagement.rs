@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// An engagement letter the assigned appraiser must accept before starting work
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngagementLetter {
+    /// Unique identifier for the letter
+    pub id: Uuid,
+
+    /// ID of the appraisal the letter covers
+    pub appraisal_id: Uuid,
+
+    /// ID of the appraiser the letter is addressed to
+    pub appraiser_id: Uuid,
+
+    /// Version of the letter for the appraisal, starting at 1
+    pub version: i32,
+
+    /// Scope of work
+    pub scope_of_work: String,
+
+    /// Agreed fee (optional)
+    pub fee: Option<f64>,
+
+    /// Agreed due date (optional)
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Intended use of the appraisal
+    pub intended_use: String,
+
+    /// Intended users of the appraisal
+    pub intended_users: Vec<String>,
+
+    /// Rendered letter text
+    pub body: String,
+
+    /// Current status of the letter
+    pub status: EngagementStatus,
+
+    /// Terms the appraiser proposed when declining (optional)
+    pub counter_offer: Option<CounterOffer>,
+
+    /// Reason given for declining (optional)
+    pub decline_reason: Option<String>,
+
+    /// When the appraiser accepted or declined (optional)
+    pub responded_at: Option<DateTime<Utc>>,
+
+    /// IP address the response came from (optional)
+    pub responded_ip: Option<String>,
+
+    /// When the letter was generated
+    pub created_at: DateTime<Utc>,
+}
+
+/// Enumeration of engagement letter statuses
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EngagementStatus {
+    /// Waiting for the appraiser's response
+    Pending,
+    /// Accepted by the appraiser
+    Accepted,
+    /// Declined by the appraiser, possibly with a counter-offer
+    Declined,
+    /// Replaced by a newer letter
+    Superseded,
+}
+
+/// Fee or due date the appraiser would accept instead
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CounterOffer {
+    /// Proposed fee (optional)
+    pub fee: Option<f64>,
+
+    /// Proposed due date (optional)
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// Note to the client (optional)
+    pub note: Option<String>,
+}
+
+/// Enumeration of appraiser decisions on an engagement letter
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EngagementDecision {
+    Accept,
+    Decline,
+}
+
+/// Request to accept or decline an engagement letter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RespondToEngagementRequest {
+    /// Accept or decline
+    pub decision: EngagementDecision,
+
+    /// Reason for declining (optional)
+    pub reason: Option<String>,
+
+    /// Terms the appraiser would accept instead (optional, decline only)
+    pub counter_offer: Option<CounterOffer>,
+}
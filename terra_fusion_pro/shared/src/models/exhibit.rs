@@ -0,0 +1,246 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+use super::report::ReportType;
+
+/// Largest accepted exhibit file, in bytes
+pub const MAX_EXHIBIT_BYTES: usize = 25 * 1024 * 1024;
+
+/// Most pages an uploaded PDF exhibit may add to a report
+pub const MAX_EXHIBIT_PDF_PAGES: usize = 50;
+
+/// A photo, sketch, map or uploaded document printed after the body of a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportExhibit {
+    /// Unique identifier for the exhibit
+    pub id: Uuid,
+
+    /// ID of the report the exhibit belongs to
+    pub report_id: Uuid,
+
+    /// What the exhibit shows
+    pub kind: ExhibitKind,
+
+    /// Caption printed with the exhibit (optional)
+    pub caption: Option<String>,
+
+    /// Print order within the report, starting at 1
+    pub position: i32,
+
+    /// Comparable sale pictured, numbered as in the sales grid (comparable photos only)
+    pub comparable_number: Option<i32>,
+
+    /// MIME type: "image/jpeg" or "application/pdf"
+    pub media_type: String,
+
+    /// Storage key of the uploaded file, under the report's own prefix
+    pub storage_key: String,
+
+    /// Number of pages an uploaded PDF adds to the report (PDFs only)
+    pub page_count: Option<i32>,
+
+    /// Size of the uploaded file in bytes
+    pub byte_size: i64,
+
+    /// ID of the appraiser who uploaded the exhibit
+    pub uploaded_by: Uuid,
+
+    /// When the exhibit was uploaded
+    pub created_at: DateTime<Utc>,
+}
+
+/// Enumeration of exhibit kinds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExhibitKind {
+    SubjectFront,    // Front of the subject
+    SubjectRear,     // Rear of the subject
+    SubjectStreet,   // Street scene in front of the subject
+    ComparablePhoto, // Front of a comparable sale
+    FloorSketch,     // Floor plan sketch with dimensions
+    LocationMap,     // Subject and comparables on a street map
+    PlatMap,         // Recorded plat of the subject's lot
+    Addendum,        // Any other uploaded document, e.g. a flood certificate
+}
+
+impl ExhibitKind {
+    /// Title printed above the exhibit
+    pub fn title(&self) -> &'static str {
+        match self {
+            ExhibitKind::SubjectFront => "Subject Front",
+            ExhibitKind::SubjectRear => "Subject Rear",
+            ExhibitKind::SubjectStreet => "Subject Street",
+            ExhibitKind::ComparablePhoto => "Comparable Photo",
+            ExhibitKind::FloorSketch => "Floor Sketch",
+            ExhibitKind::LocationMap => "Location Map",
+            ExhibitKind::PlatMap => "Plat Map",
+            ExhibitKind::Addendum => "Addendum",
+        }
+    }
+
+    /// Whether the exhibit must be a photo rather than a drawn or uploaded document
+    pub fn is_photo(&self) -> bool {
+        matches!(
+            self,
+            ExhibitKind::SubjectFront
+                | ExhibitKind::SubjectRear
+                | ExhibitKind::SubjectStreet
+                | ExhibitKind::ComparablePhoto
+        )
+    }
+}
+
+/// How many exhibits of a kind a report type needs before it can be submitted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExhibitRequirement {
+    /// Kind of exhibit
+    pub kind: ExhibitKind,
+
+    /// Least number needed; comparable photos count one per comparable sale
+    pub minimum: usize,
+}
+
+/// Requirement of at least `minimum` exhibits of a kind
+const fn require(kind: ExhibitKind, minimum: usize) -> ExhibitRequirement {
+    ExhibitRequirement { kind, minimum }
+}
+
+/// Full interior and exterior inspections: the subject from three sides, three comparables,
+/// a sketch and a location map
+const INSPECTED: &[ExhibitRequirement] = &[
+    require(ExhibitKind::SubjectFront, 1),
+    require(ExhibitKind::SubjectRear, 1),
+    require(ExhibitKind::SubjectStreet, 1),
+    require(ExhibitKind::ComparablePhoto, 3),
+    require(ExhibitKind::FloorSketch, 1),
+    require(ExhibitKind::LocationMap, 1),
+];
+
+/// Exterior-only inspections photograph the subject from the street
+const EXTERIOR_ONLY: &[ExhibitRequirement] = &[
+    require(ExhibitKind::SubjectFront, 1),
+    require(ExhibitKind::SubjectStreet, 1),
+    require(ExhibitKind::ComparablePhoto, 3),
+    require(ExhibitKind::LocationMap, 1),
+];
+
+/// Desktop appraisals are not inspected, so they carry no photos
+const DESKTOP: &[ExhibitRequirement] = &[
+    require(ExhibitKind::FloorSketch, 1),
+    require(ExhibitKind::LocationMap, 1),
+];
+
+/// Commercial reports locate the site on both a street map and its recorded plat
+const COMMERCIAL: &[ExhibitRequirement] = &[
+    require(ExhibitKind::SubjectFront, 1),
+    require(ExhibitKind::LocationMap, 1),
+    require(ExhibitKind::PlatMap, 1),
+];
+
+/// Broker price opinions are drive-by exterior views
+const BPO: &[ExhibitRequirement] = &[
+    require(ExhibitKind::SubjectFront, 1),
+    require(ExhibitKind::SubjectStreet, 1),
+];
+
+impl ReportType {
+    /// Exhibits a report of this type needs before it can be submitted
+    pub fn required_exhibits(&self) -> &'static [ExhibitRequirement] {
+        match self {
+            ReportType::Form1004 | ReportType::Form1073 | ReportType::Form1025 | ReportType::Form1004C => INSPECTED,
+            ReportType::Form2055 => EXTERIOR_ONLY,
+            ReportType::DesktopAppraisal => DESKTOP,
+            ReportType::CommercialForm => COMMERCIAL,
+            ReportType::BPO => BPO,
+            ReportType::Other => &[],
+        }
+    }
+}
+
+/// Options for uploading an exhibit; the file itself is the request body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadExhibitQuery {
+    /// What the exhibit shows
+    pub kind: ExhibitKind,
+
+    /// Caption printed with the exhibit (optional)
+    pub caption: Option<String>,
+
+    /// Comparable sale pictured (required for comparable photos)
+    pub comparable_number: Option<i32>,
+}
+
+/// Request to change an exhibit's caption
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateExhibitRequest {
+    /// New caption; blank removes it
+    pub caption: Option<String>,
+}
+
+/// Request to put a report's exhibits in a new print order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReorderExhibitsRequest {
+    /// Every exhibit of the report, in the order they should print
+    pub exhibit_ids: Vec<Uuid>,
+}
+
+/// An exhibit requirement and how far a report is from meeting it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExhibitShortfall {
+    /// Kind of exhibit
+    pub kind: ExhibitKind,
+
+    /// Number the report type needs
+    pub required: usize,
+
+    /// Number the report has
+    pub present: usize,
+}
+
+/// Whether a report has the exhibits its type requires
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExhibitCheck {
+    /// Report type the requirements come from
+    pub report_type: ReportType,
+
+    /// Whether every requirement is met
+    pub complete: bool,
+
+    /// Requirements the report does not yet meet
+    pub missing: Vec<ExhibitShortfall>,
+}
+
+impl ExhibitCheck {
+    /// Check a report's exhibits against the requirements of its type. Comparable photos count
+    /// once per comparable sale pictured, however many photos of it there are.
+    pub fn evaluate(report_type: &ReportType, exhibits: &[ReportExhibit]) -> Self {
+        let missing: Vec<ExhibitShortfall> = report_type
+            .required_exhibits()
+            .iter()
+            .filter_map(|requirement| {
+                let of_kind = exhibits.iter().filter(|exhibit| exhibit.kind == requirement.kind);
+                let present = if requirement.kind == ExhibitKind::ComparablePhoto {
+                    let mut numbers: Vec<i32> = of_kind.filter_map(|exhibit| exhibit.comparable_number).collect();
+                    numbers.sort_unstable();
+                    numbers.dedup();
+                    numbers.len()
+                } else {
+                    of_kind.count()
+                };
+
+                (present < requirement.minimum).then_some(ExhibitShortfall {
+                    kind: requirement.kind,
+                    required: requirement.minimum,
+                    present,
+                })
+            })
+            .collect();
+
+        Self {
+            report_type: report_type.clone(),
+            complete: missing.is_empty(),
+            missing,
+        }
+    }
+}
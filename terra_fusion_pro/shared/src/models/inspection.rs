@@ -0,0 +1,187 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// Represents a property inspection appointment for an appraisal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectionAppointment {
+    /// Unique identifier for the appointment
+    pub id: Uuid,
+
+    /// ID of the appraisal being inspected
+    pub appraisal_id: Uuid,
+
+    /// ID of the appraiser performing the inspection
+    pub appraiser_id: Uuid,
+
+    /// Scheduled start of the inspection
+    pub scheduled_start: DateTime<Utc>,
+
+    /// Scheduled end of the inspection
+    pub scheduled_end: DateTime<Utc>,
+
+    /// IANA timezone of the property (e.g. "America/Chicago")
+    pub timezone: String,
+
+    /// Person to contact for access to the property
+    pub contact: InspectionContact,
+
+    /// Access notes (lockbox code, gate instructions, pets, etc.)
+    pub access_notes: Option<String>,
+
+    /// Current status of the appointment
+    pub status: AppointmentStatus,
+
+    /// History of every reschedule, oldest first
+    pub reschedule_history: Vec<RescheduleEntry>,
+
+    /// When the appointment was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the appointment was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Contact person for an inspection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InspectionContact {
+    /// Contact name
+    pub name: String,
+
+    /// Contact phone number (optional)
+    pub phone: Option<String>,
+
+    /// Contact email (optional)
+    pub email: Option<String>,
+
+    /// Relationship to the property (e.g. "Owner", "Tenant", "Listing agent")
+    pub relationship: Option<String>,
+}
+
+/// Enumeration of appointment statuses
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AppointmentStatus {
+    Scheduled,
+    Completed,
+    Cancelled,
+    NoShow,
+}
+
+/// A single reschedule of an inspection appointment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RescheduleEntry {
+    /// Start time before the reschedule
+    pub previous_start: DateTime<Utc>,
+
+    /// End time before the reschedule
+    pub previous_end: DateTime<Utc>,
+
+    /// Start time after the reschedule
+    pub new_start: DateTime<Utc>,
+
+    /// End time after the reschedule
+    pub new_end: DateTime<Utc>,
+
+    /// Reason given for the reschedule (optional)
+    pub reason: Option<String>,
+
+    /// ID of the user who rescheduled (optional)
+    pub rescheduled_by: Option<String>,
+
+    /// When the reschedule happened
+    pub rescheduled_at: DateTime<Utc>,
+}
+
+/// A window of time during which an appraiser is available for inspections
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailabilitySlot {
+    /// Unique identifier for the slot
+    pub id: Uuid,
+
+    /// ID of the appraiser
+    pub appraiser_id: Uuid,
+
+    /// Start of the availability window
+    pub starts_at: DateTime<Utc>,
+
+    /// End of the availability window
+    pub ends_at: DateTime<Utc>,
+
+    /// IANA timezone the slot was entered in
+    pub timezone: String,
+
+    /// When the slot was created
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request to book an inspection appointment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateInspectionRequest {
+    /// Scheduled start of the inspection
+    pub scheduled_start: DateTime<Utc>,
+
+    /// Scheduled end of the inspection
+    pub scheduled_end: DateTime<Utc>,
+
+    /// IANA timezone of the property
+    pub timezone: String,
+
+    /// Person to contact for access to the property
+    pub contact: InspectionContact,
+
+    /// Access notes (optional)
+    pub access_notes: Option<String>,
+}
+
+/// Request to reschedule an inspection appointment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RescheduleInspectionRequest {
+    /// New start of the inspection
+    pub scheduled_start: DateTime<Utc>,
+
+    /// New end of the inspection
+    pub scheduled_end: DateTime<Utc>,
+
+    /// Reason for the reschedule (optional)
+    pub reason: Option<String>,
+}
+
+/// Request to update appointment details other than its time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateInspectionRequest {
+    /// Contact person (optional)
+    pub contact: Option<InspectionContact>,
+
+    /// Access notes (optional)
+    pub access_notes: Option<String>,
+
+    /// Appointment status (optional)
+    pub status: Option<AppointmentStatus>,
+}
+
+/// Request to add an availability slot for an appraiser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAvailabilitySlotRequest {
+    /// Start of the availability window
+    pub starts_at: DateTime<Utc>,
+
+    /// End of the availability window
+    pub ends_at: DateTime<Utc>,
+
+    /// IANA timezone the slot was entered in
+    pub timezone: String,
+}
+
+/// A subscription token for an appraiser's iCalendar feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarFeed {
+    /// ID of the appraiser who owns the feed
+    pub appraiser_id: Uuid,
+
+    /// Secret token embedded in the feed URL
+    pub token: String,
+
+    /// When the token was issued
+    pub created_at: DateTime<Utc>,
+}
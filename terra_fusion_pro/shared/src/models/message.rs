@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// Largest accepted attachment, in bytes
+pub const MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// A message in the thread attached to an appraisal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Message {
+    /// Unique identifier for the message
+    pub id: Uuid,
+
+    /// ID of the appraisal the thread belongs to
+    pub appraisal_id: Uuid,
+
+    /// ID of the user who wrote the message
+    pub author_id: String,
+
+    /// Role of the author on the appraisal
+    pub author_role: ParticipantRole,
+
+    /// Message text
+    pub body: String,
+
+    /// Files attached to the message
+    pub attachments: Vec<MessageAttachment>,
+
+    /// Who can see the message
+    pub visibility: MessageVisibility,
+
+    /// Users who have read the message
+    pub read_by: Vec<ReadReceipt>,
+
+    /// When the message was posted
+    pub created_at: DateTime<Utc>,
+}
+
+/// Enumeration of the parties taking part in an appraisal thread
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ParticipantRole {
+    Client,
+    Appraiser,
+    Reviewer,
+}
+
+/// Enumeration of message visibilities
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageVisibility {
+    /// Only the appraiser and reviewers can see the message
+    Internal,
+    /// The client can see the message as well
+    ClientVisible,
+}
+
+/// A file attached to a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    /// Unique identifier for the attachment
+    pub id: Uuid,
+
+    /// Original file name
+    pub file_name: String,
+
+    /// MIME type of the file
+    pub content_type: String,
+
+    /// File size in bytes
+    pub size_bytes: i64,
+
+    /// Key of the file in the attachment store
+    pub storage_key: String,
+}
+
+/// Record of a user reading a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadReceipt {
+    /// ID of the user who read the message
+    pub user_id: String,
+
+    /// When the message was first read
+    pub read_at: DateTime<Utc>,
+}
+
+/// Request to post a message to an appraisal thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostMessageRequest {
+    /// Message text
+    pub body: String,
+
+    /// Who can see the message; clients may only post client-visible messages
+    pub visibility: MessageVisibility,
+
+    /// Files to attach (optional)
+    pub attachments: Option<Vec<AttachmentUpload>>,
+}
+
+/// A file uploaded with a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentUpload {
+    /// Original file name
+    pub file_name: String,
+
+    /// MIME type of the file
+    pub content_type: String,
+
+    /// Base64-encoded file content
+    pub content_base64: String,
+}
+
+/// Number of unread messages in an appraisal thread
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnreadCount {
+    /// ID of the appraisal the thread belongs to
+    pub appraisal_id: Uuid,
+
+    /// Number of messages the user has not read
+    pub unread: i64,
+}
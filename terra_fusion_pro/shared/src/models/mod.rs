@@ -3,9 +3,41 @@ pub mod user;
 pub mod report;
 pub mod form;
 pub mod appraisal;
+pub mod inspection;
+pub mod credential;
+pub mod client;
+pub mod revision;
+pub mod message;
+pub mod notification;
+pub mod webhook;
+pub mod engagement;
+pub mod air;
+pub mod uad;
+pub mod signature;
+pub mod snippet;
+pub mod content;
+pub mod reconciliation;
+pub mod share;
+pub mod exhibit;
 
 pub use property::*;
 pub use user::*;
 pub use report::*;
 pub use form::*;
-pub use appraisal::*;
\ No newline at end of file
+pub use appraisal::*;
+pub use inspection::*;
+pub use credential::*;
+pub use client::*;
+pub use revision::*;
+pub use message::*;
+pub use notification::*;
+pub use webhook::*;
+pub use engagement::*;
+pub use air::*;
+pub use uad::*;
+pub use signature::*;
+pub use snippet::*;
+pub use content::*;
+pub use reconciliation::*;
+pub use share::*;
+pub use exhibit::*;
\ No newline at end of file
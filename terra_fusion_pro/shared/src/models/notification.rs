@@ -0,0 +1,233 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+use super::appraisal::AppraisalStatus;
+
+/// Number of delivery attempts before a notification is given up on
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 6;
+
+/// A domain event that users can be notified about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// An appraiser was assigned to an order
+    OrderAssigned {
+        appraisal_id: Uuid,
+        reference_number: Option<String>,
+        property_address: String,
+        due_date: Option<DateTime<Utc>>,
+    },
+    /// An order moved between statuses
+    StatusChanged {
+        appraisal_id: Uuid,
+        reference_number: Option<String>,
+        from: AppraisalStatus,
+        to: AppraisalStatus,
+    },
+    /// The appraiser submitted a report for review
+    ReportSubmitted {
+        appraisal_id: Uuid,
+        reference_number: Option<String>,
+        report_id: Uuid,
+    },
+    /// A reviewer finished reviewing a report
+    ReviewComplete {
+        appraisal_id: Uuid,
+        reference_number: Option<String>,
+        outcome: String,
+    },
+    /// An open order is about to reach its due date
+    DueSoon {
+        appraisal_id: Uuid,
+        reference_number: Option<String>,
+        due_date: DateTime<Utc>,
+    },
+}
+
+impl NotificationEvent {
+    /// Kind of the event, used for preferences and the delivery log
+    pub fn kind(&self) -> EventKind {
+        match self {
+            NotificationEvent::OrderAssigned { .. } => EventKind::OrderAssigned,
+            NotificationEvent::StatusChanged { .. } => EventKind::StatusChanged,
+            NotificationEvent::ReportSubmitted { .. } => EventKind::ReportSubmitted,
+            NotificationEvent::ReviewComplete { .. } => EventKind::ReviewComplete,
+            NotificationEvent::DueSoon { .. } => EventKind::DueSoon,
+        }
+    }
+
+    /// ID of the appraisal the event concerns
+    pub fn appraisal_id(&self) -> Uuid {
+        match self {
+            NotificationEvent::OrderAssigned { appraisal_id, .. }
+            | NotificationEvent::StatusChanged { appraisal_id, .. }
+            | NotificationEvent::ReportSubmitted { appraisal_id, .. }
+            | NotificationEvent::ReviewComplete { appraisal_id, .. }
+            | NotificationEvent::DueSoon { appraisal_id, .. } => *appraisal_id,
+        }
+    }
+
+    /// Human-readable label for the order, preferring the client reference number
+    pub fn order_label(&self) -> String {
+        let reference = match self {
+            NotificationEvent::OrderAssigned { reference_number, .. }
+            | NotificationEvent::StatusChanged { reference_number, .. }
+            | NotificationEvent::ReportSubmitted { reference_number, .. }
+            | NotificationEvent::ReviewComplete { reference_number, .. }
+            | NotificationEvent::DueSoon { reference_number, .. } => reference_number,
+        };
+
+        reference.clone().unwrap_or_else(|| self.appraisal_id().to_string())
+    }
+}
+
+/// Enumeration of notification event kinds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    OrderAssigned,
+    StatusChanged,
+    ReportSubmitted,
+    ReviewComplete,
+    DueSoon,
+}
+
+/// Enumeration of how a user wants to receive an event kind
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryMode {
+    /// Email as soon as the event happens
+    Immediate,
+    /// Collect into the daily digest email
+    Digest,
+    /// Do not notify
+    Off,
+}
+
+/// Delivery mode chosen for one event kind
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPreference {
+    /// Event kind the preference applies to
+    pub event: EventKind,
+
+    /// How the event should be delivered
+    pub mode: DeliveryMode,
+}
+
+/// A user's notification settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPreferences {
+    /// ID of the user
+    pub user_id: String,
+
+    /// Whether email notifications are enabled at all
+    pub email_enabled: bool,
+
+    /// Per-event delivery modes; event kinds not listed are delivered immediately
+    pub events: Vec<EventPreference>,
+
+    /// Hour of the day (UTC, 0-23) the digest is sent
+    pub digest_hour_utc: i32,
+
+    /// When the preferences were last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NotificationPreferences {
+    /// Default preferences for a user who has never changed them
+    pub fn defaults_for(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            email_enabled: true,
+            events: Vec::new(),
+            digest_hour_utc: 13,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// How an event kind should be delivered to this user
+    pub fn mode_for(&self, kind: EventKind) -> DeliveryMode {
+        if !self.email_enabled {
+            return DeliveryMode::Off;
+        }
+
+        self.events
+            .iter()
+            .find(|p| p.event == kind)
+            .map(|p| p.mode)
+            .unwrap_or(DeliveryMode::Immediate)
+    }
+}
+
+/// Request to update a user's notification settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateNotificationPreferencesRequest {
+    /// Whether email notifications are enabled at all (optional)
+    pub email_enabled: Option<bool>,
+
+    /// Per-event delivery modes, replacing the current list (optional)
+    pub events: Option<Vec<EventPreference>>,
+
+    /// Hour of the day (UTC, 0-23) the digest is sent (optional)
+    pub digest_hour_utc: Option<i32>,
+}
+
+/// Enumeration of notification delivery statuses
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Waiting to be sent, possibly after a failed attempt
+    Pending,
+    /// Waiting for the recipient's next digest
+    Queued,
+    /// Included in a digest email
+    Batched,
+    /// Handed to the mail server
+    Sent,
+    /// Given up after too many failed attempts
+    Dead,
+}
+
+/// An entry in the notification delivery log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationDelivery {
+    /// Unique identifier for the delivery
+    pub id: Uuid,
+
+    /// ID of the recipient user
+    pub user_id: String,
+
+    /// Event that triggered the notification (none for digest emails)
+    pub event: Option<NotificationEvent>,
+
+    /// Email address the notification is sent to
+    pub recipient: String,
+
+    /// Rendered subject line
+    pub subject: String,
+
+    /// Rendered plain-text body
+    pub body: String,
+
+    /// Current delivery status
+    pub status: DeliveryStatus,
+
+    /// Number of send attempts made
+    pub attempts: i32,
+
+    /// When the next send attempt is due
+    pub next_attempt_at: DateTime<Utc>,
+
+    /// Error from the last failed attempt (optional)
+    pub last_error: Option<String>,
+
+    /// ID of the digest email this entry was batched into (optional)
+    pub digest_id: Option<Uuid>,
+
+    /// When the delivery was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the notification was sent (optional)
+    pub sent_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,82 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::property::{PropertyValuation, ValuationMethod};
+use super::report::Report;
+
+/// Weight an appraiser gives one approach to value, and why
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ApproachWeight {
+    /// Approach: sales comparison, cost or income
+    pub method: ValuationMethod,
+
+    /// Share of the reconciled value, as a percentage from 0 to 100
+    pub weight: f64,
+
+    /// Why the approach was given this weight
+    pub justification: String,
+}
+
+/// Request to reconcile a report's approaches to value into a final value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileReportRequest {
+    /// Weight of every approach developed in the report; weights add up to 100
+    pub weights: Vec<ApproachWeight>,
+
+    /// Why the indications differ, required when they spread further apart than allowed
+    pub spread_explanation: Option<String>,
+}
+
+/// Options for reconciling a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileReportQuery {
+    /// Persist the reconciliation; without it the reconciliation is a preview
+    pub commit: Option<bool>,
+}
+
+/// One approach's indicated value and its share of the reconciled value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApproachIndication {
+    /// Approach to value
+    pub method: ValuationMethod,
+
+    /// Value the approach indicated
+    pub indicated_value: Option<f64>,
+
+    /// Weight given to the approach, as a percentage
+    pub weight: f64,
+
+    /// Amount the approach contributes to the weighted value
+    pub contribution: f64,
+}
+
+/// Outcome of reconciling a report's approaches to value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconciliationResult {
+    /// Whether the report and property were updated
+    pub committed: bool,
+
+    /// Each approach with its weight and contribution
+    pub indications: Vec<ApproachIndication>,
+
+    /// Weighted value before rounding
+    pub weighted_value: f64,
+
+    /// Increment the weighted value was rounded to
+    pub rounding_increment: f64,
+
+    /// Reconciled value after rounding
+    pub final_value: f64,
+
+    /// Gap between the highest and lowest indications as a percentage of the weighted value
+    pub spread_percent: f64,
+
+    /// Largest spread accepted without an explanation, as a percentage
+    pub max_spread_percent: f64,
+
+    /// Valuation written (or, on a preview, that would be written) to the property
+    pub valuation: PropertyValuation,
+
+    /// The report as updated (or, on a preview, as it would be updated)
+    pub report: Report,
+}
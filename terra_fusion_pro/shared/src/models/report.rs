@@ -2,6 +2,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::types::Uuid;
 
+use super::appraisal::Appraisal;
+use super::property::Property;
+
 /// Represents an appraisal report in the TerraFusionPro platform
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Report {
@@ -49,6 +52,18 @@ pub struct Report {
     
     /// Review comments
     pub review_comments: Option<String>,
+    
+    /// Comments the appraiser left when last submitting the report
+    pub submission_comments: Option<String>,
+    
+    /// Version number; amendments of a finalized report count up from 1
+    pub version: i32,
+    
+    /// ID of the finalized report this version amends (if any)
+    pub amends_id: Option<Uuid>,
+    
+    /// ID of the supervisory appraiser assigned to co-sign the report (if any)
+    pub supervisor_id: Option<Uuid>,
 }
 
 /// Enumeration of report statuses
@@ -65,6 +80,34 @@ pub enum ReportStatus {
     Finalized,
 }
 
+impl ReportStatus {
+    /// Whether the appraiser may still edit the report
+    pub fn is_editable(&self) -> bool {
+        matches!(self, ReportStatus::Draft | ReportStatus::InProgress | ReportStatus::NeedsCorrection)
+    }
+    
+    /// Whether a report may move from this status to `next`
+    pub fn can_transition_to(&self, next: &ReportStatus) -> bool {
+        use ReportStatus::*;
+        
+        matches!(
+            (self, next),
+            (Draft, InProgress)
+                | (InProgress, Draft)
+                | (NeedsCorrection, InProgress)
+                | (Draft | InProgress | NeedsCorrection, Submitted)
+                | (Submitted, UnderReview)
+                | (UnderReview, Approved | NeedsCorrection | Rejected)
+                | (Approved, Finalized)
+        )
+    }
+    
+    /// Whether the status is a review outcome a reviewer can record
+    pub fn is_review_outcome(&self) -> bool {
+        matches!(self, ReportStatus::Approved | ReportStatus::NeedsCorrection | ReportStatus::Rejected)
+    }
+}
+
 /// Enumeration of report types
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -158,4 +201,360 @@ pub struct ReportSummary {
     
     /// When the report was last updated
     pub updated_at: DateTime<Utc>,
-}
\ No newline at end of file
+}
+
+/// Report query parameters for listing reports
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReportQuery {
+    /// Filter by property
+    pub property_id: Option<Uuid>,
+    
+    /// Filter by appraiser
+    pub appraiser_id: Option<Uuid>,
+    
+    /// Filter by status
+    pub status: Option<ReportStatus>,
+    
+    /// Filter by report type
+    pub report_type: Option<ReportType>,
+    
+    /// Page number (starting at 1)
+    pub page: Option<i64>,
+    
+    /// Number of reports per page
+    pub limit: Option<i64>,
+}
+
+/// Options for importing an appraisal delivered as MISMO XML
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportReportQuery {
+    /// Client the appraisal was performed for
+    pub client_id: Uuid,
+    
+    /// Client reference number (optional; defaults to the appraiser's file number)
+    pub reference_number: Option<String>,
+    
+    /// Persist the import; without it the import is a dry run
+    pub commit: Option<bool>,
+}
+
+/// Records created (or, on a dry run, that would be created) by a MISMO import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportReportResult {
+    /// Whether the records were persisted
+    pub committed: bool,
+    
+    /// Subject property
+    pub property: Property,
+    
+    /// Appraisal order linking the property and report
+    pub appraisal: Appraisal,
+    
+    /// Imported report
+    pub report: Report,
+    
+    /// Elements and attributes of the document that were not imported
+    pub unmapped: Vec<UnmappedElement>,
+}
+
+/// A part of an imported document with no place in the platform's records
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnmappedElement {
+    /// Location in the document, e.g. "VALUATION_RESPONSE/PROPERTY/@_County"
+    pub path: String,
+    
+    /// Attribute value or element text (if any)
+    pub value: Option<String>,
+}
+
+/// The frozen record of a finalized report: what was delivered and how to prove it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportSnapshot {
+    /// Unique identifier for the snapshot
+    pub id: Uuid,
+    
+    /// ID of the finalized report
+    pub report_id: Uuid,
+    
+    /// Report version the snapshot was taken of
+    pub version: i32,
+    
+    /// Hashes of the delivered artifacts
+    pub manifest: SnapshotManifest,
+    
+    /// SHA-256 of the manifest's canonical JSON, hex encoded
+    pub manifest_sha256: String,
+    
+    /// ID of the appraiser who finalized the report
+    pub finalized_by: Uuid,
+    
+    /// When the report was finalized
+    pub created_at: DateTime<Utc>,
+}
+
+/// The artifacts of a finalized report and their SHA-256 hashes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// ID of the finalized report
+    pub report_id: Uuid,
+    
+    /// Report version
+    pub version: i32,
+    
+    /// ID of the finalized report this version amends (if any)
+    pub amends_id: Option<Uuid>,
+    
+    /// When the report was finalized
+    pub finalized_at: DateTime<Utc>,
+    
+    /// Delivered artifacts
+    pub artifacts: Vec<SnapshotArtifact>,
+}
+
+/// A file delivered with a finalized report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotArtifact {
+    /// File name, e.g. "report.pdf"
+    pub name: String,
+    
+    /// MIME type
+    pub media_type: String,
+    
+    /// Size in bytes
+    pub size: i64,
+    
+    /// SHA-256 of the file, hex encoded
+    pub sha256: String,
+}
+
+/// Whether a file is one of the artifacts delivered with a finalized report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotVerification {
+    /// ID of the finalized report
+    pub report_id: Uuid,
+    
+    /// Report version
+    pub version: i32,
+    
+    /// Whether the file matches a delivered artifact byte for byte
+    pub verified: bool,
+    
+    /// Name of the matching artifact (if any)
+    pub artifact: Option<String>,
+    
+    /// SHA-256 of the file that was checked, hex encoded
+    pub sha256: String,
+    
+    /// SHA-256 of the snapshot manifest, hex encoded
+    pub manifest_sha256: String,
+    
+    /// When the report was finalized
+    pub finalized_at: DateTime<Utc>,
+    
+    /// ID of the report version that amends this one (if any)
+    pub amended_by: Option<Uuid>,
+}
+
+/// The content of a report as it stood when it was submitted for review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportRevision {
+    /// Unique identifier for the revision
+    pub id: Uuid,
+    
+    /// ID of the report
+    pub report_id: Uuid,
+    
+    /// Submission number, starting at 1
+    pub revision: i32,
+    
+    /// Valuation amount as submitted
+    pub valuation_amount: Option<f64>,
+    
+    /// Report content as submitted
+    pub content: serde_json::Value,
+    
+    /// Appraiser's comments on the submission
+    pub submission_comments: Option<String>,
+    
+    /// When the revision was submitted
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Query parameters for comparing two revisions of a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionDiffQuery {
+    /// Earlier revision (defaults to the one before `to`)
+    pub from: Option<i32>,
+    
+    /// Later revision (defaults to the latest)
+    pub to: Option<i32>,
+}
+
+/// What changed in a report between two submissions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionDiff {
+    /// ID of the report
+    pub report_id: Uuid,
+    
+    /// Earlier revision
+    pub from_revision: i32,
+    
+    /// Later revision
+    pub to_revision: i32,
+    
+    /// When the earlier revision was submitted
+    pub from_submitted_at: DateTime<Utc>,
+    
+    /// When the later revision was submitted
+    pub to_submitted_at: DateTime<Utc>,
+    
+    /// Change to the valuation amount (if it changed)
+    pub valuation_amount: Option<AmountChange>,
+    
+    /// Comparables whose adjustments changed
+    pub adjustments: Vec<AdjustmentChange>,
+    
+    /// Every changed content value, in form order
+    pub changes: Vec<ContentChange>,
+}
+
+/// A dollar amount before and after a change
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmountChange {
+    /// Amount in the earlier revision
+    pub before: Option<f64>,
+    
+    /// Amount in the later revision
+    pub after: Option<f64>,
+    
+    /// `after - before`, when both are present
+    pub difference: Option<f64>,
+}
+
+/// Changed adjustments of one comparable sale
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjustmentChange {
+    /// Position of the comparable in the sales grid, starting at 1
+    pub comparable: usize,
+    
+    /// Address of the comparable in the later revision
+    pub address: Option<String>,
+    
+    /// Change to the net adjustment (if it changed)
+    pub net_adjustment: Option<AmountChange>,
+    
+    /// Change to the adjusted sale price (if it changed)
+    pub adjusted_price: Option<AmountChange>,
+}
+
+/// How a content value changed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Present only in the later revision
+    Added,
+    /// Present only in the earlier revision
+    Removed,
+    /// Present in both with different values
+    Changed,
+}
+
+/// A content value that differs between two revisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentChange {
+    /// Location of the value in `Report.content`, e.g. "comparables[1].net_adjustment"
+    pub path: String,
+    
+    /// Human-readable location, e.g. "Comparables › Comparable 2 › Net Adjustment"
+    pub label: String,
+    
+    /// How the value changed
+    pub kind: ChangeKind,
+    
+    /// Value in the earlier revision
+    pub before: Option<serde_json::Value>,
+    
+    /// Value in the later revision
+    pub after: Option<serde_json::Value>,
+    
+    /// Word-level diff of a changed narrative
+    pub text_diff: Option<Vec<TextSpan>>,
+}
+
+/// A run of narrative text that was kept, inserted or deleted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextSpan {
+    /// Whether the text was kept, inserted or deleted
+    pub op: TextOp,
+    
+    /// The text
+    pub text: String,
+}
+
+/// Edit applied to a run of narrative text
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TextOp {
+    /// In both revisions
+    Equal,
+    /// Only in the later revision
+    Insert,
+    /// Only in the earlier revision
+    Delete,
+}
+
+/// Query parameters for previewing a report in the browser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportPreviewQuery {
+    /// Reload the preview whenever the report is saved (defaults to true)
+    pub live: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [ReportStatus; 8] = [
+        ReportStatus::Draft,
+        ReportStatus::InProgress,
+        ReportStatus::Submitted,
+        ReportStatus::UnderReview,
+        ReportStatus::NeedsCorrection,
+        ReportStatus::Approved,
+        ReportStatus::Rejected,
+        ReportStatus::Finalized,
+    ];
+
+    #[test]
+    fn allows_only_the_workflow_transitions() {
+        use ReportStatus::*;
+
+        let allowed = [
+            (Draft, InProgress),
+            (Draft, Submitted),
+            (InProgress, Draft),
+            (InProgress, Submitted),
+            (NeedsCorrection, InProgress),
+            (NeedsCorrection, Submitted),
+            (Submitted, UnderReview),
+            (UnderReview, Approved),
+            (UnderReview, NeedsCorrection),
+            (UnderReview, Rejected),
+            (Approved, Finalized),
+        ];
+
+        for from in &STATUSES {
+            for to in &STATUSES {
+                let expected = allowed.iter().any(|(a, b)| a == from && b == to);
+                assert_eq!(from.can_transition_to(to), expected, "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn editable_reports_can_be_submitted() {
+        for status in &STATUSES {
+            assert_eq!(status.can_transition_to(&ReportStatus::Submitted), status.is_editable(), "{:?}", status);
+        }
+    }
+}
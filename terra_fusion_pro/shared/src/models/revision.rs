@@ -0,0 +1,187 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// A reviewer's request for revisions to an appraisal, made up of itemized conditions.
+/// Each request is one review round; earlier rounds are kept as history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionRequest {
+    /// Unique identifier for the revision request
+    pub id: Uuid,
+
+    /// ID of the appraisal under revision
+    pub appraisal_id: Uuid,
+
+    /// Review round, starting at 1
+    pub round: i32,
+
+    /// ID of the reviewer who requested the revisions
+    pub requested_by: String,
+
+    /// Overall note from the reviewer (optional)
+    pub summary: Option<String>,
+
+    /// Itemized conditions to address
+    pub items: Vec<RevisionItem>,
+
+    /// Current status of the request
+    pub status: RevisionStatus,
+
+    /// When the request was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the request was last updated
+    pub updated_at: DateTime<Utc>,
+
+    /// When every item was cleared (optional)
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl RevisionRequest {
+    /// Whether every item has an answer the reviewer has not rejected
+    pub fn all_items_answered(&self) -> bool {
+        self.items.iter().all(RevisionItem::is_answered)
+    }
+
+    /// Find an item by ID
+    pub fn item_mut(&mut self, item_id: Uuid) -> Option<&mut RevisionItem> {
+        self.items.iter_mut().find(|item| item.id == item_id)
+    }
+}
+
+/// Enumeration of revision request statuses
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionStatus {
+    /// Waiting on the appraiser
+    Open,
+    /// Resubmitted and waiting on the reviewer
+    Responded,
+    /// Every item cleared
+    Closed,
+}
+
+/// A single condition within a revision request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionItem {
+    /// Unique identifier for the item
+    pub id: Uuid,
+
+    /// Area of the report the condition concerns
+    pub category: RevisionCategory,
+
+    /// What needs to change
+    pub description: String,
+
+    /// Report section the condition refers to (optional)
+    pub report_section: Option<String>,
+
+    /// Appraiser responses, oldest first
+    pub responses: Vec<RevisionResponse>,
+
+    /// Reviewer's decision on the latest response
+    pub resolution: ItemResolution,
+
+    /// Reviewer's note on the decision (optional)
+    pub reviewer_note: Option<String>,
+
+    /// When the reviewer last decided on the item (optional)
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl RevisionItem {
+    /// Whether the appraiser has answered the item since it was last rejected
+    pub fn is_answered(&self) -> bool {
+        match self.resolution {
+            ItemResolution::Cleared => true,
+            ItemResolution::Rejected => false,
+            ItemResolution::Pending => !self.responses.is_empty(),
+        }
+    }
+}
+
+/// Enumeration of revision condition categories
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionCategory {
+    SubjectDescription,
+    Comparables,
+    Adjustments,
+    Reconciliation,
+    Photos,
+    Compliance,
+    Typographical,
+    Other,
+}
+
+impl RevisionCategory {
+    /// Whether items in this category bear on the opinion of value
+    pub fn is_value_related(&self) -> bool {
+        matches!(
+            self,
+            RevisionCategory::Comparables | RevisionCategory::Adjustments | RevisionCategory::Reconciliation
+        )
+    }
+}
+
+/// Enumeration of reviewer decisions on a revision item
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemResolution {
+    Pending,
+    Cleared,
+    Rejected,
+}
+
+/// An appraiser's answer to a revision item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionResponse {
+    /// Response text
+    pub body: String,
+
+    /// ID of the user who responded (optional)
+    pub responded_by: Option<String>,
+
+    /// When the response was made
+    pub responded_at: DateTime<Utc>,
+}
+
+/// Request to open a revision round on an appraisal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRevisionRequest {
+    /// Overall note from the reviewer (optional)
+    pub summary: Option<String>,
+
+    /// Itemized conditions to address
+    pub items: Vec<RevisionItemRequest>,
+}
+
+/// A condition within a new revision request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionItemRequest {
+    /// Area of the report the condition concerns
+    pub category: RevisionCategory,
+
+    /// What needs to change
+    pub description: String,
+
+    /// Report section the condition refers to (optional)
+    pub report_section: Option<String>,
+}
+
+/// Request to answer a revision item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RespondToItemRequest {
+    /// Response text
+    pub body: String,
+}
+
+/// Request to clear or reject a revision item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveItemRequest {
+    /// Decision on the latest response; must be `cleared` or `rejected`
+    pub resolution: ItemResolution,
+
+    /// Note explaining the decision (optional)
+    pub note: Option<String>,
+}
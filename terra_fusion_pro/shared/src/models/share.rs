@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// A signed, expiring link that lets someone without a login download one artifact of a
+/// finalized report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareLink {
+    /// Unique identifier for the link
+    pub id: Uuid,
+
+    /// ID of the shared report
+    pub report_id: Uuid,
+
+    /// Name of the snapshot artifact the link serves, e.g. "report.pdf"
+    pub artifact: String,
+
+    /// Who the link was sent to
+    pub recipient_role: ShareRecipientRole,
+
+    /// Recipient's name (optional)
+    pub recipient_name: Option<String>,
+
+    /// Recipient's email address (optional)
+    pub recipient_email: Option<String>,
+
+    /// Argon2 hash of the link password; never included in API responses
+    #[serde(skip_serializing)]
+    pub password_hash: Option<String>,
+
+    /// Whether the link asks for a password
+    pub password_protected: bool,
+
+    /// When the link stops working
+    pub expires_at: DateTime<Utc>,
+
+    /// Number of downloads allowed (optional; unlimited when absent)
+    pub max_downloads: Option<i32>,
+
+    /// Number of completed downloads
+    pub download_count: i32,
+
+    /// When the artifact was first downloaded, confirming delivery (optional)
+    pub first_downloaded_at: Option<DateTime<Utc>>,
+
+    /// ID of the appraiser who created the link
+    pub created_by: Uuid,
+
+    /// When the link was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the link was revoked (if it has been)
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Enumeration of share link recipients
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShareRecipientRole {
+    /// The lender or other client who ordered the appraisal
+    Client,
+    /// The borrower, who must be given a copy of the appraisal under ECOA
+    Borrower,
+    /// Anyone else the client asked to receive the report
+    Other,
+}
+
+/// Request to share an artifact of a finalized report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateShareLinkRequest {
+    /// Name of the snapshot artifact to share; defaults to "report.pdf"
+    pub artifact: Option<String>,
+
+    /// Who the link is for
+    pub recipient_role: ShareRecipientRole,
+
+    /// Recipient's name (optional)
+    pub recipient_name: Option<String>,
+
+    /// Recipient's email address (optional)
+    pub recipient_email: Option<String>,
+
+    /// Password the recipient must enter (optional)
+    pub password: Option<String>,
+
+    /// Hours until the link expires (optional)
+    pub expires_in_hours: Option<i64>,
+
+    /// Number of downloads allowed (optional)
+    pub max_downloads: Option<i32>,
+}
+
+/// A newly created share link and the path it is opened at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedShareLink {
+    /// The link
+    pub link: ShareLink,
+
+    /// Path of the signed URL, e.g. "/api/v1/shared/<token>"
+    pub path: String,
+}
+
+/// Password entered to open a protected share link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenShareLinkRequest {
+    /// Link password
+    pub password: Option<String>,
+}
+
+/// An attempt to open a share link
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareLinkAccess {
+    /// Unique identifier for the access
+    pub id: Uuid,
+
+    /// ID of the link that was opened
+    pub link_id: Uuid,
+
+    /// What came of the attempt
+    pub outcome: ShareAccessOutcome,
+
+    /// IP address the request came from (optional)
+    pub ip_address: Option<String>,
+
+    /// User agent of the request (optional)
+    pub user_agent: Option<String>,
+
+    /// When the link was opened
+    pub accessed_at: DateTime<Utc>,
+}
+
+/// Enumeration of share link access outcomes
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShareAccessOutcome {
+    /// The artifact was downloaded
+    Downloaded,
+    /// No password or the wrong password was given
+    PasswordRejected,
+    /// The link had expired
+    Expired,
+    /// The link had been revoked
+    Revoked,
+    /// The link's downloads were used up
+    LimitReached,
+    /// Too many wrong passwords were given recently
+    Locked,
+}
@@ -0,0 +1,154 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+use super::credential::LicenseLevel;
+use super::report::SnapshotManifest;
+
+/// An Ed25519 key pair a user signs reports with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigningKey {
+    /// Unique identifier for the key
+    pub id: Uuid,
+
+    /// ID of the user the key belongs to
+    pub user_id: String,
+
+    /// Ed25519 public key, hex encoded
+    pub public_key: String,
+
+    /// Private key sealed by the key vault; never included in API responses
+    #[serde(skip_serializing)]
+    pub sealed_private_key: String,
+
+    /// When the key was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the key was retired (if it has been). Signatures made before then stay valid.
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl SigningKey {
+    /// Whether new signatures can be made with the key
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+}
+
+/// Capacity a report is signed in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerRole {
+    /// The appraiser who prepared the report
+    Appraiser,
+    /// The supervisory appraiser who reviewed it
+    Supervisor,
+}
+
+/// The statement a signer attests to; its canonical JSON is what is signed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignaturePayload {
+    /// ID of the finalized report
+    pub report_id: Uuid,
+
+    /// Report version
+    pub version: i32,
+
+    /// SHA-256 of the report's snapshot manifest, hex encoded
+    pub manifest_sha256: String,
+
+    /// ID of the signing user
+    pub signer_id: String,
+
+    /// Capacity the report is signed in
+    pub role: SignerRole,
+
+    /// Two-letter state of the license the report is signed under
+    pub license_state: String,
+
+    /// Number of the license the report is signed under
+    pub license_number: String,
+
+    /// Level of the license the report is signed under
+    pub license_level: LicenseLevel,
+
+    /// When the report was signed
+    pub signed_at: DateTime<Utc>,
+}
+
+/// A signature on a finalized report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportSignature {
+    /// Unique identifier for the signature
+    pub id: Uuid,
+
+    /// ID of the key the signature was made with
+    pub key_id: Uuid,
+
+    /// Ed25519 public key of that key, hex encoded
+    pub public_key: String,
+
+    /// The signed statement
+    pub payload: SignaturePayload,
+
+    /// Ed25519 signature of the payload's canonical JSON, hex encoded
+    pub signature: String,
+}
+
+/// Request to sign a finalized report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignReportRequest {
+    /// Capacity the report is signed in
+    pub role: SignerRole,
+}
+
+/// Request to name the supervisory appraiser who co-signs a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignSupervisorRequest {
+    /// ID of the supervisory appraiser
+    pub supervisor_id: Uuid,
+}
+
+/// Public proof of who signed a finalized report and what they signed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureVerification {
+    /// ID of the finalized report
+    pub report_id: Uuid,
+
+    /// Report version
+    pub version: i32,
+
+    /// Artifacts delivered with the report; hash a copy to check it against these
+    pub manifest: SnapshotManifest,
+
+    /// SHA-256 of the manifest's canonical JSON, hex encoded
+    pub manifest_sha256: String,
+
+    /// Each signature and whether it holds
+    pub signatures: Vec<SignatureCheck>,
+
+    /// Whether the appraiser has signed and, for trainees, the supervisor has co-signed,
+    /// with every signature valid
+    pub complete: bool,
+
+    /// ID of the report version that amends this one (if any)
+    pub amended_by: Option<Uuid>,
+}
+
+/// The outcome of checking one signature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignatureCheck {
+    /// The signature
+    pub signature: ReportSignature,
+
+    /// Whether the signature is genuine and covers the report's current manifest
+    pub valid: bool,
+
+    /// When the signing key was retired (if it has been)
+    pub key_revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Storage key of the handwritten signature image printed on a user's reports
+pub fn signature_image_key(user_id: &str) -> String {
+    format!("users/{}/signature.jpg", user_id)
+}
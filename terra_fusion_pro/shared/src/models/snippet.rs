@@ -0,0 +1,106 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// A reusable narrative template, e.g. a neighborhood description or scope of work
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NarrativeSnippet {
+    /// Unique identifier for the snippet
+    pub id: Uuid,
+
+    /// Library the snippet belongs to
+    pub scope: SnippetScope,
+
+    /// ID of the appraiser who wrote the snippet and may change it
+    pub owner_id: Uuid,
+
+    /// Short name shown when choosing a snippet
+    pub name: String,
+
+    /// Report content section the text is written to, e.g. "neighborhood"
+    pub section: String,
+
+    /// Field within the section, e.g. "description"
+    pub field: String,
+
+    /// Handlebars template referring to `property`, `appraisal` and `report` fields
+    pub template: String,
+
+    /// When the snippet was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the snippet was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Enumeration of snippet libraries
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SnippetScope {
+    /// Only the owning appraiser sees the snippet
+    Personal,
+    /// Every appraiser in the organization sees the snippet
+    Organization,
+}
+
+/// Request to create or replace a snippet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetRequest {
+    /// Library the snippet belongs to
+    pub scope: SnippetScope,
+
+    /// Short name shown when choosing a snippet
+    pub name: String,
+
+    /// Report content section the text is written to
+    pub section: String,
+
+    /// Field within the section
+    pub field: String,
+
+    /// Handlebars template
+    pub template: String,
+}
+
+/// Filters for listing the snippets available to an appraiser
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetQuery {
+    /// Only snippets for this section (optional)
+    pub section: Option<String>,
+
+    /// Only snippets from this library (optional)
+    pub scope: Option<SnippetScope>,
+}
+
+/// How rendered text is combined with what the field already holds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SnippetMode {
+    /// Replace the field's text
+    Replace,
+    /// Add the text as a new paragraph after the field's text
+    Append,
+}
+
+/// Request to render a snippet into a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplySnippetRequest {
+    /// How the text is combined with the field's text (defaults to replace)
+    pub mode: Option<SnippetMode>,
+}
+
+/// A snippet rendered for a report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderedSnippet {
+    /// ID of the snippet
+    pub snippet_id: Uuid,
+
+    /// Report content section the text belongs in
+    pub section: String,
+
+    /// Field within the section
+    pub field: String,
+
+    /// Rendered text
+    pub text: String,
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Result of checking a report against the Uniform Appraisal Dataset (UAD) specification
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UadValidation {
+    /// Violations the GSE collateral portal rejects; a report with any cannot be submitted
+    pub errors: Vec<UadFinding>,
+
+    /// Likely problems worth a second look that do not block submission
+    pub warnings: Vec<UadFinding>,
+}
+
+impl UadValidation {
+    /// Whether the report has no hard errors
+    pub fn is_compliant(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// A single UAD rule violation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UadFinding {
+    /// Location of the value, e.g. "improvements.condition" or "comparables[2].view"
+    pub path: String,
+
+    /// Identifier of the rule that was violated, e.g. "condition_rating"
+    pub rule: String,
+
+    /// Description of the problem and the expected notation
+    pub message: String,
+}
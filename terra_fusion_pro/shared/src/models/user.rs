@@ -42,4 +42,13 @@ pub struct UpdateUserRequest {
     pub first_name: Option<String>,
     /// User last name (optional)
     pub last_name: Option<String>,
-}
\ No newline at end of file
+}
+
+/// Enumeration of platform roles, from least to most access
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    Member, // Appraisers, reviewers and client portal users; access follows their own records
+    Staff,  // Operations staff, who manage clients, orders and reports for every client
+    Admin,  // Administrators, who may also act on other users' records
+}
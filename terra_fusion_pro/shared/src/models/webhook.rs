@@ -0,0 +1,165 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Uuid;
+
+/// Number of delivery attempts before a webhook delivery is dead-lettered
+pub const MAX_WEBHOOK_ATTEMPTS: i32 = 8;
+
+/// An endpoint a client has registered to receive webhook events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEndpoint {
+    /// Unique identifier for the endpoint
+    pub id: Uuid,
+
+    /// ID of the client that owns the endpoint
+    pub client_id: Uuid,
+
+    /// URL events are POSTed to
+    pub url: String,
+
+    /// Free-form description (optional)
+    pub description: Option<String>,
+
+    /// Event types the endpoint is subscribed to
+    pub event_types: Vec<WebhookEventType>,
+
+    /// Signing secret; never included in API responses except when issued
+    #[serde(skip_serializing)]
+    pub secret: String,
+
+    /// Whether events are delivered to the endpoint
+    pub is_active: bool,
+
+    /// When the endpoint was registered
+    pub created_at: DateTime<Utc>,
+
+    /// When the endpoint was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An endpoint together with its freshly issued signing secret
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuedWebhookEndpoint {
+    /// The endpoint
+    #[serde(flatten)]
+    pub endpoint: WebhookEndpoint,
+
+    /// Signing secret, shown only once
+    pub secret: String,
+}
+
+/// Enumeration of webhook event types
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WebhookEventType {
+    #[serde(rename = "order.assigned")]
+    OrderAssigned,
+    #[serde(rename = "order.status_changed")]
+    OrderStatusChanged,
+    #[serde(rename = "order.inspection_scheduled")]
+    InspectionScheduled,
+    #[serde(rename = "order.revision_requested")]
+    RevisionRequested,
+    #[serde(rename = "report.submitted")]
+    ReportSubmitted,
+    #[serde(rename = "report.review_complete")]
+    ReportReviewComplete,
+}
+
+/// Enumeration of webhook delivery statuses
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its first or next attempt
+    Pending,
+    /// Accepted by the endpoint with a 2xx response
+    Succeeded,
+    /// Given up after too many failed attempts
+    DeadLetter,
+    /// Dropped because its endpoint was deactivated before it was sent
+    Cancelled,
+}
+
+/// A single event delivered (or to be delivered) to an endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// Unique identifier for the delivery
+    pub id: Uuid,
+
+    /// ID of the receiving endpoint
+    pub endpoint_id: Uuid,
+
+    /// ID of the event; stays the same across redeliveries so receivers can deduplicate
+    pub event_id: Uuid,
+
+    /// Type of the event
+    pub event_type: WebhookEventType,
+
+    /// JSON body sent to the endpoint
+    pub payload: serde_json::Value,
+
+    /// Current delivery status
+    pub status: WebhookDeliveryStatus,
+
+    /// Number of attempts made
+    pub attempts: i32,
+
+    /// When the next attempt is due
+    pub next_attempt_at: DateTime<Utc>,
+
+    /// Every attempt made, oldest first
+    pub attempt_log: Vec<WebhookAttempt>,
+
+    /// ID of the delivery this one manually redelivers (optional)
+    pub redelivery_of: Option<Uuid>,
+
+    /// When the delivery was created
+    pub created_at: DateTime<Utc>,
+
+    /// When the endpoint accepted the delivery (optional)
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// Outcome of one attempt to deliver a webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookAttempt {
+    /// When the attempt was made
+    pub attempted_at: DateTime<Utc>,
+
+    /// HTTP status returned by the endpoint (optional)
+    pub response_status: Option<u16>,
+
+    /// Transport error or non-2xx summary (optional)
+    pub error: Option<String>,
+
+    /// How long the request took, in milliseconds
+    pub duration_ms: i64,
+}
+
+/// Request to register a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookEndpointRequest {
+    /// URL events are POSTed to
+    pub url: String,
+
+    /// Free-form description (optional)
+    pub description: Option<String>,
+
+    /// Event types to subscribe to
+    pub event_types: Vec<WebhookEventType>,
+}
+
+/// Request to update a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWebhookEndpointRequest {
+    /// URL events are POSTed to (optional)
+    pub url: Option<String>,
+
+    /// Free-form description (optional)
+    pub description: Option<String>,
+
+    /// Event types to subscribe to (optional)
+    pub event_types: Option<Vec<WebhookEventType>>,
+
+    /// Whether events are delivered to the endpoint (optional)
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,133 @@
+use std::env;
+
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::error::{AppError, AppResult};
+
+/// An email ready to be handed to a mail server
+#[derive(Debug, Clone)]
+pub struct OutgoingEmail {
+    /// Recipient address
+    pub to: String,
+
+    /// Subject line
+    pub subject: String,
+
+    /// Plain-text body
+    pub body: String,
+}
+
+/// Sends emails; implemented over SMTP and easy to replace in tests
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    /// Send a single email
+    async fn send(&self, email: &OutgoingEmail) -> AppResult<()>;
+}
+
+/// SMTP connection settings
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    /// Mail server host
+    pub host: String,
+    /// Mail server port
+    pub port: u16,
+    /// Login name (optional)
+    pub username: Option<String>,
+    /// Login password (optional)
+    pub password: Option<String>,
+    /// Sender address, e.g. "TerraFusionPro <no-reply@example.com>"
+    pub from: String,
+    /// Whether to upgrade the connection with STARTTLS
+    pub starttls: bool,
+}
+
+impl SmtpConfig {
+    /// Load SMTP settings from environment variables.
+    /// The defaults point at a local SMTP sink (e.g. MailHog on port 1025) without TLS.
+    pub fn from_env() -> AppResult<Self> {
+        let host = env::var("SMTP_HOST").unwrap_or_else(|_| "localhost".to_string());
+
+        let port = env::var("SMTP_PORT")
+            .unwrap_or_else(|_| "1025".to_string())
+            .parse::<u16>()
+            .map_err(|_| AppError::Configuration("SMTP_PORT must be a number".to_string()))?;
+
+        let starttls = env::var("SMTP_STARTTLS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let from = env::var("SMTP_FROM")
+            .unwrap_or_else(|_| "TerraFusionPro <no-reply@terrafusionpro.local>".to_string());
+
+        Ok(Self {
+            host,
+            port,
+            username: env::var("SMTP_USERNAME").ok(),
+            password: env::var("SMTP_PASSWORD").ok(),
+            from,
+            starttls,
+        })
+    }
+}
+
+/// Mailer that delivers over SMTP
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    /// Create a mailer from SMTP settings
+    pub fn new(config: &SmtpConfig) -> AppResult<Self> {
+        let builder = if config.starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(|e| AppError::Configuration(format!("Invalid SMTP host: {}", e)))?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host)
+        };
+
+        let builder = match (&config.username, &config.password) {
+            (Some(username), Some(password)) => {
+                builder.credentials(Credentials::new(username.clone(), password.clone()))
+            }
+            _ => builder,
+        };
+
+        let from = config
+            .from
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::Configuration(format!("Invalid SMTP_FROM address: {}", e)))?;
+
+        Ok(Self {
+            transport: builder.port(config.port).build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: &OutgoingEmail) -> AppResult<()> {
+        let to = email
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| AppError::Validation(format!("Invalid recipient address {}: {}", email.to, e)))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(email.subject.clone())
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body.clone())
+            .map_err(|e| AppError::General(format!("Failed to build email: {}", e)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::General(format!("SMTP delivery failed: {}", e)))
+    }
+}
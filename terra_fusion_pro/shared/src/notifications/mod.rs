@@ -0,0 +1,6 @@
+pub mod mailer;
+pub mod notifier;
+pub mod templates;
+
+pub use mailer::{Mailer, OutgoingEmail, SmtpConfig, SmtpMailer};
+pub use notifier::Notifier;
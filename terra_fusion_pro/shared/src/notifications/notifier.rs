@@ -0,0 +1,230 @@
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use chrono::{DateTime, Duration, TimeZone, Timelike, Utc};
+use uuid::Uuid;
+
+use crate::{
+    db::Database,
+    error::AppResult,
+    models::notification::{
+        DeliveryMode, DeliveryStatus, NotificationDelivery, NotificationEvent,
+        NotificationPreferences, MAX_DELIVERY_ATTEMPTS,
+    },
+    models::user::User,
+    repository::{notification_repository::NotificationRepository, user_repository::UserRepository},
+};
+use super::mailer::{Mailer, OutgoingEmail};
+use super::templates::{render_digest, render_event};
+
+/// Number of due deliveries sent per pass of the background loop
+const DELIVERY_BATCH_SIZE: i64 = 50;
+
+/// Longest wait between retries
+const MAX_RETRY_DELAY_MINUTES: i64 = 6 * 60;
+
+/// Turns domain events into emails and delivers them according to user preferences
+pub struct Notifier {
+    deliveries: NotificationRepository,
+    users: UserRepository,
+    mailer: Arc<dyn Mailer>,
+}
+
+impl Notifier {
+    /// Create a new notifier
+    pub fn new(db: Arc<Database>, mailer: Arc<dyn Mailer>) -> Self {
+        Self {
+            deliveries: NotificationRepository::new(db.clone()),
+            users: UserRepository::new(db),
+            mailer,
+        }
+    }
+
+    /// Notify users of an event. Immediate emails are attempted right away;
+    /// failures are left in the delivery log for the background loop to retry.
+    pub async fn notify(&self, event: &NotificationEvent, recipient_ids: &[String]) -> AppResult<()> {
+        for user_id in recipient_ids {
+            let preferences = self.preferences_for(user_id).await?;
+            let mode = preferences.mode_for(event.kind());
+            if mode == DeliveryMode::Off {
+                continue;
+            }
+
+            let user = self.users.get_by_id(user_id.clone()).await?;
+            let recipient = match &user.email {
+                Some(email) => email.clone(),
+                None => {
+                    log::warn!("Skipping {:?} notification for user {} without an email address", event.kind(), user_id);
+                    continue;
+                }
+            };
+
+            let rendered = render_event(event, &greeting_name(&user));
+            let now = Utc::now();
+            let delivery = NotificationDelivery {
+                id: Uuid::new_v4(),
+                user_id: user_id.clone(),
+                event: Some(event.clone()),
+                recipient,
+                subject: rendered.subject,
+                body: rendered.body,
+                status: if mode == DeliveryMode::Digest { DeliveryStatus::Queued } else { DeliveryStatus::Pending },
+                attempts: 0,
+                next_attempt_at: now,
+                last_error: None,
+                digest_id: None,
+                created_at: now,
+                sent_at: None,
+            };
+
+            let delivery = self.deliveries.create_delivery(&delivery).await?;
+            if delivery.status == DeliveryStatus::Pending {
+                self.attempt(&delivery).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether a user has already been sent a notification of this event's kind for its appraisal
+    pub async fn already_notified(&self, event: &NotificationEvent, user_id: &str) -> AppResult<bool> {
+        self.deliveries
+            .has_event_for(user_id, event.kind(), event.appraisal_id())
+            .await
+    }
+
+    /// Send pending deliveries that are due, returning how many were attempted
+    pub async fn process_due(&self, now: DateTime<Utc>) -> AppResult<usize> {
+        let due = self.deliveries.claim_due(now, DELIVERY_BATCH_SIZE).await?;
+
+        for delivery in &due {
+            self.attempt(delivery).await?;
+        }
+
+        Ok(due.len())
+    }
+
+    /// Assemble digest emails for users whose digest hour has passed since their last digest,
+    /// returning how many digests were created
+    pub async fn send_digests(&self, now: DateTime<Utc>) -> AppResult<usize> {
+        let mut created = 0;
+
+        for user_id in self.deliveries.users_with_queued().await? {
+            let preferences = self.preferences_for(&user_id).await?;
+            let slot = latest_digest_slot(now, preferences.digest_hour_utc);
+
+            let last = self.deliveries.last_digest_at(&user_id).await?;
+            if last.map_or(false, |last| last >= slot) {
+                continue;
+            }
+
+            let items = self.deliveries.queued_for_user(&user_id).await?;
+            let recipient = match items.last() {
+                Some(item) => item.recipient.clone(),
+                None => continue,
+            };
+
+            let user = self.users.get_by_id(user_id.clone()).await?;
+            let rendered = render_digest(&greeting_name(&user), &items);
+            let digest = NotificationDelivery {
+                id: Uuid::new_v4(),
+                user_id: user_id.clone(),
+                event: None,
+                recipient: user.email.clone().unwrap_or(recipient),
+                subject: rendered.subject,
+                body: rendered.body,
+                status: DeliveryStatus::Pending,
+                attempts: 0,
+                next_attempt_at: now,
+                last_error: None,
+                digest_id: None,
+                created_at: now,
+                sent_at: None,
+            };
+
+            let item_ids: Vec<Uuid> = items.iter().map(|item| item.id).collect();
+            self.deliveries.create_digest(&digest, &item_ids).await?;
+            created += 1;
+        }
+
+        Ok(created)
+    }
+
+    /// Deliver due emails and digests forever, pausing between passes
+    pub async fn run(self: Arc<Self>, interval: StdDuration) {
+        loop {
+            let now = Utc::now();
+
+            if let Err(err) = self.send_digests(now).await {
+                log::error!("Error assembling notification digests: {:?}", err);
+            }
+
+            if let Err(err) = self.process_due(now).await {
+                log::error!("Error delivering notifications: {:?}", err);
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Try to send a delivery once, recording the outcome
+    async fn attempt(&self, delivery: &NotificationDelivery) -> AppResult<()> {
+        let attempts = delivery.attempts + 1;
+        let email = OutgoingEmail {
+            to: delivery.recipient.clone(),
+            subject: delivery.subject.clone(),
+            body: delivery.body.clone(),
+        };
+
+        match self.mailer.send(&email).await {
+            Ok(()) => self.deliveries.mark_sent(delivery.id, attempts, Utc::now()).await,
+            Err(err) => {
+                let now = Utc::now();
+                let status = if attempts >= MAX_DELIVERY_ATTEMPTS {
+                    log::error!("Giving up on notification {} after {} attempts: {:?}", delivery.id, attempts, err);
+                    DeliveryStatus::Dead
+                } else {
+                    log::warn!("Notification {} failed (attempt {}): {:?}", delivery.id, attempts, err);
+                    DeliveryStatus::Pending
+                };
+
+                self.deliveries
+                    .record_failure(delivery.id, attempts, &status, now + retry_delay(attempts), &err.to_string())
+                    .await
+            }
+        }
+    }
+
+    /// Saved preferences for a user, or the defaults
+    async fn preferences_for(&self, user_id: &str) -> AppResult<NotificationPreferences> {
+        Ok(self
+            .deliveries
+            .get_preferences(user_id)
+            .await?
+            .unwrap_or_else(|| NotificationPreferences::defaults_for(user_id)))
+    }
+}
+
+/// Exponential backoff: 1, 2, 4, 8... minutes after each failed attempt, capped at six hours
+pub fn retry_delay(attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    Duration::minutes((1i64 << exponent).min(MAX_RETRY_DELAY_MINUTES))
+}
+
+/// The most recent time at or before `now` that falls on the digest hour
+fn latest_digest_slot(now: DateTime<Utc>, digest_hour_utc: i32) -> DateTime<Utc> {
+    let hour = digest_hour_utc.clamp(0, 23) as u32;
+    let today = now.date_naive().and_hms_opt(hour, 0, 0).unwrap_or_else(|| now.naive_utc());
+    let today = Utc.from_utc_datetime(&today);
+
+    if now.hour() >= hour {
+        today
+    } else {
+        today - Duration::days(1)
+    }
+}
+
+/// Name used to greet a user in emails
+fn greeting_name(user: &User) -> String {
+    user.first_name.clone().unwrap_or_else(|| "there".to_string())
+}
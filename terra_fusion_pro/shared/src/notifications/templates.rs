@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+
+use crate::models::notification::{EventKind, NotificationDelivery, NotificationEvent};
+
+/// A rendered email subject and plain-text body
+#[derive(Debug, Clone)]
+pub struct RenderedEmail {
+    /// Subject line
+    pub subject: String,
+
+    /// Plain-text body
+    pub body: String,
+}
+
+/// Subject and body templates for an event kind; `{{name}}` placeholders are
+/// replaced with event values
+struct Template {
+    subject: &'static str,
+    body: &'static str,
+}
+
+const ORDER_ASSIGNED: Template = Template {
+    subject: "New assignment: {{order}}",
+    body: "Hello {{recipient}},\n\n\
+           You have been assigned appraisal order {{order}} for {{address}}.\n\
+           Due date: {{due_date}}\n\n\
+           Please schedule the inspection at your earliest convenience.\n",
+};
+
+const STATUS_CHANGED: Template = Template {
+    subject: "Order {{order}} is now {{to}}",
+    body: "Hello {{recipient}},\n\n\
+           Appraisal order {{order}} moved from {{from}} to {{to}}.\n",
+};
+
+const REPORT_SUBMITTED: Template = Template {
+    subject: "Report submitted for {{order}}",
+    body: "Hello {{recipient}},\n\n\
+           A report ({{report_id}}) was submitted for appraisal order {{order}} and is ready for review.\n",
+};
+
+const REVIEW_COMPLETE: Template = Template {
+    subject: "Review complete for {{order}}",
+    body: "Hello {{recipient}},\n\n\
+           The review of appraisal order {{order}} is complete: {{outcome}}.\n",
+};
+
+const DUE_SOON: Template = Template {
+    subject: "Order {{order}} is due soon",
+    body: "Hello {{recipient}},\n\n\
+           Appraisal order {{order}} is due {{due_date}}.\n",
+};
+
+/// Look up the template for an event kind
+fn template_for(kind: EventKind) -> &'static Template {
+    match kind {
+        EventKind::OrderAssigned => &ORDER_ASSIGNED,
+        EventKind::StatusChanged => &STATUS_CHANGED,
+        EventKind::ReportSubmitted => &REPORT_SUBMITTED,
+        EventKind::ReviewComplete => &REVIEW_COMPLETE,
+        EventKind::DueSoon => &DUE_SOON,
+    }
+}
+
+/// Replace `{{name}}` placeholders with values; unknown placeholders are left as-is
+pub fn render(template: &str, values: &BTreeMap<&str, String>) -> String {
+    values.iter().fold(template.to_string(), |text, (name, value)| {
+        text.replace(&format!("{{{{{}}}}}", name), value)
+    })
+}
+
+/// Render the email for an event addressed to a recipient
+pub fn render_event(event: &NotificationEvent, recipient_name: &str) -> RenderedEmail {
+    let mut values = BTreeMap::new();
+    values.insert("recipient", recipient_name.to_string());
+    values.insert("order", event.order_label());
+
+    match event {
+        NotificationEvent::OrderAssigned { property_address, due_date, .. } => {
+            values.insert("address", property_address.clone());
+            values.insert(
+                "due_date",
+                due_date
+                    .map(|d| d.format("%Y-%m-%d %H:%M UTC").to_string())
+                    .unwrap_or_else(|| "not set".to_string()),
+            );
+        }
+        NotificationEvent::StatusChanged { from, to, .. } => {
+            values.insert("from", status_label(from));
+            values.insert("to", status_label(to));
+        }
+        NotificationEvent::ReportSubmitted { report_id, .. } => {
+            values.insert("report_id", report_id.to_string());
+        }
+        NotificationEvent::ReviewComplete { outcome, .. } => {
+            values.insert("outcome", outcome.clone());
+        }
+        NotificationEvent::DueSoon { due_date, .. } => {
+            values.insert("due_date", due_date.format("%Y-%m-%d %H:%M UTC").to_string());
+        }
+    }
+
+    let template = template_for(event.kind());
+    RenderedEmail {
+        subject: render(template.subject, &values),
+        body: render(template.body, &values),
+    }
+}
+
+/// Render a digest email summarizing queued notifications
+pub fn render_digest(recipient_name: &str, items: &[NotificationDelivery]) -> RenderedEmail {
+    let mut body = format!(
+        "Hello {},\n\nHere is your TerraFusionPro summary ({} update{}):\n\n",
+        recipient_name,
+        items.len(),
+        if items.len() == 1 { "" } else { "s" }
+    );
+
+    for item in items {
+        body.push_str(&format!(
+            "- {} ({})\n",
+            item.subject,
+            item.created_at.format("%Y-%m-%d %H:%M UTC")
+        ));
+    }
+
+    RenderedEmail {
+        subject: format!("Your TerraFusionPro digest: {} update(s)", items.len()),
+        body,
+    }
+}
+
+/// Turn a snake_case status into words ("revision_needed" -> "revision needed")
+fn status_label<T: serde::Serialize>(status: &T) -> String {
+    crate::db::encode_enum(status).replace('_', " ")
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::{
+    db::{column, decode_enum, encode_enum, Database},
+    error::{AppError, AppResult},
+    models::credential::{AppraiserLicense, InsurancePolicy, LicenseRequest, InsurancePolicyRequest},
+};
+
+/// Repository for appraiser licenses and E&O insurance policies
+pub struct CredentialRepository {
+    db: Arc<Database>,
+}
+
+impl CredentialRepository {
+    /// Create a new credential repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get all licenses held by a user
+    pub async fn licenses_for_user(&self, user_id: &str) -> AppResult<Vec<AppraiserLicense>> {
+        let rows = sqlx::query("SELECT * FROM appraiser_licenses WHERE user_id = $1 ORDER BY state, expires_on DESC")
+            .bind(user_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch licenses: {}", e)))?;
+
+        rows.iter().map(row_to_license).collect()
+    }
+
+    /// Create a license record
+    pub async fn create_license(&self, user_id: &str, request: &LicenseRequest) -> AppResult<AppraiserLicense> {
+        let now = Utc::now();
+
+        let row = sqlx::query(
+            "INSERT INTO appraiser_licenses
+                (id, user_id, state, license_number, level, issued_on, expires_on, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING *"
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(request.state.trim().to_uppercase())
+        .bind(&request.license_number)
+        .bind(encode_enum(&request.level))
+        .bind(request.issued_on)
+        .bind(request.expires_on)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create license: {}", e)))?;
+
+        row_to_license(&row)
+    }
+
+    /// Replace a license record
+    pub async fn update_license(&self, user_id: &str, id: Uuid, request: &LicenseRequest) -> AppResult<AppraiserLicense> {
+        let row = sqlx::query(
+            "UPDATE appraiser_licenses
+             SET state = $3, license_number = $4, level = $5, issued_on = $6, expires_on = $7, updated_at = $8
+             WHERE id = $1 AND user_id = $2
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(request.state.trim().to_uppercase())
+        .bind(&request.license_number)
+        .bind(encode_enum(&request.level))
+        .bind(request.issued_on)
+        .bind(request.expires_on)
+        .bind(Utc::now())
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to update license: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_license(&row),
+            None => Err(AppError::NotFound(format!("License not found with ID: {}", id))),
+        }
+    }
+
+    /// Delete a license record
+    pub async fn delete_license(&self, user_id: &str, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM appraiser_licenses WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete license: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("License not found with ID: {}", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Get all E&O insurance policies held by a user
+    pub async fn policies_for_user(&self, user_id: &str) -> AppResult<Vec<InsurancePolicy>> {
+        let rows = sqlx::query("SELECT * FROM insurance_policies WHERE user_id = $1 ORDER BY expires_on DESC")
+            .bind(user_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch insurance policies: {}", e)))?;
+
+        rows.iter().map(row_to_policy).collect()
+    }
+
+    /// Create an E&O insurance policy record
+    pub async fn create_policy(&self, user_id: &str, request: &InsurancePolicyRequest) -> AppResult<InsurancePolicy> {
+        let now = Utc::now();
+
+        let row = sqlx::query(
+            "INSERT INTO insurance_policies
+                (id, user_id, carrier, policy_number, coverage_amount, effective_on, expires_on, document_url, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+             RETURNING *"
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(&request.carrier)
+        .bind(&request.policy_number)
+        .bind(request.coverage_amount)
+        .bind(request.effective_on)
+        .bind(request.expires_on)
+        .bind(&request.document_url)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create insurance policy: {}", e)))?;
+
+        row_to_policy(&row)
+    }
+
+    /// Delete an E&O insurance policy record
+    pub async fn delete_policy(&self, user_id: &str, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM insurance_policies WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete insurance policy: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Insurance policy not found with ID: {}", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Get licenses of all users expiring on or before the given date
+    pub async fn licenses_expiring_by(&self, date: NaiveDate) -> AppResult<Vec<AppraiserLicense>> {
+        let rows = sqlx::query("SELECT * FROM appraiser_licenses WHERE expires_on <= $1 ORDER BY expires_on")
+            .bind(date)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch expiring licenses: {}", e)))?;
+
+        rows.iter().map(row_to_license).collect()
+    }
+
+    /// Get policies of all users expiring on or before the given date
+    pub async fn policies_expiring_by(&self, date: NaiveDate) -> AppResult<Vec<InsurancePolicy>> {
+        let rows = sqlx::query("SELECT * FROM insurance_policies WHERE expires_on <= $1 ORDER BY expires_on")
+            .bind(date)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch expiring insurance policies: {}", e)))?;
+
+        rows.iter().map(row_to_policy).collect()
+    }
+}
+
+/// Convert a database row to a license model
+fn row_to_license(row: &PgRow) -> AppResult<AppraiserLicense> {
+    Ok(AppraiserLicense {
+        id: column(row, "id")?,
+        user_id: column(row, "user_id")?,
+        state: column(row, "state")?,
+        license_number: column(row, "license_number")?,
+        level: decode_enum(&column::<String>(row, "level")?)?,
+        issued_on: column(row, "issued_on")?,
+        expires_on: column(row, "expires_on")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to an insurance policy model
+fn row_to_policy(row: &PgRow) -> AppResult<InsurancePolicy> {
+    Ok(InsurancePolicy {
+        id: column(row, "id")?,
+        user_id: column(row, "user_id")?,
+        carrier: column(row, "carrier")?,
+        policy_number: column(row, "policy_number")?,
+        coverage_amount: column(row, "coverage_amount")?,
+        effective_on: column(row, "effective_on")?,
+        expires_on: column(row, "expires_on")?,
+        document_url: column(row, "document_url")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
@@ -1 +1,5 @@
-pub mod user_repository;
\ No newline at end of file
+pub mod user_repository;
+pub mod credential_repository;
+pub mod notification_repository;
+pub mod webhook_repository;
+pub mod signing_key_repository;
\ No newline at end of file
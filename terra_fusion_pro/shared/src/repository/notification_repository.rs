@@ -0,0 +1,299 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use crate::{
+    db::{column, decode_enum, encode_enum, Database},
+    error::{AppError, AppResult},
+    models::notification::{DeliveryStatus, EventKind, NotificationDelivery, NotificationPreferences},
+};
+
+/// How long a claimed delivery is hidden from other workers while it is being sent
+const CLAIM_LEASE_MINUTES: i64 = 10;
+
+/// Repository for notification preferences and the delivery log
+pub struct NotificationRepository {
+    db: Arc<Database>,
+}
+
+impl NotificationRepository {
+    /// Create a new notification repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get a user's saved notification preferences, if they have any
+    pub async fn get_preferences(&self, user_id: &str) -> AppResult<Option<NotificationPreferences>> {
+        let row = sqlx::query("SELECT * FROM notification_preferences WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch notification preferences: {}", e)))?;
+
+        row.as_ref().map(row_to_preferences).transpose()
+    }
+
+    /// Create or replace a user's notification preferences
+    pub async fn save_preferences(&self, preferences: &NotificationPreferences) -> AppResult<NotificationPreferences> {
+        let row = sqlx::query(
+            "INSERT INTO notification_preferences (user_id, email_enabled, events, digest_hour_utc, updated_at)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT (user_id) DO UPDATE
+             SET email_enabled = EXCLUDED.email_enabled, events = EXCLUDED.events,
+                 digest_hour_utc = EXCLUDED.digest_hour_utc, updated_at = EXCLUDED.updated_at
+             RETURNING *"
+        )
+        .bind(&preferences.user_id)
+        .bind(preferences.email_enabled)
+        .bind(Json(&preferences.events))
+        .bind(preferences.digest_hour_utc)
+        .bind(preferences.updated_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to save notification preferences: {}", e)))?;
+
+        row_to_preferences(&row)
+    }
+
+    /// Insert a delivery log entry
+    pub async fn create_delivery(&self, delivery: &NotificationDelivery) -> AppResult<NotificationDelivery> {
+        let row = sqlx::query(
+            "INSERT INTO notification_deliveries
+                (id, user_id, event, recipient, subject, body, status, attempts, next_attempt_at,
+                 last_error, digest_id, created_at, sent_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+             RETURNING *"
+        )
+        .bind(delivery.id)
+        .bind(&delivery.user_id)
+        .bind(delivery.event.as_ref().map(Json))
+        .bind(&delivery.recipient)
+        .bind(&delivery.subject)
+        .bind(&delivery.body)
+        .bind(encode_enum(&delivery.status))
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(&delivery.last_error)
+        .bind(delivery.digest_id)
+        .bind(delivery.created_at)
+        .bind(delivery.sent_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create notification delivery: {}", e)))?;
+
+        row_to_delivery(&row)
+    }
+
+    /// Claim pending deliveries that are due, leasing them so concurrent workers skip them
+    pub async fn claim_due(&self, now: DateTime<Utc>, limit: i64) -> AppResult<Vec<NotificationDelivery>> {
+        let rows = sqlx::query(
+            "UPDATE notification_deliveries SET next_attempt_at = $3
+             WHERE id IN (
+                 SELECT id FROM notification_deliveries
+                 WHERE status = $1 AND next_attempt_at <= $2
+                 ORDER BY next_attempt_at
+                 LIMIT $4
+                 FOR UPDATE SKIP LOCKED)
+             RETURNING *"
+        )
+        .bind(encode_enum(&DeliveryStatus::Pending))
+        .bind(now)
+        .bind(now + Duration::minutes(CLAIM_LEASE_MINUTES))
+        .bind(limit)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to claim notification deliveries: {}", e)))?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+
+    /// Record a successful send
+    pub async fn mark_sent(&self, id: Uuid, attempts: i32, sent_at: DateTime<Utc>) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE notification_deliveries SET status = $2, attempts = $3, sent_at = $4, last_error = NULL
+             WHERE id = $1"
+        )
+        .bind(id)
+        .bind(encode_enum(&DeliveryStatus::Sent))
+        .bind(attempts)
+        .bind(sent_at)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to mark notification as sent: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Record a failed send and when (if ever) to try again
+    pub async fn record_failure(
+        &self,
+        id: Uuid,
+        attempts: i32,
+        status: &DeliveryStatus,
+        next_attempt_at: DateTime<Utc>,
+        error: &str,
+    ) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE notification_deliveries SET status = $2, attempts = $3, next_attempt_at = $4, last_error = $5
+             WHERE id = $1"
+        )
+        .bind(id)
+        .bind(encode_enum(status))
+        .bind(attempts)
+        .bind(next_attempt_at)
+        .bind(error)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record notification failure: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// IDs of users with notifications waiting for a digest
+    pub async fn users_with_queued(&self) -> AppResult<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT user_id FROM notification_deliveries WHERE status = $1"
+        )
+        .bind(encode_enum(&DeliveryStatus::Queued))
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch queued notification users: {}", e)))?;
+
+        rows.iter().map(|row| column(row, "user_id")).collect()
+    }
+
+    /// Notifications waiting for a user's next digest, oldest first
+    pub async fn queued_for_user(&self, user_id: &str) -> AppResult<Vec<NotificationDelivery>> {
+        let rows = sqlx::query(
+            "SELECT * FROM notification_deliveries WHERE user_id = $1 AND status = $2 ORDER BY created_at"
+        )
+        .bind(user_id)
+        .bind(encode_enum(&DeliveryStatus::Queued))
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch queued notifications: {}", e)))?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+
+    /// When the last digest email was created for a user, if ever
+    pub async fn last_digest_at(&self, user_id: &str) -> AppResult<Option<DateTime<Utc>>> {
+        let row = sqlx::query(
+            "SELECT MAX(created_at) AS created_at FROM notification_deliveries
+             WHERE user_id = $1 AND event IS NULL"
+        )
+        .bind(user_id)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch last digest: {}", e)))?;
+
+        column(&row, "created_at")
+    }
+
+    /// Insert a digest email and mark the notifications it covers as batched
+    pub async fn create_digest(&self, digest: &NotificationDelivery, item_ids: &[Uuid]) -> AppResult<NotificationDelivery> {
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        let row = sqlx::query(
+            "INSERT INTO notification_deliveries
+                (id, user_id, event, recipient, subject, body, status, attempts, next_attempt_at,
+                 last_error, digest_id, created_at, sent_at)
+             VALUES ($1, $2, NULL, $3, $4, $5, $6, 0, $7, NULL, NULL, $8, NULL)
+             RETURNING *"
+        )
+        .bind(digest.id)
+        .bind(&digest.user_id)
+        .bind(&digest.recipient)
+        .bind(&digest.subject)
+        .bind(&digest.body)
+        .bind(encode_enum(&digest.status))
+        .bind(digest.next_attempt_at)
+        .bind(digest.created_at)
+        .fetch_one(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create digest: {}", e)))?;
+
+        sqlx::query(
+            "UPDATE notification_deliveries SET status = $2, digest_id = $3 WHERE id = ANY($1)"
+        )
+        .bind(item_ids)
+        .bind(encode_enum(&DeliveryStatus::Batched))
+        .bind(digest.id)
+        .execute(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to batch notifications: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit digest: {}", e)))?;
+
+        row_to_delivery(&row)
+    }
+
+    /// Whether a user has already been notified of an event kind for an appraisal
+    pub async fn has_event_for(&self, user_id: &str, kind: EventKind, appraisal_id: Uuid) -> AppResult<bool> {
+        let row = sqlx::query(
+            "SELECT EXISTS (
+                 SELECT 1 FROM notification_deliveries
+                 WHERE user_id = $1 AND event->>'kind' = $2 AND event->>'appraisal_id' = $3
+             ) AS found"
+        )
+        .bind(user_id)
+        .bind(encode_enum(&kind))
+        .bind(appraisal_id.to_string())
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to check notification log: {}", e)))?;
+
+        column(&row, "found")
+    }
+
+    /// Get a user's delivery log, newest first
+    pub async fn list_for_user(&self, user_id: &str, limit: i64) -> AppResult<Vec<NotificationDelivery>> {
+        let rows = sqlx::query(
+            "SELECT * FROM notification_deliveries WHERE user_id = $1 ORDER BY created_at DESC LIMIT $2"
+        )
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch notification log: {}", e)))?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+}
+
+/// Convert a database row to notification preferences
+fn row_to_preferences(row: &PgRow) -> AppResult<NotificationPreferences> {
+    Ok(NotificationPreferences {
+        user_id: column(row, "user_id")?,
+        email_enabled: column(row, "email_enabled")?,
+        events: column::<Json<_>>(row, "events")?.0,
+        digest_hour_utc: column(row, "digest_hour_utc")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to a delivery log entry
+fn row_to_delivery(row: &PgRow) -> AppResult<NotificationDelivery> {
+    Ok(NotificationDelivery {
+        id: column(row, "id")?,
+        user_id: column(row, "user_id")?,
+        event: column::<Option<Json<_>>>(row, "event")?.map(|e| e.0),
+        recipient: column(row, "recipient")?,
+        subject: column(row, "subject")?,
+        body: column(row, "body")?,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        attempts: column(row, "attempts")?,
+        next_attempt_at: column(row, "next_attempt_at")?,
+        last_error: column(row, "last_error")?,
+        digest_id: column(row, "digest_id")?,
+        created_at: column(row, "created_at")?,
+        sent_at: column(row, "sent_at")?,
+    })
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::{
+    db::{column, Database},
+    error::{AppError, AppResult},
+    models::signature::SigningKey,
+};
+
+/// Repository for users' report signing keys
+pub struct SigningKeyRepository {
+    db: Arc<Database>,
+}
+
+impl SigningKeyRepository {
+    /// Create a new signing key repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get all keys a user has held, newest first
+    pub async fn keys_for_user(&self, user_id: &str) -> AppResult<Vec<SigningKey>> {
+        let rows = sqlx::query("SELECT * FROM signing_keys WHERE user_id = $1 ORDER BY created_at DESC")
+            .bind(user_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch signing keys: {}", e)))?;
+
+        rows.iter().map(row_to_key).collect()
+    }
+
+    /// Get the key a user currently signs with (if any)
+    pub async fn active_for_user(&self, user_id: &str) -> AppResult<Option<SigningKey>> {
+        let row = sqlx::query("SELECT * FROM signing_keys WHERE user_id = $1 AND revoked_at IS NULL")
+            .bind(user_id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch signing key: {}", e)))?;
+
+        row.as_ref().map(row_to_key).transpose()
+    }
+
+    /// Get a key by ID, whether or not it has been retired
+    pub async fn get_by_id(&self, id: Uuid) -> AppResult<SigningKey> {
+        let row = sqlx::query("SELECT * FROM signing_keys WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch signing key: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_key(&row),
+            None => Err(AppError::NotFound(format!("Signing key not found with ID: {}", id))),
+        }
+    }
+
+    /// Store a new key for a user, retiring the key it replaces
+    pub async fn rotate(&self, user_id: &str, public_key: &str, sealed_private_key: &str) -> AppResult<SigningKey> {
+        let now = Utc::now();
+        let mut tx = self.db.pool.begin()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to start transaction: {}", e)))?;
+
+        sqlx::query("UPDATE signing_keys SET revoked_at = $2 WHERE user_id = $1 AND revoked_at IS NULL")
+            .bind(user_id)
+            .bind(now)
+            .execute(&mut tx)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to retire signing key: {}", e)))?;
+
+        let row = sqlx::query(
+            "INSERT INTO signing_keys (id, user_id, public_key, sealed_private_key, created_at)
+             VALUES ($1, $2, $3, $4, $5)
+             RETURNING *"
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(public_key)
+        .bind(sealed_private_key)
+        .bind(now)
+        .fetch_one(&mut tx)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create signing key: {}", e)))?;
+
+        let key = row_to_key(&row)?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to commit transaction: {}", e)))?;
+
+        Ok(key)
+    }
+
+    /// Retire a user's active key so nothing more can be signed with it
+    pub async fn revoke(&self, user_id: &str, id: Uuid) -> AppResult<SigningKey> {
+        let row = sqlx::query(
+            "UPDATE signing_keys SET revoked_at = $3
+             WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+             RETURNING *"
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(Utc::now())
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to revoke signing key: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_key(&row),
+            None => Err(AppError::NotFound(format!("Active signing key not found with ID: {}", id))),
+        }
+    }
+}
+
+/// Convert a database row to a signing key model
+fn row_to_key(row: &PgRow) -> AppResult<SigningKey> {
+    Ok(SigningKey {
+        id: column(row, "id")?,
+        user_id: column(row, "user_id")?,
+        public_key: column(row, "public_key")?,
+        sealed_private_key: column(row, "sealed_private_key")?,
+        created_at: column(row, "created_at")?,
+        revoked_at: column(row, "revoked_at")?,
+    })
+}
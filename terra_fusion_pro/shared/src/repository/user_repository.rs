@@ -4,9 +4,9 @@ use chrono::Utc;
 use sqlx::{postgres::PgRow, Row};
 
 use crate::{
-    db::Database,
+    db::{column, decode_enum, Database},
     error::{AppError, AppResult},
-    models::user::{User, UpsertUser},
+    models::user::{User, UpsertUser, UserRole},
 };
 
 /// Repository for user operations
@@ -52,6 +52,20 @@ impl UserRepository {
         }
     }
     
+    /// Get a user's platform role; unknown users have no role beyond `Member`
+    pub async fn role_of(&self, id: &str) -> AppResult<UserRole> {
+        let row = sqlx::query("SELECT role FROM users WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch user role: {}", e)))?;
+            
+        match row {
+            Some(row) => decode_enum(&column::<String>(&row, "role")?),
+            None => Ok(UserRole::Member),
+        }
+    }
+    
     /// Get all users
     pub async fn get_all(&self) -> AppResult<Vec<User>> {
         let query = "SELECT * FROM users ORDER BY created_at DESC";
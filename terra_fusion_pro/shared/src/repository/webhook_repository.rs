@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use crate::{
+    db::{column, decode_enum, encode_enum, Database},
+    error::{AppError, AppResult},
+    models::webhook::{WebhookDelivery, WebhookDeliveryStatus, WebhookEndpoint, WebhookEventType},
+};
+
+/// How long a claimed delivery is hidden from other workers while it is being sent
+const CLAIM_LEASE_MINUTES: i64 = 5;
+
+/// Repository for webhook endpoints and deliveries
+pub struct WebhookRepository {
+    db: Arc<Database>,
+}
+
+impl WebhookRepository {
+    /// Create a new webhook repository
+    pub fn new(db: Arc<Database>) -> Self {
+        Self { db }
+    }
+
+    /// Get all endpoints registered by a client
+    pub async fn endpoints_for_client(&self, client_id: Uuid) -> AppResult<Vec<WebhookEndpoint>> {
+        let rows = sqlx::query("SELECT * FROM webhook_endpoints WHERE client_id = $1 ORDER BY created_at")
+            .bind(client_id)
+            .fetch_all(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch webhook endpoints: {}", e)))?;
+
+        rows.iter().map(row_to_endpoint).collect()
+    }
+
+    /// Get an endpoint by ID
+    pub async fn get_endpoint(&self, id: Uuid) -> AppResult<WebhookEndpoint> {
+        let row = sqlx::query("SELECT * FROM webhook_endpoints WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch webhook endpoint: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_endpoint(&row),
+            None => Err(AppError::NotFound(format!("Webhook endpoint not found with ID: {}", id))),
+        }
+    }
+
+    /// Get a client's active endpoints subscribed to an event type
+    pub async fn subscribed_endpoints(&self, client_id: Uuid, event_type: WebhookEventType) -> AppResult<Vec<WebhookEndpoint>> {
+        let rows = sqlx::query(
+            "SELECT * FROM webhook_endpoints WHERE client_id = $1 AND is_active AND event_types @> $2"
+        )
+        .bind(client_id)
+        .bind(Json(vec![event_type]))
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch subscribed webhook endpoints: {}", e)))?;
+
+        rows.iter().map(row_to_endpoint).collect()
+    }
+
+    /// Insert a new endpoint
+    pub async fn create_endpoint(&self, endpoint: &WebhookEndpoint) -> AppResult<WebhookEndpoint> {
+        let row = sqlx::query(
+            "INSERT INTO webhook_endpoints
+                (id, client_id, url, description, event_types, secret, is_active, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+             RETURNING *"
+        )
+        .bind(endpoint.id)
+        .bind(endpoint.client_id)
+        .bind(&endpoint.url)
+        .bind(&endpoint.description)
+        .bind(Json(&endpoint.event_types))
+        .bind(&endpoint.secret)
+        .bind(endpoint.is_active)
+        .bind(endpoint.created_at)
+        .bind(endpoint.updated_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create webhook endpoint: {}", e)))?;
+
+        row_to_endpoint(&row)
+    }
+
+    /// Persist changes to an endpoint, including its secret
+    pub async fn update_endpoint(&self, endpoint: &WebhookEndpoint) -> AppResult<WebhookEndpoint> {
+        let row = sqlx::query(
+            "UPDATE webhook_endpoints
+             SET url = $2, description = $3, event_types = $4, secret = $5, is_active = $6, updated_at = $7
+             WHERE id = $1
+             RETURNING *"
+        )
+        .bind(endpoint.id)
+        .bind(&endpoint.url)
+        .bind(&endpoint.description)
+        .bind(Json(&endpoint.event_types))
+        .bind(&endpoint.secret)
+        .bind(endpoint.is_active)
+        .bind(endpoint.updated_at)
+        .fetch_optional(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to update webhook endpoint: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_endpoint(&row),
+            None => Err(AppError::NotFound(format!("Webhook endpoint not found with ID: {}", endpoint.id))),
+        }
+    }
+
+    /// Delete an endpoint and its delivery history
+    pub async fn delete_endpoint(&self, id: Uuid) -> AppResult<()> {
+        let result = sqlx::query("DELETE FROM webhook_endpoints WHERE id = $1")
+            .bind(id)
+            .execute(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to delete webhook endpoint: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Webhook endpoint not found with ID: {}", id)));
+        }
+
+        Ok(())
+    }
+
+    /// Insert a new delivery
+    pub async fn create_delivery(&self, delivery: &WebhookDelivery) -> AppResult<WebhookDelivery> {
+        let row = sqlx::query(
+            "INSERT INTO webhook_deliveries
+                (id, endpoint_id, event_id, event_type, payload, status, attempts, next_attempt_at,
+                 attempt_log, redelivery_of, created_at, delivered_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+             RETURNING *"
+        )
+        .bind(delivery.id)
+        .bind(delivery.endpoint_id)
+        .bind(delivery.event_id)
+        .bind(encode_enum(&delivery.event_type))
+        .bind(Json(&delivery.payload))
+        .bind(encode_enum(&delivery.status))
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(Json(&delivery.attempt_log))
+        .bind(delivery.redelivery_of)
+        .bind(delivery.created_at)
+        .bind(delivery.delivered_at)
+        .fetch_one(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to create webhook delivery: {}", e)))?;
+
+        row_to_delivery(&row)
+    }
+
+    /// Get a delivery by ID
+    pub async fn get_delivery(&self, id: Uuid) -> AppResult<WebhookDelivery> {
+        let row = sqlx::query("SELECT * FROM webhook_deliveries WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.db.pool)
+            .await
+            .map_err(|e| AppError::Database(format!("Failed to fetch webhook delivery: {}", e)))?;
+
+        match row {
+            Some(row) => row_to_delivery(&row),
+            None => Err(AppError::NotFound(format!("Webhook delivery not found with ID: {}", id))),
+        }
+    }
+
+    /// Get an endpoint's deliveries, newest first
+    pub async fn deliveries_for_endpoint(&self, endpoint_id: Uuid, limit: i64) -> AppResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(
+            "SELECT * FROM webhook_deliveries WHERE endpoint_id = $1 ORDER BY created_at DESC LIMIT $2"
+        )
+        .bind(endpoint_id)
+        .bind(limit)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to fetch webhook deliveries: {}", e)))?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+
+    /// Claim pending deliveries that are due, leasing them so concurrent workers skip them
+    pub async fn claim_due(&self, now: DateTime<Utc>, limit: i64) -> AppResult<Vec<WebhookDelivery>> {
+        let rows = sqlx::query(
+            "UPDATE webhook_deliveries SET next_attempt_at = $3
+             WHERE id IN (
+                 SELECT id FROM webhook_deliveries
+                 WHERE status = $1 AND next_attempt_at <= $2
+                 ORDER BY next_attempt_at
+                 LIMIT $4
+                 FOR UPDATE SKIP LOCKED)
+             RETURNING *"
+        )
+        .bind(encode_enum(&WebhookDeliveryStatus::Pending))
+        .bind(now)
+        .bind(now + Duration::minutes(CLAIM_LEASE_MINUTES))
+        .bind(limit)
+        .fetch_all(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to claim webhook deliveries: {}", e)))?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+
+    /// Record the outcome of a delivery attempt
+    pub async fn save_attempt(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        sqlx::query(
+            "UPDATE webhook_deliveries
+             SET status = $2, attempts = $3, next_attempt_at = $4, attempt_log = $5, delivered_at = $6
+             WHERE id = $1"
+        )
+        .bind(delivery.id)
+        .bind(encode_enum(&delivery.status))
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(Json(&delivery.attempt_log))
+        .bind(delivery.delivered_at)
+        .execute(&self.db.pool)
+        .await
+        .map_err(|e| AppError::Database(format!("Failed to record webhook attempt: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+/// Convert a database row to a webhook endpoint model
+fn row_to_endpoint(row: &PgRow) -> AppResult<WebhookEndpoint> {
+    Ok(WebhookEndpoint {
+        id: column(row, "id")?,
+        client_id: column(row, "client_id")?,
+        url: column(row, "url")?,
+        description: column(row, "description")?,
+        event_types: column::<Json<_>>(row, "event_types")?.0,
+        secret: column(row, "secret")?,
+        is_active: column(row, "is_active")?,
+        created_at: column(row, "created_at")?,
+        updated_at: column(row, "updated_at")?,
+    })
+}
+
+/// Convert a database row to a webhook delivery model
+fn row_to_delivery(row: &PgRow) -> AppResult<WebhookDelivery> {
+    Ok(WebhookDelivery {
+        id: column(row, "id")?,
+        endpoint_id: column(row, "endpoint_id")?,
+        event_id: column(row, "event_id")?,
+        event_type: decode_enum(&column::<String>(row, "event_type")?)?,
+        payload: column::<Json<_>>(row, "payload")?.0,
+        status: decode_enum(&column::<String>(row, "status")?)?,
+        attempts: column(row, "attempts")?,
+        next_attempt_at: column(row, "next_attempt_at")?,
+        attempt_log: column::<Json<_>>(row, "attempt_log")?.0,
+        redelivery_of: column(row, "redelivery_of")?,
+        created_at: column(row, "created_at")?,
+        delivered_at: column(row, "delivered_at")?,
+    })
+}
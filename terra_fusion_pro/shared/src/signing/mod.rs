@@ -0,0 +1,22 @@
+mod vault;
+
+pub use vault::KeyVault;
+
+use ed25519_dalek::{Signature, VerifyingKey};
+
+/// Check a hex Ed25519 signature of a message against a hex public key. Malformed keys and
+/// signatures never verify.
+pub fn verify(public_key: &str, message: &[u8], signature: &str) -> bool {
+    let key = hex::decode(public_key)
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes.as_slice()).ok())
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok());
+    let signature = hex::decode(signature)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok());
+
+    match (key, signature) {
+        (Some(key), Some(signature)) => key.verify_strict(message, &signature).is_ok(),
+        _ => false,
+    }
+}
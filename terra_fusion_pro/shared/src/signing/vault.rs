@@ -0,0 +1,68 @@
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use ed25519_dalek::{Signer, SigningKey};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use crate::error::{AppError, AppResult};
+
+/// Length of the random nonce stored ahead of each sealed key
+const NONCE_LENGTH: usize = 12;
+
+/// Holds users' Ed25519 private keys sealed with ChaCha20-Poly1305, so the database alone
+/// is not enough to sign on anyone's behalf. Private keys never leave the vault unsealed.
+pub struct KeyVault {
+    cipher: ChaCha20Poly1305,
+}
+
+impl KeyVault {
+    /// Create a vault whose sealing key is derived from the configured secret
+    pub fn new(secret: &str) -> Self {
+        let key = Sha256::digest(secret.as_bytes());
+        Self {
+            cipher: ChaCha20Poly1305::new(Key::from_slice(&key)),
+        }
+    }
+
+    /// Generate a key pair, returning the hex public key and the sealed private key
+    pub fn generate(&self) -> AppResult<(String, String)> {
+        let signing_key = SigningKey::generate(&mut OsRng);
+        let sealed = self.seal(signing_key.as_bytes())?;
+
+        Ok((hex::encode(signing_key.verifying_key().as_bytes()), sealed))
+    }
+
+    /// Sign a message with a sealed private key, returning the hex signature
+    pub fn sign(&self, sealed_private_key: &str, message: &[u8]) -> AppResult<String> {
+        let secret = self.open(sealed_private_key)?;
+        let secret = <[u8; 32]>::try_from(secret.as_slice())
+            .map_err(|_| AppError::General("Sealed signing key has the wrong length".to_string()))?;
+
+        Ok(hex::encode(SigningKey::from_bytes(&secret).sign(message).to_bytes()))
+    }
+
+    fn seal(&self, plaintext: &[u8]) -> AppResult<String> {
+        let mut nonce = [0u8; NONCE_LENGTH];
+        OsRng.fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| AppError::General("Failed to seal signing key".to_string()))?;
+
+        Ok(hex::encode([nonce.as_slice(), &ciphertext].concat()))
+    }
+
+    fn open(&self, sealed: &str) -> AppResult<Vec<u8>> {
+        let bytes = hex::decode(sealed)
+            .ok()
+            .filter(|bytes| bytes.len() > NONCE_LENGTH)
+            .ok_or_else(|| AppError::General("Sealed signing key is malformed".to_string()))?;
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LENGTH);
+
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| AppError::General("Failed to unseal signing key; check SIGNING_KEY_SECRET".to_string()))
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+
+use crate::error::{AppError, AppResult};
+use super::AttachmentStore;
+
+/// Attachment store backed by a directory on the local filesystem
+pub struct LocalAttachmentStore {
+    root: PathBuf,
+}
+
+impl LocalAttachmentStore {
+    /// Create a store rooted at the given directory
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Resolve a key to a path under the root, rejecting keys that could escape it
+    fn path_for(&self, key: &str) -> AppResult<PathBuf> {
+        let relative = Path::new(key);
+        let is_safe = !key.is_empty()
+            && relative.components().all(|c| matches!(c, Component::Normal(_)));
+
+        if !is_safe {
+            return Err(AppError::Validation(format!("Invalid attachment key: {}", key)));
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl AttachmentStore for LocalAttachmentStore {
+    async fn put(&self, key: &str, bytes: &[u8]) -> AppResult<()> {
+        let path = self.path_for(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| AppError::General(format!("Failed to create attachment directory: {}", e)))?;
+        }
+
+        tokio::fs::write(&path, bytes)
+            .await
+            .map_err(|e| AppError::General(format!("Failed to store attachment: {}", e)))
+    }
+
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>> {
+        let path = self.path_for(key)?;
+
+        tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            ErrorKind::NotFound => AppError::NotFound(format!("Attachment not found: {}", key)),
+            _ => AppError::General(format!("Failed to read attachment: {}", e)),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        let path = self.path_for(key)?;
+
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::General(format!("Failed to delete attachment: {}", e))),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::error::AppResult;
+
+mod local;
+
+pub use local::LocalAttachmentStore;
+
+/// Storage backend for uploaded files such as message attachments and report exhibits.
+/// Keys are slash-separated relative paths chosen by the caller.
+#[async_trait]
+pub trait AttachmentStore: Send + Sync {
+    /// Store bytes under a key, replacing any existing content
+    async fn put(&self, key: &str, bytes: &[u8]) -> AppResult<()>;
+
+    /// Read the bytes stored under a key
+    async fn get(&self, key: &str) -> AppResult<Vec<u8>>;
+
+    /// Remove the bytes stored under a key; missing keys are not an error
+    async fn delete(&self, key: &str) -> AppResult<()>;
+}
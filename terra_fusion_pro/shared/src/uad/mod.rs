@@ -0,0 +1,129 @@
+pub mod rules;
+
+use serde_json::Value;
+
+use crate::models::property::PropertyCharacteristics;
+use crate::models::report::ReportType;
+use crate::models::uad::{UadFinding, UadValidation};
+
+/// How a rule violation is treated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Rejected by the GSE collateral portal; blocks submission
+    Error,
+    /// Reported for review only
+    Warning,
+}
+
+/// A problem found by a field check
+pub struct Violation {
+    /// How the violation is treated
+    pub severity: Severity,
+
+    /// Description of the problem
+    pub message: String,
+}
+
+impl Violation {
+    pub fn error(message: impl Into<String>) -> Self {
+        Self { severity: Severity::Error, message: message.into() }
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self { severity: Severity::Warning, message: message.into() }
+    }
+}
+
+/// A check applied to a content value wherever it appears
+pub struct FieldRule {
+    /// Rule identifier reported with each finding
+    pub rule: &'static str,
+
+    /// Dotted content paths the rule applies to; `comparables[]` applies it to every comparable
+    pub paths: &'static [&'static str],
+
+    /// Whether a missing or blank value is an error
+    pub required: bool,
+
+    /// Check of a present value
+    pub check: fn(&Value) -> Option<Violation>,
+}
+
+/// Whether a report type is delivered under the UAD specification. UAD covers the
+/// single-unit residential forms; Form 1025 and 1004C deliveries are not UAD-formatted.
+pub fn applies_to(report_type: &ReportType) -> bool {
+    matches!(report_type, ReportType::Form1004 | ReportType::Form1073 | ReportType::Form2055)
+}
+
+/// Check report content, and the property record it describes, against the UAD
+/// specification. Report types outside UAD always pass.
+pub fn validate_report(
+    report_type: &ReportType,
+    content: &Value,
+    characteristics: Option<&PropertyCharacteristics>,
+) -> UadValidation {
+    let mut validation = UadValidation::default();
+    if !applies_to(report_type) {
+        return validation;
+    }
+
+    for rule in rules::FIELD_RULES {
+        for path in rule.paths {
+            for (location, value) in resolve(content, path) {
+                let violation = match value.filter(|value| !is_blank(value)) {
+                    Some(value) => (rule.check)(value),
+                    None if rule.required => Some(Violation::error("A value is required")),
+                    None => None,
+                };
+
+                if let Some(violation) = violation {
+                    record(&mut validation, location, rule.rule, violation);
+                }
+            }
+        }
+    }
+
+    if let Some(characteristics) = characteristics {
+        for (path, violation) in rules::check_property_record(content, characteristics) {
+            record(&mut validation, path, rules::PROPERTY_RECORD, violation);
+        }
+    }
+
+    validation
+}
+
+fn record(validation: &mut UadValidation, path: String, rule: &str, violation: Violation) {
+    let finding = UadFinding { path, rule: rule.to_string(), message: violation.message };
+    match violation.severity {
+        Severity::Error => validation.errors.push(finding),
+        Severity::Warning => validation.warnings.push(finding),
+    }
+}
+
+/// Every location a rule path refers to, with the value found there (if any)
+fn resolve<'a>(content: &'a Value, path: &str) -> Vec<(String, Option<&'a Value>)> {
+    match path.split_once("[].") {
+        Some((array, rest)) => match lookup(content, array).and_then(Value::as_array) {
+            Some(entries) => entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| (format!("{}[{}].{}", array, index, rest), lookup(entry, rest)))
+                .collect(),
+            None => Vec::new(),
+        },
+        None => vec![(path.to_string(), lookup(content, path))],
+    }
+}
+
+/// Value at a dotted path within a content object
+pub fn lookup<'a>(content: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(content, |value, key| value.get(key))
+}
+
+fn is_blank(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        _ => false,
+    }
+}
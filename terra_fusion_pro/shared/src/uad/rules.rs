@@ -0,0 +1,470 @@
+use chrono::{Datelike, NaiveDate, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+
+use super::{lookup, FieldRule, Violation};
+use crate::models::property::PropertyCharacteristics;
+
+/// Rule identifier of findings that compare the report with the property record
+pub const PROPERTY_RECORD: &str = "property_record";
+
+/// Field rules, in the order findings are reported
+pub const FIELD_RULES: &[FieldRule] = &[
+    FieldRule {
+        rule: "condition_rating",
+        paths: &["improvements.condition", "comparables[].condition"],
+        required: true,
+        check: condition_rating,
+    },
+    FieldRule {
+        rule: "quality_rating",
+        paths: &["improvements.quality", "comparables[].quality"],
+        required: true,
+        check: quality_rating,
+    },
+    FieldRule {
+        rule: "gross_living_area",
+        paths: &["improvements.gross_living_area", "comparables[].gross_living_area"],
+        required: true,
+        check: gross_living_area,
+    },
+    FieldRule {
+        rule: "bathroom_count",
+        paths: &["improvements.bathrooms", "comparables[].bathrooms"],
+        required: false,
+        check: bathroom_count,
+    },
+    FieldRule {
+        rule: "bedroom_count",
+        paths: &["improvements.bedrooms", "comparables[].bedrooms"],
+        required: false,
+        check: whole_number,
+    },
+    FieldRule {
+        rule: "year_built",
+        paths: &["improvements.year_built"],
+        required: false,
+        check: year_built,
+    },
+    FieldRule {
+        rule: "view",
+        paths: &["site.view", "comparables[].view"],
+        required: true,
+        check: view,
+    },
+    FieldRule {
+        rule: "location",
+        paths: &["comparables[].location"],
+        required: true,
+        check: location,
+    },
+    FieldRule {
+        rule: "sale_type",
+        paths: &["contract.sale_type", "comparables[].sale_type"],
+        required: false,
+        check: sale_type,
+    },
+    FieldRule {
+        rule: "date_of_sale",
+        paths: &["comparables[].sale_date"],
+        required: true,
+        check: date_of_sale,
+    },
+    FieldRule {
+        rule: "date",
+        paths: &["contract.contract_date"],
+        required: false,
+        check: date,
+    },
+    FieldRule {
+        rule: "effective_date",
+        paths: &["reconciliation.effective_date"],
+        required: true,
+        check: effective_date,
+    },
+];
+
+/// UAD view factors
+const VIEW_FACTORS: &[&str] = &[
+    "Wtr", "Pstrl", "Woods", "Prk", "Glfvw", "CtySky", "Mtn", "Res", "CtyStr", "Ind", "PwrLn", "LtdSght",
+];
+
+/// UAD location factors
+const LOCATION_FACTORS: &[&str] = &[
+    "Res", "Ind", "Comm", "BsyRd", "WtrFr", "GlfCse", "AdjPrk", "AdjPwr", "Lndfl", "PubTrn",
+];
+
+/// UAD sale types
+const SALE_TYPES: &[&str] = &["REO", "Short", "CrtOrd", "Estate", "Relo", "NonArm", "ArmLth", "Listing"];
+
+fn condition_rating(value: &Value) -> Option<Violation> {
+    rating(value, 'C', "condition")
+}
+
+fn quality_rating(value: &Value) -> Option<Violation> {
+    rating(value, 'Q', "quality")
+}
+
+/// A rating written exactly as the prefix and a level of 1 through 6, e.g. "C3"
+fn rating(value: &Value, prefix: char, name: &str) -> Option<Violation> {
+    let text = text(value);
+    let valid = text.strip_prefix(prefix).is_some_and(|level| {
+        level.len() == 1 && matches!(level.chars().next(), Some('1'..='6'))
+    });
+
+    if valid {
+        None
+    } else {
+        Some(Violation::error(format!(
+            "\"{}\" is not a UAD {} rating; use {}1 through {}6",
+            text, name, prefix, prefix
+        )))
+    }
+}
+
+fn gross_living_area(value: &Value) -> Option<Violation> {
+    match number(value) {
+        Some(area) if area > 0.0 && area.fract() == 0.0 => None,
+        _ => Some(Violation::error(format!(
+            "\"{}\" is not a gross living area; use whole square feet, e.g. 1850",
+            text(value)
+        ))),
+    }
+}
+
+/// Bathrooms as "full.half", e.g. "2.1" for two full baths and one half bath
+fn bathroom_count(value: &Value) -> Option<Violation> {
+    if let Value::Number(n) = value {
+        return Some(Violation::error(format!(
+            "{} is a number; write bathrooms as full.half text, e.g. \"2.1\" for two full baths and one half bath",
+            n
+        )));
+    }
+
+    match parse_bathrooms(&text(value)) {
+        Some(_) => None,
+        None => Some(Violation::error(format!(
+            "\"{}\" is not in UAD full.half notation, e.g. \"2.1\" for two full baths and one half bath",
+            text(value)
+        ))),
+    }
+}
+
+fn whole_number(value: &Value) -> Option<Violation> {
+    match number(value) {
+        Some(count) if count >= 0.0 && count.fract() == 0.0 => None,
+        _ => Some(Violation::error(format!("\"{}\" is not a whole number", text(value)))),
+    }
+}
+
+fn year_built(value: &Value) -> Option<Violation> {
+    let latest = Utc::now().year() + 1;
+    match number(value) {
+        Some(year) if year.fract() == 0.0 && (1600.0..=latest as f64).contains(&year) => None,
+        _ => Some(Violation::error(format!(
+            "\"{}\" is not a four-digit year built no later than {}",
+            text(value),
+            latest
+        ))),
+    }
+}
+
+fn view(value: &Value) -> Option<Violation> {
+    rating_with_factors(&text(value), "view", VIEW_FACTORS)
+}
+
+fn location(value: &Value) -> Option<Violation> {
+    rating_with_factors(&text(value), "location", LOCATION_FACTORS)
+}
+
+/// A UAD view or location: an overall rating of N (neutral), B (beneficial) or A (adverse)
+/// followed by one or two factors, e.g. "N;Res;" or "B;Wtr;Prk". A factor that is not a UAD
+/// abbreviation is read as a description of an "Other" factor.
+fn rating_with_factors(text: &str, name: &str, factors: &[&str]) -> Option<Violation> {
+    let parts = text.split(';').map(str::trim).collect::<Vec<_>>();
+    let expected = format!(
+        "use a rating of N, B or A and one or two factors, e.g. \"N;{};\"",
+        factors[0]
+    );
+
+    if !matches!(parts[0], "N" | "B" | "A") {
+        return Some(Violation::error(format!(
+            "\"{}\" does not start with a UAD {} rating; {}",
+            text, name, expected
+        )));
+    }
+
+    let given = parts[1..].iter().filter(|factor| !factor.is_empty()).collect::<Vec<_>>();
+    if given.is_empty() || given.len() > 2 || parts.len() > 3 {
+        return Some(Violation::error(format!("\"{}\" is not a UAD {}; {}", text, name, expected)));
+    }
+
+    given
+        .iter()
+        .find(|factor| !factors.contains(factor))
+        .map(|factor| {
+            Violation::warning(format!(
+                "\"{}\" is not a UAD {} factor and will be delivered as an \"Other\" description; codes are {}",
+                factor,
+                name,
+                factors.join(", ")
+            ))
+        })
+}
+
+fn sale_type(value: &Value) -> Option<Violation> {
+    let text = text(value);
+    if SALE_TYPES.contains(&text.as_str()) {
+        return None;
+    }
+
+    Some(Violation::error(format!(
+        "\"{}\" is not a UAD sale type; use one of {}",
+        text,
+        SALE_TYPES.join(", ")
+    )))
+}
+
+/// Date of sale and contract as "s03/23;c02/23" (or "c" date "Unk"), a pending "c02/23",
+/// "Active", or an expired "e03/23" or withdrawn "w03/23" listing
+static DATE_OF_SALE: Lazy<Regex> = Lazy::new(|| {
+    let month = r"(0[1-9]|1[0-2])/\d{2}";
+    Regex::new(&format!(r"^(s{m};(c{m}|Unk)|c{m}|Active|[ew]{m})$", m = month)).unwrap()
+});
+
+fn date_of_sale(value: &Value) -> Option<Violation> {
+    let text = text(value);
+
+    if DATE_OF_SALE.is_match(&text) {
+        None
+    } else {
+        Some(Violation::error(format!(
+            "\"{}\" is not a UAD date of sale; use e.g. \"s03/23;c02/23\", \"s03/23;Unk\", \"c02/23\", \"Active\", \"e03/23\" or \"w03/23\"",
+            text
+        )))
+    }
+}
+
+fn date(value: &Value) -> Option<Violation> {
+    match parse_date(&text(value)) {
+        Some(_) => None,
+        None => Some(Violation::error(format!(
+            "\"{}\" is not a date; use MM/DD/YYYY",
+            text(value)
+        ))),
+    }
+}
+
+fn effective_date(value: &Value) -> Option<Violation> {
+    match parse_date(&text(value)) {
+        Some(date) if date > Utc::now().date_naive() => Some(Violation::error(format!(
+            "Effective date {} is in the future",
+            date.format("%m/%d/%Y")
+        ))),
+        Some(_) => None,
+        None => date(value),
+    }
+}
+
+/// Compare the improvements section with the property record the report describes
+pub fn check_property_record(
+    content: &Value,
+    characteristics: &PropertyCharacteristics,
+) -> Vec<(String, Violation)> {
+    let mut findings = Vec::new();
+    let mut compare = |path: &str, reported: Option<f64>, recorded: Option<f64>, tolerance: f64| {
+        if let (Some(reported), Some(recorded)) = (reported, recorded) {
+            if (reported - recorded).abs() > tolerance {
+                findings.push((
+                    path.to_string(),
+                    Violation::warning(format!(
+                        "Report shows {} but the property record has {}",
+                        reported, recorded
+                    )),
+                ));
+            }
+        }
+    };
+
+    let reported = |path: &str| lookup(content, path).and_then(number);
+    compare(
+        "improvements.gross_living_area",
+        reported("improvements.gross_living_area"),
+        characteristics.square_feet,
+        1.0,
+    );
+    compare(
+        "improvements.bedrooms",
+        reported("improvements.bedrooms"),
+        characteristics.bedrooms.map(f64::from),
+        0.0,
+    );
+    compare(
+        "improvements.year_built",
+        reported("improvements.year_built"),
+        characteristics.year_built.map(f64::from),
+        0.0,
+    );
+    compare(
+        "improvements.bathrooms",
+        lookup(content, "improvements.bathrooms")
+            .and_then(Value::as_str)
+            .and_then(parse_bathrooms),
+        characteristics.bathrooms,
+        0.0,
+    );
+
+    findings
+}
+
+/// Bathroom count of a "full.half" value, counting a half bath as 0.5
+pub fn parse_bathrooms(text: &str) -> Option<f64> {
+    let (full, half) = text.trim().split_once('.')?;
+    if full.is_empty() || half.is_empty() || half.len() > 1 {
+        return None;
+    }
+
+    let full = full.parse::<u32>().ok()?;
+    let half = half.parse::<u32>().ok()?;
+    Some(f64::from(full) + f64::from(half) * 0.5)
+}
+
+/// A date written as MM/DD/YYYY, or as YYYY-MM-DD, which is converted on delivery
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%m/%d/%Y")
+        .or_else(|_| NaiveDate::parse_from_str(text, "%Y-%m-%d"))
+        .ok()
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.replace(',', "").trim().parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.trim().to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::models::property::PropertyType;
+    use crate::uad::Severity;
+
+    fn severity(check: fn(&Value) -> Option<Violation>, value: Value) -> Option<Severity> {
+        check(&value).map(|violation| violation.severity)
+    }
+
+    #[test]
+    fn ratings_take_the_prefix_and_a_level_of_one_through_six() {
+        assert_eq!(severity(condition_rating, json!("C1")), None);
+        assert_eq!(severity(condition_rating, json!(" C6 ")), None);
+        assert_eq!(severity(quality_rating, json!("Q4")), None);
+
+        for value in ["C0", "C7", "C", "C33", "c3", "Q3", "3"] {
+            assert_eq!(severity(condition_rating, json!(value)), Some(Severity::Error), "{}", value);
+        }
+        assert_eq!(severity(quality_rating, json!("C4")), Some(Severity::Error));
+    }
+
+    #[test]
+    fn bathrooms_are_full_dot_half_text() {
+        assert_eq!(severity(bathroom_count, json!("2.1")), None);
+        assert_eq!(severity(bathroom_count, json!("3.0")), None);
+
+        for value in [json!(2.1), json!("2"), json!("2.10"), json!(".1"), json!("two.one")] {
+            assert_eq!(severity(bathroom_count, value.clone()), Some(Severity::Error), "{}", value);
+        }
+        assert_eq!(parse_bathrooms("2.1"), Some(2.5));
+    }
+
+    #[test]
+    fn gross_living_area_is_whole_square_feet() {
+        assert_eq!(severity(gross_living_area, json!(1850)), None);
+        assert_eq!(severity(gross_living_area, json!("1,850")), None);
+        assert_eq!(severity(gross_living_area, json!(1850.5)), Some(Severity::Error));
+        assert_eq!(severity(gross_living_area, json!(0)), Some(Severity::Error));
+    }
+
+    #[test]
+    fn view_and_location_need_a_rating_and_one_or_two_factors() {
+        assert_eq!(severity(view, json!("N;Res;")), None);
+        assert_eq!(severity(view, json!("B;Wtr;Prk")), None);
+        assert_eq!(severity(location, json!("A;BsyRd;")), None);
+
+        assert_eq!(severity(view, json!("Res;")), Some(Severity::Error));
+        assert_eq!(severity(view, json!("N;")), Some(Severity::Error));
+        assert_eq!(severity(view, json!("N;Wtr;Prk;Mtn")), Some(Severity::Error));
+        assert_eq!(severity(location, json!("N;Beach;")), Some(Severity::Warning));
+    }
+
+    #[test]
+    fn sale_types_use_the_uad_abbreviations() {
+        for value in SALE_TYPES {
+            assert_eq!(severity(sale_type, json!(value)), None, "{}", value);
+        }
+        assert_eq!(severity(sale_type, json!("ShortSale")), Some(Severity::Error));
+        assert_eq!(severity(sale_type, json!("reo")), Some(Severity::Error));
+    }
+
+    #[test]
+    fn date_of_sale_accepts_each_uad_form() {
+        for value in ["s03/23;c02/23", "s12/22;Unk", "c02/23", "Active", "e03/23", "w03/23"] {
+            assert_eq!(severity(date_of_sale, json!(value)), None, "{}", value);
+        }
+
+        for value in ["Expired 03/23", "03/2023", "s13/23;c02/23", "s03/23", "s03/23;c02/2023", "active"] {
+            assert_eq!(severity(date_of_sale, json!(value)), Some(Severity::Error), "{}", value);
+        }
+    }
+
+    #[test]
+    fn dates_are_written_month_first_or_iso() {
+        assert_eq!(severity(date, json!("02/15/2023")), None);
+        assert_eq!(severity(date, json!("2023-02-15")), None);
+        assert_eq!(severity(date, json!("15/02/2023")), Some(Severity::Error));
+
+        assert_eq!(severity(effective_date, json!("06/01/2023")), None);
+        let tomorrow = Utc::now().date_naive().succ_opt().unwrap();
+        assert_eq!(
+            severity(effective_date, json!(tomorrow.format("%m/%d/%Y").to_string())),
+            Some(Severity::Error)
+        );
+    }
+
+    #[test]
+    fn property_record_differences_are_warnings() {
+        let content = json!({
+            "improvements": {"gross_living_area": 1850, "bedrooms": 4, "bathrooms": "2.1", "year_built": 1995}
+        });
+        let characteristics = PropertyCharacteristics {
+            property_type: PropertyType::SingleFamily,
+            year_built: Some(1995),
+            square_feet: Some(1851.0),
+            bedrooms: Some(3),
+            bathrooms: Some(2.0),
+            lot_size: None,
+            lot_size_in_sqft: None,
+            parking: None,
+            stories: None,
+            has_basement: None,
+            has_pool: None,
+            features: None,
+        };
+
+        let findings = check_property_record(&content, &characteristics);
+        let paths: Vec<&str> = findings.iter().map(|(path, _)| path.as_str()).collect();
+
+        assert_eq!(paths, ["improvements.bedrooms", "improvements.bathrooms"]);
+        assert!(findings.iter().all(|(_, violation)| violation.severity == Severity::Warning));
+    }
+}
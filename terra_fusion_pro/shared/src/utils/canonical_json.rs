@@ -0,0 +1,102 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::{AppError, AppResult};
+
+/// Serialize a value as canonical JSON: object keys sorted by their UTF-8 bytes, no whitespace,
+/// and strings and numbers written as serde_json writes them. Equal values always produce the
+/// same bytes, so the output can be hashed and signed.
+pub fn to_canonical_json<T: Serialize>(value: &T) -> AppResult<Vec<u8>> {
+    let value = serde_json::to_value(value).map_err(serialize_error)?;
+    let mut out = Vec::new();
+    write_value(&value, &mut out)?;
+    Ok(out)
+}
+
+fn write_value(value: &Value, out: &mut Vec<u8>) -> AppResult<()> {
+    match value {
+        Value::Array(items) => {
+            out.push(b'[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(b',');
+                }
+                write_value(item, out)?;
+            }
+            out.push(b']');
+        }
+        Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+
+            out.push(b'{');
+            for (index, (key, item)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, key).map_err(serialize_error)?;
+                out.push(b':');
+                write_value(item, out)?;
+            }
+            out.push(b'}');
+        }
+        scalar => serde_json::to_writer(&mut *out, scalar).map_err(serialize_error)?,
+    }
+    Ok(())
+}
+
+fn serialize_error(err: serde_json::Error) -> AppError {
+    AppError::General(format!("Failed to serialize canonical JSON: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Snapshot {
+        version: i32,
+        amount: f64,
+        appraiser: &'static str,
+    }
+
+    fn canonical<T: Serialize>(value: &T) -> String {
+        String::from_utf8(to_canonical_json(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn sorts_keys_at_every_level_without_whitespace() {
+        let value = json!({"b": 1, "a": {"z": [3, {"y": true, "x": null}], "m": "text"}});
+
+        assert_eq!(canonical(&value), r#"{"a":{"m":"text","z":[3,{"x":null,"y":true}]},"b":1}"#);
+    }
+
+    #[test]
+    fn sorts_keys_by_bytes() {
+        let value = json!({"é": 1, "Z": 2, "a": 3, "10": 4, "9": 5});
+
+        assert_eq!(canonical(&value), r#"{"10":4,"9":5,"Z":2,"a":3,"é":1}"#);
+    }
+
+    #[test]
+    fn keeps_array_order() {
+        assert_eq!(canonical(&json!([3, 1, 2])), "[3,1,2]");
+    }
+
+    #[test]
+    fn writes_structs_by_key_rather_than_field_order() {
+        let snapshot = Snapshot { version: 2, amount: 415000.5, appraiser: "J. \"Doe\"" };
+
+        assert_eq!(canonical(&snapshot), r#"{"amount":415000.5,"appraiser":"J. \"Doe\"","version":2}"#);
+    }
+
+    #[test]
+    fn equal_values_give_equal_bytes() {
+        let first: Value = serde_json::from_str(r#"{"a": 1, "b": [1, 2], "c": {"d": "e"}}"#).unwrap();
+        let second: Value = serde_json::from_str(r#"{"c": {"d": "e"}, "b": [1, 2], "a": 1}"#).unwrap();
+
+        assert_eq!(to_canonical_json(&first).unwrap(), to_canonical_json(&second).unwrap());
+    }
+}
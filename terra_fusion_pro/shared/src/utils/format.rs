@@ -1,8 +1,20 @@
+use chrono::NaiveDate;
+
 /// Format a currency value
 pub fn format_currency(amount: f64) -> String {
     format!("${:.2}", amount)
 }
 
+/// Format a date as written in narratives, e.g. "June 1, 2023"
+pub fn format_date_long(date: NaiveDate) -> String {
+    date.format("%B %-d, %Y").to_string()
+}
+
+/// Format a date as entered on appraisal forms, e.g. "06/01/2023"
+pub fn format_date_short(date: NaiveDate) -> String {
+    date.format("%m/%d/%Y").to_string()
+}
+
 /// Format an address into a single line
 pub fn format_address_single_line(
     street1: &str,
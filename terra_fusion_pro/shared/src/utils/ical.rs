@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+
+/// A single VEVENT entry in an iCalendar feed
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    /// Globally unique event identifier
+    pub uid: String,
+    /// Event start time
+    pub start: DateTime<Utc>,
+    /// Event end time
+    pub end: DateTime<Utc>,
+    /// Short event title
+    pub summary: String,
+    /// Longer event description (optional)
+    pub description: Option<String>,
+    /// Event location (optional)
+    pub location: Option<String>,
+    /// Whether the event has been cancelled
+    pub cancelled: bool,
+    /// Revision sequence, incremented each time the event is rescheduled
+    pub sequence: i32,
+    /// When the event was last modified
+    pub last_modified: DateTime<Utc>,
+}
+
+/// Render a list of events as an iCalendar (RFC 5545) document
+pub fn render_calendar(calendar_name: &str, events: &[CalendarEvent]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//TerraFusionPro//Inspection Schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", format_timestamp(&event.last_modified)));
+        lines.push(format!("LAST-MODIFIED:{}", format_timestamp(&event.last_modified)));
+        lines.push(format!("DTSTART:{}", format_timestamp(&event.start)));
+        lines.push(format!("DTEND:{}", format_timestamp(&event.end)));
+        lines.push(format!("SEQUENCE:{}", event.sequence));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+
+        let status = if event.cancelled { "CANCELLED" } else { "CONFIRMED" };
+        lines.push(format!("STATUS:{}", status));
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    lines
+        .iter()
+        .map(|line| fold_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+/// Format a timestamp in iCalendar UTC form (e.g. 20230501T143000Z)
+fn format_timestamp(datetime: &DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape commas, semicolons, backslashes and newlines in a TEXT value
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line so no physical line exceeds 75 octets
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 74 * 3);
+    let mut width = 0;
+
+    for ch in line.chars() {
+        let len = ch.len_utf8();
+        if width + len > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(ch);
+        width += len;
+    }
+
+    folded
+}
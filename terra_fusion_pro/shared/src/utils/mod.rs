@@ -1,3 +1,6 @@
 pub mod logging;
 pub mod validation;
-pub mod config_loader;
\ No newline at end of file
+pub mod format;
+pub mod config_loader;
+pub mod ical;
+pub mod canonical_json;
\ No newline at end of file
@@ -0,0 +1,123 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use reqwest::Url;
+
+use crate::error::{AppError, AppResult};
+
+/// Hosts that may be sent plain HTTP, and may resolve to loopback, in development
+const LOCAL_HOSTS: &[&str] = &["localhost", "127.0.0.1", "[::1]"];
+
+/// Parse a webhook URL and check it is an HTTPS URL that does not name an internal address.
+/// Plain HTTP to the local machine is allowed only in development.
+pub fn parse_url(url: &str, development: bool) -> AppResult<Url> {
+    let parsed = Url::parse(url.trim())
+        .map_err(|e| AppError::Validation(format!("Invalid webhook URL: {}", e)))?;
+
+    if development && is_local_host(&parsed) {
+        return match parsed.scheme() {
+            "https" | "http" => Ok(parsed),
+            _ => Err(AppError::Validation("Webhook URLs must use https".to_string())),
+        };
+    }
+
+    if parsed.scheme() != "https" {
+        return Err(AppError::Validation("Webhook URLs must use https".to_string()));
+    }
+
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| AppError::Validation("Webhook URLs must name a host".to_string()))?;
+    if let Some(ip) = literal_ip(host) {
+        ensure_public(host, ip)?;
+    }
+
+    Ok(parsed)
+}
+
+/// Resolve a webhook URL's host and check every address it resolves to is public. Run at
+/// registration and again before each send, since DNS can change in between. For a host name,
+/// returns the checked address the send must be pinned to, so a second lookup cannot swap it.
+pub async fn check_destination(url: &Url, development: bool) -> AppResult<Option<SocketAddr>> {
+    if development && is_local_host(url) {
+        return Ok(None);
+    }
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| AppError::Validation("Webhook URLs must name a host".to_string()))?;
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    if let Some(ip) = literal_ip(host) {
+        return ensure_public(host, ip).map(|_| None);
+    }
+
+    let addresses: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| AppError::Validation(format!("Webhook host {} does not resolve: {}", host, e)))?
+        .collect();
+
+    for address in &addresses {
+        ensure_public(host, address.ip())?;
+    }
+
+    addresses
+        .first()
+        .copied()
+        .map(Some)
+        .ok_or_else(|| AppError::Validation(format!("Webhook host {} does not resolve", host)))
+}
+
+/// Whether an address may receive webhooks: not private, loopback, link-local, unique local,
+/// unspecified, broadcast or multicast
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_v4(mapped),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+/// Whether an IPv4 address is outside 0/8, 10/8, 127/8, 169.254/16, 172.16/12, 192.168/16,
+/// broadcast and multicast
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.octets()[0] == 0)
+}
+
+/// Whether an IPv6 address is outside loopback, unspecified, multicast, fc00::/7 and fe80::/10
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    let unique_local = first & 0xfe00 == 0xfc00;
+    let link_local = first & 0xffc0 == 0xfe80;
+
+    !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() || unique_local || link_local)
+}
+
+/// Refuse an address a webhook must not be sent to
+fn ensure_public(host: &str, ip: IpAddr) -> AppResult<()> {
+    if is_public(ip) {
+        Ok(())
+    } else {
+        Err(AppError::Validation(format!(
+            "Webhook host {} points to the internal address {}",
+            host, ip
+        )))
+    }
+}
+
+/// The address of a host written as an IP literal; IPv6 literals keep their brackets in URLs
+fn literal_ip(host: &str) -> Option<IpAddr> {
+    host.trim_start_matches('[').trim_end_matches(']').parse().ok()
+}
+
+/// Whether a URL names the local machine
+fn is_local_host(url: &Url) -> bool {
+    url.host_str().is_some_and(|host| LOCAL_HOSTS.contains(&host))
+}
@@ -0,0 +1,286 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration as StdDuration, Instant};
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    db::Database,
+    error::{AppError, AppResult},
+    models::webhook::{
+        WebhookAttempt, WebhookDelivery, WebhookDeliveryStatus, WebhookEndpoint, WebhookEventType,
+        MAX_WEBHOOK_ATTEMPTS,
+    },
+    repository::webhook_repository::WebhookRepository,
+};
+use super::destination::{check_destination, parse_url};
+use super::signing::{signature_header, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+
+/// Number of due deliveries sent per pass of the background loop
+const DELIVERY_BATCH_SIZE: i64 = 50;
+
+/// How long an endpoint has to respond before the attempt counts as failed
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Delay before the first retry
+const BASE_RETRY_DELAY_SECS: i64 = 30;
+
+/// Longest wait between retries
+const MAX_RETRY_DELAY_SECS: i64 = 12 * 60 * 60;
+
+/// Longest response body excerpt kept in the attempt log
+const MAX_ERROR_EXCERPT: usize = 200;
+
+/// Most bytes read from a failed response's body; the rest is never downloaded
+const MAX_ERROR_BODY_BYTES: usize = 4096;
+
+/// Fans events out to subscribed client endpoints and delivers them with retries
+pub struct WebhookDispatcher {
+    webhooks: WebhookRepository,
+    client: reqwest::Client,
+    development: bool,
+}
+
+impl WebhookDispatcher {
+    /// Create a new webhook dispatcher. In development, endpoints on the local machine are
+    /// allowed and may use plain HTTP.
+    pub fn new(db: Arc<Database>, development: bool) -> AppResult<Self> {
+        Ok(Self {
+            webhooks: WebhookRepository::new(db),
+            client: http_client(None)?,
+            development,
+        })
+    }
+
+    /// Check a URL can be registered as an endpoint, returning it trimmed. It must be HTTPS
+    /// and resolve only to public addresses.
+    pub async fn check_url(&self, url: &str) -> AppResult<String> {
+        self.resolve(url).await?;
+        Ok(url.trim().to_string())
+    }
+
+    /// Queue an event for every active endpoint of the client subscribed to its type.
+    /// Deliveries are attempted by the background loop, never inline.
+    pub async fn publish(
+        &self,
+        client_id: Uuid,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) -> AppResult<usize> {
+        let endpoints = self.webhooks.subscribed_endpoints(client_id, event_type).await?;
+        if endpoints.is_empty() {
+            return Ok(0);
+        }
+
+        let now = Utc::now();
+        let event_id = Uuid::new_v4();
+        let payload = json!({
+            "id": event_id,
+            "type": event_type,
+            "created_at": now,
+            "data": data,
+        });
+
+        for endpoint in &endpoints {
+            let delivery = WebhookDelivery {
+                id: Uuid::new_v4(),
+                endpoint_id: endpoint.id,
+                event_id,
+                event_type,
+                payload: payload.clone(),
+                status: WebhookDeliveryStatus::Pending,
+                attempts: 0,
+                next_attempt_at: now,
+                attempt_log: Vec::new(),
+                redelivery_of: None,
+                created_at: now,
+                delivered_at: None,
+            };
+            self.webhooks.create_delivery(&delivery).await?;
+        }
+
+        Ok(endpoints.len())
+    }
+
+    /// Queue a fresh copy of a past delivery, keeping its event ID so receivers can deduplicate
+    pub async fn redeliver(&self, delivery_id: Uuid) -> AppResult<WebhookDelivery> {
+        let original = self.webhooks.get_delivery(delivery_id).await?;
+        let now = Utc::now();
+
+        let delivery = WebhookDelivery {
+            id: Uuid::new_v4(),
+            endpoint_id: original.endpoint_id,
+            event_id: original.event_id,
+            event_type: original.event_type,
+            payload: original.payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            attempt_log: Vec::new(),
+            redelivery_of: Some(original.id),
+            created_at: now,
+            delivered_at: None,
+        };
+
+        self.webhooks.create_delivery(&delivery).await
+    }
+
+    /// Send pending deliveries that are due, returning how many were attempted
+    pub async fn process_due(&self, now: DateTime<Utc>) -> AppResult<usize> {
+        let due = self.webhooks.claim_due(now, DELIVERY_BATCH_SIZE).await?;
+
+        for delivery in due.iter().cloned() {
+            let endpoint = match self.webhooks.get_endpoint(delivery.endpoint_id).await {
+                Ok(endpoint) => endpoint,
+                Err(AppError::NotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+
+            if !endpoint.is_active {
+                self.cancel(delivery).await?;
+                continue;
+            }
+            self.attempt(&endpoint, delivery).await?;
+        }
+
+        Ok(due.len())
+    }
+
+    /// Deliver due webhooks forever, pausing between passes
+    pub async fn run(self: Arc<Self>, interval: StdDuration) {
+        loop {
+            if let Err(err) = self.process_due(Utc::now()).await {
+                log::error!("Error delivering webhooks: {:?}", err);
+            }
+
+            tokio::time::sleep(interval).await;
+        }
+    }
+
+    /// Parse an endpoint URL and check where it currently points, returning the checked
+    /// address when the host is a name
+    async fn resolve(&self, url: &str) -> AppResult<(reqwest::Url, Option<SocketAddr>)> {
+        let parsed = parse_url(url, self.development)?;
+        let pinned = check_destination(&parsed, self.development).await?;
+
+        Ok((parsed, pinned))
+    }
+
+    /// The client for a send, pinned to the address that was checked so the host cannot be
+    /// re-resolved to an internal one between the check and the connection
+    fn client_for(&self, url: &reqwest::Url, pinned: Option<SocketAddr>) -> AppResult<reqwest::Client> {
+        match (url.host_str(), pinned) {
+            (Some(host), Some(address)) => http_client(Some((host, address))),
+            _ => Ok(self.client.clone()),
+        }
+    }
+
+    /// Drop a claimed delivery whose endpoint was deactivated after it was queued
+    async fn cancel(&self, mut delivery: WebhookDelivery) -> AppResult<()> {
+        log::info!("Cancelling webhook delivery {} to inactive endpoint {}", delivery.id, delivery.endpoint_id);
+        delivery.status = WebhookDeliveryStatus::Cancelled;
+
+        self.webhooks.save_attempt(&delivery).await
+    }
+
+    /// POST a delivery to its endpoint once, recording the outcome
+    async fn attempt(&self, endpoint: &WebhookEndpoint, mut delivery: WebhookDelivery) -> AppResult<()> {
+        let body = delivery.payload.to_string();
+        let attempted_at = Utc::now();
+        let timestamp = attempted_at.timestamp();
+        let started = Instant::now();
+
+        // The endpoint's host is resolved again, since it may point somewhere else by now
+        let result = match self.resolve(&endpoint.url).await {
+            Ok((url, pinned)) => self
+                .client_for(&url, pinned)?
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(TIMESTAMP_HEADER, timestamp.to_string())
+                .header(SIGNATURE_HEADER, signature_header(&endpoint.secret, timestamp, &body))
+                .header(EVENT_HEADER, event_type_name(delivery.event_type))
+                .header(DELIVERY_HEADER, delivery.id.to_string())
+                .body(body)
+                .send()
+                .await
+                .map_err(|err| err.to_string()),
+            Err(AppError::Validation(reason)) => Err(format!("Refused to send: {}", reason)),
+            Err(err) => return Err(err),
+        };
+
+        let (response_status, error) = match result {
+            Ok(response) if response.status().is_success() => (Some(response.status().as_u16()), None),
+            Ok(response) => {
+                let status = response.status();
+                let excerpt = error_excerpt(response).await;
+                (Some(status.as_u16()), Some(format!("Endpoint responded with {}: {}", status, excerpt)))
+            }
+            Err(error) => (None, Some(error)),
+        };
+
+        delivery.attempts += 1;
+        delivery.attempt_log.push(WebhookAttempt {
+            attempted_at,
+            response_status,
+            error: error.clone(),
+            duration_ms: started.elapsed().as_millis() as i64,
+        });
+
+        if error.is_none() {
+            delivery.status = WebhookDeliveryStatus::Succeeded;
+            delivery.delivered_at = Some(Utc::now());
+        } else if delivery.attempts >= MAX_WEBHOOK_ATTEMPTS {
+            log::warn!("Dead-lettering webhook delivery {} after {} attempts", delivery.id, delivery.attempts);
+            delivery.status = WebhookDeliveryStatus::DeadLetter;
+        } else {
+            delivery.next_attempt_at = attempted_at + retry_delay(delivery.attempts);
+        }
+
+        self.webhooks.save_attempt(&delivery).await
+    }
+}
+
+/// Build the HTTP client used for sends, optionally pinning a host to an address.
+/// Redirects are not followed, since they could lead to an internal address.
+fn http_client(pinned: Option<(&str, SocketAddr)>) -> AppResult<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(StdDuration::from_secs(REQUEST_TIMEOUT_SECS))
+        .redirect(reqwest::redirect::Policy::none());
+    if let Some((host, address)) = pinned {
+        builder = builder.resolve(host, address);
+    }
+
+    builder
+        .build()
+        .map_err(|e| AppError::Configuration(format!("Failed to build webhook HTTP client: {}", e)))
+}
+
+/// The start of a failed response's body, reading at most `MAX_ERROR_BODY_BYTES` of it
+async fn error_excerpt(mut response: reqwest::Response) -> String {
+    let mut body = Vec::new();
+    while body.len() < MAX_ERROR_BODY_BYTES {
+        match response.chunk().await {
+            Ok(Some(chunk)) => body.extend_from_slice(&chunk),
+            _ => break,
+        }
+    }
+    body.truncate(MAX_ERROR_BODY_BYTES);
+
+    String::from_utf8_lossy(&body).chars().take(MAX_ERROR_EXCERPT).collect()
+}
+
+/// Exponential backoff after a failed attempt: 30s, 1m, 2m, ... capped at 12 hours
+pub fn retry_delay(attempts: i32) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    Duration::seconds((BASE_RETRY_DELAY_SECS << exponent).min(MAX_RETRY_DELAY_SECS))
+}
+
+/// Wire name of an event type, e.g. `order.assigned`
+pub fn event_type_name(event_type: WebhookEventType) -> String {
+    serde_json::to_value(event_type)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
@@ -0,0 +1,5 @@
+pub mod destination;
+pub mod dispatcher;
+pub mod signing;
+
+pub use dispatcher::WebhookDispatcher;
@@ -0,0 +1,86 @@
+use hmac::{Hmac, Mac};
+use rand::{distributions::Alphanumeric, Rng};
+use sha2::Sha256;
+
+/// Header carrying the Unix timestamp the signature was computed at
+pub const TIMESTAMP_HEADER: &str = "X-TerraFusion-Timestamp";
+
+/// Header carrying the versioned signature, e.g. `v1=<hex>`
+pub const SIGNATURE_HEADER: &str = "X-TerraFusion-Signature";
+
+/// Header carrying the event type
+pub const EVENT_HEADER: &str = "X-TerraFusion-Event";
+
+/// Header carrying the delivery ID
+pub const DELIVERY_HEADER: &str = "X-TerraFusion-Delivery";
+
+/// Prefix of generated signing secrets
+const SECRET_PREFIX: &str = "whsec_";
+
+/// Number of random characters in a generated signing secret
+const SECRET_LENGTH: usize = 40;
+
+/// Generate a new endpoint signing secret
+pub fn generate_secret() -> String {
+    let random: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(SECRET_LENGTH)
+        .map(char::from)
+        .collect();
+
+    format!("{}{}", SECRET_PREFIX, random)
+}
+
+/// Compute the hex HMAC-SHA256 of `"{timestamp}.{body}"` with the endpoint secret
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Value of the signature header for a body
+pub fn signature_header(secret: &str, timestamp: i64, body: &str) -> String {
+    format!("v1={}", sign(secret, timestamp, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"{"event":"report.finalized"}"#;
+
+    #[test]
+    fn signs_the_timestamp_and_body_with_hmac_sha256() {
+        assert_eq!(
+            sign("whsec_test", 1700000000, BODY),
+            "1a778d1d74e9aaac1290ffceacb1db6d768ad83ab1b81922bd1af58aff0c400c"
+        );
+        assert_eq!(
+            signature_header("whsec_test", 1700000000, BODY),
+            "v1=1a778d1d74e9aaac1290ffceacb1db6d768ad83ab1b81922bd1af58aff0c400c"
+        );
+    }
+
+    #[test]
+    fn signature_changes_with_the_secret_timestamp_or_body() {
+        let signature = sign("whsec_test", 1700000000, BODY);
+
+        assert_ne!(sign("whsec_other", 1700000000, BODY), signature);
+        assert_ne!(sign("whsec_test", 1700000001, BODY), signature);
+        assert_ne!(sign("whsec_test", 1700000000, r#"{"event":"report.amended"}"#), signature);
+    }
+
+    #[test]
+    fn generates_distinct_prefixed_secrets() {
+        let secret = generate_secret();
+        let random = secret.strip_prefix(SECRET_PREFIX).unwrap();
+
+        assert_eq!(random.len(), SECRET_LENGTH);
+        assert!(random.chars().all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(generate_secret(), secret);
+    }
+}